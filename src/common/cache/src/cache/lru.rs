@@ -330,18 +330,7 @@ impl<K: Eq + Hash, V, S: BuildHasher, M: CountableMeter<K, V>> Cache<K, V, S, M>
     /// assert_eq!(cache.get_mut(&2), Some(&mut "b"));
     /// ```
     fn put(&mut self, k: K, v: V) -> Option<V> {
-        let new_size = self.meter.measure(&k, &v);
-        self.current_measure = self.meter.add(self.current_measure, new_size);
-        if let Some(old) = self.map.get(&k) {
-            self.current_measure = self
-                .meter
-                .sub(self.current_measure, self.meter.measure(&k, old));
-        }
-        let old_val = self.map.insert(k, v);
-        while self.size() > self.capacity() {
-            self.pop_by_policy();
-        }
-        old_val
+        self.put_and_evict(k, v).0
     }
 
     /// Removes the given key from the cache and returns its corresponding value.
@@ -479,6 +468,36 @@ impl<K: Eq + Hash, V, S: BuildHasher, M: CountableMeter<K, V>> Cache<K, V, S, M>
 }
 
 impl<K: Eq + Hash, V, S: BuildHasher, M: CountableMeter<K, V>> LruCache<K, V, S, M> {
+    /// Inserts a key-value pair into the cache like [`Cache::put`], returning the old value of
+    /// the key and the least recently used pairs evicted to make room for the new one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use common_cache::{Cache, LruCache};
+    ///
+    /// let mut cache = LruCache::new(2);
+    ///
+    /// cache.put(1, "a");
+    /// cache.put(2, "b");
+    /// assert_eq!(cache.put_and_evict(3, "c"), (None, vec![(1, "a")]));
+    /// ```
+    pub fn put_and_evict(&mut self, k: K, v: V) -> (Option<V>, Vec<(K, V)>) {
+        let new_size = self.meter.measure(&k, &v);
+        self.current_measure = self.meter.add(self.current_measure, new_size);
+        if let Some(old) = self.map.get(&k) {
+            self.current_measure = self
+                .meter
+                .sub(self.current_measure, self.meter.measure(&k, old));
+        }
+        let old_val = self.map.insert(k, v);
+        let mut evicted = Vec::new();
+        while self.size() > self.capacity() {
+            evicted.extend(self.pop_by_policy());
+        }
+        (old_val, evicted)
+    }
+
     /// Returns an iterator over the cache's key-value pairs in least- to most-recently-used order.
     ///
     /// Accessing the cache through the iterator does _not_ affect the cache's LRU state.
@@ -0,0 +1,233 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+use ritelinked::DefaultHashBuilder;
+
+use crate::cache::lru::LruCache;
+use crate::cache::s3fifo::S3FifoCache;
+use crate::cache::Cache;
+use crate::meter::count_meter::Count;
+use crate::meter::count_meter::CountableMeter;
+
+/// How a [`PolicyCache`] picks the key-value pairs to evict.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Evicts the least recently used pair, see [`LruCache`].
+    #[default]
+    Lru,
+    /// Admits the new pairs through a small queue first, so scans don't flush the pairs
+    /// accessed repeatedly, see [`S3FifoCache`].
+    S3Fifo,
+}
+
+impl fmt::Display for EvictionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvictionPolicy::Lru => write!(f, "lru"),
+            EvictionPolicy::S3Fifo => write!(f, "s3fifo"),
+        }
+    }
+}
+
+/// The accesses and the evictions of a [`PolicyCache`] since it was created.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStatistics {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+#[derive(Clone)]
+enum PolicyCacheInner<K: Eq + Hash, V, S: BuildHasher, M: CountableMeter<K, V>> {
+    Lru(LruCache<K, V, S, M>),
+    S3Fifo(S3FifoCache<K, V, S, M>),
+}
+
+macro_rules! dispatch {
+    ($inner:expr, $cache:ident => $body:expr) => {
+        match $inner {
+            PolicyCacheInner::Lru($cache) => $body,
+            PolicyCacheInner::S3Fifo($cache) => $body,
+        }
+    };
+}
+
+/// A cache of the eviction policy chosen at runtime, counting its hits, misses and evictions.
+#[derive(Clone)]
+pub struct PolicyCache<
+    K: Eq + Hash,
+    V,
+    S: BuildHasher = DefaultHashBuilder,
+    M: CountableMeter<K, V> = Count,
+> {
+    inner: PolicyCacheInner<K, V, S, M>,
+    statistics: CacheStatistics,
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> PolicyCache<K, V, S, M> {
+    /// Creates an empty cache of the `policy` that can hold at most `capacity` as measured by
+    /// `meter` with the given hash builder.
+    pub fn with_policy(policy: EvictionPolicy, capacity: u64, meter: M, hash_builder: S) -> Self {
+        let inner =
+            match policy {
+                EvictionPolicy::Lru => PolicyCacheInner::Lru(LruCache::with_meter_and_hasher(
+                    capacity,
+                    meter,
+                    hash_builder,
+                )),
+                EvictionPolicy::S3Fifo => PolicyCacheInner::S3Fifo(
+                    S3FifoCache::with_meter_and_hasher(capacity, meter, hash_builder),
+                ),
+            };
+        PolicyCache {
+            inner,
+            statistics: CacheStatistics::default(),
+        }
+    }
+
+    pub fn policy(&self) -> EvictionPolicy {
+        match self.inner {
+            PolicyCacheInner::Lru(_) => EvictionPolicy::Lru,
+            PolicyCacheInner::S3Fifo(_) => EvictionPolicy::S3Fifo,
+        }
+    }
+
+    pub fn statistics(&self) -> CacheStatistics {
+        self.statistics
+    }
+
+    /// Inserts a key-value pair into the cache like [`Cache::put`], returning the old value of
+    /// the key and the pairs evicted by the policy to make room for the new one.
+    pub fn put_and_evict(&mut self, k: K, v: V) -> (Option<V>, Vec<(K, V)>) {
+        let (old_val, evicted) = dispatch!(&mut self.inner, cache => cache.put_and_evict(k, v));
+        self.statistics.evictions += evicted.len() as u64;
+        (old_val, evicted)
+    }
+
+    fn count_access(&mut self, hit: bool) {
+        if hit {
+            self.statistics.hits += 1;
+        } else {
+            self.statistics.misses += 1;
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> Cache<K, V, S, M>
+    for PolicyCache<K, V, S, M>
+{
+    /// Creates an empty LRU cache, see [`PolicyCache::with_policy`] for the other policies.
+    fn with_meter_and_hasher(capacity: u64, meter: M, hash_builder: S) -> Self {
+        Self::with_policy(EvictionPolicy::Lru, capacity, meter, hash_builder)
+    }
+
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hit = dispatch!(&self.inner, cache => cache.contains(k));
+        self.count_access(hit);
+        dispatch!(&mut self.inner, cache => cache.get(k))
+    }
+
+    fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hit = dispatch!(&self.inner, cache => cache.contains(k));
+        self.count_access(hit);
+        dispatch!(&mut self.inner, cache => cache.get_mut(k))
+    }
+
+    fn peek<'a, Q>(&'a self, k: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(&self.inner, cache => cache.peek(k))
+    }
+
+    fn peek_mut<'a, Q>(&'a mut self, k: &Q) -> Option<&'a mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(&mut self.inner, cache => cache.peek_mut(k))
+    }
+
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        dispatch!(&self.inner, cache => cache.peek_by_policy())
+    }
+
+    fn put(&mut self, k: K, v: V) -> Option<V> {
+        self.put_and_evict(k, v).0
+    }
+
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(&mut self.inner, cache => cache.pop(k))
+    }
+
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        let evicted = dispatch!(&mut self.inner, cache => cache.pop_by_policy());
+        if evicted.is_some() {
+            self.statistics.evictions += 1;
+        }
+        evicted
+    }
+
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(&self.inner, cache => cache.contains(k))
+    }
+
+    fn len(&self) -> usize {
+        dispatch!(&self.inner, cache => cache.len())
+    }
+
+    fn is_empty(&self) -> bool {
+        dispatch!(&self.inner, cache => cache.is_empty())
+    }
+
+    fn capacity(&self) -> u64 {
+        dispatch!(&self.inner, cache => cache.capacity())
+    }
+
+    fn set_capacity(&mut self, capacity: u64) {
+        let len = self.len();
+        dispatch!(&mut self.inner, cache => cache.set_capacity(capacity));
+        self.statistics.evictions += (len - self.len()) as u64;
+    }
+
+    fn size(&self) -> u64 {
+        dispatch!(&self.inner, cache => cache.size())
+    }
+
+    fn clear(&mut self) {
+        dispatch!(&mut self.inner, cache => cache.clear())
+    }
+}
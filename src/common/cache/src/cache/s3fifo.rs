@@ -0,0 +1,363 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A cache evicting by the S3-FIFO algorithm, which resists scans.
+//!
+//! New keys are admitted to a small FIFO queue, taking 10% of the capacity. The keys accessed
+//! again before they leave it are moved to the main FIFO queue, the others are evicted and
+//! remembered in a ghost queue of hashes. A key in the ghost queue is put into the main queue
+//! directly the next time. The main queue gives each key accessed since it was last checked
+//! another round, up to 3, instead of evicting it.
+//!
+//! So a scan touching every key once only churns the small queue, and the keys accessed
+//! repeatedly stay in the main queue.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use common_cache::{Cache, S3FifoCache};
+//!
+//! let mut cache = S3FifoCache::new(10);
+//!
+//! cache.put(1, 10);
+//! assert_eq!(cache.get(&1), Some(&10));
+//! ```
+
+use std::borrow::Borrow;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use ritelinked::DefaultHashBuilder;
+use ritelinked::LinkedHashMap;
+
+use crate::cache::Cache;
+use crate::meter::count_meter::Count;
+use crate::meter::count_meter::CountableMeter;
+
+/// The most rounds a key of the main queue is given.
+const MAX_FREQUENCY: u8 = 3;
+
+/// The share of the capacity taken by the small queue, in percent.
+const SMALL_QUEUE_PERCENT: u64 = 10;
+
+#[derive(Clone)]
+struct Entry<V> {
+    value: V,
+    frequency: u8,
+}
+
+/// An S3-FIFO cache.
+#[derive(Clone)]
+pub struct S3FifoCache<
+    K: Eq + Hash,
+    V,
+    S: BuildHasher = DefaultHashBuilder,
+    M: CountableMeter<K, V> = Count,
+> {
+    small: LinkedHashMap<K, Entry<V>, S>,
+    main: LinkedHashMap<K, Entry<V>, S>,
+    ghost: LinkedHashMap<u64, (), S>,
+    hash_builder: S,
+    small_measure: M::Measure,
+    main_measure: M::Measure,
+    max_capacity: u64,
+    meter: M,
+}
+
+impl<K: Eq + Hash, V> S3FifoCache<K, V> {
+    /// Creates an empty cache that can hold at most `capacity` items.
+    pub fn new(capacity: u64) -> Self {
+        Self::with_meter_and_hasher(capacity, Count, DefaultHashBuilder::default())
+    }
+}
+
+impl<K: Eq + Hash, V, M: CountableMeter<K, V>> S3FifoCache<K, V, DefaultHashBuilder, M> {
+    /// Creates an empty cache that can hold at most `capacity` as measured by `meter`.
+    pub fn with_meter(capacity: u64, meter: M) -> Self {
+        Self::with_meter_and_hasher(capacity, meter, DefaultHashBuilder::default())
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> S3FifoCache<K, V, S, M> {
+    fn small_size(&self) -> u64 {
+        self.meter
+            .size(self.small_measure)
+            .unwrap_or_else(|| self.small.len() as u64)
+    }
+
+    fn main_size(&self) -> u64 {
+        self.meter
+            .size(self.main_measure)
+            .unwrap_or_else(|| self.main.len() as u64)
+    }
+
+    fn ghost_key<Q: Hash + ?Sized>(&self, k: &Q) -> u64 {
+        let mut hasher = self.hash_builder.build_hasher();
+        k.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Whether the next key to evict is taken from the small queue.
+    fn evict_from_small(&self) -> bool {
+        !self.small.is_empty()
+            && (self.main.is_empty()
+                || self.small_size() > self.max_capacity * SMALL_QUEUE_PERCENT / 100)
+    }
+
+    fn remember_evicted(&mut self, ghost_key: u64) {
+        self.ghost.insert(ghost_key, ());
+        let ghost_capacity = self.len().max(1);
+        while self.ghost.len() > ghost_capacity {
+            self.ghost.pop_front();
+        }
+    }
+
+    fn evict(&mut self) -> Option<(K, V)> {
+        loop {
+            if self.evict_from_small() {
+                let (k, entry) = self.small.pop_front()?;
+                let size = self.meter.measure(&k, &entry.value);
+                self.small_measure = self.meter.sub(self.small_measure, size);
+                if entry.frequency > 0 {
+                    self.main_measure = self.meter.add(self.main_measure, size);
+                    self.main.insert(k, Entry {
+                        value: entry.value,
+                        frequency: 0,
+                    });
+                } else {
+                    let ghost_key = self.ghost_key(&k);
+                    self.remember_evicted(ghost_key);
+                    return Some((k, entry.value));
+                }
+            } else {
+                let (k, mut entry) = self.main.pop_front()?;
+                if entry.frequency > 0 {
+                    entry.frequency -= 1;
+                    self.main.insert(k, entry);
+                } else {
+                    let size = self.meter.measure(&k, &entry.value);
+                    self.main_measure = self.meter.sub(self.main_measure, size);
+                    return Some((k, entry.value));
+                }
+            }
+        }
+    }
+
+    /// Inserts a key-value pair into the cache like [`Cache::put`], returning the old value of
+    /// the key and the pairs evicted to make room for the new one.
+    pub fn put_and_evict(&mut self, k: K, v: V) -> (Option<V>, Vec<(K, V)>) {
+        let new_size = self.meter.measure(&k, &v);
+        let old_val = if let Some(entry) = self.small.get_mut(&k) {
+            let old_val = std::mem::replace(&mut entry.value, v);
+            let old_size = self.meter.measure(&k, &old_val);
+            self.small_measure = self.meter.add(self.small_measure, new_size);
+            self.small_measure = self.meter.sub(self.small_measure, old_size);
+            Some(old_val)
+        } else if let Some(entry) = self.main.get_mut(&k) {
+            let old_val = std::mem::replace(&mut entry.value, v);
+            let old_size = self.meter.measure(&k, &old_val);
+            self.main_measure = self.meter.add(self.main_measure, new_size);
+            self.main_measure = self.meter.sub(self.main_measure, old_size);
+            Some(old_val)
+        } else {
+            let entry = Entry {
+                value: v,
+                frequency: 0,
+            };
+            let ghost_key = self.ghost_key(&k);
+            if self.ghost.remove(&ghost_key).is_some() {
+                self.main_measure = self.meter.add(self.main_measure, new_size);
+                self.main.insert(k, entry);
+            } else {
+                self.small_measure = self.meter.add(self.small_measure, new_size);
+                self.small.insert(k, entry);
+            }
+            None
+        };
+
+        let mut evicted = Vec::new();
+        while self.size() > self.capacity() {
+            evicted.extend(self.evict());
+        }
+        (old_val, evicted)
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> Cache<K, V, S, M>
+    for S3FifoCache<K, V, S, M>
+{
+    /// Creates an empty cache that can hold at most `capacity` as measured by `meter` with the
+    /// given hash builder.
+    fn with_meter_and_hasher(capacity: u64, meter: M, hash_builder: S) -> Self {
+        S3FifoCache {
+            small: LinkedHashMap::with_hasher(hash_builder.clone()),
+            main: LinkedHashMap::with_hasher(hash_builder.clone()),
+            ghost: LinkedHashMap::with_hasher(hash_builder.clone()),
+            hash_builder,
+            small_measure: Default::default(),
+            main_measure: Default::default(),
+            max_capacity: capacity,
+            meter,
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the given key in the cache, if
+    /// any. The access gives the key another round in the main queue.
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(k).map(|v| v as &V)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key in the cache, if
+    /// any. The access gives the key another round in the main queue.
+    fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = if self.small.contains_key(k) {
+            self.small.get_mut(k)
+        } else {
+            self.main.get_mut(k)
+        };
+        entry.map(|entry| {
+            entry.frequency = (entry.frequency + 1).min(MAX_FREQUENCY);
+            &mut entry.value
+        })
+    }
+
+    /// Returns a reference to the value corresponding to the key in the cache or `None` if it is
+    /// not present in the cache. Unlike `get`, `peek` does not count as an access.
+    fn peek<'a, Q>(&'a self, k: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small
+            .get(k)
+            .or_else(|| self.main.get(k))
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key in the cache or `None`
+    /// if it is not present in the cache. Unlike `get_mut`, `peek_mut` does not count as an
+    /// access.
+    fn peek_mut<'a, Q>(&'a mut self, k: &Q) -> Option<&'a mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = if self.small.contains_key(k) {
+            self.small.get_mut(k)
+        } else {
+            self.main.get_mut(k)
+        };
+        entry.map(|entry| &mut entry.value)
+    }
+
+    /// Returns the head of the queue the next key is evicted from, or `None` if the cache is
+    /// empty. The head is only evicted if it was not accessed since it was queued.
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        let front = if self.evict_from_small() {
+            self.small.front()
+        } else {
+            self.main.front()
+        };
+        front.map(|(k, entry)| (k, &entry.value))
+    }
+
+    /// Inserts a key-value pair into the cache. If the key already existed, the old value is
+    /// returned.
+    fn put(&mut self, k: K, v: V) -> Option<V> {
+        self.put_and_evict(k, v).0
+    }
+
+    /// Removes the given key from the cache and returns its corresponding value.
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(entry) = self.small.remove(k) {
+            let size = self.meter.measure(k, &entry.value);
+            self.small_measure = self.meter.sub(self.small_measure, size);
+            Some(entry.value)
+        } else if let Some(entry) = self.main.remove(k) {
+            let size = self.meter.measure(k, &entry.value);
+            self.main_measure = self.meter.sub(self.main_measure, size);
+            Some(entry.value)
+        } else {
+            None
+        }
+    }
+
+    /// Evicts and returns a key-value pair as a tuple, the keys passed over are moved to the
+    /// main queue or given another round.
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        self.evict()
+    }
+
+    /// Checks if the map contains the given key.
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small.contains_key(k) || self.main.contains_key(k)
+    }
+
+    /// Returns the number of key-value pairs in the cache.
+    fn len(&self) -> usize {
+        self.small.len() + self.main.len()
+    }
+
+    /// Returns `true` if the cache contains no key-value pairs.
+    fn is_empty(&self) -> bool {
+        self.small.is_empty() && self.main.is_empty()
+    }
+
+    /// Returns the maximum size of the key-value pairs the cache can hold, as measured by the
+    /// `Meter` used by the cache.
+    fn capacity(&self) -> u64 {
+        self.max_capacity
+    }
+
+    /// Sets the size of the key-value pairs the cache can hold, as measured by the `Meter` used by
+    /// the cache.
+    fn set_capacity(&mut self, capacity: u64) {
+        self.max_capacity = capacity;
+        while self.size() > capacity {
+            self.evict();
+        }
+    }
+
+    /// Returns the size of all the key-value pairs in the cache, as measured by the `Meter` used
+    /// by the cache.
+    fn size(&self) -> u64 {
+        self.small_size() + self.main_size()
+    }
+
+    /// Removes all key-value pairs from the cache.
+    fn clear(&mut self) {
+        self.small.clear();
+        self.main.clear();
+        self.ghost.clear();
+        self.small_measure = Default::default();
+        self.main_measure = Default::default();
+    }
+}
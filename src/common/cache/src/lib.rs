@@ -22,6 +22,10 @@ mod cache;
 mod meter;
 
 pub use cache::lru::LruCache;
+pub use cache::policy::CacheStatistics;
+pub use cache::policy::EvictionPolicy;
+pub use cache::policy::PolicyCache;
+pub use cache::s3fifo::S3FifoCache;
 pub use cache::Cache;
 pub use meter::bytes_meter::BytesMeter;
 pub use meter::count_meter::Count;
@@ -13,3 +13,5 @@
 // limitations under the License.
 
 mod lru;
+mod policy;
+mod s3fifo;
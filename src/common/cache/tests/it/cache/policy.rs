@@ -0,0 +1,70 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_cache::Cache;
+use common_cache::CacheStatistics;
+use common_cache::Count;
+use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
+use common_cache::PolicyCache;
+
+fn new_cache(policy: EvictionPolicy, capacity: u64) -> PolicyCache<u64, u64> {
+    PolicyCache::with_policy(policy, capacity, Count, DefaultHashBuilder::default())
+}
+
+#[test]
+fn test_policy() {
+    assert_eq!(
+        new_cache(EvictionPolicy::Lru, 2).policy(),
+        EvictionPolicy::Lru
+    );
+    assert_eq!(
+        new_cache(EvictionPolicy::S3Fifo, 2).policy(),
+        EvictionPolicy::S3Fifo
+    );
+    assert_eq!(EvictionPolicy::S3Fifo.to_string(), "s3fifo");
+}
+
+#[test]
+fn test_statistics() {
+    for policy in [EvictionPolicy::Lru, EvictionPolicy::S3Fifo] {
+        let mut cache = new_cache(policy, 2);
+        cache.put(1, 10);
+        cache.put(2, 20);
+        cache.put(2, 22);
+        cache.put(3, 30);
+        assert!(cache.get(&1).is_none());
+        assert_eq!(cache.get(&3), Some(&30));
+
+        cache.set_capacity(1);
+        cache.pop(&3);
+        assert_eq!(cache.statistics(), CacheStatistics {
+            hits: 1,
+            misses: 1,
+            evictions: 2,
+        });
+    }
+}
+
+#[test]
+fn test_put_and_evict() {
+    for policy in [EvictionPolicy::Lru, EvictionPolicy::S3Fifo] {
+        let mut cache = new_cache(policy, 2);
+        assert_eq!(cache.put_and_evict(1, 10), (None, vec![]));
+        assert_eq!(cache.put_and_evict(2, 20), (None, vec![]));
+        assert_eq!(cache.put_and_evict(2, 22), (Some(20), vec![]));
+        assert_eq!(cache.put_and_evict(3, 30), (None, vec![(1, 10)]));
+        assert_eq!(cache.statistics().evictions, 1);
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+
+use common_cache::Cache;
+use common_cache::LruCache;
+use common_cache::Meter;
+use common_cache::S3FifoCache;
+
+#[test]
+fn test_put_and_get() {
+    let mut cache = S3FifoCache::new(2);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    assert_eq!(cache.get(&1), Some(&10));
+    assert_eq!(cache.get_mut(&2), Some(&mut 20));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.size(), 2);
+
+    assert_eq!(cache.put(1, 11), Some(10));
+    assert_eq!(cache.peek(&1), Some(&11));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn test_pop_and_clear() {
+    let mut cache = S3FifoCache::new(3);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    assert_eq!(cache.pop(&1), Some(10));
+    assert_eq!(cache.pop(&1), None);
+    assert!(!cache.contains(&1));
+    assert_eq!(cache.len(), 1);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.size(), 0);
+}
+
+#[test]
+fn test_evict_not_accessed_first() {
+    let mut cache = S3FifoCache::new(3);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    cache.put(3, 30);
+    cache.get(&1);
+    cache.put(4, 40);
+
+    // 1 was accessed, so it moved to the main queue instead of being evicted.
+    assert!(cache.contains(&1));
+    assert!(!cache.contains(&2));
+    assert_eq!(cache.len(), 3);
+}
+
+#[test]
+fn test_ghost_readmission() {
+    let mut cache = S3FifoCache::new(2);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    cache.put(3, 30);
+    assert!(!cache.contains(&1));
+
+    // 1 was evicted recently, so it is admitted to the main queue directly.
+    cache.put(1, 10);
+    cache.put(4, 40);
+    cache.put(5, 50);
+    cache.put(6, 60);
+    assert!(cache.contains(&1));
+}
+
+#[test]
+fn test_scan_resistance() {
+    let hot_keys = 0..50;
+    let scan_keys = 1000..2000;
+
+    let mut s3fifo = S3FifoCache::new(100);
+    let mut lru = LruCache::new(100);
+    for key in hot_keys.clone() {
+        s3fifo.put(key, key);
+        lru.put(key, key);
+    }
+    for _ in 0..2 {
+        for key in hot_keys.clone() {
+            s3fifo.get(&key);
+            lru.get(&key);
+        }
+    }
+    for key in scan_keys {
+        s3fifo.put(key, key);
+        lru.put(key, key);
+    }
+
+    assert!(hot_keys.clone().all(|key| s3fifo.contains(&key)));
+    assert!(hot_keys.clone().all(|key| !lru.contains(&key)));
+    assert_eq!(s3fifo.len(), 100);
+}
+
+#[test]
+fn test_change_capacity() {
+    let mut cache = S3FifoCache::new(3);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    cache.put(3, 30);
+    cache.set_capacity(1);
+    assert_eq!(cache.capacity(), 1);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.contains(&3));
+}
+
+struct VecLen;
+
+impl<K, T> Meter<K, Vec<T>> for VecLen {
+    type Measure = usize;
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &Vec<T>) -> usize
+    where K: Borrow<Q> {
+        v.len()
+    }
+}
+
+#[test]
+fn test_metered_cache() {
+    let mut cache = S3FifoCache::with_meter(5, VecLen);
+    cache.put("foo1", vec![1, 2]);
+    assert_eq!(cache.size(), 2);
+    cache.put("foo2", vec![3, 4]);
+    cache.put("foo3", vec![5, 6]);
+    assert_eq!(cache.size(), 4);
+    assert!(!cache.contains("foo1"));
+
+    cache.put("foo2", vec![7]);
+    assert_eq!(cache.size(), 3);
+    assert_eq!(cache.pop("foo3"), Some(vec![5, 6]));
+    assert_eq!(cache.size(), 1);
+}
@@ -138,6 +138,8 @@ build_exceptions! {
     // create table or alter table add column with internal column name
     TableWithInternalColumnName(1110),
     EmptyShareEndpointConfig(1111),
+    // alter table rename or modify column that conflicts with the table definition
+    AlterColumnError(1112),
 
     // Data Related Errors
 
@@ -159,6 +161,9 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// CheckConstraintViolated is used when rows written into a table violate
+    /// a CHECK constraint of the table.
+    CheckConstraintViolated(1304),
 }
 
 // Meta service errors [2001, 3000].
@@ -183,6 +188,30 @@ build_exceptions! {
     IllegalUserInfoFormat(2203),
     UnknownRole(2204),
     InvalidRole(2206),
+    InvalidPassword(2207),
+    UserLocked(2208),
+    PasswordExpired(2209),
+    ClientIpNotAllowed(2210),
+
+    // Password policy error codes.
+    UnknownPasswordPolicy(2211),
+    PasswordPolicyAlreadyExists(2212),
+    IllegalPasswordPolicy(2213),
+    PasswordPolicyIsUsedByUser(2214),
+
+    // Network policy error codes.
+    UnknownNetworkPolicy(2221),
+    NetworkPolicyAlreadyExists(2222),
+    IllegalNetworkPolicy(2223),
+    NetworkPolicyIsUsedByUser(2224),
+
+    // Data security policy error codes.
+    UnknownMaskingPolicy(2231),
+    MaskingPolicyAlreadyExists(2232),
+    IllegalMaskingPolicy(2233),
+    UnknownRowAccessPolicy(2234),
+    RowAccessPolicyAlreadyExists(2235),
+    IllegalRowAccessPolicy(2236),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -255,6 +284,22 @@ build_exceptions! {
     UnknownShareTable(2717),
     CannotShareDatabaseCreatedFromShare(2718),
 
+    // Stream error codes.
+    IllegalStream(2730),
+
+    // Sequence error codes.
+    UnknownSequence(2741),
+    SequenceAlreadyExists(2742),
+    IllegalSequence(2743),
+
+    // Snapshot tag error codes.
+    UnknownSnapshotTag(2751),
+    SnapshotTagAlreadyExists(2752),
+
+    // Vector index error codes.
+    UnknownVectorIndex(2761),
+    VectorIndexAlreadyExists(2762),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -282,4 +327,6 @@ build_exceptions! {
 build_exceptions! {
     // A task that already stopped and can not stopped twice.
     AlreadyStopped(5002),
+    // A request to the ai provider failed transiently, e.g. it timed out or was throttled.
+    AiProviderUnavailable(5003),
 }
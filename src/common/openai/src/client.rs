@@ -0,0 +1,208 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_cache::Cache;
+use common_cache::LruCache;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::warn;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+use crate::CompletionMode;
+use crate::LLMConfig;
+use crate::LLMProvider;
+
+#[derive(Clone)]
+enum CachedResult {
+    Embedding(Vec<f32>),
+    Completion(String),
+}
+
+/// Results of all the providers in this process, keyed by the tenant, the
+/// provider id, the kind of request and the input, so the tenants don't share
+/// the results.
+static RESULT_CACHE: Lazy<Mutex<LruCache<String, CachedResult>>> =
+    Lazy::new(|| Mutex::new(LruCache::new(0)));
+
+/// The earliest time the next request of this process can be sent.
+static NEXT_REQUEST: Lazy<Mutex<Option<Instant>>> = Lazy::new(|| Mutex::new(None));
+
+/// Sends the requests of the ai_* functions to a provider.
+///
+/// The inputs are embedded in batches, transiently failed requests are retried
+/// with an exponential backoff, the requests of the process are rate limited and the
+/// results are cached, so repeated inputs are not sent again.
+pub struct LLMClient {
+    tenant: String,
+    provider: Arc<dyn LLMProvider>,
+    provider_id: String,
+    batch_size: usize,
+    max_retries: usize,
+    requests_per_second: u64,
+    cache_capacity: u64,
+}
+
+impl LLMClient {
+    pub fn create(config: &LLMConfig) -> Result<Self> {
+        let provider = config.create_provider()?;
+        Ok(Self::create_with_provider(config, provider))
+    }
+
+    /// Uses the provider given instead of the one of the config.
+    pub fn create_with_provider(config: &LLMConfig, provider: Arc<dyn LLMProvider>) -> Self {
+        LLMClient {
+            tenant: config.tenant.clone(),
+            provider_id: provider.id(),
+            provider,
+            batch_size: config.batch_size.max(1),
+            max_retries: config.max_retries,
+            requests_per_second: config.requests_per_second,
+            cache_capacity: config.cache_capacity,
+        }
+    }
+
+    /// Embeds the inputs, returns the vectors in the order of the inputs.
+    pub fn embedding(&self, inputs: &[String]) -> Result<Vec<Vec<f32>>> {
+        let mut results: Vec<Option<Vec<f32>>> = Vec::with_capacity(inputs.len());
+        // The inputs to request, each only once, with their positions.
+        let mut missing: Vec<&String> = vec![];
+        let mut positions: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (i, input) in inputs.iter().enumerate() {
+            match self.cache_get(&self.cache_key("embedding", input)) {
+                Some(CachedResult::Embedding(embedding)) => results.push(Some(embedding)),
+                _ => {
+                    results.push(None);
+                    positions
+                        .entry(input.as_str())
+                        .or_insert_with(|| {
+                            missing.push(input);
+                            vec![]
+                        })
+                        .push(i);
+                }
+            }
+        }
+
+        for chunk in missing.chunks(self.batch_size) {
+            let chunk: Vec<String> = chunk.iter().map(|input| input.to_string()).collect();
+            let (embeddings, _) = self.request(|| self.provider.embedding(&chunk))?;
+            if embeddings.len() != chunk.len() {
+                return Err(ErrorCode::Internal(format!(
+                    "ai provider {} returned {} embeddings for {} inputs",
+                    self.provider_id,
+                    embeddings.len(),
+                    chunk.len()
+                )));
+            }
+
+            for (input, embedding) in chunk.iter().zip(embeddings) {
+                for &pos in &positions[input.as_str()] {
+                    results[pos] = Some(embedding.clone());
+                }
+                self.cache_put(
+                    self.cache_key("embedding", input),
+                    CachedResult::Embedding(embedding),
+                );
+            }
+        }
+
+        Ok(results.into_iter().map(|r| r.unwrap_or_default()).collect())
+    }
+
+    /// Completes the prompt.
+    pub fn completion(&self, mode: CompletionMode, prompt: String) -> Result<String> {
+        let key = self.cache_key(&format!("{:?}", mode), &prompt);
+        if let Some(CachedResult::Completion(completion)) = self.cache_get(&key) {
+            return Ok(completion);
+        }
+
+        let (completion, _) = self.request(|| self.provider.completion(mode, prompt.clone()))?;
+        self.cache_put(key, CachedResult::Completion(completion.clone()));
+        Ok(completion)
+    }
+
+    /// Only the transient errors are retried. The functions are evaluated
+    /// synchronously, so the backoff blocks the thread.
+    fn request<T>(&self, f: impl Fn() -> Result<T>) -> Result<T> {
+        let mut retries = 0;
+        loop {
+            self.wait_rate_limit();
+            match f() {
+                Ok(v) => return Ok(v),
+                Err(e)
+                    if e.code() == ErrorCode::AI_PROVIDER_UNAVAILABLE
+                        && retries < self.max_retries =>
+                {
+                    let backoff = Duration::from_millis(100 << retries.min(6));
+                    warn!(
+                        "ai provider {} request failed, retry in {:?}: {}",
+                        self.provider_id, backoff, e
+                    );
+                    std::thread::sleep(backoff);
+                    retries += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn wait_rate_limit(&self) {
+        if self.requests_per_second == 0 {
+            return;
+        }
+
+        let interval = Duration::from_secs(1) / self.requests_per_second as u32;
+        let wait = {
+            let mut next = NEXT_REQUEST.lock();
+            let now = Instant::now();
+            let slot = match *next {
+                Some(next) if next > now => next,
+                _ => now,
+            };
+            *next = Some(slot + interval);
+            slot - now
+        };
+        if !wait.is_zero() {
+            std::thread::sleep(wait);
+        }
+    }
+
+    fn cache_key(&self, kind: &str, input: &str) -> String {
+        format!("{}:{}:{}:{}", self.tenant, self.provider_id, kind, input)
+    }
+
+    fn cache_get(&self, key: &String) -> Option<CachedResult> {
+        if self.cache_capacity == 0 {
+            return None;
+        }
+        RESULT_CACHE.lock().get(key).cloned()
+    }
+
+    fn cache_put(&self, key: String, result: CachedResult) {
+        if self.cache_capacity == 0 {
+            return;
+        }
+        let mut cache = RESULT_CACHE.lock();
+        if cache.capacity() != self.cache_capacity {
+            cache.set_capacity(self.cache_capacity);
+        }
+        cache.put(key, result);
+    }
+}
@@ -12,7 +12,6 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
-use common_exception::ErrorCode;
 use common_exception::Result;
 use log::trace;
 use openai_api_rust::chat::ChatApi;
@@ -23,14 +22,10 @@ use openai_api_rust::Role;
 
 use crate::metrics::metrics_completion_count;
 use crate::metrics::metrics_completion_token;
+use crate::openai::request_error;
+use crate::CompletionMode;
 use crate::OpenAI;
 
-#[derive(Debug)]
-enum CompletionMode {
-    Sql,
-    Text,
-}
-
 impl OpenAI {
     pub fn completion_text_request(&self, prompt: String) -> Result<(String, Option<u32>)> {
         self.completion_request(CompletionMode::Text, prompt)
@@ -40,7 +35,7 @@ impl OpenAI {
         self.completion_request(CompletionMode::Sql, prompt)
     }
 
-    fn completion_request(
+    pub(crate) fn completion_request(
         &self,
         mode: CompletionMode,
         prompt: String,
@@ -53,10 +48,8 @@ impl OpenAI {
             &self.api_base,
         );
 
-        let (max_tokens, stop) = match mode {
-            CompletionMode::Sql => (Some(150), Some(vec!["#".to_string(), ";".to_string()])),
-            CompletionMode::Text => (Some(1024), None),
-        };
+        let (max_tokens, stop) = mode.params();
+        let max_tokens = Some(max_tokens as _);
 
         let body = ChatBody {
             model: self.completion_model.to_string(),
@@ -78,12 +71,9 @@ impl OpenAI {
 
         trace!("openai {:?} completion request: {:?}", mode, body);
 
-        let resp = openai.chat_completion_create(&body).map_err(|e| {
-            ErrorCode::Internal(format!(
-                "openai {:?} completion request error: {:?}",
-                mode, e
-            ))
-        })?;
+        let resp = openai
+            .chat_completion_create(&body)
+            .map_err(|e| request_error(format!("openai {:?} completion", mode), e))?;
         trace!("openai {:?} completion response: {:?}", mode, resp);
 
         let usage = resp.usage.total_tokens;
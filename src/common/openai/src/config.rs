@@ -0,0 +1,72 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::LLMProvider;
+use crate::LocalProvider;
+use crate::MockProvider;
+use crate::OpenAI;
+
+/// The provider of the ai_* functions and how the requests are sent to it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LLMConfig {
+    /// The tenant the requests are sent for, the cached results are not shared across tenants.
+    pub tenant: String,
+    /// `openai`, `local` or `mock`, empty for `openai`.
+    pub provider: String,
+    pub api_base: String,
+    pub api_key: String,
+    pub embedding_model: String,
+    pub completion_model: String,
+    /// The endpoint of the `local` provider.
+    pub local_endpoint: String,
+    /// Max inputs embedded in one request.
+    pub batch_size: usize,
+    /// Times a transiently failed request is retried.
+    pub max_retries: usize,
+    /// Max requests per second of this process, 0 for unlimited.
+    pub requests_per_second: u64,
+    /// Max results cached in this process, 0 to disable the cache.
+    pub cache_capacity: u64,
+}
+
+impl LLMConfig {
+    pub fn create_provider(&self) -> Result<Arc<dyn LLMProvider>> {
+        match self.provider.to_lowercase().as_str() {
+            "" | "openai" => Ok(Arc::new(OpenAI::create(
+                self.api_base.clone(),
+                self.api_key.clone(),
+                self.embedding_model.clone(),
+                self.completion_model.clone(),
+            ))),
+            "local" => {
+                if self.local_endpoint.is_empty() {
+                    return Err(ErrorCode::InvalidConfig(
+                        "ai provider 'local' requires ai_local_endpoint",
+                    ));
+                }
+                Ok(Arc::new(LocalProvider::create(self.local_endpoint.clone())))
+            }
+            "mock" => Ok(Arc::new(MockProvider::create())),
+            other => Err(ErrorCode::InvalidConfig(format!(
+                "unknown ai provider '{}', expected one of openai, local, mock",
+                other
+            ))),
+        }
+    }
+}
@@ -12,7 +12,6 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
-use common_exception::ErrorCode;
 use common_exception::Result;
 use openai_api_rust::embeddings::EmbeddingsApi;
 use openai_api_rust::embeddings::EmbeddingsBody;
@@ -20,6 +19,7 @@ use openai_api_rust::Auth;
 
 use crate::metrics::metrics_embedding_count;
 use crate::metrics::metrics_embedding_token;
+use crate::openai::request_error;
 use crate::OpenAI;
 
 impl OpenAI {
@@ -39,7 +39,7 @@ impl OpenAI {
         };
         let resp = openai
             .embeddings_create(&body)
-            .map_err(|e| ErrorCode::Internal(format!("openai embedding request error: {:?}", e)))?;
+            .map_err(|e| request_error("openai embedding".to_string(), e))?;
 
         let usage = resp.usage.total_tokens;
         let embeddings = resp.data.unwrap_or(vec![]);
@@ -12,12 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod client;
 mod completion;
+mod config;
 mod embedding;
+mod local;
+mod mock;
 
 #[allow(clippy::module_inception)]
 mod openai;
 
+mod provider;
+
 pub(crate) mod metrics;
 
+pub use client::LLMClient;
+pub use config::LLMConfig;
+pub use local::LocalProvider;
+pub use mock::MockProvider;
+pub use mock::MOCK_EMBEDDING_DIMENSION;
 pub use openai::OpenAI;
+pub use provider::CompletionMode;
+pub use provider::LLMProvider;
@@ -0,0 +1,138 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::trace;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::metrics::metrics_completion_count;
+use crate::metrics::metrics_embedding_count;
+use crate::CompletionMode;
+use crate::LLMProvider;
+
+/// A local inference server speaking the protocol of the HuggingFace
+/// text-embeddings-inference (`POST /embed`) and text-generation-inference
+/// (`POST /generate`) servers.
+///
+/// Each server serves a single model, so the models are not part of the requests.
+pub struct LocalProvider {
+    endpoint: String,
+    agent: ureq::Agent,
+}
+
+#[derive(Serialize)]
+struct EmbedRequest<'a> {
+    inputs: &'a [String],
+}
+
+#[derive(Serialize)]
+struct GenerateRequest {
+    inputs: String,
+    parameters: GenerateParameters,
+}
+
+#[derive(Serialize)]
+struct GenerateParameters {
+    max_new_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct GenerateResponse {
+    generated_text: String,
+}
+
+impl LocalProvider {
+    pub fn create(endpoint: String) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(60))
+            .build();
+        LocalProvider {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            agent,
+        }
+    }
+}
+
+/// Timeouts, throttling and server errors are worth retrying, the others are not.
+fn request_error(what: String, e: ureq::Error) -> ErrorCode {
+    let transient = match &e {
+        ureq::Error::Status(status, _) => *status == 429 || *status >= 500,
+        ureq::Error::Transport(transport) => matches!(transport.kind(), ureq::ErrorKind::Io),
+    };
+    let message = format!("{} request error: {:?}", what, e);
+    if transient {
+        ErrorCode::AiProviderUnavailable(message)
+    } else {
+        ErrorCode::Internal(message)
+    }
+}
+
+impl LLMProvider for LocalProvider {
+    fn id(&self) -> String {
+        format!("local:{}", self.endpoint)
+    }
+
+    fn embedding(&self, inputs: &[String]) -> Result<(Vec<Vec<f32>>, Option<u32>)> {
+        let url = format!("{}/embed", self.endpoint);
+        let embeddings: Vec<Vec<f32>> = self
+            .agent
+            .post(&url)
+            .send_json(EmbedRequest { inputs })
+            .map_err(|e| request_error("local embedding".to_string(), e))?
+            .into_json()
+            .map_err(|e| ErrorCode::Internal(format!("local embedding response error: {:?}", e)))?;
+
+        metrics_embedding_count(1);
+        Ok((embeddings, None))
+    }
+
+    fn completion(&self, mode: CompletionMode, prompt: String) -> Result<(String, Option<u32>)> {
+        let url = format!("{}/generate", self.endpoint);
+        let (max_new_tokens, stop) = mode.params();
+        let body = GenerateRequest {
+            inputs: prompt,
+            parameters: GenerateParameters {
+                max_new_tokens,
+                stop,
+            },
+        };
+
+        let resp: GenerateResponse = self
+            .agent
+            .post(&url)
+            .send_json(body)
+            .map_err(|e| request_error(format!("local {:?} completion", mode), e))?
+            .into_json()
+            .map_err(|e| {
+                ErrorCode::Internal(format!(
+                    "local {:?} completion response error: {:?}",
+                    mode, e
+                ))
+            })?;
+        trace!(
+            "local {:?} completion response: {}",
+            mode,
+            resp.generated_text
+        );
+
+        metrics_completion_count(1);
+        Ok((resp.generated_text, None))
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::CompletionMode;
+use crate::LLMProvider;
+
+/// The dimension of the vectors embedded by the [`MockProvider`].
+pub const MOCK_EMBEDDING_DIMENSION: usize = 16;
+
+/// A deterministic provider without any network access, for tests.
+///
+/// The same text is always embedded into the same unit vector, the text completion
+/// echoes the prompt and the sql completion is always `1`.
+#[derive(Default)]
+pub struct MockProvider;
+
+impl MockProvider {
+    pub fn create() -> Self {
+        MockProvider
+    }
+
+    fn embed(input: &str) -> Vec<f32> {
+        // FNV-1a hash of the text seeds a splitmix64 sequence.
+        let mut state = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+        let mut vector: Vec<f32> = (0..MOCK_EMBEDDING_DIMENSION)
+            .map(|_| {
+                state = state.wrapping_add(0x9e3779b97f4a7c15);
+                let mut z = state;
+                z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+                z ^= z >> 31;
+                (z >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0
+            })
+            .collect();
+
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|v| *v /= norm);
+        }
+        vector
+    }
+}
+
+impl LLMProvider for MockProvider {
+    fn id(&self) -> String {
+        "mock".to_string()
+    }
+
+    fn embedding(&self, inputs: &[String]) -> Result<(Vec<Vec<f32>>, Option<u32>)> {
+        let embeddings = inputs.iter().map(|input| Self::embed(input)).collect();
+        Ok((embeddings, Some(0)))
+    }
+
+    fn completion(&self, mode: CompletionMode, prompt: String) -> Result<(String, Option<u32>)> {
+        let completion = match mode {
+            CompletionMode::Sql => "1".to_string(),
+            CompletionMode::Text => format!("mock completion: {}", prompt),
+        };
+        Ok((completion, Some(0)))
+    }
+}
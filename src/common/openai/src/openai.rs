@@ -12,6 +12,13 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::CompletionMode;
+use crate::LLMProvider;
+
+/// The OpenAI API, or any service compatible with it.
 pub struct OpenAI {
     pub(crate) api_key: String,
     pub(crate) api_base: String,
@@ -53,3 +60,39 @@ impl OpenAI {
         }
     }
 }
+
+/// Network errors, throttling and server errors are worth retrying, the others are not.
+///
+/// The client keeps the body of a failed response but not its status, so the
+/// error types of the body tell the transient errors apart.
+pub(crate) fn request_error(what: String, e: openai_api_rust::Error) -> ErrorCode {
+    let transient = match &e {
+        openai_api_rust::Error::RequestError(msg) => msg.contains("Network Error"),
+        openai_api_rust::Error::ApiError(body) => {
+            body.contains("rate_limit_exceeded") || body.contains("server_error")
+        }
+    };
+    let message = format!("{} request error: {:?}", what, e);
+    if transient {
+        ErrorCode::AiProviderUnavailable(message)
+    } else {
+        ErrorCode::Internal(message)
+    }
+}
+
+impl LLMProvider for OpenAI {
+    fn id(&self) -> String {
+        format!(
+            "openai:{}:{}:{}",
+            self.api_base, self.embedding_model, self.completion_model
+        )
+    }
+
+    fn embedding(&self, inputs: &[String]) -> Result<(Vec<Vec<f32>>, Option<u32>)> {
+        self.embedding_request(inputs)
+    }
+
+    fn completion(&self, mode: CompletionMode, prompt: String) -> Result<(String, Option<u32>)> {
+        self.completion_request(mode, prompt)
+    }
+}
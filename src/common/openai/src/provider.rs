@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+/// What the completion is used for, decides its length and stop words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompletionMode {
+    Sql,
+    Text,
+}
+
+impl CompletionMode {
+    /// The max tokens and the stop words of the completion.
+    pub fn params(&self) -> (u32, Option<Vec<String>>) {
+        match self {
+            CompletionMode::Sql => (150, Some(vec!["#".to_string(), ";".to_string()])),
+            CompletionMode::Text => (1024, None),
+        }
+    }
+}
+
+/// A backend serving the embeddings and the completions of the ai_* functions.
+///
+/// The requests are sent as is, batching, retries, rate limiting and caching
+/// are done by the [`crate::LLMClient`] wrapping the provider.
+pub trait LLMProvider: Send + Sync {
+    /// Identifies the provider and its models, the cached results of
+    /// different providers are never mixed.
+    fn id(&self) -> String;
+
+    /// Embeds all the inputs in one request, returns the vectors in the order
+    /// of the inputs and the tokens used.
+    fn embedding(&self, inputs: &[String]) -> Result<(Vec<Vec<f32>>, Option<u32>)>;
+
+    /// Completes the prompt, returns the completion and the tokens used.
+    fn completion(&self, mode: CompletionMode, prompt: String) -> Result<(String, Option<u32>)>;
+}
@@ -0,0 +1,189 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_openai::CompletionMode;
+use common_openai::LLMClient;
+use common_openai::LLMConfig;
+use common_openai::LLMProvider;
+use common_openai::MockProvider;
+use common_openai::MOCK_EMBEDDING_DIMENSION;
+use parking_lot::Mutex;
+
+/// Wraps the mock provider, records the batches and fails the first requests.
+struct RecordingProvider {
+    id: String,
+    failures: AtomicUsize,
+    transient: bool,
+    batches: Mutex<Vec<usize>>,
+}
+
+impl RecordingProvider {
+    fn create(id: &str, failures: usize) -> Arc<Self> {
+        Self::create_with_failure(id, failures, true)
+    }
+
+    fn create_with_failure(id: &str, failures: usize, transient: bool) -> Arc<Self> {
+        Arc::new(RecordingProvider {
+            id: id.to_string(),
+            failures: AtomicUsize::new(failures),
+            transient,
+            batches: Mutex::new(vec![]),
+        })
+    }
+}
+
+impl LLMProvider for RecordingProvider {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn embedding(&self, inputs: &[String]) -> Result<(Vec<Vec<f32>>, Option<u32>)> {
+        if self.failures.load(Ordering::SeqCst) > 0 {
+            self.failures.fetch_sub(1, Ordering::SeqCst);
+            return Err(if self.transient {
+                ErrorCode::AiProviderUnavailable("rate limited")
+            } else {
+                ErrorCode::Internal("invalid api key")
+            });
+        }
+        self.batches.lock().push(inputs.len());
+        MockProvider::create().embedding(inputs)
+    }
+
+    fn completion(&self, mode: CompletionMode, prompt: String) -> Result<(String, Option<u32>)> {
+        self.batches.lock().push(1);
+        MockProvider::create().completion(mode, prompt)
+    }
+}
+
+fn config(provider: &str, batch_size: usize, max_retries: usize, cache_capacity: u64) -> LLMConfig {
+    LLMConfig {
+        provider: provider.to_string(),
+        batch_size,
+        max_retries,
+        cache_capacity,
+        ..Default::default()
+    }
+}
+
+fn inputs(texts: &[&str]) -> Vec<String> {
+    texts.iter().map(|t| t.to_string()).collect()
+}
+
+#[test]
+fn test_mock_embedding() -> Result<()> {
+    let client = LLMClient::create(&config("mock", 16, 0, 0))?;
+    let embeddings = client.embedding(&inputs(&["hello", "world", "hello"]))?;
+
+    assert_eq!(embeddings.len(), 3);
+    assert_eq!(embeddings[0], embeddings[2]);
+    assert_ne!(embeddings[0], embeddings[1]);
+    for embedding in &embeddings {
+        assert_eq!(embedding.len(), MOCK_EMBEDDING_DIMENSION);
+        let norm = embedding.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-5);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_mock_completion() -> Result<()> {
+    let client = LLMClient::create(&config("mock", 16, 0, 0))?;
+    assert_eq!(
+        client.completion(CompletionMode::Text, "say hello".to_string())?,
+        "mock completion: say hello"
+    );
+    assert_eq!(
+        client.completion(CompletionMode::Sql, "select".to_string())?,
+        "1"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unknown_provider() {
+    assert!(LLMClient::create(&config("unknown", 16, 0, 0)).is_err());
+    assert!(LLMClient::create(&config("local", 16, 0, 0)).is_err());
+}
+
+#[test]
+fn test_embedding_batching() -> Result<()> {
+    let provider = RecordingProvider::create("test_embedding_batching", 0);
+    let client = LLMClient::create_with_provider(&config("", 2, 0, 0), provider.clone());
+    let embeddings = client.embedding(&inputs(&["a", "b", "c", "a", "d", "e"]))?;
+
+    // The duplicated input is only sent once.
+    assert_eq!(*provider.batches.lock(), vec![2, 2, 1]);
+    assert_eq!(embeddings.len(), 6);
+    assert_eq!(embeddings[0], embeddings[3]);
+    Ok(())
+}
+
+#[test]
+fn test_embedding_retry() -> Result<()> {
+    let provider = RecordingProvider::create("test_embedding_retry", 2);
+    let client = LLMClient::create_with_provider(&config("", 16, 2, 0), provider.clone());
+    assert_eq!(client.embedding(&inputs(&["a"]))?.len(), 1);
+
+    let provider = RecordingProvider::create("test_embedding_retry_exhausted", 2);
+    let client = LLMClient::create_with_provider(&config("", 16, 1, 0), provider.clone());
+    assert!(client.embedding(&inputs(&["a"])).is_err());
+
+    // The non-transient errors are not retried.
+    let provider = RecordingProvider::create_with_failure("test_embedding_no_retry", 1, false);
+    let client = LLMClient::create_with_provider(&config("", 16, 2, 0), provider.clone());
+    let err = client.embedding(&inputs(&["a"])).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::INTERNAL);
+    assert!(provider.batches.lock().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_result_cache() -> Result<()> {
+    let provider = RecordingProvider::create("test_result_cache", 0);
+    let client = LLMClient::create_with_provider(&config("", 16, 0, 100), provider.clone());
+    let first = client.embedding(&inputs(&["a", "b"]))?;
+    let second = client.embedding(&inputs(&["b", "c"]))?;
+    assert_eq!(first[1], second[0]);
+
+    client.completion(CompletionMode::Text, "hello".to_string())?;
+    client.completion(CompletionMode::Text, "hello".to_string())?;
+
+    // Only the uncached input and the first completion are requested.
+    assert_eq!(*provider.batches.lock(), vec![2, 1, 1]);
+    Ok(())
+}
+
+#[test]
+fn test_result_cache_per_tenant() -> Result<()> {
+    let provider = RecordingProvider::create("test_result_cache_per_tenant", 0);
+    for tenant in ["tenant1", "tenant2", "tenant1"] {
+        let config = LLMConfig {
+            tenant: tenant.to_string(),
+            ..config("", 16, 0, 100)
+        };
+        let client = LLMClient::create_with_provider(&config, provider.clone());
+        client.embedding(&inputs(&["a"]))?;
+    }
+
+    // The results are not shared across the tenants.
+    assert_eq!(*provider.batches.lock(), vec![1, 1]);
+    Ok(())
+}
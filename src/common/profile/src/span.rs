@@ -15,18 +15,44 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Instant;
 
 pub type ProfSpanSetRef<K = u32> = Arc<Mutex<ProfSpanSet<K>>>;
 
-#[derive(Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ProfSpan {
     /// The time spent to process in nanoseconds
     pub process_time: u64,
+    /// The time spent to wait for the async processing(e.g. IO) in nanoseconds
+    pub wait_time: u64,
+    /// The time from the first to the last event of a processor in nanoseconds,
+    /// the longest one is kept for the processors of the same plan.
+    pub wall_time: u64,
+    /// The number of rows produced
+    pub output_rows: u64,
+    /// The bytes of the blocks produced
+    pub output_bytes: u64,
+    /// The bytes of the column data read from the storage by a table scan
+    pub scan_bytes: u64,
+    /// The number of the spill files written
+    pub spill_count: u64,
+    /// The bytes written to the spill files
+    pub spill_bytes: u64,
+    /// The peak memory usage in bytes, summed over the processors of the same plan
+    pub peak_memory_usage: u64,
 }
 
 impl ProfSpan {
     pub fn add(&mut self, other: &Self) {
         self.process_time += other.process_time;
+        self.wait_time += other.wait_time;
+        self.wall_time = self.wall_time.max(other.wall_time);
+        self.output_rows += other.output_rows;
+        self.output_bytes += other.output_bytes;
+        self.scan_bytes += other.scan_bytes;
+        self.spill_count += other.spill_count;
+        self.spill_bytes += other.spill_bytes;
+        self.peak_memory_usage += other.peak_memory_usage;
     }
 }
 
@@ -50,11 +76,43 @@ where K: std::hash::Hash + Eq
     pub fn get(&self, k: &K) -> Option<&ProfSpan> {
         self.spans.get(k)
     }
+
+    /// Take all the spans out of the set.
+    pub fn drain(&mut self) -> impl Iterator<Item = (K, ProfSpan)> + '_ {
+        self.spans.drain()
+    }
+}
+
+/// The span of a plan, it's given to the processors which record
+/// the statistics the wrapping of the plan can't see, e.g. the spilled bytes.
+#[derive(Clone)]
+pub struct PlanProfSpan {
+    plan_id: u32,
+    prof_span_set: ProfSpanSetRef,
+}
+
+impl PlanProfSpan {
+    pub fn create(plan_id: u32, prof_span_set: ProfSpanSetRef) -> Self {
+        PlanProfSpan {
+            plan_id,
+            prof_span_set,
+        }
+    }
+
+    pub fn update(&self, span: ProfSpan) {
+        self.prof_span_set
+            .lock()
+            .unwrap()
+            .update(self.plan_id, span);
+    }
 }
 
 #[derive(Clone, Default)]
 pub struct ProfSpanBuilder {
     process_time: u64,
+    wait_time: u64,
+    start_time: Option<Instant>,
+    peak_memory_usage: u64,
 }
 
 impl ProfSpanBuilder {
@@ -62,9 +120,28 @@ impl ProfSpanBuilder {
         self.process_time += nanos;
     }
 
+    pub fn accumulate_wait_time(&mut self, nanos: u64) {
+        self.wait_time += nanos;
+    }
+
+    pub fn set_peak_memory_usage(&mut self, bytes: u64) {
+        self.peak_memory_usage = bytes;
+    }
+
+    /// Start the wall time at the first call.
+    pub fn start(&mut self) {
+        self.start_time.get_or_insert_with(Instant::now);
+    }
+
     pub fn finish(self) -> ProfSpan {
         ProfSpan {
             process_time: self.process_time,
+            wait_time: self.wait_time,
+            wall_time: self
+                .start_time
+                .map_or(0, |start_time| start_time.elapsed().as_nanos() as u64),
+            peak_memory_usage: self.peak_memory_usage,
+            ..Default::default()
         }
     }
 }
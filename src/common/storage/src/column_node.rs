@@ -22,6 +22,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ColumnId;
 use common_expression::FieldIndex;
+use common_expression::LegacyColumn;
 use common_expression::TableSchema;
 
 #[derive(Debug, Clone)]
@@ -35,11 +36,14 @@ impl ColumnNodes {
         let mut column_nodes = Vec::with_capacity(schema.fields.len());
 
         let leaf_column_ids = table_schema.map(|table_schema| table_schema.to_leaf_column_ids());
-        for field in &schema.fields {
+        for (i, field) in schema.fields.iter().enumerate() {
             let mut column_node = Self::traverse_fields_dfs(field, false, &mut leaf_id);
             if let Some(ref leaf_column_ids) = leaf_column_ids {
                 column_node.build_leaf_column_ids(leaf_column_ids);
             }
+            if let Some(table_schema) = table_schema {
+                column_node.legacy_columns = table_schema.field(i).legacy_columns().to_vec();
+            }
             column_nodes.push(column_node);
         }
 
@@ -159,6 +163,9 @@ pub struct ColumnNode {
     // Optional children column for nested types.
     pub children: Option<Vec<ColumnNode>>,
     pub leaf_column_ids: Vec<ColumnId>,
+    // The column ids and types the data of the column was stored with before its type
+    // was modified, only top level columns without children may have them.
+    pub legacy_columns: Vec<LegacyColumn>,
 }
 
 impl ColumnNode {
@@ -174,6 +181,7 @@ impl ColumnNode {
             leaf_indices,
             children,
             leaf_column_ids: vec![],
+            legacy_columns: vec![],
         }
     }
 
@@ -17,13 +17,7 @@ use common_exception::Result;
 use ndarray::ArrayView;
 
 pub fn cosine_distance(from: &[f32], to: &[f32]) -> Result<f32> {
-    if from.len() != to.len() {
-        return Err(ErrorCode::InvalidArgument(format!(
-            "Vector length not equal: {:} != {:}",
-            from.len(),
-            to.len(),
-        )));
-    }
+    check_length(from, to)?;
 
     let a = ArrayView::from(from);
     let b = ArrayView::from(to);
@@ -32,3 +26,33 @@ pub fn cosine_distance(from: &[f32], to: &[f32]) -> Result<f32> {
 
     Ok(1.0 - (&a * &b).sum() / ((aa_sum).sqrt() * (bb_sum).sqrt()))
 }
+
+pub fn l2_distance(from: &[f32], to: &[f32]) -> Result<f32> {
+    check_length(from, to)?;
+
+    let a = ArrayView::from(from);
+    let b = ArrayView::from(to);
+    let diff = &a - &b;
+
+    Ok((&diff * &diff).sum().sqrt())
+}
+
+pub fn inner_product(from: &[f32], to: &[f32]) -> Result<f32> {
+    check_length(from, to)?;
+
+    let a = ArrayView::from(from);
+    let b = ArrayView::from(to);
+
+    Ok((&a * &b).sum())
+}
+
+fn check_length(from: &[f32], to: &[f32]) -> Result<()> {
+    if from.len() != to.len() {
+        return Err(ErrorCode::InvalidArgument(format!(
+            "Vector length not equal: {:} != {:}",
+            from.len(),
+            to.len(),
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,207 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Neighbour;
+
+/// The max number of neighbours of a node in the upper layers.
+const M: usize = 16;
+/// The max number of neighbours of a node in the bottom layer.
+const M0: usize = 32;
+const EF_CONSTRUCTION: usize = 64;
+const EF_SEARCH: usize = 64;
+
+/// Hierarchical navigable small world graph, the nodes are the positions of
+/// the vectors in the index.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HnswGraph {
+    entry_point: usize,
+    /// `layers[l][node]` are the neighbours of `node` in layer `l`, empty if
+    /// the node is not in the layer.
+    layers: Vec<Vec<Vec<usize>>>,
+}
+
+impl HnswGraph {
+    /// Build the graph of `n` nodes, `dist(a, b)` is the distance between the nodes.
+    pub fn build(n: usize, dist: impl Fn(usize, usize) -> f32) -> Self {
+        let mut graph = HnswGraph {
+            entry_point: 0,
+            layers: vec![vec![vec![]; n]],
+        };
+        if n == 0 {
+            return graph;
+        }
+
+        for node in 1..n {
+            let level = random_level(node);
+            while graph.layers.len() <= level {
+                graph.layers.push(vec![vec![]; n]);
+            }
+
+            let top = graph.top_level();
+            let mut entry = graph.entry_point;
+            for layer in (level + 1..=top).rev() {
+                entry = graph.greedy_closest(layer, entry, |i| dist(node, i));
+            }
+
+            let mut entries = vec![entry];
+            for layer in (0..=level.min(top)).rev() {
+                let candidates =
+                    graph.search_layer(layer, &entries, EF_CONSTRUCTION, |i| dist(node, i));
+                let max_neighbours = if layer == 0 { M0 } else { M };
+                let neighbours: Vec<usize> = candidates
+                    .iter()
+                    .take(max_neighbours)
+                    .map(|n| n.id)
+                    .collect();
+
+                for &neighbour in &neighbours {
+                    let links = &mut graph.layers[layer][neighbour];
+                    links.push(node);
+                    if links.len() > max_neighbours {
+                        // Keep the nearest neighbours of the neighbour.
+                        links.sort_by(|a, b| dist(neighbour, *a).total_cmp(&dist(neighbour, *b)));
+                        links.truncate(max_neighbours);
+                    }
+                }
+                graph.layers[layer][node] = neighbours;
+                entries = candidates.iter().map(|n| n.id).collect();
+            }
+
+            if level > top {
+                graph.entry_point = node;
+            }
+        }
+        graph
+    }
+
+    /// Search the nearest nodes, `dist(i)` is the distance between node `i` and the target.
+    pub fn search(&self, k: usize, dist: impl Fn(usize) -> f32) -> Vec<(usize, f32)> {
+        if self.layers[0].is_empty() {
+            return vec![];
+        }
+
+        let mut entry = self.entry_point;
+        for layer in (1..=self.top_level()).rev() {
+            entry = self.greedy_closest(layer, entry, &dist);
+        }
+        self.search_layer(0, &[entry], EF_SEARCH.max(k), &dist)
+            .into_iter()
+            .take(k)
+            .map(|n| (n.id, n.distance))
+            .collect()
+    }
+
+    fn top_level(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    fn greedy_closest(&self, layer: usize, entry: usize, dist: impl Fn(usize) -> f32) -> usize {
+        let mut current = entry;
+        let mut current_dist = dist(current);
+        loop {
+            let mut changed = false;
+            for &neighbour in &self.layers[layer][current] {
+                let d = dist(neighbour);
+                if d < current_dist {
+                    current = neighbour;
+                    current_dist = d;
+                    changed = true;
+                }
+            }
+            if !changed {
+                return current;
+            }
+        }
+    }
+
+    /// Returns at most `ef` nearest nodes found in the layer, from the nearest.
+    fn search_layer(
+        &self,
+        layer: usize,
+        entries: &[usize],
+        ef: usize,
+        dist: impl Fn(usize) -> f32,
+    ) -> Vec<Neighbour> {
+        let mut visited = HashSet::new();
+        // Min-heap of the nodes to expand.
+        let mut candidates = BinaryHeap::new();
+        // Max-heap of the nearest nodes found.
+        let mut nearest = BinaryHeap::new();
+
+        for &entry in entries {
+            if visited.insert(entry) {
+                let neighbour = Neighbour {
+                    distance: dist(entry),
+                    id: entry,
+                };
+                candidates.push(Reverse(neighbour));
+                nearest.push(neighbour);
+            }
+        }
+        while nearest.len() > ef {
+            nearest.pop();
+        }
+
+        while let Some(Reverse(candidate)) = candidates.pop() {
+            if let Some(farthest) = nearest.peek() {
+                if nearest.len() >= ef && candidate.distance > farthest.distance {
+                    break;
+                }
+            }
+            for &id in &self.layers[layer][candidate.id] {
+                if !visited.insert(id) {
+                    continue;
+                }
+                let neighbour = Neighbour {
+                    distance: dist(id),
+                    id,
+                };
+                let closer = match nearest.peek() {
+                    Some(farthest) => nearest.len() < ef || neighbour < *farthest,
+                    None => true,
+                };
+                if closer {
+                    candidates.push(Reverse(neighbour));
+                    nearest.push(neighbour);
+                    if nearest.len() > ef {
+                        nearest.pop();
+                    }
+                }
+            }
+        }
+
+        nearest.into_sorted_vec()
+    }
+}
+
+/// The level of a node, deterministic so that an index is rebuilt identically.
+fn random_level(node: usize) -> usize {
+    // splitmix64
+    let mut z = (node as u64).wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^= z >> 31;
+
+    // The probability of a node in the level l is 1 / M^l.
+    let uniform = (z >> 11) as f64 / (1u64 << 53) as f64;
+    let level = -(1.0 - uniform).ln() / (M as f64).ln();
+    level as usize
+}
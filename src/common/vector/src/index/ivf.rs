@@ -0,0 +1,126 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::DistanceType;
+
+const KMEANS_ITERATIONS: usize = 10;
+
+/// Inverted lists of the vectors, each list holds the positions of the
+/// vectors nearest to its centroid.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IvfLists {
+    dimension: usize,
+    /// The centroids, flattened.
+    centroids: Vec<f32>,
+    lists: Vec<Vec<usize>>,
+}
+
+impl IvfLists {
+    pub fn build(dimension: usize, vectors: &[f32], distance: DistanceType) -> Self {
+        let n = vectors.len() / dimension;
+        let nlist = ((n as f64).sqrt() as usize).max(1);
+        let vector = |i: usize| &vectors[i * dimension..(i + 1) * dimension];
+
+        // Initialize the centroids with the vectors evenly spaced, keeps the
+        // index deterministic.
+        let mut centroids = Vec::with_capacity(nlist * dimension);
+        for c in 0..nlist.min(n) {
+            centroids.extend_from_slice(vector(c * n / nlist));
+        }
+        let mut ivf = IvfLists {
+            dimension,
+            centroids,
+            lists: vec![],
+        };
+
+        for _ in 0..KMEANS_ITERATIONS {
+            let mut lists = vec![vec![]; ivf.nlist()];
+            for i in 0..n {
+                lists[ivf.nearest_centroid(vector(i), distance)].push(i);
+            }
+
+            let mut centroids = vec![0.0; ivf.centroids.len()];
+            for (c, list) in lists.iter().enumerate() {
+                let centroid = &mut centroids[c * dimension..(c + 1) * dimension];
+                if list.is_empty() {
+                    // Keep the centroid of an empty list.
+                    centroid.copy_from_slice(ivf.centroid(c));
+                    continue;
+                }
+                for &i in list {
+                    for (x, y) in centroid.iter_mut().zip(vector(i)) {
+                        *x += y;
+                    }
+                }
+                for x in centroid.iter_mut() {
+                    *x /= list.len() as f32;
+                }
+            }
+
+            let converged = lists == ivf.lists;
+            ivf.lists = lists;
+            if converged {
+                break;
+            }
+            ivf.centroids = centroids;
+        }
+        ivf
+    }
+
+    /// Search the vectors in the lists nearest to `query`, `dist(i)` is the
+    /// distance between the vector `i` and `query`. Returns all the vectors
+    /// probed, the caller re-ranks them.
+    pub fn search(
+        &self,
+        query: &[f32],
+        k: usize,
+        distance: DistanceType,
+        dist: impl Fn(usize) -> f32,
+    ) -> Vec<(usize, f32)> {
+        let nprobe = (self.nlist() / 4).max(2);
+        let mut centroids: Vec<(usize, f32)> = (0..self.nlist())
+            .map(|c| (c, distance.rank_distance(query, self.centroid(c))))
+            .collect();
+        centroids.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let mut candidates = vec![];
+        for (probed, (c, _)) in centroids.into_iter().enumerate() {
+            // Probe more lists until there are enough candidates.
+            if probed >= nprobe && candidates.len() >= k {
+                break;
+            }
+            candidates.extend(self.lists[c].iter().map(|&i| (i, dist(i))));
+        }
+        candidates
+    }
+
+    fn nlist(&self) -> usize {
+        self.centroids.len() / self.dimension
+    }
+
+    fn centroid(&self, c: usize) -> &[f32] {
+        &self.centroids[c * self.dimension..(c + 1) * self.dimension]
+    }
+
+    fn nearest_centroid(&self, vector: &[f32], distance: DistanceType) -> usize {
+        (0..self.nlist())
+            .map(|c| (c, distance.rank_distance(vector, self.centroid(c))))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(c, _)| c)
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hnsw;
+mod ivf;
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use self::hnsw::HnswGraph;
+use self::ivf::IvfLists;
+
+/// The distance function a vector index is built for.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DistanceType {
+    Cosine,
+    L2,
+    InnerProduct,
+}
+
+impl DistanceType {
+    /// The distance type computed by the scalar function `func_name`.
+    pub fn from_func_name(func_name: &str) -> Option<Self> {
+        match func_name {
+            "cosine_distance" => Some(DistanceType::Cosine),
+            "l2_distance" => Some(DistanceType::L2),
+            "inner_product" => Some(DistanceType::InnerProduct),
+            _ => None,
+        }
+    }
+
+    pub fn func_name(&self) -> &'static str {
+        match self {
+            DistanceType::Cosine => "cosine_distance",
+            DistanceType::L2 => "l2_distance",
+            DistanceType::InnerProduct => "inner_product",
+        }
+    }
+
+    /// Whether the nearer vectors have the smaller values of the function,
+    /// the inner product is a similarity instead of a distance.
+    pub fn is_ascending(&self) -> bool {
+        !matches!(self, DistanceType::InnerProduct)
+    }
+
+    /// The distance to rank the vectors in the index, the smaller the nearer.
+    /// The vectors must have the same length.
+    pub fn rank_distance(&self, a: &[f32], b: &[f32]) -> f32 {
+        match self {
+            DistanceType::Cosine => {
+                let (mut ab, mut aa, mut bb) = (0.0, 0.0, 0.0);
+                for (x, y) in a.iter().zip(b.iter()) {
+                    ab += x * y;
+                    aa += x * x;
+                    bb += y * y;
+                }
+                1.0 - ab / (aa.sqrt() * bb.sqrt())
+            }
+            DistanceType::L2 => a
+                .iter()
+                .zip(b.iter())
+                .map(|(x, y)| (x - y) * (x - y))
+                .sum::<f32>()
+                .sqrt(),
+            DistanceType::InnerProduct => -a.iter().zip(b.iter()).map(|(x, y)| x * y).sum::<f32>(),
+        }
+    }
+}
+
+impl FromStr for DistanceType {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Ok(DistanceType::Cosine),
+            "l2" => Ok(DistanceType::L2),
+            "inner_product" => Ok(DistanceType::InnerProduct),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Unknown vector distance '{}', must be one of cosine, l2 and inner_product",
+                s
+            ))),
+        }
+    }
+}
+
+impl Display for DistanceType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DistanceType::Cosine => write!(f, "cosine"),
+            DistanceType::L2 => write!(f, "l2"),
+            DistanceType::InnerProduct => write!(f, "inner_product"),
+        }
+    }
+}
+
+/// The algorithm of a vector index.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorIndexMethod {
+    /// Hierarchical navigable small world graph.
+    Hnsw,
+    /// Inverted file index, the vectors are clustered by k-means.
+    Ivf,
+}
+
+impl FromStr for VectorIndexMethod {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_uppercase().as_str() {
+            "HNSW" => Ok(VectorIndexMethod::Hnsw),
+            "IVF" => Ok(VectorIndexMethod::Ivf),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Unknown vector index method '{}', must be HNSW or IVF",
+                s
+            ))),
+        }
+    }
+}
+
+impl Display for VectorIndexMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VectorIndexMethod::Hnsw => write!(f, "HNSW"),
+            VectorIndexMethod::Ivf => write!(f, "IVF"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+enum IndexStructure {
+    Hnsw(HnswGraph),
+    Ivf(IvfLists),
+}
+
+/// An approximate nearest neighbour index of vectors of the same dimension.
+///
+/// Every vector carries a label, e.g. the block it's stored in, the search
+/// returns the labels of the nearest vectors found.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VectorIndex {
+    distance: DistanceType,
+    dimension: usize,
+    /// The vectors, flattened.
+    vectors: Vec<f32>,
+    labels: Vec<u32>,
+    structure: IndexStructure,
+}
+
+impl VectorIndex {
+    pub fn build(
+        method: VectorIndexMethod,
+        distance: DistanceType,
+        dimension: usize,
+        vectors: Vec<f32>,
+        labels: Vec<u32>,
+    ) -> Result<Self> {
+        if dimension == 0 || vectors.len() != dimension * labels.len() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vectors to index must have the same dimension {}",
+                dimension
+            )));
+        }
+
+        let vector = |i: usize| &vectors[i * dimension..(i + 1) * dimension];
+        let structure = match method {
+            VectorIndexMethod::Hnsw => {
+                IndexStructure::Hnsw(HnswGraph::build(labels.len(), |a, b| {
+                    distance.rank_distance(vector(a), vector(b))
+                }))
+            }
+            VectorIndexMethod::Ivf => {
+                IndexStructure::Ivf(IvfLists::build(dimension, &vectors, distance))
+            }
+        };
+
+        Ok(VectorIndex {
+            distance,
+            dimension,
+            vectors,
+            labels,
+            structure,
+        })
+    }
+
+    pub fn method(&self) -> VectorIndexMethod {
+        match self.structure {
+            IndexStructure::Hnsw(_) => VectorIndexMethod::Hnsw,
+            IndexStructure::Ivf(_) => VectorIndexMethod::Ivf,
+        }
+    }
+
+    pub fn distance(&self) -> DistanceType {
+        self.distance
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Search the approximate `k` nearest vectors of `query`, returns their
+    /// labels and rank distances from the nearest to the farthest.
+    pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<(u32, f32)>> {
+        if query.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                query.len(),
+                self.dimension,
+            )));
+        }
+        if k == 0 || self.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let dist = |i: usize| {
+            self.distance.rank_distance(
+                query,
+                &self.vectors[i * self.dimension..(i + 1) * self.dimension],
+            )
+        };
+        let mut nearest = match &self.structure {
+            IndexStructure::Hnsw(graph) => graph.search(k, dist),
+            IndexStructure::Ivf(lists) => lists.search(query, k, self.distance, dist),
+        };
+        nearest.sort_by(|a, b| a.1.total_cmp(&b.1));
+        nearest.truncate(k);
+        Ok(nearest
+            .into_iter()
+            .map(|(i, distance)| (self.labels[i], distance))
+            .collect())
+    }
+}
+
+/// A vector in the index with its distance to the target, ordered by the distance.
+#[derive(Clone, Copy, Debug)]
+struct Neighbour {
+    distance: f32,
+    id: usize,
+}
+
+impl PartialEq for Neighbour {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Neighbour {}
+
+impl PartialOrd for Neighbour {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Neighbour {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.id.cmp(&other.id))
+    }
+}
@@ -13,5 +13,11 @@
 // limitations under the License.
 
 mod distance;
+mod index;
 
 pub use distance::cosine_distance;
+pub use distance::inner_product;
+pub use distance::l2_distance;
+pub use index::DistanceType;
+pub use index::VectorIndex;
+pub use index::VectorIndexMethod;
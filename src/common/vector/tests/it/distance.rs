@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use common_vector::cosine_distance;
+use common_vector::inner_product;
+use common_vector::l2_distance;
 
 #[test]
 fn test_cosine() {
@@ -39,3 +41,44 @@ fn test_cosine() {
         assert!(d.is_err());
     }
 }
+
+#[test]
+fn test_l2() {
+    {
+        let x = vec![3.0, 45.0, 7.0, 2.0, 5.0, 20.0, 13.0, 12.0];
+        let y = vec![2.0, 54.0, 13.0, 15.0, 22.0, 34.0, 50.0, 1.0];
+        let d = l2_distance(&x, &y).unwrap();
+        // from scipy.spatial.distance.euclidean
+        approx::assert_relative_eq!(d, 47.560_488);
+    }
+
+    {
+        let x = vec![1.0, 2.0, 3.0];
+        let d = l2_distance(&x, &x).unwrap();
+        approx::assert_relative_eq!(d, 0.0);
+    }
+
+    {
+        let x = vec![3.0, 45.0, 7.0, 2.0, 5.0, 20.0, 13.0, 12.0];
+        let y = vec![2.0, 54.0];
+        let d = l2_distance(&x, &y);
+        assert!(d.is_err());
+    }
+}
+
+#[test]
+fn test_inner_product() {
+    {
+        let x = vec![3.0, 45.0, 7.0, 2.0, 5.0, 20.0, 13.0, 12.0];
+        let y = vec![2.0, 54.0, 13.0, 15.0, 22.0, 34.0, 50.0, 1.0];
+        let d = inner_product(&x, &y).unwrap();
+        approx::assert_relative_eq!(d, 4009.0);
+    }
+
+    {
+        let x = vec![3.0, 45.0, 7.0, 2.0, 5.0, 20.0, 13.0, 12.0];
+        let y = vec![2.0, 54.0];
+        let d = inner_product(&x, &y);
+        assert!(d.is_err());
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_vector::DistanceType;
+use common_vector::VectorIndex;
+use common_vector::VectorIndexMethod;
+
+/// Vectors on a grid, the label of a vector is its row.
+fn grid_vectors(rows: usize, cols: usize) -> (Vec<f32>, Vec<u32>) {
+    let mut vectors = vec![];
+    let mut labels = vec![];
+    for row in 0..rows {
+        for col in 0..cols {
+            vectors.push(row as f32);
+            vectors.push(col as f32);
+            labels.push(row as u32);
+        }
+    }
+    (vectors, labels)
+}
+
+#[test]
+fn test_vector_index_search() {
+    let (vectors, labels) = grid_vectors(20, 20);
+    for method in [VectorIndexMethod::Hnsw, VectorIndexMethod::Ivf] {
+        let index =
+            VectorIndex::build(method, DistanceType::L2, 2, vectors.clone(), labels.clone())
+                .unwrap();
+        assert_eq!(index.len(), 400);
+        assert_eq!(index.method(), method);
+
+        let nearest = index.search(&[7.1, 3.2], 1).unwrap();
+        assert_eq!(nearest.len(), 1);
+        assert_eq!(nearest[0].0, 7);
+
+        let nearest = index.search(&[12.0, 5.0], 5).unwrap();
+        assert_eq!(nearest.len(), 5);
+        assert!(nearest.windows(2).all(|w| w[0].1 <= w[1].1));
+        assert!(nearest.iter().all(|(label, _)| (11..=13).contains(label)));
+    }
+}
+
+#[test]
+fn test_vector_index_inner_product() {
+    let vectors = vec![1.0, 0.0, 0.0, 1.0, 3.0, 3.0, -1.0, -1.0];
+    let labels = vec![0, 1, 2, 3];
+    let index = VectorIndex::build(
+        VectorIndexMethod::Hnsw,
+        DistanceType::InnerProduct,
+        2,
+        vectors,
+        labels,
+    )
+    .unwrap();
+
+    // The largest inner product is the nearest.
+    let nearest = index.search(&[1.0, 1.0], 2).unwrap();
+    assert_eq!(nearest[0].0, 2);
+    approx::assert_relative_eq!(nearest[0].1, -6.0);
+}
+
+#[test]
+fn test_vector_index_invalid() {
+    let index = VectorIndex::build(
+        VectorIndexMethod::Hnsw,
+        DistanceType::Cosine,
+        3,
+        vec![1.0, 2.0],
+        vec![0],
+    );
+    assert!(index.is_err());
+
+    let index = VectorIndex::build(
+        VectorIndexMethod::Ivf,
+        DistanceType::Cosine,
+        2,
+        vec![1.0, 2.0],
+        vec![0],
+    )
+    .unwrap();
+    assert!(index.search(&[1.0, 2.0, 3.0], 1).is_err());
+    assert!(index.search(&[1.0, 2.0], 0).unwrap().is_empty());
+
+    assert_eq!(
+        "hnsw".parse::<VectorIndexMethod>().unwrap(),
+        VectorIndexMethod::Hnsw
+    );
+    assert_eq!("L2".parse::<DistanceType>().unwrap(), DistanceType::L2);
+    assert!("euclid".parse::<DistanceType>().is_err());
+}
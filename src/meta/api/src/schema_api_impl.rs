@@ -86,6 +86,7 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -2177,6 +2178,21 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
                 txn_req.if_then.extend(match_operations)
             }
 
+            // the offsets of the consumed streams are advanced along with the table
+            for stream_req in &req.update_stream_metas {
+                let stream_id = TableId {
+                    table_id: stream_req.stream_id,
+                };
+                let stream_meta_seq = check_stream_meta_seq(self, stream_req).await?;
+                txn_req
+                    .condition
+                    .push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
+                txn_req.if_then.push(txn_op_put(
+                    &stream_id,
+                    serialize_struct(&stream_req.new_stream_meta)?,
+                ));
+            }
+
             let (succ, responses) = send_txn(self, txn_req).await?;
 
             debug!(id = debug(&tbid), succ = display(succ), "update_table_meta");
@@ -2202,6 +2218,10 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
 
                     // check table version
                     if req_seq.match_seq(tb_meta_seq).is_ok() {
+                        // the txn may fail because one of the streams has been consumed
+                        for stream_req in &req.update_stream_metas {
+                            check_stream_meta_seq(self, stream_req).await?;
+                        }
                         // if table version does match, but tx failed,
                         if fail_if_duplicated {
                             // report file duplication error
@@ -2798,6 +2818,37 @@ async fn get_table_id_from_share_by_name(
     }
 }
 
+/// Returns the seq of the stream meta if it still matches the seq in the request.
+///
+/// A mismatch means the stream has been consumed or altered by others.
+async fn check_stream_meta_seq(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    req: &UpdateStreamMetaReq,
+) -> Result<u64, KVAppError> {
+    let stream_id = TableId {
+        table_id: req.stream_id,
+    };
+    let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
+        get_pb_value(kv_api, &stream_id).await?;
+
+    if stream_meta_seq == 0 || stream_meta.is_none() {
+        return Err(KVAppError::AppError(AppError::UnknownTableId(
+            UnknownTableId::new(req.stream_id, "update_stream_meta"),
+        )));
+    }
+    if req.seq.match_seq(stream_meta_seq).is_err() {
+        return Err(KVAppError::AppError(AppError::from(
+            TableVersionMismatched::new(
+                req.stream_id,
+                req.seq,
+                stream_meta_seq,
+                "update_stream_meta",
+            ),
+        )));
+    }
+    Ok(stream_meta_seq)
+}
+
 fn build_upsert_table_copied_file_info_conditions(
     req: &UpsertTableCopiedFileReq,
     tb_meta_seq: u64,
@@ -1903,6 +1903,7 @@ impl SchemaApiTestSuite {
                     seq: MatchSeq::Exact(table_version),
                     new_table_meta: new_table_meta.clone(),
                     copied_files: None,
+                    update_stream_metas: vec![],
                 })
                 .await?;
 
@@ -1923,6 +1924,7 @@ impl SchemaApiTestSuite {
                         seq: MatchSeq::Exact(table_version + 1),
                         new_table_meta: new_table_meta.clone(),
                         copied_files: None,
+                        update_stream_metas: vec![],
                     })
                     .await;
 
@@ -1964,6 +1966,7 @@ impl SchemaApiTestSuite {
                     seq: MatchSeq::Exact(table_version),
                     new_table_meta: new_table_meta.clone(),
                     copied_files: Some(upsert_source_table),
+                    update_stream_metas: vec![],
                 })
                 .await?;
 
@@ -2003,6 +2006,7 @@ impl SchemaApiTestSuite {
                     seq: MatchSeq::Exact(table_version),
                     new_table_meta: new_table_meta.clone(),
                     copied_files: Some(upsert_source_table),
+                    update_stream_metas: vec![],
                 })
                 .await?;
 
@@ -2043,6 +2047,7 @@ impl SchemaApiTestSuite {
                         seq: MatchSeq::Exact(table_version),
                         new_table_meta: new_table_meta.clone(),
                         copied_files: Some(upsert_source_table),
+                        update_stream_metas: vec![],
                     })
                     .await;
                 let err = result.unwrap_err();
@@ -15,8 +15,13 @@
 //! Principal is a user or role that accesses an entity.
 
 mod file_format;
+mod masking_policy;
+mod network_policy;
+mod password_policy;
 mod principal_identity;
 mod role_info;
+mod row_access_policy;
+mod sequence;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -29,9 +34,14 @@ mod user_setting;
 mod user_stage;
 
 pub use file_format::*;
+pub use masking_policy::*;
+pub use network_policy::*;
+pub use password_policy::*;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use row_access_policy::*;
+pub use sequence::*;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
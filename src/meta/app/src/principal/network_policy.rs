@@ -0,0 +1,111 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::net::IpAddr;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use ipnet::IpNet;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A named pair of allowed and blocked ip lists that can be attached to users,
+/// or to all users of a tenant by the `network_policy` setting.
+///
+/// Each entry of the lists is either a single ip address like `192.168.1.10`
+/// or a CIDR block like `192.168.1.0/24`.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct NetworkPolicy {
+    pub name: String,
+    pub allowed_ip_list: Vec<String>,
+    pub blocked_ip_list: Vec<String>,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl NetworkPolicy {
+    pub fn new(name: &str, create_on: DateTime<Utc>) -> Self {
+        NetworkPolicy {
+            name: name.to_string(),
+            create_on,
+            ..Default::default()
+        }
+    }
+
+    /// Check that every entry of the ip lists is a valid ip address or CIDR block,
+    /// and the allowed list is not empty.
+    pub fn validate(&self) -> Result<()> {
+        if self.allowed_ip_list.is_empty() {
+            return Err(ErrorCode::IllegalNetworkPolicy(format!(
+                "ALLOWED_IP_LIST of network policy '{}' can not be empty",
+                self.name
+            )));
+        }
+        for ip in self
+            .allowed_ip_list
+            .iter()
+            .chain(self.blocked_ip_list.iter())
+        {
+            parse_ip_net(ip)?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if `ip` matches any entry of the allowed list and none of the blocked list.
+    pub fn is_allowed(&self, ip: &IpAddr) -> Result<bool> {
+        for blocked in &self.blocked_ip_list {
+            if parse_ip_net(blocked)?.contains(ip) {
+                return Ok(false);
+            }
+        }
+        for allowed in &self.allowed_ip_list {
+            if parse_ip_net(allowed)?.contains(ip) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+fn parse_ip_net(ip: &str) -> Result<IpNet> {
+    if let Ok(net) = ip.parse::<IpNet>() {
+        return Ok(net);
+    }
+    match ip.parse::<IpAddr>() {
+        Ok(addr) => Ok(IpNet::from(addr)),
+        Err(_) => Err(ErrorCode::IllegalNetworkPolicy(format!(
+            "invalid ip address or CIDR block '{}'",
+            ip
+        ))),
+    }
+}
+
+impl TryFrom<Vec<u8>> for NetworkPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalNetworkPolicy(format!(
+                "Cannot deserialize network policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
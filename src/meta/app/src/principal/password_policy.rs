@@ -0,0 +1,311 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::ops::RangeInclusive;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::principal::AuthInfo;
+
+pub const DEFAULT_PASSWORD_MIN_LENGTH: u64 = 8;
+pub const DEFAULT_PASSWORD_MAX_LENGTH: u64 = 256;
+pub const DEFAULT_PASSWORD_MIN_UPPER_CASE_CHARS: u64 = 1;
+pub const DEFAULT_PASSWORD_MIN_LOWER_CASE_CHARS: u64 = 1;
+pub const DEFAULT_PASSWORD_MIN_NUMERIC_CHARS: u64 = 1;
+pub const DEFAULT_PASSWORD_MIN_SPECIAL_CHARS: u64 = 0;
+pub const DEFAULT_PASSWORD_MAX_AGE_DAYS: u64 = 90;
+pub const DEFAULT_PASSWORD_MAX_RETRIES: u64 = 5;
+pub const DEFAULT_PASSWORD_LOCKOUT_TIME_MINS: u64 = 15;
+pub const DEFAULT_PASSWORD_HISTORY: u64 = 0;
+
+pub const PASSWORD_LENGTH_RANGE: RangeInclusive<u64> = 8..=256;
+pub const PASSWORD_CHARS_RANGE: RangeInclusive<u64> = 0..=256;
+pub const PASSWORD_MAX_AGE_DAYS_RANGE: RangeInclusive<u64> = 0..=999;
+pub const PASSWORD_MAX_RETRIES_RANGE: RangeInclusive<u64> = 1..=10;
+pub const PASSWORD_LOCKOUT_TIME_MINS_RANGE: RangeInclusive<u64> = 1..=999;
+pub const PASSWORD_HISTORY_RANGE: RangeInclusive<u64> = 0..=24;
+
+/// The max number of previous passwords kept in `UserInfo::history_auth_infos`.
+pub const MAX_PASSWORD_HISTORY: usize = 24;
+
+/// A named set of password rules that can be attached to users.
+///
+/// The complexity rules are checked when a password is set by `CREATE USER` or `ALTER USER`,
+/// the retry, lockout and expiry rules are checked on every login.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(default)]
+pub struct PasswordPolicy {
+    pub name: String,
+    pub min_length: u64,
+    pub max_length: u64,
+    pub min_upper_case_chars: u64,
+    pub min_lower_case_chars: u64,
+    pub min_numeric_chars: u64,
+    pub min_special_chars: u64,
+    /// 0 means the password never expires.
+    pub max_age_days: u64,
+    pub max_retries: u64,
+    pub lockout_time_mins: u64,
+    /// The number of most recent passwords that can not be reused.
+    pub history: u64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        PasswordPolicy {
+            name: "".to_string(),
+            min_length: DEFAULT_PASSWORD_MIN_LENGTH,
+            max_length: DEFAULT_PASSWORD_MAX_LENGTH,
+            min_upper_case_chars: DEFAULT_PASSWORD_MIN_UPPER_CASE_CHARS,
+            min_lower_case_chars: DEFAULT_PASSWORD_MIN_LOWER_CASE_CHARS,
+            min_numeric_chars: DEFAULT_PASSWORD_MIN_NUMERIC_CHARS,
+            min_special_chars: DEFAULT_PASSWORD_MIN_SPECIAL_CHARS,
+            max_age_days: DEFAULT_PASSWORD_MAX_AGE_DAYS,
+            max_retries: DEFAULT_PASSWORD_MAX_RETRIES,
+            lockout_time_mins: DEFAULT_PASSWORD_LOCKOUT_TIME_MINS,
+            history: DEFAULT_PASSWORD_HISTORY,
+            comment: "".to_string(),
+            create_on: DateTime::<Utc>::default(),
+            update_on: None,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    pub fn new(name: &str, create_on: DateTime<Utc>) -> Self {
+        PasswordPolicy {
+            name: name.to_string(),
+            create_on,
+            ..Default::default()
+        }
+    }
+
+    /// Check that every option is in its allowed range and the options are consistent.
+    pub fn validate(&self) -> Result<()> {
+        check_range(
+            "PASSWORD_MIN_LENGTH",
+            self.min_length,
+            PASSWORD_LENGTH_RANGE,
+        )?;
+        check_range(
+            "PASSWORD_MAX_LENGTH",
+            self.max_length,
+            PASSWORD_LENGTH_RANGE,
+        )?;
+        check_range(
+            "PASSWORD_MIN_UPPER_CASE_CHARS",
+            self.min_upper_case_chars,
+            PASSWORD_CHARS_RANGE,
+        )?;
+        check_range(
+            "PASSWORD_MIN_LOWER_CASE_CHARS",
+            self.min_lower_case_chars,
+            PASSWORD_CHARS_RANGE,
+        )?;
+        check_range(
+            "PASSWORD_MIN_NUMERIC_CHARS",
+            self.min_numeric_chars,
+            PASSWORD_CHARS_RANGE,
+        )?;
+        check_range(
+            "PASSWORD_MIN_SPECIAL_CHARS",
+            self.min_special_chars,
+            PASSWORD_CHARS_RANGE,
+        )?;
+        check_range(
+            "PASSWORD_MAX_AGE_DAYS",
+            self.max_age_days,
+            PASSWORD_MAX_AGE_DAYS_RANGE,
+        )?;
+        check_range(
+            "PASSWORD_MAX_RETRIES",
+            self.max_retries,
+            PASSWORD_MAX_RETRIES_RANGE,
+        )?;
+        check_range(
+            "PASSWORD_LOCKOUT_TIME_MINS",
+            self.lockout_time_mins,
+            PASSWORD_LOCKOUT_TIME_MINS_RANGE,
+        )?;
+        check_range("PASSWORD_HISTORY", self.history, PASSWORD_HISTORY_RANGE)?;
+
+        if self.min_length > self.max_length {
+            return Err(ErrorCode::IllegalPasswordPolicy(format!(
+                "PASSWORD_MIN_LENGTH {} is greater than PASSWORD_MAX_LENGTH {}",
+                self.min_length, self.max_length
+            )));
+        }
+        let min_chars = self.min_upper_case_chars
+            + self.min_lower_case_chars
+            + self.min_numeric_chars
+            + self.min_special_chars;
+        if min_chars > self.max_length {
+            return Err(ErrorCode::IllegalPasswordPolicy(format!(
+                "the sum of the min chars {} is greater than PASSWORD_MAX_LENGTH {}",
+                min_chars, self.max_length
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check the length and complexity of a plaintext password.
+    pub fn check_password(&self, password: &str) -> Result<()> {
+        let mut length = 0;
+        let mut upper_case_chars = 0;
+        let mut lower_case_chars = 0;
+        let mut numeric_chars = 0;
+        let mut special_chars = 0;
+        for c in password.chars() {
+            length += 1;
+            if c.is_uppercase() {
+                upper_case_chars += 1;
+            } else if c.is_lowercase() {
+                lower_case_chars += 1;
+            } else if c.is_ascii_digit() {
+                numeric_chars += 1;
+            } else if !c.is_alphanumeric() {
+                special_chars += 1;
+            }
+        }
+
+        let mut violations = vec![];
+        if length < self.min_length {
+            violations.push(format!("at least {} characters", self.min_length));
+        }
+        if length > self.max_length {
+            violations.push(format!("at most {} characters", self.max_length));
+        }
+        if upper_case_chars < self.min_upper_case_chars {
+            violations.push(format!(
+                "at least {} upper case characters",
+                self.min_upper_case_chars
+            ));
+        }
+        if lower_case_chars < self.min_lower_case_chars {
+            violations.push(format!(
+                "at least {} lower case characters",
+                self.min_lower_case_chars
+            ));
+        }
+        if numeric_chars < self.min_numeric_chars {
+            violations.push(format!(
+                "at least {} numeric characters",
+                self.min_numeric_chars
+            ));
+        }
+        if special_chars < self.min_special_chars {
+            violations.push(format!(
+                "at least {} special characters",
+                self.min_special_chars
+            ));
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ErrorCode::InvalidPassword(format!(
+                "password does not satisfy password policy '{}', it must contain {}",
+                self.name,
+                violations.join(", ")
+            )))
+        }
+    }
+
+    /// Check the password is not one of the `history` most recent passwords.
+    ///
+    /// `history_auth_infos` is ordered from the newest to the oldest.
+    pub fn check_history(&self, password: &[u8], history_auth_infos: &[AuthInfo]) -> Result<()> {
+        let reused = history_auth_infos
+            .iter()
+            .take(self.history as usize)
+            .any(|auth_info| match auth_info {
+                AuthInfo::Password {
+                    hash_value,
+                    hash_method,
+                } => hash_method.hash(password) == *hash_value,
+                _ => false,
+            });
+        if reused {
+            return Err(ErrorCode::InvalidPassword(format!(
+                "password can not be the same as any of the last {} passwords",
+                self.history
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns true if a password set at `password_update_on` is expired at `now`.
+    pub fn is_expired(
+        &self,
+        password_update_on: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        if self.max_age_days == 0 {
+            return false;
+        }
+        match password_update_on {
+            Some(update_on) => update_on + Duration::days(self.max_age_days as i64) < now,
+            None => false,
+        }
+    }
+
+    /// Returns the time until which the user is locked out, if `password_fails`
+    /// has reached the max retries.
+    pub fn lockout_until(&self, password_fails: &[DateTime<Utc>]) -> Option<DateTime<Utc>> {
+        if (password_fails.len() as u64) < self.max_retries {
+            return None;
+        }
+        password_fails
+            .iter()
+            .max()
+            .map(|last_fail| *last_fail + Duration::minutes(self.lockout_time_mins as i64))
+    }
+}
+
+fn check_range(name: &str, value: u64, range: RangeInclusive<u64>) -> Result<()> {
+    if !range.contains(&value) {
+        return Err(ErrorCode::IllegalPasswordPolicy(format!(
+            "invalid {} value {}, must be in range {} to {}",
+            name,
+            value,
+            range.start(),
+            range.end()
+        )));
+    }
+    Ok(())
+}
+
+impl TryFrom<Vec<u8>> for PasswordPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalPasswordPolicy(format!(
+                "Cannot deserialize password policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
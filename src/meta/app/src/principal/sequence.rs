@@ -0,0 +1,108 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A named generator of unique increasing numbers, used by `nextval()` and
+/// by the `AUTOINCREMENT`/`IDENTITY` columns.
+///
+/// `next` is the first value that has not been handed out yet. Query nodes
+/// reserve ranges of values by advancing it, so the values are unique but
+/// may have gaps.
+///
+/// `id` is generated by meta when the sequence is created, it tells apart a
+/// sequence from another one created later with the same name.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(default)]
+pub struct Sequence {
+    pub id: u64,
+    pub name: String,
+    pub start: u64,
+    pub increment: u64,
+    pub next: u64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl Default for Sequence {
+    fn default() -> Self {
+        Sequence {
+            id: 0,
+            name: String::new(),
+            start: 1,
+            increment: 1,
+            next: 1,
+            comment: String::new(),
+            create_on: DateTime::<Utc>::default(),
+            update_on: None,
+        }
+    }
+}
+
+impl Sequence {
+    pub fn new(name: &str, start: u64, increment: u64, create_on: DateTime<Utc>) -> Self {
+        Sequence {
+            name: name.to_string(),
+            start,
+            increment,
+            next: start,
+            create_on,
+            ..Default::default()
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.increment == 0 {
+            return Err(ErrorCode::IllegalSequence(format!(
+                "INCREMENT of sequence '{}' can not be zero",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reserve `count` values, returns the first one of them.
+    pub fn allocate(&mut self, count: u64) -> Result<u64> {
+        let first = self.next;
+        self.next = count
+            .checked_mul(self.increment)
+            .and_then(|step| first.checked_add(step))
+            .ok_or_else(|| {
+                ErrorCode::IllegalSequence(format!("sequence '{}' is exhausted", self.name))
+            })?;
+        Ok(first)
+    }
+}
+
+impl TryFrom<Vec<u8>> for Sequence {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(sequence) => Ok(sequence),
+            Err(serialize_error) => Err(ErrorCode::IllegalSequence(format!(
+                "Cannot deserialize sequence from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -26,11 +26,13 @@ pub enum GrantObject {
     Global,
     Database(String, String),
     Table(String, String, String),
+    Column(String, String, String, String),
 }
 
 impl GrantObject {
     /// Comparing the grant objects, the Database object contains all the Table objects inside it.
-    /// Global object contains all the Database objects.
+    /// Global object contains all the Database objects, and the Table object contains all the
+    /// Column objects of it.
     pub fn contains(&self, object: &GrantObject) -> bool {
         match (self, object) {
             (GrantObject::Global, _) => true,
@@ -41,20 +43,38 @@ impl GrantObject {
             (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
                 lcat == rcat && ldb == rdb
             }
+            (GrantObject::Database(lcat, ldb), GrantObject::Column(rcat, rdb, _, _)) => {
+                lcat == rcat && ldb == rdb
+            }
             (
                 GrantObject::Table(lcat, lhs_db, lhs_table),
                 GrantObject::Table(rcat, rhs_db, rhs_table),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            (
+                GrantObject::Table(lcat, lhs_db, lhs_table),
+                GrantObject::Column(rcat, rhs_db, rhs_table, _),
+            ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
             (GrantObject::Table(_, _, _), _) => false,
+            (
+                GrantObject::Column(lcat, lhs_db, lhs_table, lhs_column),
+                GrantObject::Column(rcat, rhs_db, rhs_table, rhs_column),
+            ) => {
+                lcat == rcat
+                    && (lhs_db == rhs_db)
+                    && (lhs_table == rhs_table)
+                    && (lhs_column == rhs_column)
+            }
+            (GrantObject::Column(_, _, _, _), _) => false,
         }
     }
 
-    /// Global, database and table has different available privileges
+    /// Global, database, table and column has different available privileges
     pub fn available_privileges(&self) -> UserPrivilegeSet {
         match self {
             GrantObject::Global => UserPrivilegeSet::available_privileges_on_global(),
             GrantObject::Database(_, _) => UserPrivilegeSet::available_privileges_on_database(),
             GrantObject::Table(_, _, _) => UserPrivilegeSet::available_privileges_on_table(),
+            GrantObject::Column(_, _, _, _) => UserPrivilegeSet::available_privileges_on_column(),
         }
     }
 }
@@ -67,6 +87,9 @@ impl fmt::Display for GrantObject {
             GrantObject::Table(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::Column(ref cat, ref db, ref table, ref column) => {
+                write!(f, "'{}'.'{}'.'{}'.'{}'", cat, db, table, column)
+            }
         }
     }
 }
@@ -121,6 +144,14 @@ impl GrantEntry {
 impl fmt::Display for GrantEntry {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
         let privileges: UserPrivilegeSet = self.privileges.into();
+        // Column privileges are always listed, in the form of `GRANT SELECT ('c1') ON t`.
+        if let GrantObject::Column(cat, db, table, column) = &self.object {
+            return write!(
+                f,
+                "GRANT {} ('{}') ON '{}'.'{}'.'{}'",
+                privileges, column, cat, db, table
+            );
+        }
         let privileges_str = if self.has_all_available_privileges() {
             "ALL".to_string()
         } else {
@@ -15,6 +15,8 @@
 use core::fmt;
 use std::convert::TryFrom;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use enumflags2::bitflags;
@@ -26,6 +28,7 @@ use crate::principal::AuthInfo;
 use crate::principal::UserGrantSet;
 use crate::principal::UserIdentity;
 use crate::principal::UserQuota;
+use crate::principal::MAX_PASSWORD_HISTORY;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(default)]
@@ -41,6 +44,14 @@ pub struct UserInfo {
     pub quota: UserQuota,
 
     pub option: UserOption,
+
+    // Previous passwords, the newest first, used to prevent password reuse.
+    pub history_auth_infos: Vec<AuthInfo>,
+
+    // The times of the consecutive failed logins since the last successful one.
+    pub password_fails: Vec<DateTime<Utc>>,
+
+    pub password_update_on: Option<DateTime<Utc>>,
 }
 
 impl UserInfo {
@@ -57,6 +68,9 @@ impl UserInfo {
             grants,
             quota,
             option,
+            history_auth_infos: vec![],
+            password_fails: vec![],
+            password_update_on: None,
         }
     }
 
@@ -77,12 +91,25 @@ impl UserInfo {
 
     pub fn update_auth_option(&mut self, auth: Option<AuthInfo>, option: Option<UserOption>) {
         if let Some(auth_info) = auth {
-            self.auth_info = auth_info;
+            if auth_info != self.auth_info {
+                self.update_auth_history(auth_info);
+            }
         };
         if let Some(user_option) = option {
             self.option = user_option;
         };
     }
+
+    /// Replace the auth info, keeping the previous password in the history.
+    pub fn update_auth_history(&mut self, auth_info: AuthInfo) {
+        let old_auth_info = std::mem::replace(&mut self.auth_info, auth_info);
+        if let AuthInfo::Password { .. } = old_auth_info {
+            self.history_auth_infos.insert(0, old_auth_info);
+            self.history_auth_infos.truncate(MAX_PASSWORD_HISTORY);
+        }
+        self.password_update_on = Some(Utc::now());
+        self.password_fails.clear();
+    }
 }
 
 impl TryFrom<Vec<u8>> for UserInfo {
@@ -105,6 +132,10 @@ pub struct UserOption {
     flags: BitFlags<UserOptionFlag>,
 
     default_role: Option<String>,
+
+    password_policy: Option<String>,
+
+    network_policy: Option<String>,
 }
 
 impl UserOption {
@@ -112,6 +143,8 @@ impl UserOption {
         Self {
             flags,
             default_role: None,
+            password_policy: None,
+            network_policy: None,
         }
     }
 
@@ -129,6 +162,16 @@ impl UserOption {
         self
     }
 
+    pub fn with_password_policy(mut self, password_policy: Option<String>) -> Self {
+        self.password_policy = password_policy;
+        self
+    }
+
+    pub fn with_network_policy(mut self, network_policy: Option<String>) -> Self {
+        self.network_policy = network_policy;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -146,6 +189,22 @@ impl UserOption {
         self.default_role = default_role;
     }
 
+    pub fn password_policy(&self) -> Option<&String> {
+        self.password_policy.as_ref()
+    }
+
+    pub fn set_password_policy(&mut self, password_policy: Option<String>) {
+        self.password_policy = password_policy;
+    }
+
+    pub fn network_policy(&self) -> Option<&String> {
+        self.network_policy.as_ref()
+    }
+
+    pub fn set_network_policy(&mut self, network_policy: Option<String>) {
+        self.network_policy = network_policy;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
@@ -23,7 +23,9 @@ use std::sync::Arc;
 
 use chrono::DateTime;
 use chrono::Utc;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_meta_types::MatchSeq;
@@ -230,6 +232,18 @@ pub struct TableMeta {
     pub statistics: TableStatistics,
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
+    // Column name -> name of the masking policy attached to it.
+    pub column_mask_policy: BTreeMap<String, String>,
+    // The row access policy attached to the table.
+    pub row_access_policy: Option<String>,
+    // The columns passed to the row access policy as arguments.
+    pub row_access_policy_columns: Vec<String>,
+    // Constraint name -> expression of the CHECK constraints.
+    pub check_constraints: BTreeMap<String, String>,
+    // Tag name -> location of the tagged snapshot, which is never purged.
+    pub snapshot_tags: BTreeMap<String, String>,
+    // Index name -> `<method>:<distance>:<column>` of the vector indexes.
+    pub vector_indexes: BTreeMap<String, String>,
 }
 
 impl TableMeta {
@@ -244,9 +258,53 @@ impl TableMeta {
     }
 
     pub fn drop_column(&mut self, column: &str) -> Result<()> {
+        if self.row_access_policy_columns.iter().any(|c| c == column) {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "column '{}' is referenced by row access policy '{}'",
+                column,
+                self.row_access_policy.clone().unwrap_or_default()
+            )));
+        }
         let mut new_schema = self.schema.as_ref().to_owned();
         new_schema.drop_column(column)?;
         self.schema = Arc::new(new_schema);
+        self.column_mask_policy.remove(column);
+        Ok(())
+    }
+
+    pub fn rename_column(&mut self, column: &str, new_column: &str) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.rename_column(column, new_column)?;
+        self.schema = Arc::new(new_schema);
+        if let Some(policy) = self.column_mask_policy.remove(column) {
+            self.column_mask_policy
+                .insert(new_column.to_string(), policy);
+        }
+        for c in self.row_access_policy_columns.iter_mut() {
+            if c == column {
+                *c = new_column.to_string();
+            }
+        }
+        Ok(())
+    }
+
+    pub fn modify_column_type(&mut self, column: &str, data_type: TableDataType) -> Result<()> {
+        if let Some(policy) = self.column_mask_policy.get(column) {
+            return Err(ErrorCode::AlterColumnError(format!(
+                "column '{}' is masked by masking policy '{}', unset it before modifying the type",
+                column, policy
+            )));
+        }
+        if self.row_access_policy_columns.iter().any(|c| c == column) {
+            return Err(ErrorCode::AlterColumnError(format!(
+                "column '{}' is referenced by row access policy '{}', drop it before modifying the type",
+                column,
+                self.row_access_policy.clone().unwrap_or_default()
+            )));
+        }
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.modify_column_type(column, data_type)?;
+        self.schema = Arc::new(new_schema);
         Ok(())
     }
 }
@@ -326,6 +384,12 @@ impl Default for TableMeta {
             drop_on: None,
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
+            column_mask_policy: BTreeMap::new(),
+            row_access_policy: None,
+            row_access_policy_columns: vec![],
+            check_constraints: BTreeMap::new(),
+            snapshot_tags: BTreeMap::new(),
+            vector_indexes: BTreeMap::new(),
         }
     }
 }
@@ -567,6 +631,16 @@ pub struct UpdateTableMetaReq {
     pub seq: MatchSeq,
     pub new_table_meta: TableMeta,
     pub copied_files: Option<UpsertTableCopiedFileReq>,
+    /// Metas of the streams consumed by the statement that updates this table,
+    /// they are committed in the same transaction as the table meta.
+    pub update_stream_metas: Vec<UpdateStreamMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateStreamMetaReq {
+    pub stream_id: u64,
+    pub seq: MatchSeq,
+    pub new_stream_meta: TableMeta,
 }
 
 impl UpsertTableOptionReq {
@@ -13,6 +13,9 @@
 //  limitations under the License.
 
 mod file_format;
+mod network_policy;
+mod password_policy;
+mod sequence;
 mod user_defined_function;
 mod user_grant;
 mod user_info;
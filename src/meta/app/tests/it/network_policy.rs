@@ -0,0 +1,64 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_exception::exception::Result;
+use common_exception::ErrorCode;
+use common_meta_app::principal::NetworkPolicy;
+
+#[test]
+fn test_network_policy_validate() -> Result<()> {
+    let now = Utc.with_ymd_and_hms(2023, 4, 24, 0, 0, 0).unwrap();
+    let policy = NetworkPolicy {
+        allowed_ip_list: vec!["192.168.1.0/24".to_string(), "10.0.0.1".to_string()],
+        blocked_ip_list: vec!["192.168.1.99".to_string()],
+        ..NetworkPolicy::new("np1", now)
+    };
+    policy.validate()?;
+
+    let policy = NetworkPolicy::new("np1", now);
+    let err = policy.validate().unwrap_err();
+    assert_eq!(err.code(), ErrorCode::IllegalNetworkPolicy("").code());
+
+    let policy = NetworkPolicy {
+        allowed_ip_list: vec!["192.168.1.0/33".to_string()],
+        ..NetworkPolicy::new("np1", now)
+    };
+    let err = policy.validate().unwrap_err();
+    assert_eq!(err.code(), ErrorCode::IllegalNetworkPolicy("").code());
+
+    Ok(())
+}
+
+#[test]
+fn test_network_policy_is_allowed() -> Result<()> {
+    let now = Utc.with_ymd_and_hms(2023, 4, 24, 0, 0, 0).unwrap();
+    let policy = NetworkPolicy {
+        allowed_ip_list: vec!["192.168.1.0/24".to_string(), "10.0.0.1".to_string()],
+        blocked_ip_list: vec!["192.168.1.99".to_string()],
+        ..NetworkPolicy::new("np1", now)
+    };
+
+    let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+    assert!(policy.is_allowed(&ip("192.168.1.10"))?);
+    assert!(policy.is_allowed(&ip("10.0.0.1"))?);
+    assert!(!policy.is_allowed(&ip("192.168.1.99"))?);
+    assert!(!policy.is_allowed(&ip("10.0.0.2"))?);
+    assert!(!policy.is_allowed(&ip("::1"))?);
+
+    Ok(())
+}
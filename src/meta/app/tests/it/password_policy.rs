@@ -0,0 +1,131 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_exception::exception::Result;
+use common_exception::ErrorCode;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::PasswordHashMethod;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::UserInfo;
+
+#[test]
+fn test_password_policy_validate() -> Result<()> {
+    let now = Utc.with_ymd_and_hms(2023, 4, 20, 0, 0, 0).unwrap();
+    let policy = PasswordPolicy::new("p1", now);
+    policy.validate()?;
+
+    let policy = PasswordPolicy {
+        min_length: 20,
+        max_length: 10,
+        ..PasswordPolicy::new("p1", now)
+    };
+    let err = policy.validate().unwrap_err();
+    assert_eq!(err.code(), ErrorCode::IllegalPasswordPolicy("").code());
+
+    let policy = PasswordPolicy {
+        max_retries: 0,
+        ..PasswordPolicy::new("p1", now)
+    };
+    let err = policy.validate().unwrap_err();
+    assert_eq!(err.code(), ErrorCode::IllegalPasswordPolicy("").code());
+
+    Ok(())
+}
+
+#[test]
+fn test_password_policy_check_password() -> Result<()> {
+    let now = Utc.with_ymd_and_hms(2023, 4, 20, 0, 0, 0).unwrap();
+    let policy = PasswordPolicy {
+        min_special_chars: 1,
+        ..PasswordPolicy::new("p1", now)
+    };
+
+    policy.check_password("Databend-1")?;
+
+    for password in [
+        "Dbend-1",
+        "databend-1",
+        "DATABEND-1",
+        "Databend-x",
+        "Databend1",
+    ] {
+        let err = policy.check_password(password).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidPassword("").code());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_password_policy_check_history() -> Result<()> {
+    let now = Utc.with_ymd_and_hms(2023, 4, 20, 0, 0, 0).unwrap();
+    let policy = PasswordPolicy {
+        history: 2,
+        ..PasswordPolicy::new("p1", now)
+    };
+
+    let method = PasswordHashMethod::Sha256;
+    let password_info = |password: &str| AuthInfo::Password {
+        hash_value: method.hash(password.as_bytes()),
+        hash_method: method,
+    };
+    let mut user = UserInfo::new("u1", "%", password_info("Password1"));
+    user.update_auth_history(password_info("Password2"));
+    user.update_auth_history(password_info("Password3"));
+    assert_eq!(user.history_auth_infos.len(), 2);
+
+    let err = policy
+        .check_history(b"Password2", &user.history_auth_infos)
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidPassword("").code());
+    policy.check_history(b"Password4", &user.history_auth_infos)?;
+
+    // Only the last `history` passwords are checked.
+    user.update_auth_history(password_info("Password4"));
+    policy.check_history(b"Password1", &user.history_auth_infos)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_password_policy_expire_and_lockout() -> Result<()> {
+    let now = Utc.with_ymd_and_hms(2023, 4, 20, 0, 0, 0).unwrap();
+    let policy = PasswordPolicy {
+        max_age_days: 30,
+        max_retries: 2,
+        lockout_time_mins: 10,
+        ..PasswordPolicy::new("p1", now)
+    };
+
+    assert!(!policy.is_expired(None, now));
+    assert!(!policy.is_expired(Some(now - Duration::days(29)), now));
+    assert!(policy.is_expired(Some(now - Duration::days(31)), now));
+
+    let never_expire = PasswordPolicy {
+        max_age_days: 0,
+        ..policy.clone()
+    };
+    assert!(!never_expire.is_expired(Some(now - Duration::days(1000)), now));
+
+    assert_eq!(policy.lockout_until(&[now]), None);
+    assert_eq!(
+        policy.lockout_until(&[now - Duration::minutes(1), now]),
+        Some(now + Duration::minutes(10))
+    );
+
+    Ok(())
+}
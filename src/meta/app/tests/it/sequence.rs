@@ -0,0 +1,41 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_exception::exception::Result;
+use common_exception::ErrorCode;
+use common_meta_app::principal::Sequence;
+
+#[test]
+fn test_sequence_allocate() -> Result<()> {
+    let now = Utc.with_ymd_and_hms(2023, 5, 10, 0, 0, 0).unwrap();
+    let mut sequence = Sequence::new("seq1", 10, 5, now);
+    sequence.validate()?;
+
+    assert_eq!(sequence.allocate(3)?, 10);
+    assert_eq!(sequence.next, 25);
+    assert_eq!(sequence.allocate(1)?, 25);
+    assert_eq!(sequence.next, 30);
+
+    let mut sequence = Sequence::new("seq2", u64::MAX - 1, 1, now);
+    let err = sequence.allocate(2).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::IllegalSequence("").code());
+
+    let sequence = Sequence::new("seq3", 1, 0, now);
+    let err = sequence.validate().unwrap_err();
+    assert_eq!(err.code(), ErrorCode::IllegalSequence("").code());
+
+    Ok(())
+}
@@ -77,6 +77,31 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("default".into(), "db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::Database("default".into(), "db1".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Table("default".into(), "db1".into(), "t".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "d".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            rhs: GrantObject::Table("default".into(), "db1".into(), "t".into()),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
@@ -79,7 +79,17 @@ impl FromToProto for ex::TableField {
             p.column_id,
         )
         .with_default_expr(p.default_expr);
-        Ok(v)
+
+        let mut legacy_columns = Vec::with_capacity(p.legacy_columns.len());
+        for c in p.legacy_columns {
+            legacy_columns.push(ex::LegacyColumn::from_pb(c)?);
+        }
+        let computed_expr = match p.computed_expr {
+            Some(computed_expr) => Some(ex::ComputedExpr::from_pb(computed_expr)?),
+            None => None,
+        };
+        Ok(v.with_legacy_columns(legacy_columns)
+            .with_computed_expr(computed_expr))
     }
 
     fn to_pb(&self) -> Result<pb::DataField, Incompatible> {
@@ -90,6 +100,77 @@ impl FromToProto for ex::TableField {
             default_expr: self.default_expr().cloned(),
             data_type: Some(self.data_type().to_pb()?),
             column_id: self.column_id(),
+            legacy_columns: self
+                .legacy_columns()
+                .iter()
+                .map(|c| c.to_pb())
+                .collect::<Result<Vec<_>, _>>()?,
+            computed_expr: match self.computed_expr() {
+                Some(computed_expr) => Some(computed_expr.to_pb()?),
+                None => None,
+            },
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for ex::LegacyColumn {
+    type PB = pb::LegacyColumn;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::LegacyColumn) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = ex::LegacyColumn {
+            column_id: p.column_id,
+            data_type: ex::TableDataType::from_pb(p.data_type.ok_or_else(|| Incompatible {
+                reason: "LegacyColumn.data_type can not be None".to_string(),
+            })?)?,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::LegacyColumn, Incompatible> {
+        let p = pb::LegacyColumn {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            column_id: self.column_id,
+            data_type: Some(self.data_type.to_pb()?),
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for ex::ComputedExpr {
+    type PB = pb::ComputedExpr;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::ComputedExpr) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let computed_expr = p.computed_expr.ok_or_else(|| Incompatible {
+            reason: "ComputedExpr.computed_expr can not be None".to_string(),
+        })?;
+        let v = match computed_expr {
+            pb::computed_expr::ComputedExpr::Virtual(expr) => ex::ComputedExpr::Virtual(expr),
+            pb::computed_expr::ComputedExpr::Stored(expr) => ex::ComputedExpr::Stored(expr),
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::ComputedExpr, Incompatible> {
+        let computed_expr = match self {
+            ex::ComputedExpr::Virtual(expr) => {
+                pb::computed_expr::ComputedExpr::Virtual(expr.clone())
+            }
+            ex::ComputedExpr::Stored(expr) => pb::computed_expr::ComputedExpr::Stored(expr.clone()),
+        };
+        let p = pb::ComputedExpr {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            computed_expr: Some(computed_expr),
         };
         Ok(p)
     }
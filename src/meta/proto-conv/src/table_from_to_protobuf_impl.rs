@@ -221,6 +221,12 @@ impl FromToProto for mt::TableMeta {
                 .transpose()?
                 .unwrap_or_default(),
             shared_by: BTreeSet::from_iter(p.shared_by.into_iter()),
+            column_mask_policy: p.column_mask_policy,
+            row_access_policy: p.row_access_policy,
+            row_access_policy_columns: p.row_access_policy_columns,
+            check_constraints: p.check_constraints,
+            snapshot_tags: p.snapshot_tags,
+            vector_indexes: p.vector_indexes,
         };
         Ok(v)
     }
@@ -256,6 +262,12 @@ impl FromToProto for mt::TableMeta {
             field_comments: self.field_comments.clone(),
             statistics: Some(self.statistics.to_pb()?),
             shared_by: Vec::from_iter(self.shared_by.clone().into_iter()),
+            column_mask_policy: self.column_mask_policy.clone(),
+            row_access_policy: self.row_access_policy.clone(),
+            row_access_policy_columns: self.row_access_policy_columns.clone(),
+            check_constraints: self.check_constraints.clone(),
+            snapshot_tags: self.snapshot_tags.clone(),
+            vector_indexes: self.vector_indexes.clone(),
         };
         Ok(p)
     }
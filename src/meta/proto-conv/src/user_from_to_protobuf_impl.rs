@@ -18,6 +18,8 @@
 use std::collections::BTreeMap;
 use std::collections::HashSet;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_meta_app as mt;
 use common_protos::pb;
 use enumflags2::BitFlags;
@@ -96,7 +98,9 @@ impl FromToProto for mt::principal::UserOption {
 
         Ok(mt::principal::UserOption::default()
             .with_flags(flags)
-            .with_default_role(p.default_role))
+            .with_default_role(p.default_role)
+            .with_password_policy(p.password_policy)
+            .with_network_policy(p.network_policy))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -105,6 +109,8 @@ impl FromToProto for mt::principal::UserOption {
             min_reader_ver: MIN_READER_VER,
             flags: self.flags().bits(),
             default_role: self.default_role().cloned(),
+            password_policy: self.password_policy().cloned(),
+            network_policy: self.network_policy().cloned(),
         })
     }
 }
@@ -158,6 +164,14 @@ impl FromToProto for mt::principal::GrantObject {
                 db,
                 table,
             })) => Ok(mt::principal::GrantObject::Table(catalog, db, table)),
+            Some(pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                catalog,
+                db,
+                table,
+                column,
+            })) => Ok(mt::principal::GrantObject::Column(
+                catalog, db, table, column,
+            )),
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -182,6 +196,14 @@ impl FromToProto for mt::principal::GrantObject {
                     table: table.clone(),
                 }),
             ),
+            mt::principal::GrantObject::Column(catalog, db, table, column) => Some(
+                pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                    catalog: catalog.clone(),
+                    db: db.clone(),
+                    table: table.clone(),
+                    column: column.clone(),
+                }),
+            ),
         };
         Ok(pb::GrantObject {
             ver: VER,
@@ -292,6 +314,20 @@ impl FromToProto for mt::principal::UserInfo {
             option: mt::principal::UserOption::from_pb(p.option.ok_or_else(|| Incompatible {
                 reason: "UserInfo.option cannot be None".to_string(),
             })?)?,
+            history_auth_infos: p
+                .history_auth_infos
+                .into_iter()
+                .map(mt::principal::AuthInfo::from_pb)
+                .collect::<Result<Vec<_>, _>>()?,
+            password_fails: p
+                .password_fails
+                .into_iter()
+                .map(DateTime::<Utc>::from_pb)
+                .collect::<Result<Vec<_>, _>>()?,
+            password_update_on: match p.password_update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
         })
     }
 
@@ -305,6 +341,20 @@ impl FromToProto for mt::principal::UserInfo {
             grants: Some(mt::principal::UserGrantSet::to_pb(&self.grants)?),
             quota: Some(mt::principal::UserQuota::to_pb(&self.quota)?),
             option: Some(mt::principal::UserOption::to_pb(&self.option)?),
+            history_auth_infos: self
+                .history_auth_infos
+                .iter()
+                .map(mt::principal::AuthInfo::to_pb)
+                .collect::<Result<Vec<_>, _>>()?,
+            password_fails: self
+                .password_fails
+                .iter()
+                .map(|t| t.to_pb())
+                .collect::<Result<Vec<_>, _>>()?,
+            password_update_on: match self.password_update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
         })
     }
 }
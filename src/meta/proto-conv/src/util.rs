@@ -63,6 +63,15 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (31, "2023-02-21: Add: CopyOptions::max_files", ),
     (32, "2023-04-05: Add: file_format.proto/FileFormatParams", ),
     (33, "2023-04-13: Update: add `shared_by` field into TableMeta", ),
+    (34, "2023-04-20: Add: user.proto/UserOption::password_policy, UserInfo::history_auth_infos, password_fails, password_update_on", ),
+    (35, "2023-04-24: Add: user.proto/UserOption::network_policy", ),
+    (36, "2023-04-26: Add: table.proto/TableMeta::column_mask_policy, row_access_policy, row_access_policy_columns", ),
+    (37, "2023-04-27: Add: user.proto/GrantObject::GrantColumnObject", ),
+    (38, "2023-04-28: Add: metadata.proto/DataField::legacy_columns", ),
+    (39, "2023-05-04: Add: metadata.proto/DataField::computed_expr", ),
+    (40, "2023-05-06: Add: table.proto/TableMeta::check_constraints", ),
+    (41, "2023-05-08: Add: table.proto/TableMeta::snapshot_tags", ),
+    (42, "2023-05-09: Add: table.proto/TableMeta::vector_indexes", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
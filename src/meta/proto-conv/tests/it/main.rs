@@ -40,3 +40,12 @@ mod v030_user_stage;
 mod v031_copy_max_file;
 mod v032_file_format_params;
 mod v033_table_meta;
+mod v034_user_info;
+mod v035_user_option;
+mod v036_table_meta;
+mod v037_grant_column;
+mod v038_data_field;
+mod v039_data_field;
+mod v040_table_meta;
+mod v041_table_meta;
+mod v042_table_meta;
@@ -197,6 +197,12 @@ fn new_table_meta() -> mt::TableMeta {
         drop_on: None,
         statistics: Default::default(),
         shared_by: btreeset! {1},
+        column_mask_policy: btreemap! {s("c") => s("mask")},
+        row_access_policy: Some(s("rap")),
+        row_access_policy_columns: vec![s("a"), s("b")],
+        check_constraints: btreemap! {s("c1") => s("a > 0")},
+        snapshot_tags: btreemap! {},
+        vector_indexes: btreemap! {},
     }
 }
 
@@ -145,6 +145,12 @@ fn test_decode_v24_table_meta() -> anyhow::Result<()> {
         drop_on: None,
         statistics: Default::default(),
         shared_by: BTreeSet::new(),
+        column_mask_policy: btreemap! {},
+        row_access_policy: None,
+        row_access_policy_columns: vec![],
+        check_constraints: btreemap! {},
+        snapshot_tags: btreemap! {},
+        vector_indexes: btreemap! {},
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -0,0 +1,69 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app as mt;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v34_user_info() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 9, 116, 101, 115, 116, 95, 117, 115, 101, 114, 18, 1, 37, 26, 13, 18, 5, 10, 1, 3, 16,
+        2, 160, 6, 34, 168, 6, 24, 34, 6, 160, 6, 34, 168, 6, 24, 42, 6, 160, 6, 34, 168, 6, 24,
+        50, 10, 26, 2, 112, 49, 160, 6, 34, 168, 6, 24, 58, 13, 18, 5, 10, 1, 2, 16, 2, 160, 6, 34,
+        168, 6, 24, 58, 13, 18, 5, 10, 1, 1, 16, 1, 160, 6, 34, 168, 6, 24, 66, 23, 50, 48, 50, 51,
+        45, 48, 52, 45, 50, 48, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 74, 23, 50, 48,
+        50, 51, 45, 48, 52, 45, 49, 57, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 160, 6,
+        34, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::UserInfo {
+        name: "test_user".to_string(),
+        hostname: "%".to_string(),
+        auth_info: mt::principal::AuthInfo::Password {
+            hash_value: vec![3],
+            hash_method: mt::principal::PasswordHashMethod::Sha256,
+        },
+        grants: mt::principal::UserGrantSet::default(),
+        quota: mt::principal::UserQuota::no_limit(),
+        option: mt::principal::UserOption::default().with_password_policy(Some("p1".to_string())),
+        history_auth_infos: vec![
+            mt::principal::AuthInfo::Password {
+                hash_value: vec![2],
+                hash_method: mt::principal::PasswordHashMethod::Sha256,
+            },
+            mt::principal::AuthInfo::Password {
+                hash_value: vec![1],
+                hash_method: mt::principal::PasswordHashMethod::DoubleSha1,
+            },
+        ],
+        password_fails: vec![Utc.with_ymd_and_hms(2023, 4, 20, 10, 0, 0).unwrap()],
+        password_update_on: Some(Utc.with_ymd_and_hms(2023, 4, 19, 0, 0, 0).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 34, want())
+}
@@ -0,0 +1,44 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression as ce;
+use common_expression::types::NumberDataType;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v39_data_field() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 1, 98, 26, 17, 154, 2, 8, 66, 0, 160, 6, 39, 168, 6, 24, 160, 6, 39, 168, 6, 24, 32, 1,
+        50, 13, 18, 5, 97, 32, 43, 32, 49, 160, 6, 39, 168, 6, 24, 160, 6, 39, 168, 6, 24,
+    ];
+
+    let want = || {
+        ce::TableField::new_from_column_id("b", ce::TableDataType::Number(NumberDataType::Int64), 1)
+            .with_computed_expr(Some(ce::ComputedExpr::Stored("a + 1".to_string())))
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 39, want())
+}
@@ -1251,12 +1251,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
-            CreateTableSource::Columns(columns) => {
-                let mut children = Vec::with_capacity(columns.len());
+            CreateTableSource::Columns(columns, check_constraints) => {
+                let mut children = Vec::with_capacity(columns.len() + check_constraints.len());
                 for column in columns.iter() {
                     self.visit_column_definition(column);
                     children.push(self.children.pop().unwrap());
                 }
+                for constraint in check_constraints.iter() {
+                    self.visit_expr(&constraint.expr);
+                    let child = self.children.pop().unwrap();
+                    let name = match &constraint.name {
+                        Some(name) => format!("CheckConstraint {name}"),
+                        None => "CheckConstraint".to_string(),
+                    };
+                    let format_ctx = AstFormatContext::with_children(name, 1);
+                    children.push(FormatTreeNode::with_children(format_ctx, vec![child]));
+                }
                 let name = "ColumnsDefinition".to_string();
                 let format_ctx = AstFormatContext::with_children(name, children.len());
                 let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1274,6 +1284,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                ..
+            } => {
+                self.visit_table_ref(catalog, database, table);
+                let child = self.children.pop().unwrap();
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, 1);
+                let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1323,6 +1346,14 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                let action_name = format!("Action Rename column {} to {}", old_column, new_column);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
             AlterTableAction::DropColumn { column } => {
                 let action_name = format!("Action Drop column {}", column);
                 let action_format_ctx = AstFormatContext::new(action_name);
@@ -1362,6 +1393,44 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::with_children(action_name, 1);
                 FormatTreeNode::with_children(action_format_ctx, vec![point_node])
             }
+            AlterTableAction::ModifyColumn { column, action } => {
+                let action_name = format!("Action Modify column {} {}", column, action);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let action_name =
+                    format!("Action Add row access policy {} on ({})", policy, columns);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy => {
+                let action_name = "Action Drop row access policy".to_string();
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::CreateTag { tag, travel_point } => {
+                let action_name = format!("Action Create tag {}", tag);
+                if let Some(point) = travel_point {
+                    self.visit_time_travel_point(point);
+                    let point_node = self.children.pop().unwrap();
+                    let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                    FormatTreeNode::with_children(action_format_ctx, vec![point_node])
+                } else {
+                    let action_format_ctx = AstFormatContext::new(action_name);
+                    FormatTreeNode::new(action_format_ctx)
+                }
+            }
+            AlterTableAction::DropTag { tag } => {
+                let action_name = format!("Action Drop tag {}", tag);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1461,6 +1530,102 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        let mut table_name = "TableIdentifier ".to_string();
+        if let Some(database) = &stmt.table_database {
+            table_name.push_str(&database.to_string());
+            table_name.push('.');
+        }
+        table_name.push_str(&stmt.table.to_string());
+        let table_child = FormatTreeNode::new(AstFormatContext::new(table_name));
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_vector_index(&mut self, stmt: &'ast CreateVectorIndexStmt) {
+        let index_format_ctx =
+            AstFormatContext::new(format!("VectorIndexIdentifier {}", stmt.index));
+        let index_child = FormatTreeNode::new(index_format_ctx);
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        let column_format_ctx = AstFormatContext::new(format!("ColumnIdentifier {}", stmt.column));
+        let column_child = FormatTreeNode::new(column_format_ctx);
+        let mut method_name = format!("Method {}", stmt.method);
+        if let Some(distance) = &stmt.distance {
+            method_name.push_str(&format!(" Distance {}", distance));
+        }
+        let method_child = FormatTreeNode::new(AstFormatContext::new(method_name));
+
+        let name = "CreateVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 4);
+        let node = FormatTreeNode::with_children(format_ctx, vec![
+            index_child,
+            table_child,
+            column_child,
+            method_child,
+        ]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_vector_index(&mut self, stmt: &'ast DropVectorIndexStmt) {
+        let index_format_ctx =
+            AstFormatContext::new(format!("VectorIndexIdentifier {}", stmt.index));
+        let index_child = FormatTreeNode::new(index_format_ctx);
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_sequence(&mut self, stmt: &'ast CreateSequenceStmt) {
+        let mut children = Vec::new();
+        let sequence_format_ctx =
+            AstFormatContext::new(format!("SequenceIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(sequence_format_ctx));
+        if let Some(start) = stmt.start {
+            let start_format_ctx = AstFormatContext::new(format!("Start {}", start));
+            children.push(FormatTreeNode::new(start_format_ctx));
+        }
+        if let Some(increment) = stmt.increment {
+            let increment_format_ctx = AstFormatContext::new(format!("Increment {}", increment));
+            children.push(FormatTreeNode::new(increment_format_ctx));
+        }
+
+        let name = "CreateSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_sequence(&mut self, stmt: &'ast DropSequenceStmt) {
+        let sequence_format_ctx =
+            AstFormatContext::new(format!("SequenceIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(sequence_format_ctx);
+
+        let name = "DropSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_show_users(&mut self) {
         let name = "ShowUsers".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -1924,6 +2089,204 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_password_policy(&mut self, stmt: &'ast CreatePasswordPolicyStmt) {
+        let policy_format_ctx =
+            AstFormatContext::new(format!("PasswordPolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "CreatePasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_password_policy(&mut self, stmt: &'ast AlterPasswordPolicyStmt) {
+        let mut children = Vec::new();
+        let policy_format_ctx =
+            AstFormatContext::new(format!("PasswordPolicyIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(policy_format_ctx));
+        let action_format_ctx = AstFormatContext::new(format!("Action {}", stmt.action));
+        children.push(FormatTreeNode::new(action_format_ctx));
+
+        let name = "AlterPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_password_policy(&mut self, stmt: &'ast DropPasswordPolicyStmt) {
+        let policy_format_ctx =
+            AstFormatContext::new(format!("PasswordPolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DropPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_password_policy(&mut self, stmt: &'ast DescPasswordPolicyStmt) {
+        let policy_format_ctx =
+            AstFormatContext::new(format!("PasswordPolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DescPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_password_policies(&mut self) {
+        let name = "ShowPasswordPolicies".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_network_policy(&mut self, stmt: &'ast CreateNetworkPolicyStmt) {
+        let mut children = Vec::new();
+        let policy_format_ctx =
+            AstFormatContext::new(format!("NetworkPolicyIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(policy_format_ctx));
+        let allowed_format_ctx =
+            AstFormatContext::new(format!("AllowedIpList {:?}", stmt.allowed_ip_list));
+        children.push(FormatTreeNode::new(allowed_format_ctx));
+        if !stmt.blocked_ip_list.is_empty() {
+            let blocked_format_ctx =
+                AstFormatContext::new(format!("BlockedIpList {:?}", stmt.blocked_ip_list));
+            children.push(FormatTreeNode::new(blocked_format_ctx));
+        }
+
+        let name = "CreateNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_alter_network_policy(&mut self, stmt: &'ast AlterNetworkPolicyStmt) {
+        let mut children = Vec::new();
+        let policy_format_ctx =
+            AstFormatContext::new(format!("NetworkPolicyIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(policy_format_ctx));
+        if let Some(allowed_ip_list) = &stmt.allowed_ip_list {
+            let allowed_format_ctx =
+                AstFormatContext::new(format!("AllowedIpList {:?}", allowed_ip_list));
+            children.push(FormatTreeNode::new(allowed_format_ctx));
+        }
+        if let Some(blocked_ip_list) = &stmt.blocked_ip_list {
+            let blocked_format_ctx =
+                AstFormatContext::new(format!("BlockedIpList {:?}", blocked_ip_list));
+            children.push(FormatTreeNode::new(blocked_format_ctx));
+        }
+
+        let name = "AlterNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_network_policy(&mut self, stmt: &'ast DropNetworkPolicyStmt) {
+        let policy_format_ctx =
+            AstFormatContext::new(format!("NetworkPolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DropNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_network_policy(&mut self, stmt: &'ast DescNetworkPolicyStmt) {
+        let policy_format_ctx =
+            AstFormatContext::new(format!("NetworkPolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DescNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_masking_policy(&mut self, stmt: &'ast CreateMaskingPolicyStmt) {
+        let mut children = Vec::new();
+        let policy_format_ctx =
+            AstFormatContext::new(format!("MaskingPolicyIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(policy_format_ctx));
+        for (arg_name, arg_type) in stmt.args.iter() {
+            let arg_format_ctx = AstFormatContext::new(format!("Arg {} {}", arg_name, arg_type));
+            children.push(FormatTreeNode::new(arg_format_ctx));
+        }
+        self.visit_expr(&stmt.body);
+        children.push(self.children.pop().unwrap());
+
+        let name = "CreateMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_masking_policy(&mut self, stmt: &'ast DropMaskingPolicyStmt) {
+        let policy_format_ctx =
+            AstFormatContext::new(format!("MaskingPolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DropMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_masking_policy(&mut self, stmt: &'ast DescMaskingPolicyStmt) {
+        let policy_format_ctx =
+            AstFormatContext::new(format!("MaskingPolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DescMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let mut children = Vec::new();
+        let policy_format_ctx =
+            AstFormatContext::new(format!("RowAccessPolicyIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(policy_format_ctx));
+        for (arg_name, arg_type) in stmt.args.iter() {
+            let arg_format_ctx = AstFormatContext::new(format!("Arg {} {}", arg_name, arg_type));
+            children.push(FormatTreeNode::new(arg_format_ctx));
+        }
+        self.visit_expr(&stmt.body);
+        children.push(self.children.pop().unwrap());
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let policy_format_ctx =
+            AstFormatContext::new(format!("RowAccessPolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_row_access_policy(&mut self, stmt: &'ast DescRowAccessPolicyStmt) {
+        let policy_format_ctx =
+            AstFormatContext::new(format!("RowAccessPolicyIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DescRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_share(&mut self, stmt: &'ast CreateShareStmt) {
         let mut children = Vec::new();
         let share_format_ctx = AstFormatContext::new(format!("ShareIdentifier {}", stmt.share));
@@ -2426,6 +2789,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            TimeTravelPoint::Tag(tag) => {
+                let name = format!("Tag {}", tag);
+                let format_ctx = AstFormatContext::new(name);
+                let node = FormatTreeNode::new(format_ctx);
+                self.children.push(node);
+            }
         }
     }
 
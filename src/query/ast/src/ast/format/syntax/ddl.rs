@@ -105,14 +105,21 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
 
 fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
     match source {
-        CreateTableSource::Columns(columns) => RcDoc::space().append(parenthesized(
-            interweave_comma(
-                columns
-                    .into_iter()
-                    .map(|column| RcDoc::text(column.to_string())),
-            )
-            .group(),
-        )),
+        CreateTableSource::Columns(columns, check_constraints) => {
+            RcDoc::space().append(parenthesized(
+                interweave_comma(
+                    columns
+                        .into_iter()
+                        .map(|column| RcDoc::text(column.to_string()))
+                        .chain(
+                            check_constraints
+                                .into_iter()
+                                .map(|constraint| RcDoc::text(constraint.to_string())),
+                        ),
+                )
+                .group(),
+            ))
+        }
         CreateTableSource::Like {
             catalog,
             database,
@@ -131,6 +138,35 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(match travel_point {
+                Some(TimeTravelPoint::Snapshot(sid)) => {
+                    RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
+                }
+                Some(TimeTravelPoint::Timestamp(ts)) => {
+                    RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+                }
+                Some(TimeTravelPoint::Tag(tag)) => RcDoc::text(format!(" AT (TAG => {tag})")),
+                None => RcDoc::nil(),
+            }),
     }
 }
 
@@ -157,6 +193,14 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::AddColumn { column } => RcDoc::line()
             .append(RcDoc::text("ADD COLUMN "))
             .append(RcDoc::text(column.to_string())),
+        AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        } => RcDoc::line()
+            .append(RcDoc::text("RENAME COLUMN "))
+            .append(RcDoc::text(old_column.to_string()))
+            .append(RcDoc::text(" TO "))
+            .append(RcDoc::text(new_column.to_string())),
         AlterTableAction::DropColumn { column } => RcDoc::line()
             .append(RcDoc::text("DROP COLUMN "))
             .append(RcDoc::text(column.to_string())),
@@ -190,7 +234,32 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::RevertTo { point } => match point {
             TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
+            TimeTravelPoint::Tag(tag) => RcDoc::text(format!(" AT (TAG => {tag})")),
         },
+        AlterTableAction::ModifyColumn { column, action } => RcDoc::line()
+            .append(RcDoc::text("MODIFY COLUMN "))
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::space())
+            .append(RcDoc::text(action.to_string())),
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text("ADD ROW ACCESS POLICY "))
+            .append(RcDoc::text(policy.to_string()))
+            .append(RcDoc::text(" ON "))
+            .append(parenthesized(
+                interweave_comma(columns.into_iter().map(|c| RcDoc::text(c.to_string()))).group(),
+            )),
+        AlterTableAction::DropRowAccessPolicy => {
+            RcDoc::line().append(RcDoc::text("DROP ROW ACCESS POLICY"))
+        }
+        AlterTableAction::CreateTag { tag, travel_point } => RcDoc::line()
+            .append(RcDoc::text(format!("CREATE TAG {tag}")))
+            .append(match travel_point {
+                Some(point) => RcDoc::text(format!(" AT{point}")),
+                None => RcDoc::nil(),
+            }),
+        AlterTableAction::DropTag { tag } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP TAG {tag}")))
+        }
     }
 }
 
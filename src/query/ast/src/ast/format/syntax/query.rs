@@ -64,7 +64,12 @@ fn pretty_with(with: Option<With>) -> RcDoc<'static> {
 }
 
 fn pretty_cte(cte: CTE) -> RcDoc<'static> {
-    RcDoc::text(format!("{} AS", cte.alias))
+    let materialized = match cte.materialized {
+        Some(true) => " MATERIALIZED",
+        Some(false) => " NOT MATERIALIZED",
+        None => "",
+    };
+    RcDoc::text(format!("{} AS{}", cte.alias, materialized))
         .append(RcDoc::softline())
         .append(parenthesized(pretty_query(cte.query)))
 }
@@ -302,6 +307,8 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
         } else if let Some(TimeTravelPoint::Timestamp(ts)) = travel_point {
             RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+        } else if let Some(TimeTravelPoint::Tag(tag)) = travel_point {
+            RcDoc::text(format!(" AT (TAG => {tag})"))
         } else {
             RcDoc::nil()
         })
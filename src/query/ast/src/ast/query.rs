@@ -58,6 +58,9 @@ pub struct CTE {
     pub span: Span,
     pub alias: TableAlias,
     pub query: Query,
+    /// `MATERIALIZED` or `NOT MATERIALIZED` hint, None to let the planner choose
+    /// whether to compute the CTE once, or to inline it into each reference.
+    pub materialized: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -185,6 +188,7 @@ pub enum Indirection {
 pub enum TimeTravelPoint {
     Snapshot(String),
     Timestamp(Box<Expr>),
+    Tag(Identifier),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -385,6 +389,10 @@ impl Display for TableReference {
                     write!(f, " AT (TIMESTAMP => {ts})")?;
                 }
 
+                if let Some(TimeTravelPoint::Tag(tag)) = travel_point {
+                    write!(f, " AT (TAG => {tag})")?;
+                }
+
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
@@ -627,7 +635,13 @@ impl Display for SetExpr {
 
 impl Display for CTE {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} AS ({})", self.alias, self.query)?;
+        write!(f, "{} AS ", self.alias)?;
+        match self.materialized {
+            Some(true) => write!(f, "MATERIALIZED ")?,
+            Some(false) => write!(f, "NOT MATERIALIZED ")?,
+            None => {}
+        }
+        write!(f, "({})", self.query)?;
         Ok(())
     }
 }
@@ -683,6 +697,9 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Timestamp(ts) => {
                 write!(f, " (TIMESTAMP => {ts})")?;
             }
+            TimeTravelPoint::Tag(tag) => {
+                write!(f, " (TAG => {tag})")?;
+            }
         }
 
         Ok(())
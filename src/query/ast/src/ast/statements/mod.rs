@@ -20,16 +20,23 @@ mod database;
 mod explain;
 mod insert;
 mod kill;
+mod masking_policy;
+mod network_policy;
+mod password_policy;
 mod presign;
 mod replace;
+mod row_access_policy;
+mod sequence;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
 mod unset;
 mod update;
 mod user;
+mod vector_index;
 mod view;
 
 pub use call::*;
@@ -40,14 +47,21 @@ pub use database::*;
 pub use explain::*;
 pub use insert::*;
 pub use kill::*;
+pub use masking_policy::*;
+pub use network_policy::*;
+pub use password_policy::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
 pub use unset::*;
 pub use update::*;
 pub use user::*;
+pub use vector_index::*;
 pub use view::*;
@@ -0,0 +1,175 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatePasswordPolicyStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub set_options: PasswordSetOptions,
+}
+
+impl Display for CreatePasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE PASSWORD POLICY")?;
+        if self.if_not_exists {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {}{}", self.name, self.set_options)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterPasswordPolicyStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub action: AlterPasswordAction,
+}
+
+impl Display for AlterPasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER PASSWORD POLICY")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " {} {}", self.name, self.action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterPasswordAction {
+    SetOptions(PasswordSetOptions),
+    UnSetOptions(PasswordUnSetOptions),
+}
+
+impl Display for AlterPasswordAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlterPasswordAction::SetOptions(set_options) => write!(f, "SET{set_options}"),
+            AlterPasswordAction::UnSetOptions(unset_options) => write!(f, "UNSET{unset_options}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropPasswordPolicyStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropPasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DROP PASSWORD POLICY")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " {}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescPasswordPolicyStmt {
+    pub name: String,
+}
+
+impl Display for DescPasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DESCRIBE PASSWORD POLICY {}", self.name)
+    }
+}
+
+/// The options of `CREATE PASSWORD POLICY` and `ALTER PASSWORD POLICY ... SET`,
+/// `None` means the option is not specified.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PasswordSetOptions {
+    pub min_length: Option<u64>,
+    pub max_length: Option<u64>,
+    pub min_upper_case_chars: Option<u64>,
+    pub min_lower_case_chars: Option<u64>,
+    pub min_numeric_chars: Option<u64>,
+    pub min_special_chars: Option<u64>,
+    pub max_age_days: Option<u64>,
+    pub max_retries: Option<u64>,
+    pub lockout_time_mins: Option<u64>,
+    pub history: Option<u64>,
+    pub comment: Option<String>,
+}
+
+impl Display for PasswordSetOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let options = [
+            ("PASSWORD_MIN_LENGTH", self.min_length),
+            ("PASSWORD_MAX_LENGTH", self.max_length),
+            ("PASSWORD_MIN_UPPER_CASE_CHARS", self.min_upper_case_chars),
+            ("PASSWORD_MIN_LOWER_CASE_CHARS", self.min_lower_case_chars),
+            ("PASSWORD_MIN_NUMERIC_CHARS", self.min_numeric_chars),
+            ("PASSWORD_MIN_SPECIAL_CHARS", self.min_special_chars),
+            ("PASSWORD_MAX_AGE_DAYS", self.max_age_days),
+            ("PASSWORD_MAX_RETRIES", self.max_retries),
+            ("PASSWORD_LOCKOUT_TIME_MINS", self.lockout_time_mins),
+            ("PASSWORD_HISTORY", self.history),
+        ];
+        for (name, value) in options {
+            if let Some(value) = value {
+                write!(f, " {name} = {value}")?;
+            }
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        Ok(())
+    }
+}
+
+/// The options of `ALTER PASSWORD POLICY ... UNSET`, `true` means the option is reset to default.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PasswordUnSetOptions {
+    pub min_length: bool,
+    pub max_length: bool,
+    pub min_upper_case_chars: bool,
+    pub min_lower_case_chars: bool,
+    pub min_numeric_chars: bool,
+    pub min_special_chars: bool,
+    pub max_age_days: bool,
+    pub max_retries: bool,
+    pub lockout_time_mins: bool,
+    pub history: bool,
+    pub comment: bool,
+}
+
+impl Display for PasswordUnSetOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let options = [
+            ("PASSWORD_MIN_LENGTH", self.min_length),
+            ("PASSWORD_MAX_LENGTH", self.max_length),
+            ("PASSWORD_MIN_UPPER_CASE_CHARS", self.min_upper_case_chars),
+            ("PASSWORD_MIN_LOWER_CASE_CHARS", self.min_lower_case_chars),
+            ("PASSWORD_MIN_NUMERIC_CHARS", self.min_numeric_chars),
+            ("PASSWORD_MIN_SPECIAL_CHARS", self.min_special_chars),
+            ("PASSWORD_MAX_AGE_DAYS", self.max_age_days),
+            ("PASSWORD_MAX_RETRIES", self.max_retries),
+            ("PASSWORD_LOCKOUT_TIME_MINS", self.lockout_time_mins),
+            ("PASSWORD_HISTORY", self.history),
+            ("COMMENT", self.comment),
+        ];
+        for (name, unset) in options {
+            if unset {
+                write!(f, " {name}")?;
+            }
+        }
+        Ok(())
+    }
+}
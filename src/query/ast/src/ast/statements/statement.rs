@@ -122,6 +122,18 @@ pub enum Statement {
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
 
+    // Streams
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
+
+    // Vector indexes
+    CreateVectorIndex(CreateVectorIndexStmt),
+    DropVectorIndex(DropVectorIndexStmt),
+
+    // Sequences
+    CreateSequence(CreateSequenceStmt),
+    DropSequence(DropSequenceStmt),
+
     // User
     ShowUsers,
     CreateUser(CreateUserStmt),
@@ -145,6 +157,27 @@ pub enum Statement {
     },
     Revoke(RevokeStmt),
 
+    // Password policy
+    CreatePasswordPolicy(CreatePasswordPolicyStmt),
+    AlterPasswordPolicy(AlterPasswordPolicyStmt),
+    DropPasswordPolicy(DropPasswordPolicyStmt),
+    DescPasswordPolicy(DescPasswordPolicyStmt),
+    ShowPasswordPolicies,
+
+    // Network policy
+    CreateNetworkPolicy(CreateNetworkPolicyStmt),
+    AlterNetworkPolicy(AlterNetworkPolicyStmt),
+    DropNetworkPolicy(DropNetworkPolicyStmt),
+    DescNetworkPolicy(DescNetworkPolicyStmt),
+
+    // Data security policy
+    CreateMaskingPolicy(CreateMaskingPolicyStmt),
+    DropMaskingPolicy(DropMaskingPolicyStmt),
+    DescMaskingPolicy(DescMaskingPolicyStmt),
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    DescRowAccessPolicy(DescRowAccessPolicyStmt),
+
     // UDF
     CreateUDF {
         if_not_exists: bool,
@@ -364,6 +397,12 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateVectorIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropVectorIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
             Statement::ShowUsers => write!(f, "SHOW USERS")?,
             Statement::ShowRoles => write!(f, "SHOW ROLES")?,
             Statement::CreateUser(stmt) => write!(f, "{stmt}")?,
@@ -490,6 +529,21 @@ impl Display for Statement {
                 write!(f, " {name}")?;
             }
             Statement::ShowFileFormats => write!(f, "SHOW FILE FORMATS")?,
+            Statement::CreatePasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterPasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropPasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescPasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowPasswordPolicies => write!(f, "SHOW PASSWORD POLICIES")?,
+            Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterNetworkPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescNetworkPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaskingPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaskingPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescMaskingPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::Call(stmt) => write!(f, "{stmt}")?,
             Statement::Presign(stmt) => write!(f, "{stmt}")?,
             Statement::CreateShareEndpoint(stmt) => write!(f, "{stmt}")?,
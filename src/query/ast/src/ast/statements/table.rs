@@ -162,20 +162,30 @@ impl Display for CreateTableStmt {
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>),
+    Columns(Vec<ColumnDefinition>, Vec<CheckConstraint>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, check_constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
+                if !check_constraints.is_empty() {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, check_constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -186,7 +196,35 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckConstraint {
+    pub name: Option<Identifier>,
+    pub expr: Expr,
+}
+
+impl Display for CheckConstraint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "CONSTRAINT {name} ")?;
         }
+        write!(f, "CHECK ({})", self.expr)
     }
 }
 
@@ -285,6 +323,10 @@ pub enum AlterTableAction {
     AddColumn {
         column: ColumnDefinition,
     },
+    RenameColumn {
+        old_column: Identifier,
+        new_column: Identifier,
+    },
     DropColumn {
         column: Identifier,
     },
@@ -299,6 +341,22 @@ pub enum AlterTableAction {
     RevertTo {
         point: TimeTravelPoint,
     },
+    ModifyColumn {
+        column: Identifier,
+        action: ModifyColumnAction,
+    },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy,
+    CreateTag {
+        tag: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
+    DropTag {
+        tag: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -310,6 +368,12 @@ impl Display for AlterTableAction {
             AlterTableAction::AddColumn { column } => {
                 write!(f, "ADD COLUMN {column}")
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                write!(f, "RENAME COLUMN {old_column} TO {new_column}")
+            }
             AlterTableAction::DropColumn { column } => {
                 write!(f, "DROP COLUMN {column}")
             }
@@ -337,6 +401,50 @@ impl Display for AlterTableAction {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::ModifyColumn { column, action } => {
+                write!(f, "MODIFY COLUMN {column} {action}")
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            AlterTableAction::DropRowAccessPolicy => {
+                write!(f, "DROP ROW ACCESS POLICY")
+            }
+            AlterTableAction::CreateTag { tag, travel_point } => {
+                write!(f, "CREATE TAG {tag}")?;
+                if let Some(point) = travel_point {
+                    write!(f, " AT{point}")?;
+                }
+                Ok(())
+            }
+            AlterTableAction::DropTag { tag } => {
+                write!(f, "DROP TAG {tag}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModifyColumnAction {
+    SetMaskingPolicy(Identifier),
+    UnsetMaskingPolicy,
+    SetDataType(TypeName),
+}
+
+impl Display for ModifyColumnAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ModifyColumnAction::SetMaskingPolicy(policy) => {
+                write!(f, "SET MASKING POLICY {policy}")
+            }
+            ModifyColumnAction::UnsetMaskingPolicy => {
+                write!(f, "UNSET MASKING POLICY")
+            }
+            ModifyColumnAction::SetDataType(data_type) => {
+                write!(f, "{data_type}")
+            }
         }
     }
 }
@@ -541,9 +649,34 @@ pub struct ColumnDefinition {
     pub name: Identifier,
     pub data_type: TypeName,
     pub default_expr: Option<Box<Expr>>,
+    pub identity: Option<ColumnIdentity>,
+    pub computed_expr: Option<ComputedExpr>,
     pub comment: Option<String>,
 }
 
+/// `AUTOINCREMENT` or `IDENTITY` column, filled by a sequence created along with the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnIdentity {
+    pub start: u64,
+    pub increment: u64,
+}
+
+/// `AS (<expr>) STORED | VIRTUAL` column, generated from the other columns of the table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComputedExpr {
+    Virtual(Box<Expr>),
+    Stored(Box<Expr>),
+}
+
+impl Display for ComputedExpr {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ComputedExpr::Virtual(expr) => write!(f, "AS ({expr}) VIRTUAL"),
+            ComputedExpr::Stored(expr) => write!(f, "AS ({expr}) STORED"),
+        }
+    }
+}
+
 impl Display for ColumnDefinition {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "{} {}", self.name, self.data_type)?;
@@ -555,6 +688,12 @@ impl Display for ColumnDefinition {
         if let Some(default_expr) = &self.default_expr {
             write!(f, " DEFAULT {default_expr}")?;
         }
+        if let Some(identity) = &self.identity {
+            write!(f, " IDENTITY ({}, {})", identity.start, identity.increment)?;
+        }
+        if let Some(computed_expr) = &self.computed_expr {
+            write!(f, " {computed_expr}")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT '{comment}'")?;
         }
@@ -152,12 +152,18 @@ pub enum AccountMgrLevel {
     Global,
     Database(Option<String>),
     Table(Option<String>, String),
+    // Columns of a table, only used by column privileges like `SELECT (c1, c2) ON db.t`.
+    Column(Option<String>, String, Vec<String>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UserOptionItem {
     TenantSetting(bool),
     DefaultRole(String),
+    SetPasswordPolicy(String),
+    UnsetPasswordPolicy,
+    SetNetworkPolicy(String),
+    UnsetNetworkPolicy,
 }
 
 impl UserOptionItem {
@@ -167,6 +173,10 @@ impl UserOptionItem {
                 option.switch_option_flag(UserOptionFlag::TenantSetting, *enabled);
             }
             Self::DefaultRole(v) => option.set_default_role(Some(v.clone())),
+            Self::SetPasswordPolicy(v) => option.set_password_policy(Some(v.clone())),
+            Self::UnsetPasswordPolicy => option.set_password_policy(None),
+            Self::SetNetworkPolicy(v) => option.set_network_policy(Some(v.clone())),
+            Self::UnsetNetworkPolicy => option.set_network_policy(None),
         }
     }
 }
@@ -178,6 +188,11 @@ impl Display for AccountMgrSource {
             AccountMgrSource::Privs { privileges, level } => {
                 write!(f, " ")?;
                 write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
+                if let AccountMgrLevel::Column(_, _, columns) = level {
+                    write!(f, " (")?;
+                    write_comma_separated_list(f, columns)?;
+                    write!(f, ")")?;
+                }
                 write!(f, " ON")?;
                 match level {
                     AccountMgrLevel::Global => write!(f, " *.*")?,
@@ -188,7 +203,8 @@ impl Display for AccountMgrSource {
                             write!(f, " *")?;
                         }
                     }
-                    AccountMgrLevel::Table(database_name, table_name) => {
+                    AccountMgrLevel::Table(database_name, table_name)
+                    | AccountMgrLevel::Column(database_name, table_name, _) => {
                         if let Some(database_name) = database_name {
                             write!(f, " {database_name}.{table_name}")?;
                         } else {
@@ -199,6 +215,11 @@ impl Display for AccountMgrSource {
             }
             AccountMgrSource::ALL { level, .. } => {
                 write!(f, " ALL PRIVILEGES")?;
+                if let AccountMgrLevel::Column(_, _, columns) = level {
+                    write!(f, " (")?;
+                    write_comma_separated_list(f, columns)?;
+                    write!(f, ")")?;
+                }
                 write!(f, " ON")?;
                 match level {
                     AccountMgrLevel::Global => write!(f, " *.*")?,
@@ -209,7 +230,8 @@ impl Display for AccountMgrSource {
                             write!(f, " *")?;
                         }
                     }
-                    AccountMgrLevel::Table(database_name, table_name) => {
+                    AccountMgrLevel::Table(database_name, table_name)
+                    | AccountMgrLevel::Column(database_name, table_name, _) => {
                         if let Some(database_name) = database_name {
                             write!(f, " {database_name}.{table_name}")?;
                         } else {
@@ -229,6 +251,10 @@ impl Display for UserOptionItem {
             UserOptionItem::TenantSetting(true) => write!(f, "TENANTSETTING"),
             UserOptionItem::TenantSetting(false) => write!(f, "NOTENANTSETTING"),
             UserOptionItem::DefaultRole(v) => write!(f, "DEFAULT_ROLE = '{}'", v),
+            UserOptionItem::SetPasswordPolicy(v) => write!(f, "SET PASSWORD POLICY = '{}'", v),
+            UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
+            UserOptionItem::SetNetworkPolicy(v) => write!(f, "SET NETWORK POLICY = '{}'", v),
+            UserOptionItem::UnsetNetworkPolicy => write!(f, "UNSET NETWORK POLICY"),
         }
     }
 }
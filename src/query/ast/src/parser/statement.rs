@@ -28,6 +28,7 @@ use nom::branch::alt;
 use nom::combinator::consumed;
 use nom::combinator::map;
 use nom::combinator::value;
+use nom::combinator::verify;
 use nom::Slice;
 
 use crate::ast::*;
@@ -474,7 +475,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ #create_table_source?
             ~ ( #engine )?
             ~ ( #uri_location )?
-            ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( CLUSTER ~ ^BY ~ ^#cluster_by )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -500,7 +501,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 engine,
                 uri_location,
                 cluster_by: opt_cluster_by
-                    .map(|(_, _, _, exprs, _)| exprs)
+                    .map(|(_, _, exprs)| exprs)
                     .unwrap_or_default(),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
@@ -652,6 +653,126 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #period_separated_idents_1_to_2
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, stream),
+            _,
+            _,
+            (table_database, table),
+            opt_comment,
+        )| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
+
+    let create_vector_index = map(
+        rule! {
+            CREATE ~ VECTOR ~ INDEX ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ON ~ #period_separated_idents_1_to_3 ~ "(" ~ #ident ~ ")"
+            ~ USING ~ #ident
+            ~ ( DISTANCE ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            index,
+            _,
+            (catalog, database, table),
+            _,
+            column,
+            _,
+            _,
+            method,
+            opt_distance,
+        )| {
+            Statement::CreateVectorIndex(CreateVectorIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index,
+                catalog,
+                database,
+                table,
+                column,
+                method,
+                distance: opt_distance.map(|(_, _, distance)| distance),
+            })
+        },
+    );
+    let drop_vector_index = map(
+        rule! {
+            DROP ~ VECTOR ~ INDEX ~ ( IF ~ EXISTS )? ~ #ident
+            ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index, _, (catalog, database, table))| {
+            Statement::DropVectorIndex(DropVectorIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ( START ~ ( WITH | "=" )? ~ ^#literal_u64 )?
+            ~ ( INCREMENT ~ ( BY | "=" )? ~ ^#literal_u64 )?
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, _, opt_if_not_exists, name, opt_start, opt_increment, opt_comment)| {
+            Statement::CreateSequence(CreateSequenceStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                start: opt_start.map(|(_, _, start)| start),
+                increment: opt_increment.map(|(_, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
     let alter_view = map(
         rule! {
             ALTER ~ VIEW
@@ -1124,6 +1245,214 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
 
     let show_file_formats = value(Statement::ShowFileFormats, rule! { SHOW ~ FILE ~ FORMATS });
 
+    let create_password_policy = map(
+        rule! {
+            CREATE ~ PASSWORD ~ POLICY ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ #password_set_options
+        },
+        |(_, _, _, opt_if_not_exists, name, set_options)| {
+            Statement::CreatePasswordPolicy(CreatePasswordPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                set_options,
+            })
+        },
+    );
+    let alter_password_policy = map(
+        rule! {
+            ALTER ~ PASSWORD ~ POLICY ~ ( IF ~ EXISTS )?
+            ~ #ident ~ #alter_password_action
+        },
+        |(_, _, _, opt_if_exists, name, action)| {
+            Statement::AlterPasswordPolicy(AlterPasswordPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                action,
+            })
+        },
+    );
+    let drop_password_policy = map(
+        rule! {
+            DROP ~ PASSWORD ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropPasswordPolicy(DropPasswordPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let desc_password_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ PASSWORD ~ POLICY ~ #ident
+        },
+        |(_, _, _, name)| {
+            Statement::DescPasswordPolicy(DescPasswordPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_password_policies = value(
+        Statement::ShowPasswordPolicies,
+        rule! { SHOW ~ PASSWORD ~ POLICIES },
+    );
+    let create_network_policy = map(
+        rule! {
+            CREATE ~ NETWORK ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ALLOWED_IP_LIST ~ ^"=" ~ ^#ip_list
+            ~ ( BLOCKED_IP_LIST ~ ^"=" ~ ^#ip_list )?
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            allowed_ip_list,
+            opt_blocked_ip_list,
+            opt_comment,
+        )| {
+            Statement::CreateNetworkPolicy(CreateNetworkPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                allowed_ip_list,
+                blocked_ip_list: opt_blocked_ip_list
+                    .map(|(_, _, ip_list)| ip_list)
+                    .unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let alter_network_policy = map(
+        rule! {
+            ALTER ~ NETWORK ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident ~ SET
+            ~ ( ALLOWED_IP_LIST ~ ^"=" ~ ^#ip_list )?
+            ~ ( BLOCKED_IP_LIST ~ ^"=" ~ ^#ip_list )?
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_exists,
+            name,
+            _,
+            opt_allowed_ip_list,
+            opt_blocked_ip_list,
+            opt_comment,
+        )| {
+            Statement::AlterNetworkPolicy(AlterNetworkPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                allowed_ip_list: opt_allowed_ip_list.map(|(_, _, ip_list)| ip_list),
+                blocked_ip_list: opt_blocked_ip_list.map(|(_, _, ip_list)| ip_list),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_network_policy = map(
+        rule! {
+            DROP ~ NETWORK ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropNetworkPolicy(DropNetworkPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let desc_network_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ NETWORK ~ POLICY ~ #ident
+        },
+        |(_, _, _, name)| {
+            Statement::DescNetworkPolicy(DescNetworkPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
+    let create_masking_policy = map(
+        rule! {
+            CREATE ~ MASKING ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ AS ~ #policy_args
+            ~ RETURNS ~ ^#type_name ~ ^"->" ~ ^#expr
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, _, _, opt_if_not_exists, name, _, args, _, return_type, _, body, opt_comment)| {
+            Statement::CreateMaskingPolicy(CreateMaskingPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                args,
+                return_type,
+                body,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_masking_policy = map(
+        rule! {
+            DROP ~ MASKING ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropMaskingPolicy(DropMaskingPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let desc_masking_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ MASKING ~ POLICY ~ #ident
+        },
+        |(_, _, _, name)| {
+            Statement::DescMaskingPolicy(DescMaskingPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ AS ~ #policy_args
+            ~ RETURNS ~ ^BOOLEAN ~ ^"->" ~ ^#expr
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, _, _, _, opt_if_not_exists, name, _, args, _, _, _, body, opt_comment)| {
+            Statement::CreateRowAccessPolicy(CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                args,
+                body,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let desc_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| {
+            Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
@@ -1155,6 +1484,25 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #set_variable : "`SET <variable> = <value>`"
             | #unset_variable : "`UNSET <variable>`"
         ),
+        // password, network and data security policy, must be parsed before `DESC <table>`
+        // since `PASSWORD`, `NETWORK`, `MASKING` and `ROW` are not reserved
+        rule!(
+            #create_password_policy: "`CREATE PASSWORD POLICY [IF NOT EXISTS] <policy_name> [PASSWORD_MIN_LENGTH = <u64_literal>] ... [COMMENT = '<string_literal>']`"
+            | #alter_password_policy: "`ALTER PASSWORD POLICY [IF EXISTS] <policy_name> { SET [PASSWORD_MIN_LENGTH = <u64_literal>] ... [COMMENT = '<string_literal>'] | UNSET [PASSWORD_MIN_LENGTH] ... [COMMENT] }`"
+            | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] <policy_name>`"
+            | #desc_password_policy: "`{DESC | DESCRIBE} PASSWORD POLICY <policy_name>`"
+            | #show_password_policies: "`SHOW PASSWORD POLICIES`"
+            | #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] <policy_name> ALLOWED_IP_LIST = ('<ip>', ...) [BLOCKED_IP_LIST = ('<ip>', ...)] [COMMENT = '<string_literal>']`"
+            | #alter_network_policy: "`ALTER NETWORK POLICY [IF EXISTS] <policy_name> SET [ALLOWED_IP_LIST = ('<ip>', ...)] [BLOCKED_IP_LIST = ('<ip>', ...)] [COMMENT = '<string_literal>']`"
+            | #drop_network_policy: "`DROP NETWORK POLICY [IF EXISTS] <policy_name>`"
+            | #desc_network_policy: "`{DESC | DESCRIBE} NETWORK POLICY <policy_name>`"
+            | #create_masking_policy: "`CREATE MASKING POLICY [IF NOT EXISTS] <policy_name> AS (<arg_name> <type>, ...) RETURNS <type> -> <expr> [COMMENT = '<string_literal>']`"
+            | #drop_masking_policy: "`DROP MASKING POLICY [IF EXISTS] <policy_name>`"
+            | #desc_masking_policy: "`{DESC | DESCRIBE} MASKING POLICY <policy_name>`"
+            | #create_row_access_policy: "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <policy_name> AS (<arg_name> <type>, ...) RETURNS BOOLEAN -> <expr> [COMMENT = '<string_literal>']`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] <policy_name>`"
+            | #desc_row_access_policy: "`{DESC | DESCRIBE} ROW ACCESS POLICY <policy_name>`"
+        ),
         rule!(
             #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
             | #show_columns : "`SHOW [FULL] COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
@@ -1178,6 +1526,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
         ),
+        rule!(
+            #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [COMMENT = '<comment>']`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
+            | #create_vector_index : "`CREATE VECTOR INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>) USING {HNSW | IVF} [DISTANCE = '<distance>']`"
+            | #drop_vector_index : "`DROP VECTOR INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #create_sequence : "`CREATE SEQUENCE [IF NOT EXISTS] <sequence> [START [WITH | =] <u64_literal>] [INCREMENT [BY | =] <u64_literal>] [COMMENT = '<comment>']`"
+            | #drop_sequence : "`DROP SEQUENCE [IF EXISTS] <sequence>`"
+        ),
         rule!(
             #show_users : "`SHOW USERS`"
             | #create_user : "`CREATE USER [IF NOT EXISTS] '<username>'@'hostname' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
@@ -1340,6 +1696,8 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     enum ColumnConstraint {
         Nullable(bool),
         DefaultExpr(Box<Expr>),
+        Identity(ColumnIdentity),
+        ComputedExpr(ComputedExpr),
     }
 
     let nullable = alt((
@@ -1353,6 +1711,44 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         |(_, default_expr)| ColumnConstraint::DefaultExpr(Box::new(default_expr)),
     );
 
+    let identity_args = alt((
+        map(
+            rule! {
+                "(" ~ ^#literal_u64 ~ ^"," ~ ^#literal_u64 ~ ^")"
+            },
+            |(_, start, _, increment, _)| (start, increment),
+        ),
+        map(
+            rule! {
+                START ~ ^#literal_u64 ~ ^INCREMENT ~ ^#literal_u64
+            },
+            |(_, start, _, increment)| (start, increment),
+        ),
+    ));
+    let identity = map(
+        rule! {
+            ( AUTOINCREMENT | IDENTITY ) ~ #identity_args?
+        },
+        |(_, args)| {
+            let (start, increment) = args.unwrap_or((1, 1));
+            ColumnConstraint::Identity(ColumnIdentity { start, increment })
+        },
+    );
+
+    let computed_expr = map(
+        rule! {
+            AS ~ ^"(" ~ ^#subexpr(0) ~ ^")" ~ ^( VIRTUAL | STORED )
+        },
+        |(_, _, expr, _, kind)| {
+            let expr = Box::new(expr);
+            if kind.kind == VIRTUAL {
+                ColumnConstraint::ComputedExpr(ComputedExpr::Virtual(expr))
+            } else {
+                ColumnConstraint::ComputedExpr(ComputedExpr::Stored(expr))
+            }
+        },
+    );
+
     let comment = map(
         rule! {
             COMMENT ~ #literal_string
@@ -1364,15 +1760,17 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         rule! {
             #ident
             ~ #type_name
-            ~ ( #nullable | #default_expr )*
+            ~ ( #nullable | #default_expr | #identity | #computed_expr )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <default value>] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <default value> | AUTOINCREMENT [(<start>, <increment>)] | AS (<expr>) STORED | VIRTUAL] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let mut def = ColumnDefinition {
                 name,
                 data_type,
                 default_expr: None,
+                identity: None,
+                computed_expr: None,
                 comment,
             };
             for constraint in constraints {
@@ -1380,6 +1778,10 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
                     ColumnConstraint::DefaultExpr(default_expr) => {
                         def.default_expr = Some(default_expr)
                     }
+                    ColumnConstraint::Identity(identity) => def.identity = Some(identity),
+                    ColumnConstraint::ComputedExpr(computed_expr) => {
+                        def.computed_expr = Some(computed_expr)
+                    }
                     ColumnConstraint::Nullable(nullable) => {
                         if nullable {
                             def.data_type = def.data_type.wrap_nullable();
@@ -1399,6 +1801,20 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         },
         |(_, role_name)| AccountMgrSource::Role { role: role_name },
     );
+    let column_privs = map(
+        rule! {
+            #comma_separated_list1(priv_type) ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+            ~ ON ~ ( #ident ~ "." )? ~ #parameter_to_string
+        },
+        |(privs, _, columns, _, _, database, table)| AccountMgrSource::Privs {
+            privileges: privs,
+            level: AccountMgrLevel::Column(
+                database.map(|(database, _)| database.name),
+                table,
+                columns.into_iter().map(|column| column.name).collect(),
+            ),
+        },
+    );
     let privs = map(
         rule! {
             #comma_separated_list1(priv_type) ~ ON ~ #grant_level
@@ -1415,6 +1831,7 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
 
     rule!(
         #role : "ROLE <role_name>"
+        | #column_privs : "<privileges> (<column>, ...) ON <database>.<table>"
         | #privs : "<privileges> ON <privileges_level>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
@@ -1559,12 +1976,44 @@ pub fn grant_option(i: Input) -> IResult<PrincipalIdentity> {
     )(i)
 }
 
+pub fn check_constraint(i: Input) -> IResult<CheckConstraint> {
+    map(
+        rule! {
+            ( CONSTRAINT ~ ^#ident )? ~ CHECK ~ "(" ~ ^#expr ~ ^")"
+        },
+        |(opt_name, _, _, expr, _)| CheckConstraint {
+            name: opt_name.map(|(_, name)| name),
+            expr,
+        },
+    )(i)
+}
+
 pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
+    #[derive(Clone)]
+    enum TableElement {
+        Column(ColumnDefinition),
+        Check(CheckConstraint),
+    }
+
+    let table_element = alt((
+        map(check_constraint, TableElement::Check),
+        map(column_def, TableElement::Column),
+    ));
     let columns = map(
         rule! {
-            "(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+            "(" ~ ^#comma_separated_list1(table_element) ~ ^")"
+        },
+        |(_, elements, _)| {
+            let mut columns = Vec::with_capacity(elements.len());
+            let mut check_constraints = Vec::new();
+            for element in elements {
+                match element {
+                    TableElement::Column(column) => columns.push(column),
+                    TableElement::Check(constraint) => check_constraints.push(constraint),
+                }
+            }
+            CreateTableSource::Columns(columns, check_constraints)
         },
-        |(_, columns, _)| CreateTableSource::Columns(columns),
     );
     let like = map(
         rule! {
@@ -1577,9 +2026,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone = map(
+        rule! {
+            CLONE ~ #period_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, point)| point),
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -1603,6 +2065,15 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
         |(_, _, new_table)| AlterTableAction::RenameTable { new_table },
     );
+    let rename_column = map(
+        rule! {
+            RENAME ~ COLUMN ~ #ident ~ TO ~ #ident
+        },
+        |(_, _, old_column, _, new_column)| AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        },
+    );
     let add_column = map(
         rule! {
             ADD ~ COLUMN ~ #column_def
@@ -1617,9 +2088,9 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     );
     let alter_table_cluster_key = map(
         rule! {
-            CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+            CLUSTER ~ ^BY ~ ^#cluster_by
         },
-        |(_, _, _, cluster_by, _)| AlterTableAction::AlterTableClusterKey { cluster_by },
+        |(_, _, cluster_by)| AlterTableAction::AlterTableClusterKey { cluster_by },
     );
 
     let drop_table_cluster_key = map(
@@ -1646,14 +2117,110 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, point)| AlterTableAction::RevertTo { point },
     );
 
+    let modify_column = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ #modify_column_action
+        },
+        |(_, _, column, action)| AlterTableAction::ModifyColumn { column, action },
+    );
+
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY
+        },
+        |(_, _, _, _)| AlterTableAction::DropRowAccessPolicy,
+    );
+
+    let create_tag = map(
+        rule! {
+            CREATE ~ TAG ~ #ident ~ (AT ~ ^#travel_point)?
+        },
+        |(_, _, tag, opt_travel_point)| AlterTableAction::CreateTag {
+            tag,
+            travel_point: opt_travel_point.map(|(_, p)| p),
+        },
+    );
+
+    let drop_tag = map(
+        rule! {
+            DROP ~ TAG ~ #ident
+        },
+        |(_, _, tag)| AlterTableAction::DropTag { tag },
+    );
+
     rule!(
         #rename_table
+        | #rename_column
         | #add_column
         | #drop_column
         | #alter_table_cluster_key
         | #drop_table_cluster_key
         | #recluster_table
         | #revert_table
+        | #modify_column
+        | #add_row_access_policy
+        | #drop_row_access_policy
+        | #create_tag
+        | #drop_tag
+    )(i)
+}
+
+pub fn cluster_by(i: Input) -> IResult<Vec<Expr>> {
+    let linear = map(
+        rule! {
+            "(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, exprs, _)| exprs,
+    );
+    // `CLUSTER BY HILBERT(a, b)` is short for `CLUSTER BY (HILBERT(a, b))`.
+    let curve = map(
+        verify(expr, |expr: &Expr| {
+            matches!(
+                expr,
+                Expr::FunctionCall { name, .. }
+                    if name.name.eq_ignore_ascii_case("hilbert")
+                        || name.name.eq_ignore_ascii_case("zorder")
+            )
+        }),
+        |expr| vec![expr],
+    );
+
+    rule!(
+        #linear
+        | #curve
+    )(i)
+}
+
+pub fn modify_column_action(i: Input) -> IResult<ModifyColumnAction> {
+    alt((
+        map(
+            rule! { SET ~ MASKING ~ POLICY ~ #ident },
+            |(_, _, _, policy)| ModifyColumnAction::SetMaskingPolicy(policy),
+        ),
+        value(
+            ModifyColumnAction::UnsetMaskingPolicy,
+            rule! { UNSET ~ MASKING ~ POLICY },
+        ),
+        map(rule! { #type_name }, ModifyColumnAction::SetDataType),
+    ))(i)
+}
+
+pub fn policy_args(i: Input) -> IResult<Vec<(Identifier, TypeName)>> {
+    map(
+        rule! {
+            "(" ~ #comma_separated_list1(rule! { #ident ~ #type_name }) ~ ")"
+        },
+        |(_, args, _)| args,
     )(i)
 }
 
@@ -1840,6 +2407,18 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, role)| UserOptionItem::DefaultRole(role),
     );
+    let set_password_policy_option = map(
+        rule! {
+            SET ~ PASSWORD ~ POLICY ~ "=" ~ #literal_string
+        },
+        |(_, _, _, _, policy)| UserOptionItem::SetPasswordPolicy(policy),
+    );
+    let set_network_policy_option = map(
+        rule! {
+            SET ~ NETWORK ~ POLICY ~ "=" ~ #literal_string
+        },
+        |(_, _, _, _, policy)| UserOptionItem::SetNetworkPolicy(policy),
+    );
     alt((
         value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING }),
         value(
@@ -1847,9 +2426,134 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
             rule! { NOTENANTSETTING },
         ),
         default_role_option,
+        set_password_policy_option,
+        value(
+            UserOptionItem::UnsetPasswordPolicy,
+            rule! { UNSET ~ PASSWORD ~ POLICY },
+        ),
+        set_network_policy_option,
+        value(
+            UserOptionItem::UnsetNetworkPolicy,
+            rule! { UNSET ~ NETWORK ~ POLICY },
+        ),
     ))(i)
 }
 
+pub fn ip_list(i: Input) -> IResult<Vec<String>> {
+    map(
+        rule! {
+            "(" ~ #comma_separated_list0(literal_string) ~ ")"
+        },
+        |(_, ip_list, _)| ip_list,
+    )(i)
+}
+
+pub fn password_set_options(i: Input) -> IResult<PasswordSetOptions> {
+    map(
+        rule! {
+            ( PASSWORD_MIN_LENGTH ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( PASSWORD_MAX_LENGTH ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( PASSWORD_MIN_UPPER_CASE_CHARS ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( PASSWORD_MIN_LOWER_CASE_CHARS ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( PASSWORD_MIN_NUMERIC_CHARS ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( PASSWORD_MIN_SPECIAL_CHARS ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( PASSWORD_MAX_AGE_DAYS ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( PASSWORD_MAX_RETRIES ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( PASSWORD_LOCKOUT_TIME_MINS ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( PASSWORD_HISTORY ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            opt_min_length,
+            opt_max_length,
+            opt_min_upper_case_chars,
+            opt_min_lower_case_chars,
+            opt_min_numeric_chars,
+            opt_min_special_chars,
+            opt_max_age_days,
+            opt_max_retries,
+            opt_lockout_time_mins,
+            opt_history,
+            opt_comment,
+        )| PasswordSetOptions {
+            min_length: opt_min_length.map(|(_, _, v)| v),
+            max_length: opt_max_length.map(|(_, _, v)| v),
+            min_upper_case_chars: opt_min_upper_case_chars.map(|(_, _, v)| v),
+            min_lower_case_chars: opt_min_lower_case_chars.map(|(_, _, v)| v),
+            min_numeric_chars: opt_min_numeric_chars.map(|(_, _, v)| v),
+            min_special_chars: opt_min_special_chars.map(|(_, _, v)| v),
+            max_age_days: opt_max_age_days.map(|(_, _, v)| v),
+            max_retries: opt_max_retries.map(|(_, _, v)| v),
+            lockout_time_mins: opt_lockout_time_mins.map(|(_, _, v)| v),
+            history: opt_history.map(|(_, _, v)| v),
+            comment: opt_comment.map(|(_, _, v)| v),
+        },
+    )(i)
+}
+
+pub fn password_unset_options(i: Input) -> IResult<PasswordUnSetOptions> {
+    map(
+        rule! {
+            PASSWORD_MIN_LENGTH?
+            ~ PASSWORD_MAX_LENGTH?
+            ~ PASSWORD_MIN_UPPER_CASE_CHARS?
+            ~ PASSWORD_MIN_LOWER_CASE_CHARS?
+            ~ PASSWORD_MIN_NUMERIC_CHARS?
+            ~ PASSWORD_MIN_SPECIAL_CHARS?
+            ~ PASSWORD_MAX_AGE_DAYS?
+            ~ PASSWORD_MAX_RETRIES?
+            ~ PASSWORD_LOCKOUT_TIME_MINS?
+            ~ PASSWORD_HISTORY?
+            ~ COMMENT?
+        },
+        |(
+            opt_min_length,
+            opt_max_length,
+            opt_min_upper_case_chars,
+            opt_min_lower_case_chars,
+            opt_min_numeric_chars,
+            opt_min_special_chars,
+            opt_max_age_days,
+            opt_max_retries,
+            opt_lockout_time_mins,
+            opt_history,
+            opt_comment,
+        )| PasswordUnSetOptions {
+            min_length: opt_min_length.is_some(),
+            max_length: opt_max_length.is_some(),
+            min_upper_case_chars: opt_min_upper_case_chars.is_some(),
+            min_lower_case_chars: opt_min_lower_case_chars.is_some(),
+            min_numeric_chars: opt_min_numeric_chars.is_some(),
+            min_special_chars: opt_min_special_chars.is_some(),
+            max_age_days: opt_max_age_days.is_some(),
+            max_retries: opt_max_retries.is_some(),
+            lockout_time_mins: opt_lockout_time_mins.is_some(),
+            history: opt_history.is_some(),
+            comment: opt_comment.is_some(),
+        },
+    )(i)
+}
+
+pub fn alter_password_action(i: Input) -> IResult<AlterPasswordAction> {
+    let set_options = map(
+        rule! {
+            SET ~ #password_set_options
+        },
+        |(_, set_options)| AlterPasswordAction::SetOptions(set_options),
+    );
+    let unset_options = map(
+        rule! {
+            UNSET ~ #password_unset_options
+        },
+        |(_, unset_options)| AlterPasswordAction::UnSetOptions(unset_options),
+    );
+
+    rule!(
+        #set_options
+        | #unset_options
+    )(i)
+}
+
 pub fn user_identity(i: Input) -> IResult<UserIdentity> {
     map(
         rule! {
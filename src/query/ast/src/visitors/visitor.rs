@@ -448,6 +448,18 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
+
+    fn visit_create_vector_index(&mut self, _stmt: &'ast CreateVectorIndexStmt) {}
+
+    fn visit_drop_vector_index(&mut self, _stmt: &'ast DropVectorIndexStmt) {}
+
+    fn visit_create_sequence(&mut self, _stmt: &'ast CreateSequenceStmt) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &'ast DropSequenceStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &'ast CreateUserStmt) {}
@@ -468,6 +480,36 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_revoke(&mut self, _revoke: &'ast RevokeStmt) {}
 
+    fn visit_create_password_policy(&mut self, _stmt: &'ast CreatePasswordPolicyStmt) {}
+
+    fn visit_alter_password_policy(&mut self, _stmt: &'ast AlterPasswordPolicyStmt) {}
+
+    fn visit_drop_password_policy(&mut self, _stmt: &'ast DropPasswordPolicyStmt) {}
+
+    fn visit_desc_password_policy(&mut self, _stmt: &'ast DescPasswordPolicyStmt) {}
+
+    fn visit_show_password_policies(&mut self) {}
+
+    fn visit_create_network_policy(&mut self, _stmt: &'ast CreateNetworkPolicyStmt) {}
+
+    fn visit_alter_network_policy(&mut self, _stmt: &'ast AlterNetworkPolicyStmt) {}
+
+    fn visit_drop_network_policy(&mut self, _stmt: &'ast DropNetworkPolicyStmt) {}
+
+    fn visit_desc_network_policy(&mut self, _stmt: &'ast DescNetworkPolicyStmt) {}
+
+    fn visit_create_masking_policy(&mut self, _stmt: &'ast CreateMaskingPolicyStmt) {}
+
+    fn visit_drop_masking_policy(&mut self, _stmt: &'ast DropMaskingPolicyStmt) {}
+
+    fn visit_desc_masking_policy(&mut self, _stmt: &'ast DescMaskingPolicyStmt) {}
+
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &'ast DescRowAccessPolicyStmt) {}
+
     fn visit_create_udf(
         &mut self,
         _if_not_exists: bool,
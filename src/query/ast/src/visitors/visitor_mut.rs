@@ -449,6 +449,18 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
+
+    fn visit_create_vector_index(&mut self, _stmt: &mut CreateVectorIndexStmt) {}
+
+    fn visit_drop_vector_index(&mut self, _stmt: &mut DropVectorIndexStmt) {}
+
+    fn visit_create_sequence(&mut self, _stmt: &mut CreateSequenceStmt) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &mut DropSequenceStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &mut CreateUserStmt) {}
@@ -469,6 +481,36 @@ pub trait VisitorMut: Sized {
 
     fn visit_revoke(&mut self, _revoke: &mut RevokeStmt) {}
 
+    fn visit_create_password_policy(&mut self, _stmt: &mut CreatePasswordPolicyStmt) {}
+
+    fn visit_alter_password_policy(&mut self, _stmt: &mut AlterPasswordPolicyStmt) {}
+
+    fn visit_drop_password_policy(&mut self, _stmt: &mut DropPasswordPolicyStmt) {}
+
+    fn visit_desc_password_policy(&mut self, _stmt: &mut DescPasswordPolicyStmt) {}
+
+    fn visit_show_password_policies(&mut self) {}
+
+    fn visit_create_network_policy(&mut self, _stmt: &mut CreateNetworkPolicyStmt) {}
+
+    fn visit_alter_network_policy(&mut self, _stmt: &mut AlterNetworkPolicyStmt) {}
+
+    fn visit_drop_network_policy(&mut self, _stmt: &mut DropNetworkPolicyStmt) {}
+
+    fn visit_desc_network_policy(&mut self, _stmt: &mut DescNetworkPolicyStmt) {}
+
+    fn visit_create_masking_policy(&mut self, _stmt: &mut CreateMaskingPolicyStmt) {}
+
+    fn visit_drop_masking_policy(&mut self, _stmt: &mut DropMaskingPolicyStmt) {}
+
+    fn visit_desc_masking_policy(&mut self, _stmt: &mut DescMaskingPolicyStmt) {}
+
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &mut DescRowAccessPolicyStmt) {}
+
     fn visit_create_udf(
         &mut self,
         _if_not_exists: bool,
@@ -102,6 +102,7 @@ fn test_statement() {
         r#"drop database ctl.t;"#,
         r#"drop database if exists t;"#,
         r#"create table c(a DateTime null, b DateTime(3));"#,
+        r#"create table t (a int, b int, constraint c1 check (a > 0), check (a < b));"#,
         r#"create view v as select number % 3 as a from numbers(1000);"#,
         r#"alter view v as select number % 3 as a from numbers(1000);"#,
         r#"drop view v;"#,
@@ -163,14 +164,25 @@ fn test_statement() {
         r#"create role 'test'"#,
         r#"drop role if exists 'test'"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
+        r#"ALTER TABLE t CLUSTER BY HILBERT(a, b);"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0;"#,
         r#"ALTER TABLE t ADD COLUMN a float default 101 COMMENT 'hello';"#,
         r#"ALTER TABLE t DROP COLUMN b;"#,
+        r#"ALTER TABLE t CREATE TAG t1 AT (SNAPSHOT => 'abc');"#,
+        r#"ALTER TABLE t DROP TAG t1;"#,
+        r#"ALTER TABLE t RENAME COLUMN a TO b;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a SET MASKING POLICY mask1;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a UNSET MASKING POLICY;"#,
+        r#"CREATE VECTOR INDEX IF NOT EXISTS idx ON db.t(embedding) USING HNSW DISTANCE = 'l2';"#,
+        r#"DROP VECTOR INDEX idx ON t;"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
         r#"CREATE TABLE t (a INT COMMENT 'col comment') COMMENT='table comment';"#,
+        r#"CREATE TABLE t2 CLONE db.t1;"#,
+        r#"CREATE STREAM IF NOT EXISTS db.s1 ON TABLE db.t1 COMMENT = 'test';"#,
+        r#"DROP STREAM IF EXISTS s1;"#,
         r#"GRANT CREATE, CREATE USER ON * TO 'test-grant'@'localhost';"#,
         r#"GRANT SELECT, CREATE ON * TO 'test-grant'@'localhost';"#,
         r#"GRANT SELECT, CREATE ON *.* TO 'test-grant'@'localhost';"#,
@@ -191,6 +203,7 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO ROLE 'role1';"#,
         r#"GRANT SELECT ON tb1 TO ROLE 'role1';"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
+        r#"GRANT SELECT (c1, c2) ON db01.tb1 TO ROLE 'role1';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant'@'localhost';"#,
         r#"SHOW GRANTS FOR USER 'test-grant'@'localhost';"#,
@@ -198,6 +211,22 @@ fn test_statement() {
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant'@'localhost';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"REVOKE ALL ON tb1 FROM 'u1';"#,
+        r#"REVOKE SELECT (c1) ON tb1 FROM 'u1';"#,
+        r#"CREATE PASSWORD POLICY IF NOT EXISTS p1 PASSWORD_MIN_LENGTH = 8 PASSWORD_MAX_RETRIES = 3 COMMENT = 'test';"#,
+        r#"ALTER PASSWORD POLICY p1 SET PASSWORD_MAX_AGE_DAYS = 30;"#,
+        r#"ALTER PASSWORD POLICY IF EXISTS p1 UNSET PASSWORD_MIN_LENGTH COMMENT;"#,
+        r#"DROP PASSWORD POLICY IF EXISTS p1;"#,
+        r#"DESC PASSWORD POLICY p1;"#,
+        r#"SHOW PASSWORD POLICIES;"#,
+        r#"CREATE NETWORK POLICY np1 ALLOWED_IP_LIST = ('192.168.1.0/24') BLOCKED_IP_LIST = ('192.168.1.99') COMMENT = 'test';"#,
+        r#"ALTER NETWORK POLICY np1 SET ALLOWED_IP_LIST = ('10.0.0.0/8', '172.16.0.0/12');"#,
+        r#"DROP NETWORK POLICY IF EXISTS np1;"#,
+        r#"DESC NETWORK POLICY np1;"#,
+        r#"DROP MASKING POLICY IF EXISTS mask1;"#,
+        r#"DESC MASKING POLICY mask1;"#,
+        r#"DROP ROW ACCESS POLICY rap1;"#,
+        r#"ALTER USER u1 WITH SET PASSWORD POLICY = 'p1', SET NETWORK POLICY = 'np1';"#,
+        r#"ALTER USER u1 WITH UNSET PASSWORD POLICY, UNSET NETWORK POLICY;"#,
         r#"COPY INTO mytable
                 FROM @~/mybucket/data.csv
                 FILE_FORMAT = (
@@ -466,6 +495,7 @@ fn test_query() {
         r#"select * from customer natural join orders left outer join detail using (id)"#,
         r#"with t2(tt) as (select a from t) select t2.tt from t2  where t2.tt > 1"#,
         r#"with t2 as (select a from t) select t2.a from t2  where t2.a > 1"#,
+        r#"with t2 as materialized (select a from t) select t2.a from t2  where t2.a > 1"#,
         r#"with t2(tt) as (select a from t), t3 as (select * from t), t4 as (select a from t where a > 1) select t2.tt, t3.a, t4.a from t2, t3, t4 where t2.tt > 1"#,
         r#"with recursive t2(tt) as (select a from t1 union select tt from t2) select t2.tt from t2"#,
         r#"select c_count cc, count(*) as custdist, sum(c_acctbal) as totacctbal
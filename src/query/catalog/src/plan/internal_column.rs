@@ -33,6 +33,10 @@ pub const SNAPSHOT_NAME: &str = "_snapshot_name";
 pub const SEGMENT_NAME: &str = "_segment_name";
 pub const BLOCK_NAME: &str = "_block_name";
 
+// Metadata columns of the rows returned by a stream.
+pub const CHANGE_ACTION: &str = "change$action";
+pub const CHANGE_ROW_ID: &str = "change$row_id";
+
 // meta data for generate internal columns
 #[derive(Debug)]
 pub struct InternalColumnMeta {
@@ -41,6 +45,8 @@ pub struct InternalColumnMeta {
     pub block_location: String,
     pub segment_location: String,
     pub snapshot_location: String,
+    // whether the block is removed from the table, only used by streams.
+    pub is_deleted: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
@@ -49,6 +55,8 @@ pub enum InternalColumnType {
     BlockName,
     SegmentName,
     SnapshotName,
+    ChangeAction,
+    ChangeRowId,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
@@ -75,6 +83,8 @@ impl InternalColumn {
             InternalColumnType::BlockName => TableDataType::String,
             InternalColumnType::SegmentName => TableDataType::String,
             InternalColumnType::SnapshotName => TableDataType::String,
+            InternalColumnType::ChangeAction => TableDataType::String,
+            InternalColumnType::ChangeRowId => TableDataType::String,
         }
     }
 
@@ -93,6 +103,8 @@ impl InternalColumn {
             InternalColumnType::BlockName => u32::MAX - 1,
             InternalColumnType::SegmentName => u32::MAX - 2,
             InternalColumnType::SnapshotName => u32::MAX - 3,
+            InternalColumnType::ChangeAction => u32::MAX - 4,
+            InternalColumnType::ChangeRowId => u32::MAX - 5,
         }
     }
 
@@ -141,6 +153,19 @@ impl InternalColumn {
                     value: Value::Scalar(Scalar::String(builder.build_scalar())),
                 }
             }
+            InternalColumnType::ChangeAction => {
+                let action = if meta.is_deleted { "DELETE" } else { "INSERT" };
+                let mut builder = StringColumnBuilder::with_capacity(1, action.len());
+                builder.put_str(action);
+                builder.commit_row();
+                BlockEntry {
+                    data_type: DataType::String,
+                    value: Value::Scalar(Scalar::String(builder.build_scalar())),
+                }
+            }
+            InternalColumnType::ChangeRowId => {
+                unreachable!("change$row_id is generated by the stream from the values of the rows")
+            }
         }
     }
 }
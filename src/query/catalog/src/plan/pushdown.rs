@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 
 use common_expression::types::DataType;
+use common_expression::types::F32;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
@@ -22,6 +23,7 @@ use common_expression::TableField;
 use common_expression::TableSchema;
 
 use crate::plan::Projection;
+use crate::runtime_filter_info::RuntimeFilterTarget;
 
 /// Information of Virtual Columns.
 ///
@@ -66,6 +68,19 @@ pub struct PrewhereInfo {
     pub virtual_columns: Option<Vec<VirtualColumnInfo>>,
 }
 
+/// Information to probe the vector indexes by, pushed down from
+/// `ORDER BY <distance>(column, <const>) LIMIT k` without any filter.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorTopK {
+    /// The vector column
+    pub column: String,
+    /// The distance function, such as `cosine_distance`
+    pub func_name: String,
+    /// The constant vector to find the nearest neighbours of
+    pub query: Vec<F32>,
+    pub limit: usize,
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct PushDownInfo {
@@ -88,6 +103,11 @@ pub struct PushDownInfo {
     pub order_by: Vec<(RemoteExpr<String>, bool, bool)>,
     /// Optional virtual columns
     pub virtual_columns: Option<Vec<VirtualColumnInfo>>,
+    /// Runtime filters of the hash joins the scan is the probe side of,
+    /// which are only known once the build sides have been read
+    pub runtime_filters: Vec<RuntimeFilterTarget>,
+    /// Optional nearest neighbours search to prune the blocks by vector indexes
+    pub vector_top_k: Option<VectorTopK>,
 }
 
 /// TopK is a wrapper for topk push down items.
@@ -0,0 +1,228 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime filters published by the build side of a hash join, and consumed by the
+//! table scans of the probe side to skip the blocks that can't match any build row.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::Notify;
+use common_expression::types::DataType;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use parking_lot::Mutex;
+use siphasher::sip::SipHasher24;
+
+/// Identifies the runtime filter of a key of a hash join.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RuntimeFilterKey {
+    /// The plan id of the hash join.
+    pub join_id: u32,
+    /// The index of the key in the equi conditions of the hash join.
+    pub key_index: usize,
+}
+
+/// A runtime filter pushed down to a table scan.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RuntimeFilterTarget {
+    pub key: RuntimeFilterKey,
+    /// The probe key of the hash join, over the columns of the table.
+    pub expr: RemoteExpr<String>,
+}
+
+/// Summary of the keys of the build side of a hash join.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct RuntimeFilterInfo {
+    pub data_type: DataType,
+    /// The minimum and maximum of the non-NULL keys, `None` if there are none,
+    /// in which case no row of the probe side can match.
+    pub min_max: Option<(Scalar, Scalar)>,
+    /// The distinct non-NULL keys, if there are not too many of them.
+    pub inlist: Option<Vec<Scalar>>,
+    /// The bloom filter of the non-NULL keys, checked against the rows of the probe side.
+    pub bloom: Option<RuntimeFilterBloom>,
+}
+
+impl RuntimeFilterInfo {
+    /// Merges the filter of the keys another node of the cluster has, so that the result
+    /// covers the keys of both.
+    pub fn merge(&mut self, other: RuntimeFilterInfo) {
+        self.min_max = match (self.min_max.take(), other.min_max) {
+            (Some((lo, hi)), Some((min, max))) => Some((
+                if min < lo { min } else { lo },
+                if max > hi { max } else { hi },
+            )),
+            (min_max, None) | (None, min_max) => min_max,
+        };
+        self.inlist = match (self.inlist.take(), other.inlist) {
+            (Some(lhs), Some(rhs)) => {
+                let values = lhs.into_iter().chain(rhs).collect::<HashSet<_>>();
+                Some(values.into_iter().collect())
+            }
+            _ => None,
+        };
+        self.bloom = match (self.bloom.take(), other.bloom) {
+            (Some(mut lhs), Some(rhs)) => {
+                lhs.merge(rhs);
+                Some(lhs)
+            }
+            _ => None,
+        };
+    }
+}
+
+const BLOOM_BITS_PER_KEY: usize = 8;
+const BLOOM_MIN_BITS: usize = 64;
+const BLOOM_MAX_BITS: usize = 1 << 26;
+const BLOOM_NUM_HASHES: u64 = 4;
+
+/// A bloom filter of the hashes of the keys, sized by a power of two bits so that the
+/// filters of different sizes built by the nodes of a cluster can be merged.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct RuntimeFilterBloom {
+    words: Vec<u64>,
+}
+
+impl RuntimeFilterBloom {
+    pub fn with_capacity(num_keys: usize) -> Self {
+        let num_bits = num_keys
+            .saturating_mul(BLOOM_BITS_PER_KEY)
+            .next_power_of_two()
+            .clamp(BLOOM_MIN_BITS, BLOOM_MAX_BITS);
+        RuntimeFilterBloom {
+            words: vec![0; num_bits / 64],
+        }
+    }
+
+    /// The hash of a key, which has to be the same on all the nodes of the cluster.
+    pub fn hash(key: ScalarRef) -> u64 {
+        let mut hasher = SipHasher24::new();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub fn insert(&mut self, hash: u64) {
+        for bit in self.bits(hash) {
+            self.words[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Whether the key of the hash may be in the filter.
+    pub fn contains(&self, hash: u64) -> bool {
+        self.bits(hash)
+            .all(|bit| self.words[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Adds the keys of another filter. The bits of the larger filter are folded in halves
+    /// down to the size of the smaller one, a bit keeping its position modulo the size.
+    pub fn merge(&mut self, mut other: RuntimeFilterBloom) {
+        if other.words.len() > self.words.len() {
+            std::mem::swap(self, &mut other);
+        }
+        while self.words.len() > other.words.len() {
+            let half = self.words.len() / 2;
+            let (lo, hi) = self.words.split_at(half);
+            self.words = lo.iter().zip(hi).map(|(lo, hi)| lo | hi).collect();
+        }
+        for (word, other) in self.words.iter_mut().zip(other.words) {
+            *word |= other;
+        }
+    }
+
+    fn bits(&self, hash: u64) -> impl Iterator<Item = usize> {
+        let mask = (self.words.len() * 64 - 1) as u64;
+        let step = hash.rotate_left(32) | 1;
+        (0..BLOOM_NUM_HASHES)
+            .map(move |i| (hash.wrapping_add(i.wrapping_mul(step)) & mask) as usize)
+    }
+}
+
+enum RuntimeFilterState {
+    /// The number of filters received from the nodes sharing the build side, merged.
+    Pending(usize, Option<RuntimeFilterInfo>),
+    Published(Option<Arc<RuntimeFilterInfo>>),
+}
+
+/// Hands a runtime filter over from the build side of a hash join to the scans waiting for it.
+pub struct RuntimeFilterChannel {
+    state: Mutex<RuntimeFilterState>,
+    notify: Notify,
+}
+
+impl Default for RuntimeFilterChannel {
+    fn default() -> Self {
+        RuntimeFilterChannel {
+            state: Mutex::new(RuntimeFilterState::Pending(0, None)),
+            notify: Notify::new(),
+        }
+    }
+}
+
+impl RuntimeFilterChannel {
+    /// Publishes the filter, `None` means the scans have to read everything.
+    /// Only the first publication is taken into account.
+    pub fn publish(&self, filter: Option<RuntimeFilterInfo>) {
+        self.publish_partial(filter, 1)
+    }
+
+    /// Publishes the filter of the build rows of one of the `num_partials` nodes the rows
+    /// are shuffled to, the filters are merged until all of them are received.
+    /// `None` publishes right away that the scans have to read everything.
+    pub fn publish_partial(&self, filter: Option<RuntimeFilterInfo>, num_partials: usize) {
+        let mut state = self.state.lock();
+        let (received, merged) = match &mut *state {
+            RuntimeFilterState::Pending(received, merged) => (received, merged),
+            RuntimeFilterState::Published(_) => return,
+        };
+
+        let filter = match filter {
+            Some(filter) => filter,
+            None => {
+                *state = RuntimeFilterState::Published(None);
+                self.notify.notify_waiters();
+                return;
+            }
+        };
+        *received += 1;
+        match merged {
+            Some(merged) => merged.merge(filter),
+            None => *merged = Some(filter),
+        }
+
+        if *received >= num_partials {
+            *state = RuntimeFilterState::Published(merged.take().map(Arc::new));
+            self.notify.notify_waiters();
+        }
+    }
+
+    pub fn is_published(&self) -> bool {
+        matches!(*self.state.lock(), RuntimeFilterState::Published(_))
+    }
+
+    /// Waits until the filter is published.
+    #[async_backtrace::framed]
+    pub async fn wait(&self) -> Option<Arc<RuntimeFilterInfo>> {
+        loop {
+            let notified = self.notify.notified();
+            if let RuntimeFilterState::Published(filter) = &*self.state.lock() {
+                return filter.clone();
+            }
+            notified.await;
+        }
+    }
+}
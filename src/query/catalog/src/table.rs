@@ -383,6 +383,7 @@ impl<T: ?Sized> TableExt for T where T: Table {}
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
+    Tag(String),
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -399,6 +400,17 @@ pub struct ColumnStatistics {
     pub max: Scalar,
     pub null_count: u64,
     pub number_of_distinct_values: u64,
+    // uncompressed size of the column in bytes, 0 if unknown.
+    pub in_memory_size: u64,
+    // equi-height histogram collected by `ANALYZE TABLE`, if any.
+    pub histogram: Option<Vec<ColumnHistogramBucket>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnHistogramBucket {
+    pub upper_bound: Scalar,
+    pub num_values: f64,
+    pub num_distinct: f64,
 }
 
 pub enum CompactTarget {
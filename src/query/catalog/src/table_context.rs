@@ -31,7 +31,9 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_pipeline_core::InputError;
+use common_profile::PlanProfSpan;
 use common_settings::ChangeValue;
 use common_settings::Settings;
 use common_storage::DataOperator;
@@ -44,6 +46,8 @@ use crate::cluster_info::Cluster;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
+use crate::runtime_filter_info::RuntimeFilterChannel;
+use crate::runtime_filter_info::RuntimeFilterKey;
 use crate::table::Table;
 
 #[derive(Debug)]
@@ -90,6 +94,9 @@ pub trait TableContext: Send + Sync {
     fn get_write_progress_value(&self) -> ProgressValues;
     fn get_result_progress(&self) -> Arc<Progress>;
     fn get_result_progress_value(&self) -> ProgressValues;
+    /// The span of the table scan being built, the storages record the bytes
+    /// they read into it. It's None if the query is not profiled.
+    fn get_scan_prof_span(&self) -> Option<PlanProfSpan>;
     fn get_status_info(&self) -> String;
     fn set_status_info(&self, info: &str);
 
@@ -140,6 +147,15 @@ pub trait TableContext: Send + Sync {
     fn get_data_operator(&self) -> Result<DataOperator>;
     fn push_precommit_block(&self, block: DataBlock);
     fn consume_precommit_blocks(&self) -> Vec<DataBlock>;
+    /// Record the new offset of a stream read by the query, it is committed
+    /// together with the table modified by the query.
+    fn add_consumed_stream(&self, req: UpdateStreamMetaReq);
+    fn get_consumed_streams(&self) -> Vec<UpdateStreamMetaReq>;
+    /// Whether the streams read by the query are consumed, only DML statements consume streams.
+    fn get_consume_streams(&self) -> bool;
+    fn set_consume_streams(&self, consume: bool);
+    /// Get the channel of a runtime filter of the query, it is created on first access.
+    fn get_runtime_filter_channel(&self, key: RuntimeFilterKey) -> Arc<RuntimeFilterChannel>;
 
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams>;
 
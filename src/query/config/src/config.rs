@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::env;
 use std::fmt;
@@ -1215,6 +1216,28 @@ pub struct QueryConfig {
     #[clap(long, default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// The fuse table (`database.table`) to persist the query log and login events into.
+    /// Empty means disabled.
+    #[clap(long, default_value_t)]
+    pub audit_log_table: String,
+
+    /// Flush the buffered audit log events once this many rows are pending.
+    #[clap(long, default_value = "1000")]
+    pub audit_log_flush_rows: usize,
+
+    /// Flush the buffered audit log events at least every this many seconds.
+    #[clap(long, default_value = "10")]
+    pub audit_log_flush_interval_secs: u64,
+
+    /// Enable the background service that compacts, reclusters and purges the fuse tables
+    /// that opted in by the table options `auto_compact`, `auto_recluster` and `auto_purge`.
+    #[clap(long)]
+    pub enable_background_service: bool,
+
+    /// Check the tables that need background maintenance every this many seconds.
+    #[clap(long, default_value = "60")]
+    pub background_service_interval_secs: u64,
+
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     #[clap(long)]
     pub management_mode: bool,
@@ -1322,6 +1345,31 @@ pub struct QueryConfig {
     /// https://platform.openai.com/docs/guides/chat
     #[clap(long, default_value = "gpt-3.5-turbo")]
     pub openai_api_completion_model: String,
+
+    /// The provider of the ai_* functions: `openai` (or any OpenAI compatible api at
+    /// openai_api_base_url), `local` (an inference server at ai_local_endpoint) or `mock`.
+    #[clap(long, default_value = "openai")]
+    pub ai_provider: String,
+
+    /// The endpoint of the `local` ai provider, serving `/embed` and `/generate`.
+    #[clap(long, default_value = "")]
+    pub ai_local_endpoint: String,
+
+    /// Max texts embedded in one request to the ai provider.
+    #[clap(long, default_value = "16")]
+    pub ai_request_batch_size: u64,
+
+    /// Times a failed request to the ai provider is retried with backoff.
+    #[clap(long, default_value = "3")]
+    pub ai_request_max_retries: u64,
+
+    /// Max requests per second to the ai provider of this node, 0 for unlimited.
+    #[clap(long, default_value = "0")]
+    pub ai_requests_per_second: u64,
+
+    /// Max embeddings and completions cached by this node, 0 to disable the cache.
+    #[clap(long, default_value = "10000")]
+    pub ai_cache_capacity: u64,
 }
 
 impl Default for QueryConfig {
@@ -1369,6 +1417,11 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             wait_timeout_mills: self.wait_timeout_mills,
             max_query_log_size: self.max_query_log_size,
+            audit_log_table: self.audit_log_table,
+            audit_log_flush_rows: self.audit_log_flush_rows,
+            audit_log_flush_interval_secs: self.audit_log_flush_interval_secs,
+            enable_background_service: self.enable_background_service,
+            background_service_interval_secs: self.background_service_interval_secs,
             management_mode: self.management_mode,
             jwt_key_file: self.jwt_key_file,
             jwt_key_files: self.jwt_key_files,
@@ -1387,6 +1440,12 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             openai_api_key: self.openai_api_key,
             openai_api_completion_model: self.openai_api_completion_model,
             openai_api_embedding_model: self.openai_api_embedding_model,
+            ai_provider: self.ai_provider,
+            ai_local_endpoint: self.ai_local_endpoint,
+            ai_request_batch_size: self.ai_request_batch_size,
+            ai_request_max_retries: self.ai_request_max_retries,
+            ai_requests_per_second: self.ai_requests_per_second,
+            ai_cache_capacity: self.ai_cache_capacity,
         })
     }
 }
@@ -1435,6 +1494,11 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             wait_timeout_mills: inner.wait_timeout_mills,
             max_query_log_size: inner.max_query_log_size,
+            audit_log_table: inner.audit_log_table,
+            audit_log_flush_rows: inner.audit_log_flush_rows,
+            audit_log_flush_interval_secs: inner.audit_log_flush_interval_secs,
+            enable_background_service: inner.enable_background_service,
+            background_service_interval_secs: inner.background_service_interval_secs,
             management_mode: inner.management_mode,
             jwt_key_file: inner.jwt_key_file,
             jwt_key_files: inner.jwt_key_files,
@@ -1464,6 +1528,12 @@ impl From<InnerQueryConfig> for QueryConfig {
             openai_api_key: inner.openai_api_key,
             openai_api_completion_model: inner.openai_api_completion_model,
             openai_api_embedding_model: inner.openai_api_embedding_model,
+            ai_provider: inner.ai_provider,
+            ai_local_endpoint: inner.ai_local_endpoint,
+            ai_request_batch_size: inner.ai_request_batch_size,
+            ai_request_max_retries: inner.ai_request_max_retries,
+            ai_requests_per_second: inner.ai_requests_per_second,
+            ai_cache_capacity: inner.ai_cache_capacity,
         }
     }
 }
@@ -1943,6 +2013,32 @@ pub struct CacheConfig {
     /// and the access pattern will benefit from caching, consider enabled this cache.
     #[clap(long = "cache-table-data-deserialized-data-bytes", default_value = "0")]
     pub table_data_deserialized_data_bytes: u64,
+
+    /// Demote the table column objects evicted from the in-memory table column object cache
+    /// to the disk data cache instead of dropping them, and promote them back once hit.
+    ///
+    /// Takes effect only if both caches are enabled. The raw data of the columns kept by the
+    /// column object cache are not populated to the disk data cache then, while the raw data of
+    /// the other columns (e.g. nested columns) still are.
+    #[clap(
+        long = "cache-enable-table-column-array-demotion",
+        parse(try_from_str),
+        default_value = "false"
+    )]
+    pub enable_table_column_array_demotion: bool,
+
+    /// Eviction policy of the in-memory caches and the disk table data cache, `lru` or `s3fifo`.
+    ///
+    /// `s3fifo` admits the new items through a small queue first, so a large table scan
+    /// doesn't flush the segments, bloom filters and columns accessed repeatedly.
+    #[clap(long = "cache-eviction-policy", value_enum, default_value_t)]
+    pub eviction_policy: CacheEvictionPolicyConfig,
+
+    /// Eviction policies of single caches overriding `eviction_policy`, as comma separated
+    /// `name=policy` pairs of the cache names in `system.caches`, for example
+    /// `segment_info_cache=s3fifo,table_column_array_cache=s3fifo`.
+    #[clap(long = "cache-eviction-policy-overrides", default_value = "")]
+    pub eviction_policy_overrides: String,
 }
 
 impl Default for CacheConfig {
@@ -1971,6 +2067,20 @@ impl Default for CacheStorageTypeConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheEvictionPolicyConfig {
+    Lru,
+    #[clap(name = "s3fifo")]
+    S3Fifo,
+}
+
+impl Default for CacheEvictionPolicyConfig {
+    fn default() -> Self {
+        Self::Lru
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args, Default)]
 #[serde(default, deny_unknown_fields)]
 pub struct DiskCacheConfig {
@@ -2058,10 +2168,48 @@ mod cache_config_converters {
                     .table_data_cache_population_queue_size,
                 disk_cache_config: value.disk_cache_config.try_into()?,
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
+                enable_table_column_array_demotion: value.enable_table_column_array_demotion,
+                eviction_policy: value.eviction_policy.try_into()?,
+                eviction_policy_overrides: parse_eviction_policy_overrides(
+                    &value.eviction_policy_overrides,
+                )?,
             })
         }
     }
 
+    fn parse_eviction_policy_overrides(
+        overrides: &str,
+    ) -> Result<BTreeMap<String, inner::CacheEvictionPolicyConfig>> {
+        let mut policies = BTreeMap::new();
+        for pair in overrides
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+        {
+            let (name, policy) = pair.split_once('=').ok_or_else(|| {
+                ErrorCode::InvalidConfig(format!(
+                    "invalid cache eviction policy override '{pair}', expect 'name=policy'"
+                ))
+            })?;
+            let name = name.trim();
+            if !inner::CacheConfig::CACHE_NAMES.contains(&name) {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "unknown cache '{name}' in the eviction policy overrides, expect one of {}",
+                    inner::CacheConfig::CACHE_NAMES.join(", ")
+                )));
+            }
+            let policy = <CacheEvictionPolicyConfig as ValueEnum>::from_str(policy.trim(), true)
+                .map_err(|e| {
+                    ErrorCode::InvalidConfig(format!(
+                        "invalid eviction policy of cache '{name}': {e}"
+                    ))
+                })?
+                .try_into()?;
+            policies.insert(name.to_string(), policy);
+        }
+        Ok(policies)
+    }
+
     impl From<inner::CacheConfig> for CacheConfig {
         fn from(value: inner::CacheConfig) -> Self {
             Self {
@@ -2078,6 +2226,14 @@ mod cache_config_converters {
                     .table_data_cache_population_queue_size,
                 disk_cache_config: value.disk_cache_config.into(),
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
+                enable_table_column_array_demotion: value.enable_table_column_array_demotion,
+                eviction_policy: value.eviction_policy.into(),
+                eviction_policy_overrides: value
+                    .eviction_policy_overrides
+                    .iter()
+                    .map(|(name, policy)| name.clone() + "=" + &policy.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
             }
         }
     }
@@ -2119,4 +2275,23 @@ mod cache_config_converters {
             }
         }
     }
+
+    impl TryFrom<CacheEvictionPolicyConfig> for inner::CacheEvictionPolicyConfig {
+        type Error = ErrorCode;
+        fn try_from(value: CacheEvictionPolicyConfig) -> std::result::Result<Self, Self::Error> {
+            Ok(match value {
+                CacheEvictionPolicyConfig::Lru => inner::CacheEvictionPolicyConfig::Lru,
+                CacheEvictionPolicyConfig::S3Fifo => inner::CacheEvictionPolicyConfig::S3Fifo,
+            })
+        }
+    }
+
+    impl From<inner::CacheEvictionPolicyConfig> for CacheEvictionPolicyConfig {
+        fn from(value: inner::CacheEvictionPolicyConfig) -> Self {
+            match value {
+                inner::CacheEvictionPolicyConfig::Lru => CacheEvictionPolicyConfig::Lru,
+                inner::CacheEvictionPolicyConfig::S3Fifo => CacheEvictionPolicyConfig::S3Fifo,
+            }
+        }
+    }
 }
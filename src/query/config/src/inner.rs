@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
@@ -160,6 +161,14 @@ pub struct QueryConfig {
     pub table_engine_memory_enabled: bool,
     pub wait_timeout_mills: u64,
     pub max_query_log_size: usize,
+    /// The fuse table (`database.table`) to persist the query log and login events into.
+    /// Empty means disabled.
+    pub audit_log_table: String,
+    pub audit_log_flush_rows: usize,
+    pub audit_log_flush_interval_secs: u64,
+    /// Compact, recluster and purge the fuse tables that opted in, in the background.
+    pub enable_background_service: bool,
+    pub background_service_interval_secs: u64,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
     pub jwt_key_file: String,
@@ -180,6 +189,13 @@ pub struct QueryConfig {
     pub openai_api_base_url: String,
     pub openai_api_embedding_model: String,
     pub openai_api_completion_model: String,
+    /// The provider of the ai_* functions and how the requests are sent to it.
+    pub ai_provider: String,
+    pub ai_local_endpoint: String,
+    pub ai_request_batch_size: u64,
+    pub ai_request_max_retries: u64,
+    pub ai_requests_per_second: u64,
+    pub ai_cache_capacity: u64,
 }
 
 impl Default for QueryConfig {
@@ -218,6 +234,11 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10_000,
+            audit_log_table: "".to_string(),
+            audit_log_flush_rows: 1000,
+            audit_log_flush_interval_secs: 10,
+            enable_background_service: false,
+            background_service_interval_secs: 60,
             management_mode: false,
             jwt_key_file: "".to_string(),
             jwt_key_files: Vec::new(),
@@ -235,6 +256,12 @@ impl Default for QueryConfig {
             openai_api_key: "".to_string(),
             openai_api_completion_model: "gpt-3.5-turbo".to_string(),
             openai_api_embedding_model: "text-embedding-ada-002".to_string(),
+            ai_provider: "openai".to_string(),
+            ai_local_endpoint: "".to_string(),
+            ai_request_batch_size: 16,
+            ai_request_max_retries: 3,
+            ai_requests_per_second: 0,
+            ai_cache_capacity: 10000,
         }
     }
 }
@@ -484,6 +511,38 @@ pub struct CacheConfig {
     /// Only if query nodes have plenty of un-utilized memory, the working set can be fitted into,
     /// and the access pattern will benefit from caching, consider enabled this cache.
     pub table_data_deserialized_data_bytes: u64,
+
+    /// Demote the table column objects evicted from memory to the disk data cache.
+    pub enable_table_column_array_demotion: bool,
+
+    /// Eviction policy of the in-memory caches and the disk table data cache.
+    pub eviction_policy: CacheEvictionPolicyConfig,
+
+    /// Eviction policies of single caches by name, overriding `eviction_policy`.
+    pub eviction_policy_overrides: BTreeMap<String, CacheEvictionPolicyConfig>,
+}
+
+impl CacheConfig {
+    /// The names of the caches in `system.caches`, whose eviction policies can be overridden.
+    pub const CACHE_NAMES: [&'static str; 9] = [
+        "table_snapshot_cache",
+        "table_snapshot_statistic_cache",
+        "segment_info_cache",
+        "bloom_index_filter_cache",
+        "bloom_index_meta_cache",
+        "prune_partitions_cache",
+        "file_meta_data_cache",
+        "table_data_cache",
+        "table_column_array_cache",
+    ];
+
+    /// The eviction policy of the cache named `name` in `system.caches`.
+    pub fn eviction_policy_of(&self, name: &str) -> CacheEvictionPolicyConfig {
+        self.eviction_policy_overrides
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| self.eviction_policy.clone())
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -508,6 +567,27 @@ impl ToString for CacheStorageTypeConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CacheEvictionPolicyConfig {
+    Lru,
+    S3Fifo,
+}
+
+impl Default for CacheEvictionPolicyConfig {
+    fn default() -> Self {
+        Self::Lru
+    }
+}
+
+impl ToString for CacheEvictionPolicyConfig {
+    fn to_string(&self) -> String {
+        match self {
+            CacheEvictionPolicyConfig::Lru => "lru".to_string(),
+            CacheEvictionPolicyConfig::S3Fifo => "s3fifo".to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DiskCacheConfig {
     /// Max bytes of cached raw table data. Default 20GB, set it to 0 to disable it.
@@ -541,6 +621,9 @@ impl Default for CacheConfig {
             table_data_cache_population_queue_size: 65536,
             disk_cache_config: Default::default(),
             table_data_deserialized_data_bytes: 0,
+            enable_table_column_array_demotion: false,
+            eviction_policy: Default::default(),
+            eviction_policy_overrides: BTreeMap::new(),
         }
     }
 }
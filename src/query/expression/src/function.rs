@@ -85,11 +85,18 @@ pub enum FunctionEval {
 #[derive(Clone, Default)]
 pub struct FunctionContext {
     pub tz: TzLUT,
+    pub tenant: String,
 
     pub openai_api_base_url: String,
     pub openai_api_key: String,
     pub openai_api_embedding_model: String,
     pub openai_api_completion_model: String,
+    pub ai_provider: String,
+    pub ai_local_endpoint: String,
+    pub ai_request_batch_size: u64,
+    pub ai_request_max_retries: u64,
+    pub ai_requests_per_second: u64,
+    pub ai_cache_capacity: u64,
 }
 
 #[derive(Clone)]
@@ -76,6 +76,40 @@ pub struct TableField {
     data_type: TableDataType,
     #[serde(default = "uninit_column_id")]
     column_id: ColumnId,
+    #[serde(default)]
+    legacy_columns: Vec<LegacyColumn>,
+    #[serde(default)]
+    computed_expr: Option<ComputedExpr>,
+}
+
+/// The expression of a computed field, represent as raw sql.
+///
+/// The value of a stored computed field is generated when rows are written, and kept in
+/// blocks like other fields. The value of a virtual computed field is not stored, it is
+/// generated when the field is read.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ComputedExpr {
+    Virtual(String),
+    Stored(String),
+}
+
+impl ComputedExpr {
+    pub fn expr(&self) -> &String {
+        match self {
+            ComputedExpr::Virtual(expr) => expr,
+            ComputedExpr::Stored(expr) => expr,
+        }
+    }
+}
+
+/// The storage of a field before its type was changed by `ALTER TABLE MODIFY COLUMN`.
+///
+/// Blocks written before the change still keep the data of the field under the old
+/// column id and in the old type, readers cast it to the current type of the field.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LegacyColumn {
+    pub column_id: ColumnId,
+    pub data_type: TableDataType,
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -319,7 +353,12 @@ impl TableSchema {
 
     pub fn is_column_deleted(&self, column_id: ColumnId) -> bool {
         for field in &self.fields {
-            if field.contain_column_id(column_id) {
+            if field.contain_column_id(column_id)
+                || field
+                    .legacy_columns()
+                    .iter()
+                    .any(|c| c.column_id == column_id)
+            {
                 return false;
             }
         }
@@ -364,6 +403,85 @@ impl TableSchema {
         Ok(())
     }
 
+    pub fn rename_column(&mut self, column: &str, new_column: &str) -> Result<()> {
+        if self.index_of(new_column).is_ok() {
+            return Err(ErrorCode::AddColumnExistError(format!(
+                "rename column {} to {} already exist",
+                column, new_column,
+            )));
+        }
+        let i = self.index_of(column)?;
+        self.fields[i].name = new_column.to_string();
+
+        Ok(())
+    }
+
+    // The data already written keeps the old column id and type, so the field gets a new
+    // column id, and remembers the old one as a legacy column that readers cast from.
+    pub fn modify_column_type(&mut self, column: &str, data_type: TableDataType) -> Result<()> {
+        let i = self.index_of(column)?;
+        let field = &self.fields[i];
+        if field.data_type() == &data_type {
+            return Ok(());
+        }
+        if !field.data_type().can_widen_to(&data_type) {
+            return Err(ErrorCode::AlterColumnError(format!(
+                "cannot modify type of column {} from {} to {}",
+                column,
+                field.data_type(),
+                data_type,
+            )));
+        }
+
+        let mut legacy_columns = field.legacy_columns.clone();
+        legacy_columns.push(LegacyColumn {
+            column_id: field.column_id,
+            data_type: field.data_type.clone(),
+        });
+        let field = TableField::new(column, data_type)
+            .with_default_expr(field.default_expr.clone())
+            .with_legacy_columns(legacy_columns)
+            .with_computed_expr(field.computed_expr.clone())
+            .build_column_id(&mut self.next_column_id);
+        self.fields[i] = field;
+
+        Ok(())
+    }
+
+    /// Returns the schema without the computed fields, which can not be written directly.
+    pub fn remove_computed_fields(&self) -> TableSchema {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|f| f.computed_expr().is_none())
+            .cloned()
+            .collect();
+        Self {
+            fields,
+            metadata: self.metadata.clone(),
+            next_column_id: self.next_column_id,
+        }
+    }
+
+    /// Returns the schema of the fields stored in blocks, i.e. without the virtual computed fields.
+    pub fn remove_virtual_computed_fields(&self) -> TableSchema {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|f| !f.is_virtual_computed())
+            .cloned()
+            .collect();
+        Self {
+            fields,
+            metadata: self.metadata.clone(),
+            next_column_id: self.next_column_id,
+        }
+    }
+
+    pub fn has_computed_fields(&self) -> bool {
+        self.fields.iter().any(|f| f.computed_expr().is_some())
+    }
+
     pub fn to_leaf_column_id_set(&self) -> HashSet<ColumnId> {
         HashSet::from_iter(self.to_leaf_column_ids().iter().cloned())
     }
@@ -826,6 +944,8 @@ impl TableField {
             default_expr: None,
             data_type,
             column_id: 0,
+            legacy_columns: vec![],
+            computed_expr: None,
         }
     }
 
@@ -835,6 +955,8 @@ impl TableField {
             default_expr: None,
             data_type,
             column_id,
+            legacy_columns: vec![],
+            computed_expr: None,
         }
     }
 
@@ -880,6 +1002,8 @@ impl TableField {
             default_expr: self.default_expr.clone(),
             data_type: self.data_type.clone(),
             column_id,
+            legacy_columns: self.legacy_columns.clone(),
+            computed_expr: self.computed_expr.clone(),
         }
     }
 
@@ -918,6 +1042,18 @@ impl TableField {
         self
     }
 
+    #[must_use]
+    pub fn with_legacy_columns(mut self, legacy_columns: Vec<LegacyColumn>) -> Self {
+        self.legacy_columns = legacy_columns;
+        self
+    }
+
+    #[must_use]
+    pub fn with_computed_expr(mut self, computed_expr: Option<ComputedExpr>) -> Self {
+        self.computed_expr = computed_expr;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -930,6 +1066,18 @@ impl TableField {
         self.default_expr.as_ref()
     }
 
+    pub fn legacy_columns(&self) -> &[LegacyColumn] {
+        &self.legacy_columns
+    }
+
+    pub fn computed_expr(&self) -> Option<&ComputedExpr> {
+        self.computed_expr.as_ref()
+    }
+
+    pub fn is_virtual_computed(&self) -> bool {
+        matches!(self.computed_expr, Some(ComputedExpr::Virtual(_)))
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -991,6 +1139,33 @@ impl TableDataType {
         }
     }
 
+    /// Whether the values of this type can be cast to `dest` without loss, so that the
+    /// stored data of a column can be read as `dest` after `ALTER TABLE MODIFY COLUMN`.
+    pub fn can_widen_to(&self, dest: &TableDataType) -> bool {
+        match (self, dest) {
+            (src, dest) if src == dest => true,
+            (TableDataType::Nullable(src), TableDataType::Nullable(dest)) => src.can_widen_to(dest),
+            (TableDataType::Nullable(_), _) => false,
+            (src, TableDataType::Nullable(dest)) => src.can_widen_to(dest),
+            (TableDataType::Number(src), TableDataType::Number(dest)) => {
+                src.can_lossless_cast_to(*dest)
+            }
+            (TableDataType::Decimal(src), TableDataType::Decimal(dest)) => {
+                dest.scale() >= src.scale() && dest.leading_digits() >= src.leading_digits()
+            }
+            (TableDataType::Date, TableDataType::Timestamp) => true,
+            (
+                TableDataType::Boolean
+                | TableDataType::Number(_)
+                | TableDataType::Decimal(_)
+                | TableDataType::Date
+                | TableDataType::Timestamp,
+                TableDataType::String,
+            ) => true,
+            _ => false,
+        }
+    }
+
     pub fn remove_recursive_nullable(&self) -> Self {
         match self {
             TableDataType::Nullable(ty) => ty.as_ref().remove_recursive_nullable(),
@@ -1150,6 +1325,8 @@ impl From<&ArrowField> for TableField {
             data_type: f.into(),
             default_expr: None,
             column_id: 0,
+            legacy_columns: vec![],
+            computed_expr: None,
         }
     }
 }
@@ -527,6 +527,76 @@ fn test_schema_modify_field() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_schema_alter_column() -> Result<()> {
+    let fields = vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("b", TableDataType::String),
+    ];
+    let mut schema = TableSchema::new(fields);
+
+    // rename column a
+    schema.rename_column("a", "c")?;
+    assert!(schema.rename_column("c", "b").is_err());
+    assert!(schema.column_id_of("a").is_err());
+    assert_eq!(schema.column_id_of("c").unwrap(), 0);
+    assert_eq!(schema.next_column_id(), 2);
+
+    // narrowing the type is not allowed
+    assert!(
+        schema
+            .modify_column_type("c", TableDataType::Number(NumberDataType::Int16))
+            .is_err()
+    );
+    assert!(
+        schema
+            .modify_column_type("b", TableDataType::Number(NumberDataType::Int64))
+            .is_err()
+    );
+
+    // modify type of column c
+    schema.modify_column_type("c", TableDataType::Number(NumberDataType::Int64))?;
+    let field = schema.field_with_name("c")?;
+    assert_eq!(
+        field.data_type(),
+        &TableDataType::Number(NumberDataType::Int64)
+    );
+    assert_eq!(field.column_id(), 2);
+    assert_eq!(field.legacy_columns().len(), 1);
+    assert_eq!(field.legacy_columns()[0].column_id, 0);
+    assert_eq!(
+        field.legacy_columns()[0].data_type,
+        TableDataType::Number(NumberDataType::Int32)
+    );
+    assert_eq!(schema.is_column_deleted(0), false);
+    assert_eq!(schema.to_leaf_column_ids(), vec![2, 1]);
+    assert_eq!(schema.next_column_id(), 3);
+
+    // modify type of column c again
+    let data_type =
+        TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int64)));
+    schema.modify_column_type("c", data_type.clone())?;
+    let field = schema.field_with_name("c")?;
+    assert_eq!(field.data_type(), &data_type);
+    assert_eq!(field.column_id(), 3);
+    assert_eq!(
+        field
+            .legacy_columns()
+            .iter()
+            .map(|c| c.column_id)
+            .collect::<Vec<_>>(),
+        vec![0, 2]
+    );
+
+    // drop column c
+    schema.drop_column("c")?;
+    assert_eq!(schema.is_column_deleted(0), true);
+    assert_eq!(schema.is_column_deleted(2), true);
+    assert_eq!(schema.is_column_deleted(3), true);
+
+    Ok(())
+}
+
 #[test]
 fn test_leaf_columns_of() -> Result<()> {
     let fields = vec![
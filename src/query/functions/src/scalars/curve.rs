@@ -0,0 +1,208 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Space-filling curve keys, used by `CLUSTER BY HILBERT(a, b, ...)` and
+//! `CLUSTER BY ZORDER(a, b, ...)`.
+//!
+//! Sorting by the key of a space-filling curve keeps the rows that are close in every
+//! dimension close to each other, so the min/max statistics of the blocks are narrow
+//! on all the columns, not only on the leading one as with a linear cluster key.
+
+use std::sync::Arc;
+
+use common_expression::types::number::NumberScalar;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::EvalContext;
+use common_expression::Function;
+use common_expression::FunctionDomain;
+use common_expression::FunctionEval;
+use common_expression::FunctionRegistry;
+use common_expression::FunctionSignature;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+const SIGN_BIT: u64 = 1 << 63;
+
+#[derive(Clone, Copy)]
+enum Curve {
+    Hilbert,
+    ZOrder,
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    for (name, curve) in [("hilbert", Curve::Hilbert), ("zorder", Curve::ZOrder)] {
+        registry.register_function_factory(name, move |_, args_type| {
+            // A curve over a single dimension is just the linear order.
+            if args_type.len() < 2 || !args_type.iter().all(is_curve_dimension_type) {
+                return None;
+            }
+            Some(Arc::new(Function {
+                signature: FunctionSignature {
+                    name: name.to_string(),
+                    args_type: args_type.to_vec(),
+                    return_type: DataType::String,
+                },
+                eval: FunctionEval::Scalar {
+                    calc_domain: Box::new(|_| FunctionDomain::Full),
+                    eval: Box::new(move |args, ctx| eval_curve_key(curve, args, ctx)),
+                },
+            }))
+        });
+    }
+}
+
+fn is_curve_dimension_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        DataType::Boolean
+            | DataType::String
+            | DataType::Number(_)
+            | DataType::Decimal(_)
+            | DataType::Date
+            | DataType::Timestamp
+    )
+}
+
+/// The key of a point is the index of the point on the curve, which has 64 bits
+/// per dimension, as big-endian bytes so that keys compare as strings.
+fn eval_curve_key(curve: Curve, args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let size = len.unwrap_or(1);
+    let mut builder = StringColumnBuilder::with_capacity(size, size * args.len() * 8);
+    let mut point = vec![0u64; args.len()];
+    for row in 0..size {
+        for (coordinate, arg) in point.iter_mut().zip(args.iter()) {
+            *coordinate = order_preserving_key(unsafe { arg.index_unchecked(row) });
+        }
+        if let Curve::Hilbert = curve {
+            axes_to_transpose(&mut point);
+        }
+        interleave(&point, &mut builder.data);
+        builder.commit_row();
+    }
+
+    match len {
+        Some(_) => Value::Column(Column::String(builder.build())),
+        None => Value::Scalar(Scalar::String(builder.build_scalar())),
+    }
+}
+
+/// Maps a value to a coordinate that keeps the order of the values.
+///
+/// Numbers are mapped by their float64 representation rather than by their integer
+/// bits, so that the varying bits of columns of different scales end up in the
+/// high bits of the coordinates, where the curve takes them into account first.
+/// Strings are mapped by their first 8 bytes, NULL is the smallest coordinate.
+fn order_preserving_key(value: ScalarRef) -> u64 {
+    match value {
+        ScalarRef::Boolean(v) => (v as u64) << 63,
+        ScalarRef::Number(v) => float_key(match v {
+            NumberScalar::UInt8(v) => v as f64,
+            NumberScalar::UInt16(v) => v as f64,
+            NumberScalar::UInt32(v) => v as f64,
+            NumberScalar::UInt64(v) => v as f64,
+            NumberScalar::Int8(v) => v as f64,
+            NumberScalar::Int16(v) => v as f64,
+            NumberScalar::Int32(v) => v as f64,
+            NumberScalar::Int64(v) => v as f64,
+            NumberScalar::Float32(v) => v.0 as f64,
+            NumberScalar::Float64(v) => v.0,
+        }),
+        ScalarRef::Decimal(v) => float_key(v.to_float64()),
+        ScalarRef::Date(v) => float_key(v as f64),
+        ScalarRef::Timestamp(v) => float_key(v as f64),
+        ScalarRef::String(v) => {
+            let mut bytes = [0u8; 8];
+            let len = v.len().min(8);
+            bytes[..len].copy_from_slice(&v[..len]);
+            u64::from_be_bytes(bytes)
+        }
+        _ => 0,
+    }
+}
+
+fn float_key(v: f64) -> u64 {
+    let bits = v.to_bits();
+    if bits & SIGN_BIT != 0 {
+        !bits
+    } else {
+        bits | SIGN_BIT
+    }
+}
+
+/// Interleaves the bits of the coordinates, from the most significant bit.
+fn interleave(point: &[u64], out: &mut Vec<u8>) {
+    let mut byte = 0u8;
+    let mut num_bits = 0;
+    for bit in (0..64).rev() {
+        for coordinate in point {
+            byte = (byte << 1) | ((coordinate >> bit) & 1) as u8;
+            num_bits += 1;
+            if num_bits == 8 {
+                out.push(byte);
+                byte = 0;
+                num_bits = 0;
+            }
+        }
+    }
+}
+
+/// Converts the coordinates of a point into the transposed Hilbert index, whose
+/// interleaved bits are the index of the point on the Hilbert curve.
+///
+/// See John Skilling, "Programming the Hilbert curve", AIP Conf. Proc. 707, 381 (2004).
+#[allow(clippy::needless_range_loop)]
+fn axes_to_transpose(x: &mut [u64]) {
+    let n = x.len();
+
+    // Inverse undo excess work.
+    let mut q = SIGN_BIT;
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..n {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+
+    // Gray encode.
+    for i in 1..n {
+        x[i] ^= x[i - 1];
+    }
+    let mut t = 0;
+    let mut q = SIGN_BIT;
+    while q > 1 {
+        if x[n - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for v in x.iter_mut() {
+        *v ^= t;
+    }
+}
@@ -19,6 +19,7 @@ mod arithmetic_modulo;
 mod array;
 mod boolean;
 mod control;
+mod curve;
 mod datetime;
 mod geo;
 mod map;
@@ -38,6 +39,7 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use vector::llm_config;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -45,6 +47,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     array::register(registry);
     boolean::register(registry);
     control::register(registry);
+    curve::register(registry);
     comparison::register(registry);
     datetime::register(registry);
     math::register(registry);
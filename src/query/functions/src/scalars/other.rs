@@ -26,6 +26,7 @@ use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::Float64Type;
 use common_expression::types::number::Int64Type;
 use common_expression::types::number::UInt32Type;
+use common_expression::types::number::UInt64Type;
 use common_expression::types::number::UInt8Type;
 use common_expression::types::number::F64;
 use common_expression::types::string::StringColumn;
@@ -78,6 +79,24 @@ pub fn register(registry: &mut FunctionRegistry) {
         "rand".to_string(),
         FunctionProperty::default().non_deterministic(),
     );
+    registry.properties.insert(
+        "nextval".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
+
+    // nextval('seq') takes values from a sequence in the meta service, the calls are replaced
+    // by columns of values fetched for each block when the expressions are executed.
+    registry.register_1_arg_core::<StringType, UInt64Type, _, _>(
+        "nextval",
+        |_| FunctionDomain::MayThrow,
+        |_, ctx| {
+            ctx.set_error(
+                0,
+                "nextval can only be used in the SELECT list or the VALUES of INSERT",
+            );
+            Value::Scalar(0_u64)
+        },
+    );
 
     registry.register_passthrough_nullable_1_arg::<Float64Type, StringType, _, _>(
         "humanize_size",
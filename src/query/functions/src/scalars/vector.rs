@@ -13,16 +13,26 @@
 // limitations under the License.
 
 use common_arrow::arrow::buffer::Buffer;
+use common_expression::types::ArgType;
 use common_expression::types::ArrayType;
 use common_expression::types::Float32Type;
 use common_expression::types::StringType;
+use common_expression::types::ValueType;
 use common_expression::types::F32;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionContext;
 use common_expression::FunctionDomain;
 use common_expression::FunctionRegistry;
-use common_openai::OpenAI;
+use common_expression::Value;
+use common_expression::ValueRef;
+use common_openai::CompletionMode;
+use common_openai::LLMClient;
+use common_openai::LLMConfig;
 use common_vector::cosine_distance;
+use common_vector::inner_product;
+use common_vector::l2_distance;
 
 pub fn register(registry: &mut FunctionRegistry) {
     // cosine_distance
@@ -50,50 +60,67 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
-    // embedding_vector
-    // This function takes two strings as input, sends an API request to OpenAI, and returns the Float32 array of embeddings.
-    // The OpenAI API key is pre-configured during the binder phase, so we rewrite this function and set the API key.
-    registry.register_passthrough_nullable_1_arg::<StringType, ArrayType<Float32Type>, _, _>(
-        "ai_embedding_vector",
-        |_| FunctionDomain::MayThrow,
-        vectorize_with_builder_1_arg::<StringType, ArrayType<Float32Type>>(|data, output, ctx| {
-            if let Some(validity) = &ctx.validity {
-                if !validity.get_bit(output.len()) {
-                    output.push(vec![F32::from(0.0)].into());
-                    return;
+    // l2_distance
+    // This function takes two Float32 arrays as input and computes the euclidean distance between them.
+    registry.register_passthrough_nullable_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>, Float32Type, _, _>(
+        "l2_distance",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>,  Float32Type>(
+            |lhs, rhs, output, ctx| {
+                let l_f32=
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(lhs) };
+                let r_f32=
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(rhs) };
+
+                match l2_distance(l_f32.as_slice(), r_f32.as_slice()) {
+                    Ok(dist) => {
+                        output.push(F32::from(dist));
+                    }
+                    Err(err) => {
+                        ctx.set_error(output.len(), err.to_string());
+                        output.push(F32::from(0.0));
+                    }
                 }
             }
-            let data = std::str::from_utf8(data).unwrap();
+        ),
+    );
 
-            let api_base = ctx.func_ctx.openai_api_base_url.clone();
-            let api_key = ctx.func_ctx.openai_api_key.clone();
-            let embedding_model = ctx.func_ctx.openai_api_embedding_model.clone();
-            let completion_model = ctx.func_ctx.openai_api_completion_model.clone();
+    // inner_product
+    // This function takes two Float32 arrays as input and computes the inner product between them.
+    registry.register_passthrough_nullable_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>, Float32Type, _, _>(
+        "inner_product",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>,  Float32Type>(
+            |lhs, rhs, output, ctx| {
+                let l_f32=
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(lhs) };
+                let r_f32=
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(rhs) };
 
-            let openai = OpenAI::create(api_base, api_key, embedding_model, completion_model);
-            let result = openai.embedding_request(&[data.to_string()]);
-            match result {
-                Ok((embeddings, _)) => {
-                    let result = embeddings[0]
-                        .iter()
-                        .map(|x| F32::from(*x))
-                        .collect::<Vec<F32>>();
-                    output.push(result.into());
-                }
-                Err(e) => {
-                    ctx.set_error(
-                        output.len(),
-                        format!("openai embedding request error:{:?}", e),
-                    );
-                    output.push(vec![F32::from(0.0)].into());
+                match inner_product(l_f32.as_slice(), r_f32.as_slice()) {
+                    Ok(dist) => {
+                        output.push(F32::from(dist));
+                    }
+                    Err(err) => {
+                        ctx.set_error(output.len(), err.to_string());
+                        output.push(F32::from(0.0));
+                    }
                 }
             }
-        }),
+        ),
+    );
+
+    // embedding_vector
+    // This function takes a string as input, sends an API request to the configured ai provider, and returns the Float32 array of embeddings.
+    // The rows of a block are embedded together, in batches of ai_request_batch_size.
+    registry.register_passthrough_nullable_1_arg::<StringType, ArrayType<Float32Type>, _, _>(
+        "ai_embedding_vector",
+        |_| FunctionDomain::MayThrow,
+        eval_ai_embedding_vector,
     );
 
     // text_completion
-    // This function takes two strings as input, sends an API request to OpenAI, and returns the AI-generated completion as a string.
-    // The OpenAI API key is pre-configured during the binder phase, so we rewrite this function and set the API key.
+    // This function takes a string as input, sends an API request to the configured ai provider, and returns the AI-generated completion as a string.
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "ai_text_completion",
         |_| FunctionDomain::MayThrow,
@@ -106,23 +133,15 @@ pub fn register(registry: &mut FunctionRegistry) {
                 }
             }
 
-            let data = std::str::from_utf8(data).unwrap();
-            let api_base = ctx.func_ctx.openai_api_base_url.clone();
-            let api_key = ctx.func_ctx.openai_api_key.clone();
-            let embedding_model = ctx.func_ctx.openai_api_embedding_model.clone();
-            let completion_model = ctx.func_ctx.openai_api_completion_model.clone();
-
-            let openai = OpenAI::create(api_base, api_key, embedding_model, completion_model);
-            let result = openai.completion_text_request(data.to_string());
+            let data = String::from_utf8_lossy(data).into_owned();
+            let result = LLMClient::create(&llm_config(ctx.func_ctx))
+                .and_then(|client| client.completion(CompletionMode::Text, data));
             match result {
-                Ok((resp, _)) => {
+                Ok(resp) => {
                     output.put_str(&resp);
                 }
                 Err(e) => {
-                    ctx.set_error(
-                        output.len(),
-                        format!("openai completion request error:{:?}", e),
-                    );
+                    ctx.set_error(output.len(), format!("ai completion request error:{:?}", e));
                     output.put_str("");
                 }
             }
@@ -130,3 +149,68 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 }
+
+/// The ai provider of the functions, and how the requests are sent to it.
+pub fn llm_config(func_ctx: &FunctionContext) -> LLMConfig {
+    LLMConfig {
+        tenant: func_ctx.tenant.clone(),
+        provider: func_ctx.ai_provider.clone(),
+        api_base: func_ctx.openai_api_base_url.clone(),
+        api_key: func_ctx.openai_api_key.clone(),
+        embedding_model: func_ctx.openai_api_embedding_model.clone(),
+        completion_model: func_ctx.openai_api_completion_model.clone(),
+        local_endpoint: func_ctx.ai_local_endpoint.clone(),
+        batch_size: func_ctx.ai_request_batch_size as usize,
+        max_retries: func_ctx.ai_request_max_retries as usize,
+        requests_per_second: func_ctx.ai_requests_per_second,
+        cache_capacity: func_ctx.ai_cache_capacity,
+    }
+}
+
+fn eval_ai_embedding_vector(
+    val: ValueRef<StringType>,
+    ctx: &mut EvalContext,
+) -> Value<ArrayType<Float32Type>> {
+    // The texts of the rows, None for the NULL rows.
+    let texts: Vec<Option<String>> = match &val {
+        ValueRef::Scalar(data) => vec![Some(String::from_utf8_lossy(data).into_owned())],
+        ValueRef::Column(col) => StringType::iter_column(col)
+            .enumerate()
+            .map(|(row, data)| match &ctx.validity {
+                Some(validity) if !validity.get_bit(row) => None,
+                _ => Some(String::from_utf8_lossy(data).into_owned()),
+            })
+            .collect(),
+    };
+    let inputs: Vec<String> = texts.iter().flatten().cloned().collect();
+    let result =
+        LLMClient::create(&llm_config(ctx.func_ctx)).and_then(|client| client.embedding(&inputs));
+
+    let mut output = ArrayType::<Float32Type>::create_builder(texts.len(), ctx.generics);
+    match result {
+        Ok(embeddings) => {
+            let mut embeddings = embeddings.into_iter();
+            for text in &texts {
+                let embedding = match text {
+                    Some(_) => embeddings.next().unwrap_or_default(),
+                    None => vec![0.0],
+                };
+                let embedding = embedding.into_iter().map(F32::from).collect::<Vec<F32>>();
+                output.push(embedding.into());
+            }
+        }
+        Err(e) => {
+            if let Some(row) = texts.iter().position(|text| text.is_some()) {
+                ctx.set_error(row, format!("ai embedding request error:{:?}", e));
+            }
+            for _ in &texts {
+                output.push(vec![F32::from(0.0)].into());
+            }
+        }
+    }
+
+    match val {
+        ValueRef::Scalar(_) => Value::Scalar(ArrayType::<Float32Type>::build_scalar(output)),
+        ValueRef::Column(_) => Value::Column(ArrayType::<Float32Type>::build_column(output)),
+    }
+}
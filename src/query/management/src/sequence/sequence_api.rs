@@ -0,0 +1,37 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::Sequence;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait SequenceApi: Sync + Send {
+    // Add a sequence to /tenant/sequence-name, a new id is assigned to it.
+    async fn add_sequence(&self, sequence: Sequence) -> Result<u64>;
+
+    // Get sequence by name.
+    async fn get_sequence(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Sequence>>;
+
+    // Get all the sequences for a tenant.
+    async fn get_sequences(&self) -> Result<Vec<Sequence>>;
+
+    // Drop the tenant's sequence by name.
+    async fn drop_sequence(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    // Reserve `count` values of the sequence in a meta transaction,
+    // returns the sequence with `next` pointing to the first reserved value.
+    async fn allocate_sequence_values(&self, name: &str, count: u64) -> Result<Sequence>;
+}
@@ -0,0 +1,188 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::reply::txn_reply_to_api_result;
+use common_meta_api::txn_cond_seq;
+use common_meta_api::txn_op_put;
+use common_meta_app::app_error::TxnRetryMaxTimes;
+use common_meta_app::principal::Sequence;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::ConditionResult::Eq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::TxnRequest;
+
+use crate::sequence::SequenceApi;
+
+static SEQUENCE_API_KEY_PREFIX: &str = "__fd_sequences";
+// The seq of this key is bumped to generate the ids of sequences,
+// in the same key space as the other id generators of meta.
+static SEQUENCE_ID_GEN_KEY: &str = "__fd_id_gen/sequence_id";
+const TXN_MAX_RETRY_TIMES: u32 = 10;
+
+pub struct SequenceMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    sequence_prefix: String,
+}
+
+impl SequenceMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while sequence mgr create)",
+            ));
+        }
+
+        Ok(SequenceMgr {
+            kv_api,
+            sequence_prefix: format!("{}/{}", SEQUENCE_API_KEY_PREFIX, escape_for_key(tenant)?),
+        })
+    }
+
+    // Generate a new id, the seq numbers of meta are increasing.
+    #[async_backtrace::framed]
+    async fn fetch_sequence_id(&self) -> Result<u64> {
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                SEQUENCE_ID_GEN_KEY,
+                MatchSeq::GE(0),
+                Operation::Update(vec![]),
+                None,
+            ))
+            .await?;
+        // MatchSeq::GE(0) always succeeds.
+        Ok(res.result.map(|v| v.seq).unwrap_or_default())
+    }
+
+    fn make_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.sequence_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SequenceApi for SequenceMgr {
+    #[async_backtrace::framed]
+    async fn add_sequence(&self, mut sequence: Sequence) -> Result<u64> {
+        sequence.id = self.fetch_sequence_id().await?;
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&sequence)?);
+        let key = self.make_key(&sequence.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::SequenceAlreadyExists(format!(
+                "Sequence '{}' already exists, seq [{}]",
+                sequence.name, v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequence(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Sequence>> {
+        let key = self.make_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownSequence(format!("Unknown sequence {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownSequence(format!(
+                "Unknown sequence {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequences(&self) -> Result<Vec<Sequence>> {
+        let values = self.kv_api.prefix_list_kv(&self.sequence_prefix).await?;
+
+        let mut sequences = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let sequence = Sequence::try_from(value.data)?;
+            sequences.push(sequence);
+        }
+        Ok(sequences)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_sequence(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownSequence(format!(
+                "Unknown sequence {}",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn allocate_sequence_values(&self, name: &str, count: u64) -> Result<Sequence> {
+        let key = self.make_key(name)?;
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let seq_v = self.get_sequence(name, MatchSeq::GE(0)).await?;
+            let mut sequence = seq_v.data;
+            let reserved = sequence.clone();
+            sequence.allocate(count)?;
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // nobody else reserved values in the meantime
+                    txn_cond_seq(&key, Eq, seq_v.seq),
+                ],
+                if_then: vec![txn_op_put(&key, serde_json::to_vec(&sequence)?)],
+                else_then: vec![],
+            };
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(reserved);
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("allocate_sequence_values", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+}
@@ -62,6 +62,7 @@ use crate::input_formats::input_pipeline::RowBatchTrait;
 use crate::input_formats::input_split::DynData;
 use crate::input_formats::input_split::FileInfo;
 use crate::input_formats::InputContext;
+use crate::input_formats::InputFileMeta;
 use crate::input_formats::InputFormat;
 use crate::input_formats::SplitInfo;
 
@@ -238,6 +239,7 @@ impl DynData for SplitMeta {
 
 pub struct RowGroupInMemory {
     pub split_info: String,
+    pub file_path: String,
     pub meta: RowGroupMetaData,
     // for input, they are in the order of schema.
     // for select, they are the fields used in query.
@@ -259,7 +261,7 @@ impl RowBatchTrait for RowGroupInMemory {
 
 impl RowGroupInMemory {
     fn read<R: Read + Seek>(
-        split_info: String,
+        split_info: &SplitInfo,
         reader: &mut R,
         meta: RowGroupMetaData,
         fields: Arc<Vec<Field>>,
@@ -273,7 +275,8 @@ impl RowGroupInMemory {
             filed_arrays.push(data)
         }
         Ok(Self {
-            split_info,
+            split_info: split_info.to_string(),
+            file_path: split_info.file.path.clone(),
             meta,
             field_meta_indexes,
             field_arrays: filed_arrays,
@@ -322,6 +325,7 @@ impl RowGroupInMemory {
 
         Ok(Self {
             split_info: split_info.to_string(),
+            file_path: split_info.file.path.clone(),
             meta,
             field_meta_indexes,
             field_arrays,
@@ -419,6 +423,13 @@ impl BlockBuilderTrait for ParquetBlockBuilder {
                 })
                 .collect();
 
+            // The errors found after reading are accounted by the file of the rows.
+            if self.ctx.on_error_map.is_some() {
+                return blocks
+                    .into_iter()
+                    .map(|block| block.add_meta(Some(InputFileMeta::create(rg.file_path.clone()))))
+                    .collect();
+            }
             Ok(blocks)
         } else {
             Ok(vec![])
@@ -436,7 +447,6 @@ impl AligningStateTrait for ParquetAligningState {
     type Pipe = ParquetFormatPipe;
 
     fn align(&mut self, read_batch: Option<ReadBatch>) -> Result<Vec<RowGroupInMemory>> {
-        let split_info = self.split_info.to_string();
         if let Some(rb) = read_batch {
             if let ReadBatch::Buffer(b) = rb {
                 self.buffers.push(b)
@@ -461,7 +471,7 @@ impl AligningStateTrait for ParquetAligningState {
             let mut row_batches = Vec::with_capacity(file_meta.row_groups.len());
             for row_group in file_meta.row_groups.into_iter() {
                 row_batches.push(RowGroupInMemory::read(
-                    split_info.clone(),
+                    &self.split_info,
                     &mut cursor,
                     row_group,
                     fields.clone(),
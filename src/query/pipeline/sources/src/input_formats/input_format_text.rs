@@ -46,6 +46,7 @@ use crate::input_formats::input_split::FileInfo;
 use crate::input_formats::split_by_size;
 use crate::input_formats::BeyondEndReader;
 use crate::input_formats::InputContext;
+use crate::input_formats::InputFileMeta;
 use crate::input_formats::InputFormat;
 use crate::input_formats::SplitInfo;
 
@@ -481,6 +482,9 @@ pub struct BlockBuilder<T> {
     pub ctx: Arc<InputContext>,
     pub mutable_columns: Vec<ColumnBuilder>,
     pub num_rows: usize,
+    /// The file of the rows in `mutable_columns` when copying, the blocks don't mix the
+    /// rows of different files.
+    pub file_name: Option<String>,
     phantom: PhantomData<T>,
 }
 
@@ -505,6 +509,7 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
             ctx,
             mutable_columns: columns,
             num_rows: 0,
+            file_name: None,
             field_decoder,
             phantom: PhantomData,
         }
@@ -529,7 +534,15 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
         if columns.is_empty() || columns[0].len() == 0 {
             Ok(vec![])
         } else {
-            Ok(vec![DataBlock::new_from_columns(columns)])
+            let block = DataBlock::new_from_columns(columns);
+            match &self.file_name {
+                Some(file_name) => {
+                    Ok(vec![block.add_meta(Some(InputFileMeta::create(
+                        file_name.clone(),
+                    )))?])
+                }
+                None => Ok(vec![block]),
+            }
         }
     }
 
@@ -557,6 +570,12 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
     fn deserialize(&mut self, batch: Option<RowBatch>) -> Result<Vec<DataBlock>> {
         if let Some(b) = batch {
             let file_name = b.split_info.file.path.clone();
+            // The errors found after reading are accounted by the file of the rows.
+            let mut blocks = vec![];
+            if self.ctx.on_error_map.is_some() && self.file_name.as_ref() != Some(&file_name) {
+                blocks = self.flush()?;
+                self.file_name = Some(file_name.clone());
+            }
             self.num_rows += b.row_ends.len();
             let r = T::deserialize(self, b)?;
             self.merge_map(r, file_name);
@@ -569,10 +588,9 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
             if self.num_rows >= self.ctx.block_compact_thresholds.min_rows_per_block
                 || mem > self.ctx.block_compact_thresholds.max_bytes_per_block
             {
-                self.flush()
-            } else {
-                Ok(vec![])
+                blocks.extend(self.flush()?);
             }
+            Ok(blocks)
         } else {
             self.flush()
         }
@@ -15,6 +15,7 @@
 mod beyond_end_reader;
 mod impls;
 mod input_context;
+mod input_file_meta;
 mod input_format;
 mod input_format_text;
 mod input_pipeline;
@@ -27,6 +28,7 @@ pub use beyond_end_reader::BeyondEndReader;
 pub use input_context::InputContext;
 pub use input_context::InputPlan;
 pub use input_context::StreamPlan;
+pub use input_file_meta::InputFileMeta;
 pub use input_format::InputFormat;
 pub use input_format_text::*;
 pub use input_pipeline::StreamingReadBatch;
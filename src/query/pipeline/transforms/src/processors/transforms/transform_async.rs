@@ -121,12 +121,12 @@ impl<T: AsyncTransform> AsyncTransformer<T> {
     fn pull_data(&mut self) -> Result<Event> {
         if self.input.has_data() {
             self.input_data = Some(self.input.pull_data().unwrap()?);
-            return Ok(Event::Sync);
+            return Ok(Event::Async);
         }
 
         if self.input.is_finished() {
             return match !self.called_on_finish {
-                true => Ok(Event::Sync),
+                true => Ok(Event::Async),
                 false => {
                     self.output.finish();
                     Ok(Event::Finished)
@@ -145,7 +145,7 @@ impl<T: AsyncTransform> AsyncTransformer<T> {
 
     fn finish_input(&mut self) -> Result<Event> {
         match !self.called_on_finish {
-            true => Ok(Event::Sync),
+            true => Ok(Event::Async),
             false => {
                 self.input.finish();
                 Ok(Event::Finished)
@@ -25,15 +25,18 @@ use common_base::base::GlobalInstance;
 use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::Thread;
 use common_base::runtime::TrySpawn;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use common_catalog::runtime_filter_info::RuntimeFilterKey;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_grpc::ConnectionFactory;
-use common_profile::ProfSpanSetRef;
+use common_meta_types::NodeInfo;
 use common_sql::executor::PhysicalPlan;
 use parking_lot::Mutex;
 use parking_lot::ReentrantMutex;
 use tonic::Status;
+use tracing::warn;
 
 use crate::api::rpc::exchange::exchange_params::ExchangeParams;
 use crate::api::rpc::exchange::exchange_params::MergeExchangeParams;
@@ -42,6 +45,7 @@ use crate::api::rpc::exchange::exchange_sink::ExchangeSink;
 use crate::api::rpc::exchange::exchange_transform::ExchangeTransform;
 use crate::api::rpc::exchange::statistics_receiver::StatisticsReceiver;
 use crate::api::rpc::exchange::statistics_sender::StatisticsSender;
+use crate::api::rpc::flight_actions::PublishRuntimeFilter;
 use crate::api::rpc::flight_client::FlightExchange;
 use crate::api::rpc::flight_client::FlightReceiver;
 use crate::api::rpc::flight_client::FlightSender;
@@ -49,6 +53,7 @@ use crate::api::rpc::Packet;
 use crate::api::DataExchange;
 use crate::api::DefaultExchangeInjector;
 use crate::api::ExchangeInjector;
+use crate::api::FlightAction;
 use crate::api::FlightClient;
 use crate::api::FragmentPlanPacket;
 use crate::api::InitNodesChannelPacket;
@@ -171,6 +176,68 @@ impl DataExchangeManager {
             .expect("create client future must be joined successfully")
     }
 
+    /// Publishes the runtime filter of the build rows of a hash join this node has, and sends
+    /// it to the other nodes the build rows are shuffled to, which merge it with theirs.
+    pub fn publish_runtime_filter(
+        &self,
+        query_id: &str,
+        key: RuntimeFilterKey,
+        filter: RuntimeFilterInfo,
+    ) -> Result<()> {
+        let (ctx, current_executor, executors_info) = {
+            let queries_coordinator_guard = self.queries_coordinator.lock();
+            let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
+
+            match queries_coordinator
+                .get(query_id)
+                .and_then(|coordinator| coordinator.info.as_ref())
+            {
+                Some(info) => (
+                    info.query_ctx.clone(),
+                    info.current_executor.clone(),
+                    info.executors_info.clone(),
+                ),
+                None => {
+                    return Err(ErrorCode::Internal(format!(
+                        "Query {} not found in cluster.",
+                        query_id
+                    )));
+                }
+            }
+        };
+
+        let num_partials = executors_info.len();
+        let timeout = ctx.get_settings().get_flight_client_timeout()?;
+        for (executor, node) in executors_info {
+            if executor == current_executor {
+                continue;
+            }
+
+            let action = FlightAction::PublishRuntimeFilter(PublishRuntimeFilter {
+                query_id: query_id.to_string(),
+                key,
+                num_partials,
+                filter: filter.clone(),
+            });
+            GlobalIORuntime::instance().spawn(async move {
+                let res = match Self::create_client(&node.flight_address).await {
+                    Ok(mut client) => client.execute_action(action, timeout).await,
+                    Err(cause) => Err(cause),
+                };
+                if let Err(cause) = res {
+                    warn!(
+                        "Cannot send the runtime filter to the node {}, cause: {:?}",
+                        executor, cause
+                    );
+                }
+            });
+        }
+
+        ctx.get_runtime_filter_channel(key)
+            .publish_partial(Some(filter), num_partials);
+        Ok(())
+    }
+
     // Execute query in background
     pub fn execute_partial_query(&self, query_id: &str) -> Result<()> {
         let queries_coordinator_guard = self.queries_coordinator.lock();
@@ -389,8 +456,10 @@ impl DataExchangeManager {
 struct QueryInfo {
     query_id: String,
     current_executor: String,
+    enable_profiling: bool,
     query_ctx: Arc<QueryContext>,
     query_executor: Option<Arc<PipelineCompleteExecutor>>,
+    executors_info: HashMap<String, Arc<NodeInfo>>,
 }
 
 static FLIGHT_SENDER: u8 = 1;
@@ -547,7 +616,9 @@ impl QueryCoordinator {
             query_ctx: ctx.clone(),
             query_id: packet.query_id.clone(),
             current_executor: packet.executor.clone(),
+            enable_profiling: packet.enable_profiling,
             query_executor: None,
+            executors_info: packet.executors_info.clone(),
         });
 
         for fragment in &packet.fragments {
@@ -560,7 +631,7 @@ impl QueryCoordinator {
         for fragment in &packet.fragments {
             let fragment_id = fragment.fragment_id;
             if let Some(coordinator) = self.fragments_coordinator.get_mut(&fragment_id) {
-                coordinator.prepare_pipeline(ctx.clone())?;
+                coordinator.prepare_pipeline(ctx.clone(), packet.enable_profiling)?;
             }
         }
 
@@ -576,7 +647,7 @@ impl QueryCoordinator {
         // Merge pipelines if exist locally pipeline
         if let Some(mut fragment_coordinator) = self.fragments_coordinator.remove(&fragment_id) {
             let info = self.info.as_ref().expect("QueryInfo is none");
-            fragment_coordinator.prepare_pipeline(ctx.clone())?;
+            fragment_coordinator.prepare_pipeline(ctx.clone(), info.enable_profiling)?;
 
             if fragment_coordinator.pipeline_build_res.is_none() {
                 return Err(ErrorCode::Internal(
@@ -781,13 +852,18 @@ impl FragmentCoordinator {
         Err(ErrorCode::Internal("Cannot find data exchange."))
     }
 
-    pub fn prepare_pipeline(&mut self, ctx: Arc<QueryContext>) -> Result<()> {
+    pub fn prepare_pipeline(
+        &mut self,
+        ctx: Arc<QueryContext>,
+        enable_profiling: bool,
+    ) -> Result<()> {
         if !self.initialized {
             self.initialized = true;
 
+            let prof_span_set = ctx.get_prof_span_set();
             let pipeline_ctx = QueryContext::create_from(ctx);
             let pipeline_builder =
-                PipelineBuilder::create(pipeline_ctx, false, ProfSpanSetRef::default());
+                PipelineBuilder::create(pipeline_ctx, enable_profiling, prof_span_set);
             self.pipeline_build_res = Some(pipeline_builder.finalize(&self.physical_plan)?);
         }
 
@@ -165,6 +165,7 @@ impl StatisticsReceiver {
             Ok(Some(DataPacket::ProgressAndPrecommit {
                 progress,
                 precommit,
+                profiles,
             })) => {
                 for progress_info in progress {
                     progress_info.inc(ctx);
@@ -174,6 +175,10 @@ impl StatisticsReceiver {
                     precommit_block.precommit(ctx);
                 }
 
+                for profile_info in profiles {
+                    profile_info.merge(ctx);
+                }
+
                 Ok(false)
             }
         }
@@ -27,6 +27,7 @@ use futures_util::future::Either;
 use crate::api::rpc::flight_client::FlightExchange;
 use crate::api::rpc::flight_client::FlightSender;
 use crate::api::rpc::packets::PrecommitBlock;
+use crate::api::rpc::packets::ProfileInfo;
 use crate::api::rpc::packets::ProgressInfo;
 use crate::api::DataPacket;
 use crate::sessions::QueryContext;
@@ -154,6 +155,7 @@ impl StatisticsSender {
                     .send(DataPacket::ProgressAndPrecommit {
                         progress: Self::fetch_progress(ctx).await?,
                         precommit: Self::fetch_precommit(ctx).await?,
+                        profiles: Self::fetch_profiles(ctx).await?,
                     })
                     .await
             }
@@ -188,6 +190,18 @@ impl StatisticsSender {
         Ok(progress_info)
     }
 
+    #[async_backtrace::framed]
+    async fn fetch_profiles(ctx: &Arc<QueryContext>) -> Result<Vec<ProfileInfo>> {
+        // The spans are recorded when the processors finish, so each of them is sent once.
+        Ok(ctx
+            .get_prof_span_set()
+            .lock()
+            .unwrap()
+            .drain()
+            .map(|(plan_id, prof_span)| ProfileInfo { plan_id, prof_span })
+            .collect())
+    }
+
     #[async_backtrace::framed]
     async fn fetch_precommit(ctx: &Arc<QueryContext>) -> Result<Vec<PrecommitBlock>> {
         Ok(ctx
@@ -15,6 +15,8 @@
 use std::convert::TryInto;
 
 use common_arrow::arrow_format::flight::data::Action;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use common_catalog::runtime_filter_info::RuntimeFilterKey;
 use common_exception::ErrorCode;
 use common_exception::ToErrorCode;
 use tonic::Status;
@@ -76,11 +78,45 @@ impl TryInto<Vec<u8>> for InitNodesChannel {
     }
 }
 
+/// The runtime filter of the build rows a node has, for a hash join whose build rows
+/// are shuffled to the nodes of the cluster.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct PublishRuntimeFilter {
+    pub query_id: String,
+    pub key: RuntimeFilterKey,
+    /// The number of nodes whose filters are merged.
+    pub num_partials: usize,
+    pub filter: RuntimeFilterInfo,
+}
+
+impl TryInto<PublishRuntimeFilter> for Vec<u8> {
+    type Error = Status;
+
+    fn try_into(self) -> Result<PublishRuntimeFilter, Self::Error> {
+        match serde_json::from_slice::<PublishRuntimeFilter>(&self) {
+            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            Ok(action) => Ok(action),
+        }
+    }
+}
+
+impl TryInto<Vec<u8>> for PublishRuntimeFilter {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&self).map_err_to_code(
+            ErrorCode::Internal,
+            || "Logical error: cannot serialize PublishRuntimeFilter.",
+        )
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FlightAction {
     InitQueryFragmentsPlan(InitQueryFragmentsPlan),
     InitNodesChannel(InitNodesChannel),
     ExecutePartialQuery(String),
+    PublishRuntimeFilter(PublishRuntimeFilter),
 }
 
 impl TryInto<FlightAction> for Action {
@@ -98,6 +134,7 @@ impl TryInto<FlightAction> for Action {
                     buf, length, capacity,
                 )))
             },
+            "PublishRuntimeFilter" => Ok(FlightAction::PublishRuntimeFilter(self.body.try_into()?)),
             un_implemented => Err(Status::unimplemented(format!(
                 "UnImplement action {}",
                 un_implemented
@@ -123,6 +160,10 @@ impl TryInto<Action> for FlightAction {
                 r#type: String::from("ExecutePartialQuery"),
                 body: query_id.into_bytes(),
             }),
+            FlightAction::PublishRuntimeFilter(publish_runtime_filter) => Ok(Action {
+                r#type: String::from("PublishRuntimeFilter"),
+                body: publish_runtime_filter.try_into()?,
+            }),
         }
     }
 }
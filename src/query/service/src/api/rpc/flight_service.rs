@@ -44,6 +44,7 @@ use crate::api::rpc::request_builder::RequestGetter;
 use crate::api::DataExchangeManager;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::TableContext;
 
 pub type FlightStream<T> =
     Pin<Box<dyn Stream<Item = Result<T, tonic::Status>> + Send + Sync + 'static>>;
@@ -205,6 +206,17 @@ impl FlightService for DatabendQueryFlightService {
                     return Err(cause.into());
                 }
 
+                FlightResult { body: vec![] }
+            }
+            FlightAction::PublishRuntimeFilter(publish_runtime_filter) => {
+                DataExchangeManager::instance()
+                    .get_query_ctx(&publish_runtime_filter.query_id)?
+                    .get_runtime_filter_channel(publish_runtime_filter.key)
+                    .publish_partial(
+                        Some(publish_runtime_filter.filter),
+                        publish_runtime_filter.num_partials,
+                    );
+
                 FlightResult { body: vec![] }
             }
         };
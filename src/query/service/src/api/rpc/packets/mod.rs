@@ -15,6 +15,7 @@
 mod packet;
 mod packet_data;
 mod packet_data_precommit;
+mod packet_data_profile;
 mod packet_data_progressinfo;
 mod packet_execute;
 mod packet_executor;
@@ -25,6 +26,7 @@ pub use packet::Packet;
 pub use packet_data::DataPacket;
 pub use packet_data::FragmentData;
 pub use packet_data_precommit::PrecommitBlock;
+pub use packet_data_profile::ProfileInfo;
 pub use packet_data_progressinfo::ProgressInfo;
 pub use packet_execute::ExecutePartialQueryPacket;
 pub use packet_executor::QueryFragmentsPlanPacket;
@@ -24,6 +24,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use tracing::error;
 
+use crate::api::rpc::packets::ProfileInfo;
 use crate::api::rpc::packets::ProgressInfo;
 use crate::api::PrecommitBlock;
 
@@ -56,6 +57,7 @@ pub enum DataPacket {
     ProgressAndPrecommit {
         progress: Vec<ProgressInfo>,
         precommit: Vec<PrecommitBlock>,
+        profiles: Vec<ProfileInfo>,
     },
 }
 
@@ -78,10 +80,12 @@ impl TryFrom<DataPacket> for FlightData {
             DataPacket::ProgressAndPrecommit {
                 progress,
                 precommit,
+                profiles,
             } => {
                 let mut data_body = vec![];
                 data_body.write_u64::<BigEndian>(progress.len() as u64)?;
                 data_body.write_u64::<BigEndian>(precommit.len() as u64)?;
+                data_body.write_u64::<BigEndian>(profiles.len() as u64)?;
 
                 // Progress.
                 for progress_info in progress {
@@ -93,6 +97,11 @@ impl TryFrom<DataPacket> for FlightData {
                     precommit_block.write(&mut data_body)?;
                 }
 
+                // Profiles.
+                for profile_info in profiles {
+                    profile_info.write(&mut data_body)?;
+                }
+
                 FlightData {
                     data_body,
                     data_header: vec![],
@@ -138,6 +147,7 @@ impl TryFrom<FlightData> for DataPacket {
                 let mut bytes = flight_data.data_body.as_slice();
                 let progress_size = bytes.read_u64::<BigEndian>()?;
                 let precommit_size = bytes.read_u64::<BigEndian>()?;
+                let profiles_size = bytes.read_u64::<BigEndian>()?;
 
                 // Progress.
                 let mut progress_info = Vec::with_capacity(progress_size as usize);
@@ -151,9 +161,16 @@ impl TryFrom<FlightData> for DataPacket {
                     precommit.push(PrecommitBlock::read(&mut bytes)?);
                 }
 
+                // Profiles.
+                let mut profiles = Vec::with_capacity(profiles_size as usize);
+                for _index in 0..profiles_size {
+                    profiles.push(ProfileInfo::read(&mut bytes)?);
+                }
+
                 Ok(DataPacket::ProgressAndPrecommit {
                     precommit,
                     progress: progress_info,
+                    profiles,
                 })
             }
             0x05 => Ok(DataPacket::Dictionary(flight_data)),
@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::io::Write;
+use std::sync::Arc;
+
+use byteorder::BigEndian;
+use byteorder::ReadBytesExt;
+use byteorder::WriteBytesExt;
+use common_exception::Result;
+use common_profile::ProfSpan;
+
+use crate::sessions::QueryContext;
+
+/// The runtime statistics of a plan collected in a remote node.
+#[derive(Clone, Debug)]
+pub struct ProfileInfo {
+    pub plan_id: u32,
+    pub prof_span: ProfSpan,
+}
+
+impl ProfileInfo {
+    pub fn merge(self, ctx: &Arc<QueryContext>) {
+        ctx.get_prof_span_set()
+            .lock()
+            .unwrap()
+            .update(self.plan_id, self.prof_span);
+    }
+
+    pub fn write<T: Write>(self, bytes: &mut T) -> Result<()> {
+        bytes.write_u32::<BigEndian>(self.plan_id)?;
+        bytes.write_u64::<BigEndian>(self.prof_span.process_time)?;
+        bytes.write_u64::<BigEndian>(self.prof_span.wait_time)?;
+        bytes.write_u64::<BigEndian>(self.prof_span.wall_time)?;
+        bytes.write_u64::<BigEndian>(self.prof_span.output_rows)?;
+        bytes.write_u64::<BigEndian>(self.prof_span.output_bytes)?;
+        bytes.write_u64::<BigEndian>(self.prof_span.scan_bytes)?;
+        bytes.write_u64::<BigEndian>(self.prof_span.spill_count)?;
+        bytes.write_u64::<BigEndian>(self.prof_span.spill_bytes)?;
+        bytes.write_u64::<BigEndian>(self.prof_span.peak_memory_usage)?;
+        Ok(())
+    }
+
+    pub fn read<T: Read>(bytes: &mut T) -> Result<ProfileInfo> {
+        let plan_id = bytes.read_u32::<BigEndian>()?;
+        let prof_span = ProfSpan {
+            process_time: bytes.read_u64::<BigEndian>()?,
+            wait_time: bytes.read_u64::<BigEndian>()?,
+            wall_time: bytes.read_u64::<BigEndian>()?,
+            output_rows: bytes.read_u64::<BigEndian>()?,
+            output_bytes: bytes.read_u64::<BigEndian>()?,
+            scan_bytes: bytes.read_u64::<BigEndian>()?,
+            spill_count: bytes.read_u64::<BigEndian>()?,
+            spill_bytes: bytes.read_u64::<BigEndian>()?,
+            peak_memory_usage: bytes.read_u64::<BigEndian>()?,
+        };
+        Ok(ProfileInfo { plan_id, prof_span })
+    }
+}
@@ -113,6 +113,7 @@ impl AuthMgr {
                     }
                 };
 
+                self.check_network_policy(&session, &user).await?;
                 session.set_authed_user(user, jwt.custom.role).await?;
             }
             Credential::Password {
@@ -124,6 +125,8 @@ impl AuthMgr {
                 let user = user_api
                     .get_user_with_client_ip(&tenant, n, h.as_ref().unwrap_or(&"%".to_string()))
                     .await?;
+                self.check_network_policy(&session, &user).await?;
+                user_api.check_login_lockout(&tenant, &user).await?;
                 let user = match &user.auth_info {
                     AuthInfo::None => user,
                     AuthInfo::Password {
@@ -135,15 +138,32 @@ impl AuthMgr {
                             if *h == t.hash(p) {
                                 user
                             } else {
+                                user_api.record_login_failure(&tenant, &user).await?;
                                 return Err(ErrorCode::AuthenticateFailure("wrong password"));
                             }
                         }
                     },
                     _ => return Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 };
+                user_api.record_login_success(&tenant, &user).await?;
                 session.set_authed_user(user, None).await?;
             }
         };
         Ok(())
     }
+
+    // Check the peer address of the session is allowed by the network policy of the user,
+    // or the global one set by `network_policy` setting.
+    #[async_backtrace::framed]
+    async fn check_network_policy(&self, session: &Arc<Session>, user: &UserInfo) -> Result<()> {
+        let global_policy = session.get_settings().get_network_policy()?;
+        UserApiProvider::instance()
+            .check_network_policy(
+                &session.get_current_tenant(),
+                user,
+                Some(&global_policy),
+                session.get_client_host().map(|addr| addr.ip()),
+            )
+            .await
+    }
 }
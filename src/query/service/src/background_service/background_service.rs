@@ -0,0 +1,344 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::Table;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::Scalar;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::schema::DatabaseType;
+use common_sql::Planner;
+use common_storages_fuse::table_functions::ClusteringInformation;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::DEFAULT_AVG_DEPTH_THRESHOLD;
+use common_storages_fuse::DEFAULT_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_AUTO_COMPACT;
+use common_storages_fuse::FUSE_OPT_KEY_AUTO_PURGE;
+use common_storages_fuse::FUSE_OPT_KEY_AUTO_RECLUSTER;
+use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
+use common_storages_system::BackgroundJobLogElement;
+use common_storages_system::BackgroundJobQueue;
+use common_users::UserApiProvider;
+use futures::TryStreamExt;
+use parking_lot::Mutex;
+use storages_common_table_meta::meta::SnapshotId;
+use storages_common_table_meta::meta::TableSnapshot;
+use tracing::info;
+use tracing::warn;
+
+use crate::background_service::compact_reason;
+use crate::background_service::recluster_reason;
+use crate::background_service::MaintenanceJobType;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+// How often the history snapshots of a table are purged.
+const PURGE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// BackgroundService periodically checks the statistics of the fuse tables that opted in by
+/// the table options `auto_compact`, `auto_recluster` and `auto_purge`, and compacts,
+/// reclusters or purges them when needed. The jobs are logged into `system.background_jobs`.
+///
+/// In a cluster, each table is maintained by only one of the nodes.
+pub struct BackgroundService {
+    tenant: String,
+    interval: Duration,
+    // The snapshot that a job of the table was last checked against, the table is not checked
+    // for the job again until it has a new snapshot.
+    checked_snapshots: Mutex<HashMap<(u64, MaintenanceJobType), SnapshotId>>,
+    last_purged: Mutex<HashMap<u64, Instant>>,
+}
+
+impl BackgroundService {
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        if !cfg.query.enable_background_service {
+            return Ok(());
+        }
+
+        let service = Arc::new(BackgroundService {
+            tenant: cfg.query.tenant_id.clone(),
+            interval: Duration::from_secs(cfg.query.background_service_interval_secs.max(1)),
+            checked_snapshots: Mutex::new(HashMap::new()),
+            last_purged: Mutex::new(HashMap::new()),
+        });
+        service.start();
+        Ok(())
+    }
+
+    fn start(self: Arc<Self>) {
+        tokio::spawn(async_backtrace::location!().frame(async move {
+            loop {
+                tokio::time::sleep(self.interval).await;
+                if let Err(cause) = self.check_tables().await {
+                    warn!("background service failed to check tables: {}", cause);
+                }
+            }
+        }));
+    }
+
+    #[async_backtrace::framed]
+    async fn check_tables(&self) -> Result<()> {
+        let ctx = self.create_context().await?;
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        for database in catalog.list_databases(&self.tenant).await? {
+            for table in catalog.list_tables(&self.tenant, database.name()).await? {
+                if let Err(cause) = self
+                    .check_table(&ctx, database.name(), table.as_ref())
+                    .await
+                {
+                    warn!(
+                        "background service failed to check table {}.{}: {}",
+                        database.name(),
+                        table.name(),
+                        cause
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn check_table(
+        &self,
+        ctx: &Arc<QueryContext>,
+        database: &str,
+        table: &dyn Table,
+    ) -> Result<()> {
+        let table_info = table.get_table_info();
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Ok(());
+        }
+        let fuse_table = match FuseTable::try_from_table(table) {
+            Ok(fuse_table) => fuse_table,
+            Err(_) => return Ok(()),
+        };
+        let auto_compact = fuse_table.get_option(FUSE_OPT_KEY_AUTO_COMPACT, false);
+        let auto_recluster = fuse_table.get_option(FUSE_OPT_KEY_AUTO_RECLUSTER, false);
+        let auto_purge = fuse_table.get_option(FUSE_OPT_KEY_AUTO_PURGE, false);
+        let table_id = table_info.ident.table_id;
+        if !(auto_compact || auto_recluster || auto_purge) || !self.is_owner(ctx, table_id) {
+            return Ok(());
+        }
+
+        let snapshot = match fuse_table.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let mut job = None;
+        if auto_compact && self.is_new_snapshot(table_id, MaintenanceJobType::Compact, &snapshot) {
+            let block_per_segment =
+                fuse_table.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+            job = compact_reason(
+                &snapshot.summary,
+                snapshot.segments.len() as u64,
+                fuse_table.get_block_compact_thresholds(),
+                block_per_segment as u64,
+            )
+            .map(|reason| (MaintenanceJobType::Compact, reason));
+            if job.is_none() {
+                self.mark_snapshot_checked(table_id, MaintenanceJobType::Compact, &snapshot);
+            }
+        }
+        if job.is_none()
+            && auto_recluster
+            && fuse_table.cluster_key_str().is_some()
+            && self.is_new_snapshot(table_id, MaintenanceJobType::Recluster, &snapshot)
+        {
+            job = self
+                .recluster_reason(ctx, fuse_table, &snapshot)
+                .await?
+                .map(|reason| (MaintenanceJobType::Recluster, reason));
+            if job.is_none() {
+                self.mark_snapshot_checked(table_id, MaintenanceJobType::Recluster, &snapshot);
+            }
+        }
+        if let Some((job_type, reason)) = job {
+            // A failed job leaves the snapshot unchecked, so it is retried in the next round.
+            if self.run_job(database, table.name(), job_type, reason).await {
+                self.mark_snapshot_checked(table_id, job_type, &snapshot);
+            }
+        }
+
+        if auto_purge && snapshot.prev_snapshot_id.is_some() {
+            let purge_due = self
+                .last_purged
+                .lock()
+                .get(&table_id)
+                .map_or(true, |t| t.elapsed() >= PURGE_INTERVAL);
+            if purge_due {
+                self.run_job(
+                    database,
+                    table.name(),
+                    MaintenanceJobType::Purge,
+                    "the table has history snapshots".to_string(),
+                )
+                .await;
+                self.last_purged.lock().insert(table_id, Instant::now());
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn recluster_reason(
+        &self,
+        ctx: &Arc<QueryContext>,
+        fuse_table: &FuseTable,
+        snapshot: &TableSnapshot,
+    ) -> Result<Option<String>> {
+        let cluster_keys = fuse_table.cluster_keys(ctx.clone());
+        let plain_cluster_keys = fuse_table.cluster_key_str().cloned().unwrap_or_default();
+        let info =
+            ClusteringInformation::new(ctx.clone(), fuse_table, plain_cluster_keys, cluster_keys)
+                .get_clustering_info()
+                .await?;
+        let average_depth = match info.get_by_offset(4).value.as_scalar() {
+            Some(Scalar::Number(NumberScalar::Float64(depth))) => depth.0,
+            _ => return Ok(None),
+        };
+        let avg_depth_threshold = fuse_table.get_option(
+            FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD,
+            DEFAULT_AVG_DEPTH_THRESHOLD,
+        );
+        Ok(recluster_reason(
+            snapshot.summary.block_count,
+            average_depth,
+            avg_depth_threshold,
+        ))
+    }
+
+    // Each table is assigned to one node of the cluster by its id, so that the nodes do not
+    // run the jobs of the same table concurrently.
+    fn is_owner(&self, ctx: &QueryContext, table_id: u64) -> bool {
+        let cluster = ctx.get_cluster();
+        if cluster.is_empty() {
+            return true;
+        }
+        let mut nodes = cluster
+            .nodes
+            .iter()
+            .map(|node| node.id.as_str())
+            .collect::<Vec<_>>();
+        nodes.sort();
+        nodes[(table_id % nodes.len() as u64) as usize] == cluster.local_id
+    }
+
+    fn is_new_snapshot(
+        &self,
+        table_id: u64,
+        job_type: MaintenanceJobType,
+        snapshot: &TableSnapshot,
+    ) -> bool {
+        self.checked_snapshots.lock().get(&(table_id, job_type)) != Some(&snapshot.snapshot_id)
+    }
+
+    fn mark_snapshot_checked(
+        &self,
+        table_id: u64,
+        job_type: MaintenanceJobType,
+        snapshot: &TableSnapshot,
+    ) {
+        self.checked_snapshots
+            .lock()
+            .insert((table_id, job_type), snapshot.snapshot_id);
+    }
+
+    #[async_backtrace::framed]
+    async fn run_job(
+        &self,
+        database: &str,
+        table: &str,
+        job_type: MaintenanceJobType,
+        reason: String,
+    ) -> bool {
+        info!(
+            "background service starts to {} table {}.{}, because {}",
+            job_type, database, table, reason
+        );
+        let start = SystemTime::now();
+        let (status, error_message) = match self.execute_sql(&job_type.sql(database, table)).await {
+            Ok(_) => ("Succeeded", "".to_string()),
+            Err(cause) => {
+                warn!(
+                    "background service failed to {} table {}.{}: {}",
+                    job_type, database, table, cause
+                );
+                ("Failed", cause.to_string())
+            }
+        };
+
+        if let Ok(queue) = BackgroundJobQueue::instance() {
+            let _ = queue.append_data(BackgroundJobLogElement {
+                start_time: start
+                    .duration_since(UNIX_EPOCH)
+                    .expect("Time went backwards")
+                    .as_micros() as i64,
+                end_time: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("Time went backwards")
+                    .as_micros() as i64,
+                database: database.to_string(),
+                table: table.to_string(),
+                job_type: job_type.to_string(),
+                reason,
+                status: status.to_string(),
+                error_message,
+            });
+        }
+        status == "Succeeded"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_sql(&self, sql: &str) -> Result<()> {
+        let ctx = self.create_context().await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, extras) = planner.plan_sql(sql).await?;
+        ctx.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        let _: Vec<_> = stream.try_collect().await?;
+        Ok(())
+    }
+
+    // Create a query context of an internal session with the privileges of the builtin root user.
+    #[async_backtrace::framed]
+    async fn create_context(&self) -> Result<Arc<QueryContext>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::HTTPAPI("BackgroundService".to_string()))
+            .await?;
+        session.set_current_tenant(self.tenant.clone());
+        let user = UserApiProvider::instance()
+            .get_user(&self.tenant, UserIdentity::new("root", "localhost"))
+            .await?;
+        session.set_authed_user(user, None).await?;
+        session.create_query_context().await
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_expression::BlockThresholds;
+use storages_common_table_meta::meta::Statistics;
+
+// A table is compacted once it has this many times more blocks (or segments) than it
+// would have if all of them were full.
+const FRAGMENTATION_RATIO: u64 = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MaintenanceJobType {
+    Compact,
+    Recluster,
+    Purge,
+}
+
+impl MaintenanceJobType {
+    pub fn sql(&self, database: &str, table: &str) -> String {
+        match self {
+            MaintenanceJobType::Compact => {
+                format!("OPTIMIZE TABLE `{}`.`{}` COMPACT", database, table)
+            }
+            MaintenanceJobType::Recluster => {
+                format!("ALTER TABLE `{}`.`{}` RECLUSTER", database, table)
+            }
+            MaintenanceJobType::Purge => format!("OPTIMIZE TABLE `{}`.`{}` PURGE", database, table),
+        }
+    }
+}
+
+impl Display for MaintenanceJobType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaintenanceJobType::Compact => write!(f, "compact"),
+            MaintenanceJobType::Recluster => write!(f, "recluster"),
+            MaintenanceJobType::Purge => write!(f, "purge"),
+        }
+    }
+}
+
+/// Returns why the table needs to be compacted, or None if the blocks and segments of the
+/// table are not fragmented.
+pub fn compact_reason(
+    summary: &Statistics,
+    segment_count: u64,
+    thresholds: BlockThresholds,
+    block_per_segment: u64,
+) -> Option<String> {
+    let small_blocks = summary
+        .block_count
+        .saturating_sub(summary.perfect_block_count);
+    let expected_blocks = std::cmp::max(
+        ceil_div(summary.row_count, thresholds.max_rows_per_block as u64),
+        ceil_div(
+            summary.uncompressed_byte_size,
+            thresholds.max_bytes_per_block as u64,
+        ),
+    )
+    .max(1);
+    if small_blocks > 1 && summary.block_count > expected_blocks * FRAGMENTATION_RATIO {
+        return Some(format!(
+            "{} blocks ({} not full) hold {} rows, expect {} blocks",
+            summary.block_count, small_blocks, summary.row_count, expected_blocks
+        ));
+    }
+
+    let expected_segments = ceil_div(summary.block_count, block_per_segment.max(1)).max(1);
+    if segment_count > expected_segments * FRAGMENTATION_RATIO {
+        return Some(format!(
+            "{} segments hold {} blocks, expect {} segments",
+            segment_count, summary.block_count, expected_segments
+        ));
+    }
+    None
+}
+
+/// Returns why the table needs to be reclustered, or None if the average depth of the blocks
+/// is within the threshold, which is the same as the one `ALTER TABLE RECLUSTER` selects the
+/// blocks with.
+pub fn recluster_reason(
+    block_count: u64,
+    average_depth: f64,
+    avg_depth_threshold: f64,
+) -> Option<String> {
+    let threshold = if block_count > 100 {
+        block_count as f64 * avg_depth_threshold
+    } else {
+        1.0
+    };
+    if average_depth > threshold {
+        Some(format!(
+            "average depth {} of {} blocks exceeds {}",
+            average_depth, block_count, threshold
+        ))
+    } else {
+        None
+    }
+}
+
+fn ceil_div(a: u64, b: u64) -> u64 {
+    if b == 0 { 0 } else { (a + b - 1) / b }
+}
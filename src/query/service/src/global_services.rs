@@ -29,8 +29,10 @@ use storages_common_cache_manager::CacheManager;
 
 use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
+use crate::background_service::BackgroundService;
 use crate::catalogs::CatalogManagerHelper;
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::AuditLogWriter;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
 
@@ -80,6 +82,8 @@ impl GlobalServices {
         .await?;
         RoleCacheManager::init()?;
         ShareEndpointManager::init()?;
+        AuditLogWriter::init(&config)?;
+        BackgroundService::init(&config)?;
 
         Ok(())
     }
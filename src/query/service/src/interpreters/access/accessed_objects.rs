@@ -0,0 +1,63 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+
+use common_sql::plans::InsertInputSource;
+
+use crate::interpreters::access::privilege_access::collect_scan_columns;
+use crate::interpreters::access::privilege_access::used_column_names;
+use crate::sessions::AccessedObjects;
+use crate::sql::plans::Plan;
+
+/// Extract the databases, tables and columns accessed by the bound plan.
+pub fn collect_accessed_objects(plan: &Plan) -> AccessedObjects {
+    let mut objects = AccessedObjects::default();
+    match plan {
+        Plan::Query {
+            s_expr, metadata, ..
+        } => {
+            let metadata = metadata.read();
+            let mut scan_columns = HashMap::new();
+            collect_scan_columns(s_expr, &mut scan_columns);
+            for table in metadata.tables() {
+                objects.add_table(table.catalog(), table.database(), table.name());
+                let columns =
+                    used_column_names(&metadata, table.index(), scan_columns.get(&table.index()));
+                for column in columns {
+                    objects.add_column(table.catalog(), table.database(), table.name(), &column);
+                }
+            }
+        }
+        Plan::Insert(plan) => {
+            objects.add_table(&plan.catalog, &plan.database, &plan.table);
+            if let InsertInputSource::SelectPlan(select_plan) = &plan.source {
+                objects.merge(collect_accessed_objects(select_plan));
+            }
+        }
+        Plan::Replace(plan) => {
+            objects.add_table(&plan.catalog, &plan.database, &plan.table);
+            if let InsertInputSource::SelectPlan(select_plan) = &plan.source {
+                objects.merge(collect_accessed_objects(select_plan));
+            }
+        }
+        Plan::Delete(plan) => {
+            objects.add_table(&plan.catalog_name, &plan.database_name, &plan.table_name);
+        }
+        Plan::Update(plan) => {
+            objects.add_table(&plan.catalog, &plan.database, &plan.table);
+        }
+        _ => {}
+    }
+    objects
+}
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod accessed_objects;
 mod accessor;
 mod management_mode_access;
 mod privilege_access;
 
+pub use accessed_objects::collect_accessed_objects;
 pub use accessor::AccessChecker;
 pub use accessor::Accessor;
 pub use management_mode_access::ManagementModeAccess;
+pub use privilege_access::validate_sequence_nextval;
 pub use privilege_access::PrivilegeAccess;
@@ -12,14 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::plan::CHANGE_ACTION;
+use common_catalog::plan::CHANGE_ROW_ID;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserPrivilegeType;
+use common_sql::optimizer::SExpr;
 use common_sql::plans::CopyPlan;
+use common_sql::plans::InsertInputSource;
+use common_sql::plans::RelOperator;
 use common_sql::plans::RewriteKind;
+use common_sql::plans::ScalarExpr;
+use common_sql::ColumnEntry;
+use common_sql::ColumnSet;
+use common_sql::IndexType;
+use common_sql::Metadata;
+use common_storages_fuse::STREAM_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_SOURCE_DATABASE_NAME;
+use storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_NAME;
 
 use crate::interpreters::access::AccessChecker;
 use crate::sessions::QueryContext;
@@ -33,6 +49,64 @@ impl PrivilegeAccess {
     pub fn create(ctx: Arc<QueryContext>) -> Box<dyn AccessChecker> {
         Box::new(PrivilegeAccess { ctx })
     }
+
+    // Validate the SELECT privilege on the table, without the table privilege, all the
+    // `columns` read from the table should be granted by column privileges.
+    #[async_backtrace::framed]
+    async fn validate_table_select(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        columns: &BTreeSet<String>,
+    ) -> Result<()> {
+        let session = self.ctx.get_current_session();
+        let result = session
+            .validate_privilege(
+                &GrantObject::Table(catalog.to_string(), database.to_string(), table.to_string()),
+                vec![UserPrivilegeType::Select],
+            )
+            .await;
+        let e = match result {
+            Err(e) if e.code() == ErrorCode::PERMISSION_DENIED => e,
+            result => return result,
+        };
+
+        let mut denied_columns = vec![];
+        for column in columns.iter() {
+            let result = session
+                .validate_privilege(
+                    &GrantObject::Column(
+                        catalog.to_string(),
+                        database.to_string(),
+                        table.to_string(),
+                        column.clone(),
+                    ),
+                    vec![UserPrivilegeType::Select],
+                )
+                .await;
+            match result {
+                Err(e) if e.code() == ErrorCode::PERMISSION_DENIED => {
+                    denied_columns.push(column.as_str());
+                }
+                result => result?,
+            }
+        }
+        if denied_columns.len() == columns.len() {
+            return Err(e);
+        }
+        if !denied_columns.is_empty() {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "Permission denied, user {} requires [Select] privilege on columns ({}) of '{}'.'{}'.'{}'",
+                &session.get_current_user()?.identity(),
+                denied_columns.join(", "),
+                catalog,
+                database,
+                table,
+            )));
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -43,6 +117,7 @@ impl AccessChecker for PrivilegeAccess {
 
         match plan {
             Plan::Query {
+                s_expr,
                 metadata,
                 rewrite_kind,
                 ..
@@ -58,21 +133,45 @@ impl AccessChecker for PrivilegeAccess {
                     }
                     _ => {}
                 };
+                if uses_nextval(s_expr) {
+                    validate_sequence_nextval(&self.ctx).await?;
+                }
                 let metadata = metadata.read().clone();
+                let mut scan_columns = HashMap::new();
+                collect_scan_columns(s_expr, &mut scan_columns);
                 for table in metadata.tables() {
                     if table.is_source_of_view() {
                         continue;
                     }
-                    session
-                        .validate_privilege(
-                            &GrantObject::Table(
-                                table.catalog().to_string(),
-                                table.database().to_string(),
-                                table.name().to_string(),
-                            ),
-                            vec![UserPrivilegeType::Select],
-                        )
-                        .await?
+                    let columns = used_column_names(
+                        &metadata,
+                        table.index(),
+                        scan_columns.get(&table.index()),
+                    );
+                    self.validate_table_select(
+                        table.catalog(),
+                        table.database(),
+                        table.name(),
+                        &columns,
+                    )
+                    .await?;
+
+                    // The changes read from a stream are the rows of its source table.
+                    let stream = table.table();
+                    if stream.engine() == STREAM_ENGINE {
+                        let options = stream.options();
+                        if let (Some(database), Some(name)) = (
+                            options.get(OPT_KEY_SOURCE_DATABASE_NAME),
+                            options.get(OPT_KEY_SOURCE_TABLE_NAME),
+                        ) {
+                            let columns = columns
+                                .into_iter()
+                                .filter(|c| c != CHANGE_ACTION && c != CHANGE_ROW_ID)
+                                .collect();
+                            self.validate_table_select(table.catalog(), database, name, &columns)
+                                .await?;
+                        }
+                    }
                 }
             }
             Plan::ExplainAnalyze { plan } | Plan::Explain { plan, .. } => self.check(plan).await?,
@@ -86,12 +185,15 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?
             }
-            Plan::CreateUDF(_) | Plan::CreateDatabase(_) => {
+            Plan::CreateUDF(_) | Plan::CreateDatabase(_) | Plan::CreateSequence(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
                     .await?;
             }
-            Plan::DropDatabase(_) | Plan::UndropDatabase(_) | Plan::DropUDF(_) => {
+            Plan::DropDatabase(_)
+            | Plan::UndropDatabase(_)
+            | Plan::DropUDF(_)
+            | Plan::DropSequence(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Drop])
                     .await?;
@@ -138,6 +240,25 @@ impl AccessChecker for PrivilegeAccess {
                         vec![UserPrivilegeType::Create],
                     )
                     .await?;
+                if let Some(as_select) = &plan.as_select {
+                    self.check(as_select).await?;
+                }
+                if let Some(source) = &plan.clone_source {
+                    // The clone reads all the columns of the source table.
+                    let columns = plan
+                        .schema
+                        .fields()
+                        .iter()
+                        .map(|field| field.name().clone())
+                        .collect();
+                    self.validate_table_select(
+                        &source.catalog,
+                        &source.database,
+                        &source.table,
+                        &columns,
+                    )
+                    .await?;
+                }
             }
             Plan::DropTable(plan) => {
                 session
@@ -203,6 +324,54 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::RenameTableColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::ModifyTableColumnType(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::CreateTableTag(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropTableTag(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::AlterTableClusterKey(plan) => {
                 session
                     .validate_privilege(
@@ -287,6 +456,10 @@ impl AccessChecker for PrivilegeAccess {
                         vec![UserPrivilegeType::Insert],
                     )
                     .await?;
+                // The source query is checked like a plain query, including the column privileges.
+                if let InsertInputSource::SelectPlan(plan) = &plan.source {
+                    self.check(plan).await?;
+                }
             }
             Plan::Replace(plan) => {
                 session
@@ -299,6 +472,9 @@ impl AccessChecker for PrivilegeAccess {
                         vec![UserPrivilegeType::Insert, UserPrivilegeType::Delete],
                     )
                     .await?;
+                if let InsertInputSource::SelectPlan(plan) = &plan.source {
+                    self.check(plan).await?;
+                }
             }
             Plan::Delete(plan) => {
                 session
@@ -348,6 +524,56 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.table_database.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
+            Plan::CreateVectorIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropVectorIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::CreateUser(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::CreateUser])
@@ -432,10 +658,11 @@ impl AccessChecker for PrivilegeAccess {
                         )
                         .await?;
                 }
-                CopyPlan::IntoStage { .. } => {
+                CopyPlan::IntoStage { from, .. } => {
                     session
                         .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
                         .await?;
+                    self.check(from).await?;
                 }
             },
             Plan::CreateShareEndpoint(_)
@@ -454,7 +681,27 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::RemoveStage(_)
             | Plan::CreateFileFormat(_)
             | Plan::DropFileFormat(_)
-            | Plan::ShowFileFormats(_) => {
+            | Plan::ShowFileFormats(_)
+            | Plan::CreatePasswordPolicy(_)
+            | Plan::AlterPasswordPolicy(_)
+            | Plan::DropPasswordPolicy(_)
+            | Plan::DescPasswordPolicy(_)
+            | Plan::ShowPasswordPolicies(_)
+            | Plan::CreateNetworkPolicy(_)
+            | Plan::AlterNetworkPolicy(_)
+            | Plan::DropNetworkPolicy(_)
+            | Plan::DescNetworkPolicy(_)
+            | Plan::CreateMaskingPolicy(_)
+            | Plan::DropMaskingPolicy(_)
+            | Plan::DescMaskingPolicy(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::DescRowAccessPolicy(_)
+            // Attaching or detaching a policy changes what other users can see,
+            // so it requires more than ALTER on the table.
+            | Plan::SetTableColumnMaskingPolicy(_)
+            | Plan::AddTableRowAccessPolicy(_)
+            | Plan::DropTableRowAccessPolicy(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
@@ -470,3 +717,92 @@ impl AccessChecker for PrivilegeAccess {
         Ok(())
     }
 }
+
+// Taking values from a sequence changes it, so `nextval` requires the global UPDATE privilege,
+// as creating and dropping a sequence require the global CREATE and DROP privileges.
+#[async_backtrace::framed]
+pub async fn validate_sequence_nextval(ctx: &QueryContext) -> Result<()> {
+    ctx.get_current_session()
+        .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Update])
+        .await
+}
+
+// Whether the plan takes values from a sequence with `nextval`.
+fn uses_nextval(s_expr: &SExpr) -> bool {
+    fn scalar_uses_nextval(scalar: &ScalarExpr) -> bool {
+        match scalar {
+            ScalarExpr::FunctionCall(func) => {
+                func.func_name.eq_ignore_ascii_case("nextval")
+                    || func.arguments.iter().any(scalar_uses_nextval)
+            }
+            ScalarExpr::CastExpr(cast) => scalar_uses_nextval(&cast.argument),
+            ScalarExpr::SubqueryExpr(subquery) => uses_nextval(&subquery.subquery),
+            _ => false,
+        }
+    }
+
+    if let RelOperator::EvalScalar(eval_scalar) = s_expr.plan() {
+        if eval_scalar
+            .items
+            .iter()
+            .any(|item| scalar_uses_nextval(&item.scalar))
+        {
+            return true;
+        }
+    }
+    s_expr.children().iter().any(uses_nextval)
+}
+
+// Collect the columns read by each table scan of the optimized plan.
+pub(super) fn collect_scan_columns(
+    s_expr: &SExpr,
+    scan_columns: &mut HashMap<IndexType, ColumnSet>,
+) {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        let columns = scan_columns.entry(scan.table_index).or_default();
+        columns.extend(scan.columns.iter());
+        if let Some(prewhere) = &scan.prewhere {
+            columns.extend(prewhere.prewhere_columns.iter());
+        }
+    }
+    for child in s_expr.children() {
+        collect_scan_columns(child, scan_columns);
+    }
+}
+
+// Get the names of the table columns read by the query. If the table is not scanned
+// by the plan directly, for example in a subquery expression, all the columns are used.
+pub(super) fn used_column_names(
+    metadata: &Metadata,
+    table_index: IndexType,
+    scan_columns: Option<&ColumnSet>,
+) -> BTreeSet<String> {
+    let table_schema = metadata.table(table_index).table().schema();
+    let mut names = BTreeSet::new();
+    let columns = metadata
+        .columns_by_table_index(table_index)
+        .into_iter()
+        .chain(metadata.virtual_columns_by_table_index(table_index));
+    for column in columns {
+        if let Some(scan_columns) = scan_columns {
+            if !scan_columns.contains(&column.index()) {
+                continue;
+            }
+        }
+        match column {
+            ColumnEntry::BaseTableColumn(base) => {
+                // The inner column of a nested type is granted by its outermost column.
+                let name = match &base.path_indices {
+                    Some(path_indices) => table_schema.field(path_indices[0]).name().clone(),
+                    None => base.column_name,
+                };
+                names.insert(name);
+            }
+            ColumnEntry::VirtualColumn(virtual_column) => {
+                names.insert(virtual_column.source_column_name);
+            }
+            ColumnEntry::InternalColumn(_) | ColumnEntry::DerivedColumn(_) => {}
+        }
+    }
+    names
+}
@@ -48,6 +48,18 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Column(catalog_name, database_name, table_name, column_name) => {
+            let catalog = ctx.get_catalog(catalog_name)?;
+            let table = catalog
+                .get_table(tenant.as_str(), database_name, table_name)
+                .await?;
+            if !table.schema().has_field(column_name) {
+                return Err(common_exception::ErrorCode::UnknownColumn(format!(
+                    "column {} not exists in table {}.{}",
+                    column_name, database_name, table_name,
+                )));
+            }
+        }
         GrantObject::Database(catalog_name, database_name) => {
             let catalog = ctx.get_catalog(catalog_name)?;
             if !catalog
@@ -13,7 +13,13 @@
 // limitations under the License.
 
 mod grant;
+mod query_profile;
+mod result_cache;
 mod table;
 
 pub use grant::validate_grant_object_exists;
+pub use query_profile::write_query_profile;
+pub use result_cache::collect_plan_snapshots;
+pub use result_cache::gen_plan_result_cache_key;
+pub use result_cache::prepare_subresult_caches;
 pub use table::append2table;
@@ -0,0 +1,93 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PruningStatistics;
+use common_exception::Result;
+use common_profile::ProfSpanSet;
+use common_sql::executor::PhysicalPlan;
+use common_storages_system::QueryProfileElement;
+use common_storages_system::QueryProfileQueue;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Write the runtime statistics collected for every operator of `plan`
+/// into `system.query_profile`.
+pub fn write_query_profile(ctx: &Arc<QueryContext>, plan: &PhysicalPlan) -> Result<()> {
+    let query_id = ctx.get_id();
+    let prof_span_set = ctx.get_prof_span_set();
+    let prof_span_set = prof_span_set.lock().unwrap();
+
+    let mut elements = vec![];
+    collect_profile(&query_id, plan, None, &prof_span_set, &mut elements);
+
+    let queue = QueryProfileQueue::instance()?;
+    for element in elements {
+        queue.append_data(element)?;
+    }
+    Ok(())
+}
+
+fn collect_profile(
+    query_id: &str,
+    plan: &PhysicalPlan,
+    parent_plan_id: Option<u32>,
+    prof_span_set: &ProfSpanSet,
+    elements: &mut Vec<QueryProfileElement>,
+) {
+    // Plans without an id (e.g. exchanges) are transparent, their children
+    // are attached to the nearest ancestor which has an id.
+    let plan_id = plan.get_id();
+    if let Some(plan_id) = plan_id {
+        let prof_span = prof_span_set.get(&plan_id).cloned().unwrap_or_default();
+        let pruning_stats = match plan {
+            PhysicalPlan::TableScan(scan) => scan.source.statistics.pruning_stats.clone(),
+            _ => PruningStatistics::default(),
+        };
+        elements.push(QueryProfileElement {
+            query_id: query_id.to_string(),
+            plan_id,
+            parent_plan_id,
+            plan_name: plan.name(),
+            output_rows: prof_span.output_rows,
+            output_bytes: prof_span.output_bytes,
+            scan_bytes: prof_span.scan_bytes,
+            process_time: prof_span.process_time,
+            wait_time: prof_span.wait_time,
+            wall_time: prof_span.wall_time,
+            peak_memory_usage: prof_span.peak_memory_usage,
+            spill_count: prof_span.spill_count,
+            spill_bytes: prof_span.spill_bytes,
+            segments_range_pruned: pruning_stats
+                .segments_range_pruning_before
+                .saturating_sub(pruning_stats.segments_range_pruning_after)
+                as u64,
+            blocks_range_pruned: pruning_stats
+                .blocks_range_pruning_before
+                .saturating_sub(pruning_stats.blocks_range_pruning_after)
+                as u64,
+            blocks_bloom_pruned: pruning_stats
+                .blocks_bloom_pruning_before
+                .saturating_sub(pruning_stats.blocks_bloom_pruning_after)
+                as u64,
+        });
+    }
+
+    let parent_plan_id = plan_id.or(parent_plan_id);
+    for child in plan.children() {
+        collect_profile(query_id, child, parent_plan_id, prof_span_set, elements);
+    }
+}
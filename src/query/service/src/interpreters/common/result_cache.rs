@@ -0,0 +1,204 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_store::MetaStore;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::PhysicalPlanReplacer;
+use common_sql::executor::TableScan;
+use common_sql::ColumnBinding;
+use common_storages_result_cache::gen_result_cache_key;
+use common_storages_result_cache::ResultCacheReader;
+use common_storages_result_cache::SubresultCache;
+use serde_json::Value as JsonValue;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::sessions::QueryContext;
+
+/// The fields of the serialized plan left out of the key: the operator ids, the estimated
+/// statistics and the display names of the columns, which are aliases or the original text
+/// of the query while the indexes identify the columns.
+const UNKEYED_PLAN_FIELDS: &[&str] = &["plan_id", "stat_info", "display_name"];
+
+/// Generate the result cache key of a query from its normalized physical plan,
+/// so queries differing only in whitespaces, keyword casing or aliases share a key.
+pub fn gen_plan_result_cache_key(
+    plan: &PhysicalPlan,
+    result_columns: &[ColumnBinding],
+) -> Result<String> {
+    let mut normalized = normalize_plan(plan)?;
+    normalized.push_str("\nresult columns:");
+    for column in result_columns {
+        normalized.push_str(&format!(" {}", column.index));
+    }
+    Ok(gen_result_cache_key(&normalized))
+}
+
+/// The snapshots of all the tables scanned by the plan, a cached result is
+/// valid only if none of the tables has a new snapshot.
+pub fn collect_plan_snapshots(plan: &PhysicalPlan) -> Vec<String> {
+    let mut snapshots = vec![];
+    collect_snapshots(plan, &mut snapshots);
+    snapshots
+}
+
+/// Look up the cached results of the aggregations of the plan, the topmost
+/// aggregations only scanning table snapshots are cacheable. Unlike the result
+/// of the whole query, the key contains the snapshots. The state of each of
+/// them is registered into the context for the pipeline builder.
+#[async_backtrace::framed]
+pub async fn prepare_subresult_caches(
+    ctx: &Arc<QueryContext>,
+    plan: &PhysicalPlan,
+    kv_store: Arc<MetaStore>,
+) -> Result<()> {
+    let mut aggregates = vec![];
+    collect_cacheable_aggregates(plan, &mut aggregates);
+
+    for aggregate in aggregates {
+        let mut normalized = format!("subresult\n{}", normalize_plan(aggregate)?);
+        for snapshot in collect_plan_snapshots(aggregate) {
+            normalized.push('\n');
+            normalized.push_str(&snapshot);
+        }
+        let key = gen_result_cache_key(&normalized);
+
+        // The snapshots are part of the key, the partitions need not to be checked.
+        let reader = ResultCacheReader::create(ctx.clone(), &key, vec![], kv_store.clone(), true);
+        let cache = match reader.try_read_cached_result().await {
+            Ok(Some(blocks)) => SubresultCache::Hit(blocks),
+            Ok(None) => SubresultCache::Miss(key),
+            Err(e) => {
+                tracing::error!("Failed to read aggregate result cache. {}", e);
+                continue;
+            }
+        };
+        // Only AggregateFinal is collected, which always has an id.
+        if let Some(plan_id) = aggregate.get_id() {
+            ctx.set_subresult_cache(plan_id, cache);
+        }
+    }
+    Ok(())
+}
+
+/// Serialize the plan with the identities of the scanned tables and the indexes of the columns.
+fn normalize_plan(plan: &PhysicalPlan) -> Result<String> {
+    let plan = ScanIdentities.replace(plan)?;
+    let mut value = serde_json::to_value(&plan)?;
+    remove_unkeyed_fields(&mut value);
+    Ok(value.to_string())
+}
+
+fn remove_unkeyed_fields(value: &mut JsonValue) {
+    match value {
+        JsonValue::Object(fields) => {
+            for field in UNKEYED_PLAN_FIELDS {
+                fields.remove(*field);
+            }
+            fields.values_mut().for_each(remove_unkeyed_fields);
+        }
+        JsonValue::Array(values) => values.iter_mut().for_each(remove_unkeyed_fields),
+        _ => {}
+    }
+}
+
+/// Replace the data sources of the table scans by the identities of the tables, the pruned
+/// partitions and the statistics depend on the snapshots, which are checked separately.
+struct ScanIdentities;
+
+impl PhysicalPlanReplacer for ScanIdentities {
+    fn replace_table_scan(&mut self, plan: &TableScan) -> Result<PhysicalPlan> {
+        let source_info = match &plan.source.source_info {
+            DataSourceInfo::TableSource(table_info) => DataSourceInfo::TableSource(TableInfo {
+                ident: TableIdent::new(table_info.ident.table_id, 0),
+                desc: table_info.desc.clone(),
+                name: table_info.name.clone(),
+                ..Default::default()
+            }),
+            source_info => source_info.clone(),
+        };
+        let source = DataSourcePlan {
+            catalog: plan.source.catalog.clone(),
+            source_info,
+            output_schema: plan.source.output_schema.clone(),
+            parts: Partitions::create_nolazy(plan.source.parts.kind.clone(), vec![]),
+            statistics: PartStatistics::default(),
+            description: String::new(),
+            tbl_args: plan.source.tbl_args.clone(),
+            push_downs: plan.source.push_downs.clone(),
+            query_internal_columns: plan.source.query_internal_columns,
+        };
+        Ok(PhysicalPlan::TableScan(TableScan {
+            source: Box::new(source),
+            ..plan.clone()
+        }))
+    }
+}
+
+/// Collect the snapshots of the scanned tables, returns false if some of the
+/// scanned data can't be identified by a snapshot.
+fn collect_snapshots(plan: &PhysicalPlan, snapshots: &mut Vec<String>) -> bool {
+    let mut pinned = match plan {
+        PhysicalPlan::TableScan(scan) => match &scan.source.source_info {
+            DataSourceInfo::TableSource(table_info) => {
+                match table_info.options().get(OPT_KEY_SNAPSHOT_LOCATION) {
+                    Some(location) => {
+                        snapshots.push(format!("{}:{}", table_info.ident.table_id, location));
+                        true
+                    }
+                    None => {
+                        snapshots.push(format!(
+                            "{}:{}",
+                            table_info.ident.table_id, table_info.ident.seq
+                        ));
+                        false
+                    }
+                }
+            }
+            _ => false,
+        },
+        // The result of the CTE is produced out of the sub plan.
+        PhysicalPlan::CteScan(_) => false,
+        _ => true,
+    };
+
+    for child in plan.children() {
+        pinned &= collect_snapshots(child, snapshots);
+    }
+    pinned
+}
+
+fn collect_cacheable_aggregates<'a>(
+    plan: &'a PhysicalPlan,
+    aggregates: &mut Vec<&'a PhysicalPlan>,
+) {
+    if let PhysicalPlan::AggregateFinal(_) = plan {
+        if collect_snapshots(plan, &mut vec![]) {
+            aggregates.push(plan);
+            return;
+        }
+    }
+
+    for child in plan.children() {
+        collect_cacheable_aggregates(child, aggregates);
+    }
+}
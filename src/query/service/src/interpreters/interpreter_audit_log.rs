@@ -0,0 +1,282 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::GlobalInstance;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::AppendMode;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_meta_app::principal::UserIdentity;
+use common_sql::Planner;
+use common_storages_system::QueryLogElement;
+use common_storages_system::SystemLogElement;
+use common_users::UserApiProvider;
+use futures::TryStreamExt;
+use parking_lot::Mutex;
+use tracing::info;
+use tracing::warn;
+
+use crate::interpreters::common::append2table;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+// How often the rows out of `retention_period` are removed from the audit log table.
+const RETENTION_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// AuditLogWriter persists the query log and login events of user sessions into
+/// the fuse table configured by `audit_log_table`, in batches.
+pub struct AuditLogWriter {
+    tenant: String,
+    database: String,
+    table: String,
+    flush_rows: usize,
+    flush_interval: Duration,
+    // The max rows kept in the buffer if the events can not be flushed.
+    max_buffer_rows: usize,
+    buffer: Mutex<Vec<QueryLogElement>>,
+    notify: Notify,
+    table_created: AtomicBool,
+}
+
+impl AuditLogWriter {
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        let writer = match cfg.query.audit_log_table.as_str() {
+            "" => None,
+            name => {
+                let (database, table) = name.split_once('.').ok_or_else(|| {
+                    ErrorCode::InvalidConfig(format!(
+                        "audit_log_table must be in the form of `database.table`, but got '{}'",
+                        name
+                    ))
+                })?;
+                let writer = Arc::new(AuditLogWriter {
+                    tenant: cfg.query.tenant_id.clone(),
+                    database: database.to_string(),
+                    table: table.to_string(),
+                    flush_rows: cfg.query.audit_log_flush_rows.max(1),
+                    flush_interval: Duration::from_secs(
+                        cfg.query.audit_log_flush_interval_secs.max(1),
+                    ),
+                    max_buffer_rows: cfg
+                        .query
+                        .max_query_log_size
+                        .max(cfg.query.audit_log_flush_rows),
+                    buffer: Mutex::new(vec![]),
+                    notify: Notify::new(),
+                    table_created: AtomicBool::new(false),
+                });
+                writer.clone().background_flush();
+                Some(writer)
+            }
+        };
+        GlobalInstance::set(writer);
+        Ok(())
+    }
+
+    pub fn instance() -> Option<Arc<AuditLogWriter>> {
+        GlobalInstance::get()
+    }
+
+    pub fn append(&self, event: QueryLogElement) {
+        let mut buffer = self.buffer.lock();
+        if buffer.len() >= self.max_buffer_rows {
+            warn!(
+                "audit log buffer is full, drop the event of query {}",
+                event.query_id
+            );
+            return;
+        }
+        buffer.push(event);
+        if buffer.len() >= self.flush_rows {
+            self.notify.notify_one();
+        }
+    }
+
+    fn background_flush(self: Arc<Self>) {
+        tokio::spawn(async_backtrace::location!().frame(async move {
+            let mut last_retention: Option<Instant> = None;
+            loop {
+                let _ = tokio::time::timeout(self.flush_interval, self.notify.notified()).await;
+                if let Err(cause) = self.flush().await {
+                    warn!("audit log flush failed: {}", cause);
+                }
+
+                if last_retention.map_or(true, |t| t.elapsed() >= RETENTION_INTERVAL) {
+                    if let Err(cause) = self.apply_retention().await {
+                        warn!("audit log retention failed: {}", cause);
+                    }
+                    last_retention = Some(Instant::now());
+                }
+            }
+        }));
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(&self) -> Result<()> {
+        let events = std::mem::take(&mut *self.buffer.lock());
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        if let Err(cause) = self.write_events(&events).await {
+            // Put the events back, they will be retried by the next flush.
+            let mut buffer = self.buffer.lock();
+            let keep = self.max_buffer_rows.saturating_sub(buffer.len());
+            buffer.splice(0..0, events.into_iter().take(keep));
+            return Err(cause);
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn write_events(&self, events: &[QueryLogElement]) -> Result<()> {
+        let ctx = self.create_context().await?;
+        let table = ctx
+            .get_table(CATALOG_DEFAULT, &self.database, &self.table)
+            .await?;
+
+        let schema = QueryLogElement::schema();
+        let mut builders: Vec<ColumnBuilder> = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let data_type: DataType = field.data_type().into();
+                ColumnBuilder::with_capacity(&data_type, events.len())
+            })
+            .collect();
+        for event in events {
+            event.fill_to_data_block(&mut builders)?;
+        }
+        let columns = builders.into_iter().map(|b| b.build()).collect();
+        let block = DataBlock::new_from_columns(columns);
+
+        let mut build_res = PipelineBuildResult::from_blocks(vec![block])?;
+        append2table(
+            ctx.clone(),
+            table,
+            Arc::new(DataSchema::from(schema)),
+            &mut build_res,
+            false,
+            true,
+            AppendMode::Normal,
+        )?;
+
+        let settings = ctx.get_settings();
+        let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+        let executor =
+            PipelineCompleteExecutor::try_create(build_res.main_pipeline, executor_settings)?;
+        ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+        executor.execute()?;
+        info!(
+            "audit log flushed {} events into {}.{}",
+            events.len(),
+            self.database,
+            self.table
+        );
+        Ok(())
+    }
+
+    // Remove the events older than the `retention_period` hours, and purge the history
+    // snapshots of the audit log table.
+    #[async_backtrace::framed]
+    async fn apply_retention(&self) -> Result<()> {
+        let ctx = self.create_context().await?;
+        let retention_period = ctx.get_settings().get_retention_period()?;
+        self.execute_sql(
+            &ctx,
+            &format!(
+                "DELETE FROM `{}`.`{}` WHERE event_time < subtract_hours(now(), {})",
+                self.database, self.table, retention_period
+            ),
+        )
+        .await?;
+
+        let ctx = self.create_context().await?;
+        self.execute_sql(
+            &ctx,
+            &format!("OPTIMIZE TABLE `{}`.`{}` PURGE", self.database, self.table),
+        )
+        .await
+    }
+
+    // Create a query context of an internal session with the privileges of the builtin root
+    // user, and make sure the audit log table exists.
+    #[async_backtrace::framed]
+    async fn create_context(&self) -> Result<Arc<QueryContext>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::HTTPAPI("AuditLog".to_string()))
+            .await?;
+        session.set_current_tenant(self.tenant.clone());
+        let user = UserApiProvider::instance()
+            .get_user(&self.tenant, UserIdentity::new("root", "localhost"))
+            .await?;
+        session.set_authed_user(user, None).await?;
+
+        if !self.table_created.load(Ordering::Acquire) {
+            let ctx = session.create_query_context().await?;
+            self.execute_sql(&ctx, &self.create_table_sql()).await?;
+            self.table_created.store(true, Ordering::Release);
+        }
+        session.create_query_context().await
+    }
+
+    fn create_table_sql(&self) -> String {
+        let columns = QueryLogElement::schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                format!(
+                    "`{}` {} NOT NULL",
+                    field.name(),
+                    field.data_type().sql_name()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "CREATE TABLE IF NOT EXISTS `{}`.`{}` ({}) ENGINE = FUSE",
+            self.database, self.table, columns
+        )
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_sql(&self, ctx: &Arc<QueryContext>, sql: &str) -> Result<()> {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, extras) = planner.plan_sql(sql).await?;
+        ctx.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        let _: Vec<DataBlock> = stream.try_collect().await?;
+        Ok(())
+    }
+}
@@ -47,6 +47,7 @@ use crate::interpreters::SelectInterpreter;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
 use crate::pipelines::processors::TransformCastSchema;
 use crate::pipelines::processors::TransformLimit;
+use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -180,7 +181,9 @@ impl CopyInterpreter {
             .get_table(catalog_name, database_name, table_name)
             .await?;
 
-        let dst_schema = Arc::new(to_table.schema().into());
+        // Computed columns are filled after the cast.
+        let dst_schema: DataSchemaRef =
+            Arc::new((&to_table.schema().remove_computed_fields()).into());
         if source_schema != dst_schema {
             let func_ctx = ctx.get_function_context()?;
             build_res.main_pipeline.add_transform(
@@ -195,6 +198,10 @@ impl CopyInterpreter {
                 },
             )?;
         }
+        Self::fill_computed_columns(&ctx, &to_table, &dst_schema, &mut build_res)?;
+
+        // CHECK constraint violations are handled according to ON_ERROR.
+        ctx.set_on_error_mode(stage_info.copy_options.on_error.clone());
 
         // Build append data pipeline.
         to_table.append_data(
@@ -358,7 +365,8 @@ impl CopyInterpreter {
             .get_type()
             .has_inner_schema()
         {
-            let dst_schema: Arc<DataSchema> = Arc::new(to_table.schema().into());
+            let dst_schema: Arc<DataSchema> =
+                Arc::new((&to_table.schema().remove_computed_fields()).into());
             let func_ctx = self.ctx.get_function_context()?;
             build_res.main_pipeline.add_transform(
                 |transform_input_port, transform_output_port| {
@@ -371,6 +379,11 @@ impl CopyInterpreter {
                 },
             )?;
         }
+        let source_schema: DataSchemaRef = Arc::new(stage_table_info.schema.as_ref().into());
+        Self::fill_computed_columns(&ctx, &to_table, &source_schema, &mut build_res)?;
+
+        // CHECK constraint violations are handled according to ON_ERROR.
+        ctx.set_on_error_mode(stage_table_info.stage_info.copy_options.on_error.clone());
 
         // Build append data pipeline.
         to_table.append_data(
@@ -403,6 +416,28 @@ impl CopyInterpreter {
         Ok(build_res)
     }
 
+    fn fill_computed_columns(
+        ctx: &Arc<QueryContext>,
+        to_table: &Arc<dyn Table>,
+        source_schema: &DataSchemaRef,
+        build_res: &mut PipelineBuildResult,
+    ) -> Result<()> {
+        if !to_table.schema().has_computed_fields() {
+            return Ok(());
+        }
+        build_res
+            .main_pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformResortAddOn::try_create(
+                    ctx.clone(),
+                    transform_input_port,
+                    transform_output_port,
+                    source_schema.clone(),
+                    to_table.clone(),
+                )
+            })
+    }
+
     /// Pipeline finish.
     /// 1. commit the data.
     /// 2. update the NeedCopy file into to meta.
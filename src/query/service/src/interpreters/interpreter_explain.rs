@@ -27,6 +27,7 @@ use common_expression::FromData;
 use common_profile::ProfSpanSetRef;
 use common_sql::MetadataRef;
 
+use crate::interpreters::common::write_query_profile;
 use crate::interpreters::Interpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
@@ -263,7 +264,7 @@ impl ExplainInterpreter {
 
         let root_fragment = Fragmenter::try_create(ctx.clone())?.build_fragment(&plan)?;
 
-        let mut fragments_actions = QueryFragmentsActions::create(ctx.clone());
+        let mut fragments_actions = QueryFragmentsActions::create(ctx.clone(), false);
         root_fragment.get_actions(ctx, &mut fragments_actions)?;
 
         let display_string = fragments_actions.display_indent(&metadata).to_string();
@@ -308,6 +309,10 @@ impl ExplainInterpreter {
             while (pulling_executor.pull_data()?).is_some() {}
         }
 
+        if self.ctx.get_settings().get_enable_query_profiling()? {
+            write_query_profile(&self.ctx, &plan)?;
+        }
+
         let result = plan
             .format(metadata.clone(), prof_span_set)?
             .format_pretty()?;
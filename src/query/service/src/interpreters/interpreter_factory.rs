@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use tracing::error;
@@ -23,14 +24,30 @@ use super::interpreter_catalog_create::CreateCatalogInterpreter;
 use super::interpreter_share_desc::DescShareInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
+use crate::interpreters::access::collect_accessed_objects;
 use crate::interpreters::access::Accessor;
 use crate::interpreters::interpreter_catalog_drop::DropCatalogInterpreter;
 use crate::interpreters::interpreter_copy::CopyInterpreter;
 use crate::interpreters::interpreter_file_format_create::CreateFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_drop::DropFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_show::ShowFileFormatsInterpreter;
+use crate::interpreters::interpreter_masking_policy_create::CreateMaskingPolicyInterpreter;
+use crate::interpreters::interpreter_masking_policy_desc::DescMaskingPolicyInterpreter;
+use crate::interpreters::interpreter_masking_policy_drop::DropMaskingPolicyInterpreter;
+use crate::interpreters::interpreter_network_policy_alter::AlterNetworkPolicyInterpreter;
+use crate::interpreters::interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
+use crate::interpreters::interpreter_network_policy_desc::DescNetworkPolicyInterpreter;
+use crate::interpreters::interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
+use crate::interpreters::interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
+use crate::interpreters::interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
+use crate::interpreters::interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
+use crate::interpreters::interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
+use crate::interpreters::interpreter_password_policy_show::ShowPasswordPoliciesInterpreter;
 use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
+use crate::interpreters::interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+use crate::interpreters::interpreter_row_access_policy_desc::DescRowAccessPolicyInterpreter;
+use crate::interpreters::interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
 use crate::interpreters::AlterUserInterpreter;
@@ -57,6 +74,17 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+        ctx.set_accessed_objects(collect_accessed_objects(plan));
+        // The streams read by a query are consumed only if the query modifies a table.
+        ctx.set_consume_streams(matches!(
+            plan,
+            Plan::Insert(_)
+                | Plan::Replace(_)
+                | Plan::Copy(_)
+                | Plan::CreateTable(_)
+                | Plan::Delete(_)
+                | Plan::Update(_)
+        ));
         Self::get_inner(ctx, plan)
     }
 
@@ -176,6 +204,29 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::RenameTableColumn(plan) => Ok(Arc::new(
+                RenameTableColumnInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::ModifyTableColumnType(plan) => Ok(Arc::new(
+                ModifyTableColumnTypeInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::SetTableColumnMaskingPolicy(plan) => Ok(Arc::new(
+                SetTableColumnMaskingPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::AddTableRowAccessPolicy(plan) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(plan) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::CreateTableTag(plan) => Ok(Arc::new(CreateTableTagInterpreter::try_create(
+                ctx,
+                *plan.clone(),
+            )?)),
+            Plan::DropTableTag(plan) => Ok(Arc::new(DropTableTagInterpreter::try_create(
+                ctx,
+                *plan.clone(),
+            )?)),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -214,6 +265,34 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
+
+            // Vector indexes
+            Plan::CreateVectorIndex(plan) => Ok(Arc::new(
+                CreateVectorIndexInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::DropVectorIndex(plan) => Ok(Arc::new(DropVectorIndexInterpreter::try_create(
+                ctx,
+                *plan.clone(),
+            )?)),
+
+            // Sequences
+            Plan::CreateSequence(create_sequence) => Ok(Arc::new(
+                CreateSequenceInterpreter::try_create(ctx, *create_sequence.clone())?,
+            )),
+            Plan::DropSequence(drop_sequence) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *drop_sequence.clone(),
+            )?)),
+
             // Users
             Plan::CreateUser(create_user) => Ok(Arc::new(CreateUserInterpreter::try_create(
                 ctx,
@@ -284,6 +363,60 @@ impl InterpreterFactory {
                 ShowFileFormatsInterpreter::try_create(ctx, *show_file_formats.clone())?,
             )),
 
+            // Password policies
+            Plan::CreatePasswordPolicy(create_password_policy) => Ok(Arc::new(
+                CreatePasswordPolicyInterpreter::try_create(ctx, *create_password_policy.clone())?,
+            )),
+            Plan::AlterPasswordPolicy(alter_password_policy) => Ok(Arc::new(
+                AlterPasswordPolicyInterpreter::try_create(ctx, *alter_password_policy.clone())?,
+            )),
+            Plan::DropPasswordPolicy(drop_password_policy) => Ok(Arc::new(
+                DropPasswordPolicyInterpreter::try_create(ctx, *drop_password_policy.clone())?,
+            )),
+            Plan::DescPasswordPolicy(desc_password_policy) => Ok(Arc::new(
+                DescPasswordPolicyInterpreter::try_create(ctx, *desc_password_policy.clone())?,
+            )),
+            Plan::ShowPasswordPolicies(show_password_policies) => Ok(Arc::new(
+                ShowPasswordPoliciesInterpreter::try_create(ctx, *show_password_policies.clone())?,
+            )),
+
+            // Network policies
+            Plan::CreateNetworkPolicy(create_network_policy) => Ok(Arc::new(
+                CreateNetworkPolicyInterpreter::try_create(ctx, *create_network_policy.clone())?,
+            )),
+            Plan::AlterNetworkPolicy(alter_network_policy) => Ok(Arc::new(
+                AlterNetworkPolicyInterpreter::try_create(ctx, *alter_network_policy.clone())?,
+            )),
+            Plan::DropNetworkPolicy(drop_network_policy) => Ok(Arc::new(
+                DropNetworkPolicyInterpreter::try_create(ctx, *drop_network_policy.clone())?,
+            )),
+            Plan::DescNetworkPolicy(desc_network_policy) => Ok(Arc::new(
+                DescNetworkPolicyInterpreter::try_create(ctx, *desc_network_policy.clone())?,
+            )),
+
+            // Data security policies
+            Plan::CreateMaskingPolicy(create_masking_policy) => Ok(Arc::new(
+                CreateMaskingPolicyInterpreter::try_create(ctx, *create_masking_policy.clone())?,
+            )),
+            Plan::DropMaskingPolicy(drop_masking_policy) => Ok(Arc::new(
+                DropMaskingPolicyInterpreter::try_create(ctx, *drop_masking_policy.clone())?,
+            )),
+            Plan::DescMaskingPolicy(desc_masking_policy) => Ok(Arc::new(
+                DescMaskingPolicyInterpreter::try_create(ctx, *desc_masking_policy.clone())?,
+            )),
+            Plan::CreateRowAccessPolicy(create_row_access_policy) => {
+                Ok(Arc::new(CreateRowAccessPolicyInterpreter::try_create(
+                    ctx,
+                    *create_row_access_policy.clone(),
+                )?))
+            }
+            Plan::DropRowAccessPolicy(drop_row_access_policy) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *drop_row_access_policy.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(desc_row_access_policy) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *desc_row_access_policy.clone())?,
+            )),
+
             // Grant
             Plan::GrantPriv(grant_priv) => Ok(Arc::new(GrantPrivilegeInterpreter::try_create(
                 ctx,
@@ -84,9 +84,12 @@ use parking_lot::RwLock;
 use tracing::error;
 use tracing::info;
 
+use crate::interpreters::access::validate_sequence_nextval;
 use crate::interpreters::common::append2table;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::pipelines::processors::transforms::next_sequence_columns;
+use crate::pipelines::processors::transforms::rewrite_nextval;
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
 use crate::pipelines::processors::TransformResortAddOn;
@@ -547,7 +550,7 @@ impl Interpreter for InsertInterpreter {
 
 pub struct ValueSource {
     data: String,
-    ctx: Arc<dyn TableContext>,
+    ctx: Arc<QueryContext>,
     name_resolution_ctx: NameResolutionContext,
     bind_context: BindContext,
     schema: DataSchemaRef,
@@ -593,7 +596,7 @@ impl AsyncSource for ValueSource {
 impl ValueSource {
     pub fn new(
         data: String,
-        ctx: Arc<dyn TableContext>,
+        ctx: Arc<QueryContext>,
         name_resolution_ctx: NameResolutionContext,
         schema: DataSchemaRef,
     ) -> Self {
@@ -848,7 +851,7 @@ async fn fill_default_value(
 async fn exprs_to_scalar(
     exprs: Vec<AExpr>,
     schema: &DataSchemaRef,
-    ctx: Arc<dyn TableContext>,
+    ctx: Arc<QueryContext>,
     name_resolution_ctx: &NameResolutionContext,
     bind_context: &mut BindContext,
     metadata: MetadataRef,
@@ -919,16 +922,25 @@ async fn exprs_to_scalar(
         map_exprs.push(expr);
     }
 
+    // The values of `nextval` are taken for the row and appended to the chunk.
+    let sequences = rewrite_nextval(&mut map_exprs, 1);
+    if !sequences.is_empty() {
+        validate_sequence_nextval(&ctx).await?;
+    }
+
     let mut operators = Vec::with_capacity(schema_fields_len);
     operators.push(BlockOperator::Map { exprs: map_exprs });
 
-    let one_row_chunk = DataBlock::new(
+    let mut one_row_chunk = DataBlock::new(
         vec![BlockEntry {
             data_type: DataType::Number(NumberDataType::UInt8),
             value: Value::Scalar(Scalar::Number(NumberScalar::UInt8(1))),
         }],
         1,
     );
+    for entry in next_sequence_columns(&ctx.get_tenant(), &sequences, 1).await? {
+        one_row_chunk.add_column(entry);
+    }
     let func_ctx = ctx.get_function_context()?;
     let mut expression_transform = CompoundBlockOperator {
         operators,
@@ -938,7 +950,7 @@ async fn exprs_to_scalar(
     let scalars: Vec<Scalar> = res
         .columns()
         .iter()
-        .skip(1)
+        .skip(1 + sequences.len())
         .map(|col| unsafe { col.value.as_ref().index_unchecked(0).to_owned() })
         .collect();
     Ok(scalars)
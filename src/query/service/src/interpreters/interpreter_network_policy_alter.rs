@@ -0,0 +1,75 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::AlterNetworkPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterNetworkPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterNetworkPolicyPlan,
+}
+
+impl AlterNetworkPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterNetworkPolicyPlan) -> Result<Self> {
+        Ok(AlterNetworkPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterNetworkPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AlterNetworkPolicyInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let user_mgr = UserApiProvider::instance();
+
+        let mut policy = match user_mgr.get_network_policy(&plan.tenant, &plan.name).await {
+            Ok(policy) => policy,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_NETWORK_POLICY => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+
+        if let Some(allowed_ip_list) = plan.allowed_ip_list {
+            policy.allowed_ip_list = allowed_ip_list;
+        }
+        if let Some(blocked_ip_list) = plan.blocked_ip_list {
+            policy.blocked_ip_list = blocked_ip_list;
+        }
+        if let Some(comment) = plan.comment {
+            policy.comment = comment;
+        }
+        policy.update_on = Some(Utc::now());
+
+        user_mgr.update_network_policy(&plan.tenant, policy).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
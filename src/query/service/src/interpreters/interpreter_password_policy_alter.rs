@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_ast::ast::AlterPasswordAction;
+use common_ast::ast::PasswordUnSetOptions;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::DEFAULT_PASSWORD_HISTORY;
+use common_meta_app::principal::DEFAULT_PASSWORD_LOCKOUT_TIME_MINS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_AGE_DAYS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_LENGTH;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_RETRIES;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_LENGTH;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_LOWER_CASE_CHARS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_NUMERIC_CHARS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_SPECIAL_CHARS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_UPPER_CASE_CHARS;
+use common_sql::plans::AlterPasswordPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::interpreter_password_policy_create::apply_password_set_options;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterPasswordPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterPasswordPolicyPlan,
+}
+
+impl AlterPasswordPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterPasswordPolicyPlan) -> Result<Self> {
+        Ok(AlterPasswordPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterPasswordPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AlterPasswordPolicyInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let user_mgr = UserApiProvider::instance();
+
+        let mut policy = match user_mgr.get_password_policy(&plan.tenant, &plan.name).await {
+            Ok(policy) => policy,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+
+        match &plan.action {
+            AlterPasswordAction::SetOptions(set_options) => {
+                apply_password_set_options(&mut policy, set_options);
+            }
+            AlterPasswordAction::UnSetOptions(unset_options) => {
+                apply_password_unset_options(&mut policy, unset_options);
+            }
+        }
+        policy.update_on = Some(Utc::now());
+
+        user_mgr
+            .update_password_policy(&plan.tenant, policy)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+fn apply_password_unset_options(policy: &mut PasswordPolicy, unset_options: &PasswordUnSetOptions) {
+    if unset_options.min_length {
+        policy.min_length = DEFAULT_PASSWORD_MIN_LENGTH;
+    }
+    if unset_options.max_length {
+        policy.max_length = DEFAULT_PASSWORD_MAX_LENGTH;
+    }
+    if unset_options.min_upper_case_chars {
+        policy.min_upper_case_chars = DEFAULT_PASSWORD_MIN_UPPER_CASE_CHARS;
+    }
+    if unset_options.min_lower_case_chars {
+        policy.min_lower_case_chars = DEFAULT_PASSWORD_MIN_LOWER_CASE_CHARS;
+    }
+    if unset_options.min_numeric_chars {
+        policy.min_numeric_chars = DEFAULT_PASSWORD_MIN_NUMERIC_CHARS;
+    }
+    if unset_options.min_special_chars {
+        policy.min_special_chars = DEFAULT_PASSWORD_MIN_SPECIAL_CHARS;
+    }
+    if unset_options.max_age_days {
+        policy.max_age_days = DEFAULT_PASSWORD_MAX_AGE_DAYS;
+    }
+    if unset_options.max_retries {
+        policy.max_retries = DEFAULT_PASSWORD_MAX_RETRIES;
+    }
+    if unset_options.lockout_time_mins {
+        policy.lockout_time_mins = DEFAULT_PASSWORD_LOCKOUT_TIME_MINS;
+    }
+    if unset_options.history {
+        policy.history = DEFAULT_PASSWORD_HISTORY;
+    }
+    if unset_options.comment {
+        policy.comment = "".to_string();
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_ast::ast::PasswordSetOptions;
+use common_exception::Result;
+use common_meta_app::principal::PasswordPolicy;
+use common_sql::plans::CreatePasswordPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreatePasswordPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreatePasswordPolicyPlan,
+}
+
+impl CreatePasswordPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreatePasswordPolicyPlan) -> Result<Self> {
+        Ok(CreatePasswordPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreatePasswordPolicyInterpreter {
+    fn name(&self) -> &str {
+        "CreatePasswordPolicyInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let mut policy = PasswordPolicy::new(&plan.name, Utc::now());
+        apply_password_set_options(&mut policy, &plan.set_options);
+
+        let _ = UserApiProvider::instance()
+            .add_password_policy(&plan.tenant, policy, plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+pub(crate) fn apply_password_set_options(
+    policy: &mut PasswordPolicy,
+    set_options: &PasswordSetOptions,
+) {
+    if let Some(min_length) = set_options.min_length {
+        policy.min_length = min_length;
+    }
+    if let Some(max_length) = set_options.max_length {
+        policy.max_length = max_length;
+    }
+    if let Some(min_upper_case_chars) = set_options.min_upper_case_chars {
+        policy.min_upper_case_chars = min_upper_case_chars;
+    }
+    if let Some(min_lower_case_chars) = set_options.min_lower_case_chars {
+        policy.min_lower_case_chars = min_lower_case_chars;
+    }
+    if let Some(min_numeric_chars) = set_options.min_numeric_chars {
+        policy.min_numeric_chars = min_numeric_chars;
+    }
+    if let Some(min_special_chars) = set_options.min_special_chars {
+        policy.min_special_chars = min_special_chars;
+    }
+    if let Some(max_age_days) = set_options.max_age_days {
+        policy.max_age_days = max_age_days;
+    }
+    if let Some(max_retries) = set_options.max_retries {
+        policy.max_retries = max_retries;
+    }
+    if let Some(lockout_time_mins) = set_options.lockout_time_mins {
+        policy.lockout_time_mins = lockout_time_mins;
+    }
+    if let Some(history) = set_options.history {
+        policy.history = history;
+    }
+    if let Some(comment) = &set_options.comment {
+        policy.comment = comment.clone();
+    }
+}
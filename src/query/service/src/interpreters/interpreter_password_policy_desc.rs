@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_meta_app::principal::DEFAULT_PASSWORD_HISTORY;
+use common_meta_app::principal::DEFAULT_PASSWORD_LOCKOUT_TIME_MINS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_AGE_DAYS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_LENGTH;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_RETRIES;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_LENGTH;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_LOWER_CASE_CHARS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_NUMERIC_CHARS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_SPECIAL_CHARS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_UPPER_CASE_CHARS;
+use common_sql::plans::DescPasswordPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescPasswordPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescPasswordPolicyPlan,
+}
+
+impl DescPasswordPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescPasswordPolicyPlan) -> Result<Self> {
+        Ok(DescPasswordPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescPasswordPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DescPasswordPolicyInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let policy = UserApiProvider::instance()
+            .get_password_policy(&tenant, &self.plan.name)
+            .await?;
+
+        let rows: Vec<(&str, String, String, &str)> = vec![
+            (
+                "NAME",
+                policy.name.clone(),
+                "".to_string(),
+                "Name of password policy.",
+            ),
+            (
+                "COMMENT",
+                policy.comment.clone(),
+                "".to_string(),
+                "Comment of password policy.",
+            ),
+            (
+                "PASSWORD_MIN_LENGTH",
+                policy.min_length.to_string(),
+                DEFAULT_PASSWORD_MIN_LENGTH.to_string(),
+                "Minimum length of new password.",
+            ),
+            (
+                "PASSWORD_MAX_LENGTH",
+                policy.max_length.to_string(),
+                DEFAULT_PASSWORD_MAX_LENGTH.to_string(),
+                "Maximum length of new password.",
+            ),
+            (
+                "PASSWORD_MIN_UPPER_CASE_CHARS",
+                policy.min_upper_case_chars.to_string(),
+                DEFAULT_PASSWORD_MIN_UPPER_CASE_CHARS.to_string(),
+                "Minimum number of uppercase characters in new password.",
+            ),
+            (
+                "PASSWORD_MIN_LOWER_CASE_CHARS",
+                policy.min_lower_case_chars.to_string(),
+                DEFAULT_PASSWORD_MIN_LOWER_CASE_CHARS.to_string(),
+                "Minimum number of lowercase characters in new password.",
+            ),
+            (
+                "PASSWORD_MIN_NUMERIC_CHARS",
+                policy.min_numeric_chars.to_string(),
+                DEFAULT_PASSWORD_MIN_NUMERIC_CHARS.to_string(),
+                "Minimum number of numeric characters in new password.",
+            ),
+            (
+                "PASSWORD_MIN_SPECIAL_CHARS",
+                policy.min_special_chars.to_string(),
+                DEFAULT_PASSWORD_MIN_SPECIAL_CHARS.to_string(),
+                "Minimum number of special characters in new password.",
+            ),
+            (
+                "PASSWORD_MAX_AGE_DAYS",
+                policy.max_age_days.to_string(),
+                DEFAULT_PASSWORD_MAX_AGE_DAYS.to_string(),
+                "Period after which password must be changed, 0 means never expire.",
+            ),
+            (
+                "PASSWORD_MAX_RETRIES",
+                policy.max_retries.to_string(),
+                DEFAULT_PASSWORD_MAX_RETRIES.to_string(),
+                "Number of attempts users have to enter the correct password before their account is locked.",
+            ),
+            (
+                "PASSWORD_LOCKOUT_TIME_MINS",
+                policy.lockout_time_mins.to_string(),
+                DEFAULT_PASSWORD_LOCKOUT_TIME_MINS.to_string(),
+                "Period of time for which users will be locked after entering their password incorrectly many times (specified by MAX_RETRIES).",
+            ),
+            (
+                "PASSWORD_HISTORY",
+                policy.history.to_string(),
+                DEFAULT_PASSWORD_HISTORY.to_string(),
+                "Number of most recent passwords that may not be repeated by the user.",
+            ),
+        ];
+
+        let properties = rows
+            .iter()
+            .map(|row| row.0.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let values = rows
+            .iter()
+            .map(|row| row.1.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let defaults = rows
+            .iter()
+            .map(|row| row.2.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let descriptions = rows
+            .iter()
+            .map(|row| row.3.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(properties),
+            StringType::from_data(values),
+            StringType::from_data(defaults),
+            StringType::from_data(descriptions),
+        ])])
+    }
+}
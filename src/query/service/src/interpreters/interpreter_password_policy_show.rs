@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_sql::plans::ShowPasswordPoliciesPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowPasswordPoliciesInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowPasswordPoliciesPlan,
+}
+
+impl ShowPasswordPoliciesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowPasswordPoliciesPlan) -> Result<Self> {
+        Ok(ShowPasswordPoliciesInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowPasswordPoliciesInterpreter {
+    fn name(&self) -> &str {
+        "ShowPasswordPoliciesInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let mut policies = UserApiProvider::instance()
+            .get_password_policies(&tenant)
+            .await?;
+
+        policies.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names = policies
+            .iter()
+            .map(|x| x.name.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+
+        let comments = policies
+            .iter()
+            .map(|x| x.comment.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+
+        let options = policies
+            .iter()
+            .map(|x| {
+                format!(
+                    "MIN_LENGTH={}, MAX_LENGTH={}, MIN_UPPER_CASE_CHARS={}, MIN_LOWER_CASE_CHARS={}, MIN_NUMERIC_CHARS={}, MIN_SPECIAL_CHARS={}, MAX_AGE_DAYS={}, MAX_RETRIES={}, LOCKOUT_TIME_MINS={}, HISTORY={}",
+                    x.min_length,
+                    x.max_length,
+                    x.min_upper_case_chars,
+                    x.min_lower_case_chars,
+                    x.min_numeric_chars,
+                    x.min_special_chars,
+                    x.max_age_days,
+                    x.max_retries,
+                    x.lockout_time_mins,
+                    x.history
+                )
+                .as_bytes()
+                .to_vec()
+            })
+            .collect::<Vec<_>>();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(comments),
+            StringType::from_data(options),
+        ])])
+    }
+}
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::SystemTime;
@@ -30,6 +32,7 @@ use tracing::error;
 use tracing::info;
 use tracing::subscriber;
 
+use crate::interpreters::AuditLogWriter;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 
@@ -58,8 +61,19 @@ fn error_fields(log_type: LogType, err: Option<ErrorCode>) -> (LogType, i32, Str
     }
 }
 
+fn accessed_objects_fields(ctx: &QueryContext) -> (String, String, String) {
+    let objects = ctx.get_accessed_objects();
+    let join = |names: BTreeSet<String>| names.into_iter().collect::<Vec<_>>().join(",");
+    (
+        join(objects.databases),
+        join(objects.tables),
+        join(objects.columns),
+    )
+}
+
 impl InterpreterQueryLog {
-    fn write_log(event: QueryLogElement) -> Result<()> {
+    // Write the event to the query log, and the audit log if `audit` is true.
+    fn write_log(event: QueryLogElement, audit: bool) -> Result<()> {
         let event_str = serde_json::to_string(&event)?;
         if let Some(logger) = QueryLogger::instance().get_subscriber() {
             subscriber::with_default(logger, || {
@@ -68,6 +82,11 @@ impl InterpreterQueryLog {
         } else {
             info!("{}", event_str);
         };
+        if audit {
+            if let Some(writer) = AuditLogWriter::instance() {
+                writer.append(event.clone());
+            }
+        }
         QueryLogQueue::instance()?.append_data(event)
     }
 
@@ -76,6 +95,73 @@ impl InterpreterQueryLog {
             .unwrap_or_else(|e| error!("fail to write query_log {:?}", e));
     }
 
+    pub fn log_login(
+        tenant_id: String,
+        handler_type: String,
+        sql_user: String,
+        client_address: Option<SocketAddr>,
+        err: Option<ErrorCode>,
+    ) {
+        let event_time = convert_query_log_timestamp(SystemTime::now());
+        let event_date = (event_time / (24 * 3_600_000_000)) as i32;
+        let client_address = match client_address {
+            Some(addr) => format!("{:?}", addr),
+            None => "".to_string(),
+        };
+        let (log_type, exception_code, exception_text) = match err {
+            None => (LogType::LoginSuccess, 0, "".to_string()),
+            Some(e) => (LogType::LoginFailure, e.code().into(), e.to_string()),
+        };
+
+        Self::write_log(
+            QueryLogElement {
+                log_type,
+                handler_type,
+                tenant_id,
+                cluster_id: GlobalConfig::instance().query.cluster_id.clone(),
+                sql_user,
+                sql_user_quota: "".to_string(),
+                sql_user_privileges: "".to_string(),
+                query_id: "".to_string(),
+                query_kind: "Login".to_string(),
+                query_text: "".to_string(),
+                event_date,
+                event_time,
+                query_start_time: event_time,
+                query_duration_ms: 0,
+                current_database: "".to_string(),
+                databases: "".to_string(),
+                tables: "".to_string(),
+                columns: "".to_string(),
+                projections: "".to_string(),
+                written_rows: 0,
+                written_bytes: 0,
+                written_io_bytes: 0,
+                written_io_bytes_cost_ms: 0,
+                scan_rows: 0,
+                scan_bytes: 0,
+                scan_io_bytes: 0,
+                scan_io_bytes_cost_ms: 0,
+                scan_partitions: 0,
+                total_partitions: 0,
+                result_rows: 0,
+                result_bytes: 0,
+                cpu_usage: 0,
+                memory_usage: 0,
+                client_info: "".to_string(),
+                client_address,
+                exception_code,
+                exception_text,
+                stack_trace: "".to_string(),
+                server_version: "".to_string(),
+                session_settings: "".to_string(),
+                extra: "".to_string(),
+            },
+            true,
+        )
+        .unwrap_or_else(|e| error!("fail to write query_log {:?}", e));
+    }
+
     pub fn log_start(ctx: &QueryContext, now: SystemTime, err: Option<ErrorCode>) -> Result<()> {
         // User.
         let handler_type = ctx.get_current_session().get_type().to_string();
@@ -129,54 +215,61 @@ impl InterpreterQueryLog {
 
         session_settings.push_str("scope: SESSION");
 
+        // Accessed objects.
+        let (databases, tables, columns) = accessed_objects_fields(ctx);
+
         // Error
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Start, err);
 
-        Self::write_log(QueryLogElement {
-            log_type,
-            handler_type,
-            tenant_id,
-            cluster_id,
-            sql_user,
-            sql_user_quota,
-            sql_user_privileges,
-            query_id,
-            query_kind,
-            query_text,
-            event_date,
-            event_time,
-            query_start_time,
-            query_duration_ms: 0,
-            current_database,
-            databases: "".to_string(),
-            tables: "".to_string(),
-            columns: "".to_string(),
-            projections: "".to_string(),
-            written_rows,
-            written_bytes,
-            written_io_bytes,
-            written_io_bytes_cost_ms,
-            scan_rows,
-            scan_bytes,
-            scan_io_bytes,
-            scan_io_bytes_cost_ms,
-            scan_partitions,
-            total_partitions,
-            result_rows,
-            result_bytes,
-            cpu_usage,
-            memory_usage,
-            client_info: "".to_string(),
-            client_address,
-
-            exception_code,
-            exception_text,
-            stack_trace,
-            server_version: "".to_string(),
-            session_settings,
-            extra: "".to_string(),
-        })
+        let audit = ctx.get_current_session().get_type().is_user_session();
+        Self::write_log(
+            QueryLogElement {
+                log_type,
+                handler_type,
+                tenant_id,
+                cluster_id,
+                sql_user,
+                sql_user_quota,
+                sql_user_privileges,
+                query_id,
+                query_kind,
+                query_text,
+                event_date,
+                event_time,
+                query_start_time,
+                query_duration_ms: 0,
+                current_database,
+                databases,
+                tables,
+                columns,
+                projections: "".to_string(),
+                written_rows,
+                written_bytes,
+                written_io_bytes,
+                written_io_bytes_cost_ms,
+                scan_rows,
+                scan_bytes,
+                scan_io_bytes,
+                scan_io_bytes_cost_ms,
+                scan_partitions,
+                total_partitions,
+                result_rows,
+                result_bytes,
+                cpu_usage,
+                memory_usage,
+                client_info: "".to_string(),
+                client_address,
+
+                exception_code,
+                exception_text,
+                stack_trace,
+                server_version: "".to_string(),
+                session_settings,
+                extra: "".to_string(),
+            },
+            audit,
+        )
     }
 
     pub fn log_finish(ctx: &QueryContext, now: SystemTime, err: Option<ErrorCode>) -> Result<()> {
@@ -240,54 +333,61 @@ impl InterpreterQueryLog {
 
         session_settings.push_str("scope: SESSION");
 
+        // Accessed objects.
+        let (databases, tables, columns) = accessed_objects_fields(ctx);
+
         // Error
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Finish, err);
 
-        Self::write_log(QueryLogElement {
-            log_type,
-            handler_type,
-            tenant_id,
-            cluster_id,
-            sql_user,
-            sql_user_quota,
-            sql_user_privileges,
-            query_id,
-            query_kind,
-            query_text,
-            event_date,
-            event_time,
-            query_start_time,
-            query_duration_ms,
-            databases: "".to_string(),
-            tables: "".to_string(),
-            columns: "".to_string(),
-            projections: "".to_string(),
-            written_rows,
-            written_bytes,
-            written_io_bytes,
-            written_io_bytes_cost_ms,
-            scan_rows,
-            scan_bytes,
-            scan_io_bytes,
-            scan_io_bytes_cost_ms,
-            scan_partitions,
-            total_partitions,
-            result_rows,
-            result_bytes,
-            cpu_usage,
-            memory_usage,
-            client_info: "".to_string(),
-            client_address,
-            current_database,
-
-            exception_code,
-            exception_text,
-            stack_trace,
-            server_version: "".to_string(),
-            session_settings,
-            extra: "".to_string(),
-        })
+        let audit = ctx.get_current_session().get_type().is_user_session();
+        Self::write_log(
+            QueryLogElement {
+                log_type,
+                handler_type,
+                tenant_id,
+                cluster_id,
+                sql_user,
+                sql_user_quota,
+                sql_user_privileges,
+                query_id,
+                query_kind,
+                query_text,
+                event_date,
+                event_time,
+                query_start_time,
+                query_duration_ms,
+                databases,
+                tables,
+                columns,
+                projections: "".to_string(),
+                written_rows,
+                written_bytes,
+                written_io_bytes,
+                written_io_bytes_cost_ms,
+                scan_rows,
+                scan_bytes,
+                scan_io_bytes,
+                scan_io_bytes_cost_ms,
+                scan_partitions,
+                total_partitions,
+                result_rows,
+                result_bytes,
+                cpu_usage,
+                memory_usage,
+                client_info: "".to_string(),
+                client_address,
+                current_database,
+
+                exception_code,
+                exception_text,
+                stack_trace,
+                server_version: "".to_string(),
+                session_settings,
+                extra: "".to_string(),
+            },
+            audit,
+        )
     }
 }
 
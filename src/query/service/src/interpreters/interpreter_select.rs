@@ -19,24 +19,20 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_table_schema;
 use common_expression::DataSchemaRef;
-use common_expression::TableSchemaRef;
-use common_meta_store::MetaStore;
-use common_pipeline_core::pipe::Pipe;
-use common_pipeline_core::pipe::PipeItem;
-use common_pipeline_core::processors::port::InputPort;
-use common_pipeline_core::processors::port::OutputPort;
-use common_pipeline_core::Pipeline;
-use common_pipeline_transforms::processors::transforms::TransformDummy;
 use common_sql::executor::PhysicalPlan;
 use common_sql::parse_result_scan_args;
 use common_sql::MetadataRef;
-use common_storages_result_cache::gen_result_cache_key;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::WriteResultCacheSink;
 use common_users::UserApiProvider;
 
+use crate::interpreters::common::collect_plan_snapshots;
+use crate::interpreters::common::gen_plan_result_cache_key;
+use crate::interpreters::common::prepare_subresult_caches;
+use crate::interpreters::common::write_query_profile;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::PipelineBuilder;
 use crate::schedulers::build_query_pipeline;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -82,84 +78,26 @@ impl SelectInterpreter {
 
     #[async_backtrace::framed]
     pub async fn build_pipeline(&self, physical_plan: PhysicalPlan) -> Result<PipelineBuildResult> {
-        build_query_pipeline(
+        let enable_profiling = self.ctx.get_settings().get_enable_query_profiling()?;
+        let mut build_res = build_query_pipeline(
             &self.ctx,
             &self.bind_context.columns,
             &physical_plan,
             self.ignore_result,
-            false,
+            enable_profiling,
         )
-        .await
-    }
-
-    /// Add pipelines for writing query result cache.
-    fn add_result_cache(
-        &self,
-        key: &str,
-        schema: TableSchemaRef,
-        pipeline: &mut Pipeline,
-        kv_store: Arc<MetaStore>,
-    ) -> Result<()> {
-        //              ┌─────────┐ 1  ┌─────────┐ 1
-        //              │         ├───►│         ├───►Dummy───►Downstream
-        // Upstream────►│Duplicate│ 2  │         │ 3
-        //              │         ├───►│         ├───►Dummy───►Downstream
-        //              └─────────┘    │         │
-        //                             │ Shuffle │
-        //              ┌─────────┐ 3  │         │ 2  ┌─────────┐
-        //              │         ├───►│         ├───►│  Write  │
-        // Upstream────►│Duplicate│ 4  │         │ 4  │ Result  │
-        //              │         ├───►│         ├───►│  Cache  │
-        //              └─────────┘    └─────────┘    └─────────┘
-
-        // 1. Duplicate the pipes.
-        pipeline.duplicate(false)?;
-        // 2. Reorder the pipes.
-        let output_len = pipeline.output_len();
-        debug_assert!(output_len % 2 == 0);
-        let mut rule = vec![0; output_len];
-        for (i, r) in rule.iter_mut().enumerate().take(output_len) {
-            *r = if i % 2 == 0 {
-                i / 2
-            } else {
-                output_len / 2 + i / 2
-            };
-        }
-        pipeline.reorder_inputs(rule);
-
-        // `output_len` / 2 for `TransformDummy`; 1 for `WriteResultCacheSink`.
-        let mut items = Vec::with_capacity(output_len / 2 + 1);
-        // 3. Add `TransformDummy` to the front half pipes.
-        for _ in 0..output_len / 2 {
-            let input = InputPort::create();
-            let output = OutputPort::create();
-            items.push(PipeItem::create(
-                TransformDummy::create(input.clone(), output.clone()),
-                vec![input],
-                vec![output],
-            ));
-        }
+        .await?;
 
-        // 4. Add `WriteResultCacheSink` (`AsyncMpscSinker`) to the back half pipes.
-        let mut sink_inputs = Vec::with_capacity(output_len / 2);
-        for _ in 0..output_len / 2 {
-            sink_inputs.push(InputPort::create());
+        if enable_profiling {
+            let ctx = self.ctx.clone();
+            build_res.main_pipeline.set_on_finished(move |may_error| {
+                if may_error.is_none() {
+                    return write_query_profile(&ctx, &physical_plan);
+                }
+                Ok(())
+            });
         }
-        items.push(PipeItem::create(
-            WriteResultCacheSink::try_create(
-                self.ctx.clone(),
-                key,
-                schema,
-                sink_inputs.clone(),
-                kv_store,
-            )?,
-            sink_inputs,
-            vec![],
-        ));
-
-        pipeline.add_pipe(Pipe::create(output_len, output_len / 2, items));
-
-        Ok(())
+        Ok(build_res)
     }
 
     fn result_scan_table(&self) -> Result<Option<Arc<dyn Table>>> {
@@ -197,8 +135,13 @@ impl Interpreter for SelectInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         // 0. Need to build physical plan first to get the partitions.
         let physical_plan = self.build_physical_plan().await?;
-        if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
-            let key = gen_result_cache_key(self.formatted_ast.as_ref().unwrap());
+        // Queries without the formatted AST are issued internally, never cache them.
+        if self.ctx.get_settings().get_enable_query_result_cache()?
+            && self.ctx.get_cacheable()
+            && self.formatted_ast.is_some()
+        {
+            let key = gen_plan_result_cache_key(&physical_plan, &self.bind_context.columns)?;
+            let snapshot_ids = collect_plan_snapshots(&physical_plan);
             // 1. Try to get result from cache.
             let kv_store = UserApiProvider::instance().get_meta_store_client();
 
@@ -225,6 +168,7 @@ impl Interpreter for SelectInterpreter {
             let cache_reader = ResultCacheReader::create(
                 self.ctx.clone(),
                 &key,
+                snapshot_ids.clone(),
                 kv_store.clone(),
                 self.ctx
                     .get_settings()
@@ -241,14 +185,34 @@ impl Interpreter for SelectInterpreter {
                     return PipelineBuildResult::from_blocks(blocks);
                 }
                 Ok(None) => {
+                    // 2.2 If not found result in cache, try to reuse the results of the aggregations.
+                    if self
+                        .ctx
+                        .get_settings()
+                        .get_enable_aggregate_result_cache()?
+                        && !physical_plan.is_distributed_plan()
+                    {
+                        prepare_subresult_caches(&self.ctx, &physical_plan, kv_store.clone())
+                            .await?;
+                    }
                     let mut build_res = self.build_pipeline(physical_plan).await?;
-                    // 2.2 If not found result in cache, add pipelines to write the result to cache.
+                    // 2.3 Add pipelines to write the result to cache.
                     let schema = infer_table_schema(&self.schema())?;
-                    self.add_result_cache(&key, schema, &mut build_res.main_pipeline, kv_store)?;
+                    let ctx = self.ctx.clone();
+                    PipelineBuilder::add_result_cache(&mut build_res.main_pipeline, |inputs| {
+                        WriteResultCacheSink::try_create(
+                            ctx,
+                            &key,
+                            snapshot_ids,
+                            schema,
+                            inputs,
+                            kv_store,
+                        )
+                    })?;
                     return Ok(build_res);
                 }
                 Err(e) => {
-                    // 2.3 If an error occurs, turn back to the normal pipeline.
+                    // 2.4 If an error occurs, turn back to the normal pipeline.
                     tracing::error!("Failed to read query result cache. {}", e);
                 }
             }
@@ -0,0 +1,95 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateStreamPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::StreamTable;
+use common_storages_fuse::STREAM_ENGINE;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStreamPlan,
+}
+
+impl CreateStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStreamPlan) -> Result<Self> {
+        Ok(CreateStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStreamInterpreter {
+    fn name(&self) -> &str {
+        "CreateStreamInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let source = self
+            .ctx
+            .get_table(&plan.catalog, &plan.table_database, &plan.table_name)
+            .await?;
+        let fuse_table = source.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
+            ErrorCode::IllegalStream(format!(
+                "{}.{} is not a FUSE table, streams can only be created on FUSE tables",
+                plan.table_database, plan.table_name
+            ))
+        })?;
+
+        // The stream starts from the current snapshot of the table.
+        let offset_snapshot_location = fuse_table.snapshot_loc().await?;
+        let options =
+            StreamTable::build_options(fuse_table, &plan.table_database, offset_snapshot_location)?;
+
+        // The data security policies of the table apply to its streams as well.
+        let source_meta = &source.get_table_info().meta;
+        let req = CreateTableReq {
+            if_not_exists: plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: plan.tenant.clone(),
+                db_name: plan.database.clone(),
+                table_name: plan.stream_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: Arc::new(StreamTable::stream_schema(&source.schema())),
+                engine: STREAM_ENGINE.to_string(),
+                options,
+                storage_params: source_meta.storage_params.clone(),
+                part_prefix: source_meta.part_prefix.clone(),
+                comment: plan.comment.clone().unwrap_or_default(),
+                column_mask_policy: source_meta.column_mask_policy.clone(),
+                row_access_policy: source_meta.row_access_policy.clone(),
+                row_access_policy_columns: source_meta.row_access_policy_columns.clone(),
+                ..Default::default()
+            },
+        };
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        catalog.create_table(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
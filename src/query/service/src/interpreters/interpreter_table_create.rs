@@ -14,11 +14,14 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::Sequence;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
@@ -27,11 +30,13 @@ use common_sql::binder::INTERNAL_COLUMN_FACTORY;
 use common_sql::field_default_value;
 use common_sql::plans::CreateTablePlan;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::FuseTable;
 use common_users::UserApiProvider;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use uuid::Uuid;
 
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
@@ -109,9 +114,17 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
-        match &self.plan.as_select {
-            Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
-            None => self.create_table().await,
+        let clone_snapshot_location = self
+            .plan
+            .clone_source
+            .as_ref()
+            .and_then(|source| source.snapshot_location.as_ref());
+        match (&self.plan.as_select, clone_snapshot_location) {
+            (Some(select_plan_node), _) => {
+                self.create_table_as_select(select_plan_node.clone()).await
+            }
+            (None, Some(snapshot_location)) => self.create_table_by_clone(snapshot_location).await,
+            (None, None) => self.create_table().await,
         }
     }
 }
@@ -123,7 +136,7 @@ impl CreateTableInterpreter {
         let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
 
         // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
-        catalog.create_table(self.build_request(None)?).await?;
+        self.create_table_with_sequences(None).await?;
         let table = catalog
             .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
             .await?;
@@ -154,7 +167,6 @@ impl CreateTableInterpreter {
 
     #[async_backtrace::framed]
     async fn create_table(&self) -> Result<PipelineBuildResult> {
-        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str())?;
         let mut stat = None;
         if !GlobalConfig::instance().query.management_mode {
             if let Some(snapshot_loc) = self.plan.options.get(OPT_KEY_SNAPSHOT_LOCATION) {
@@ -177,19 +189,108 @@ impl CreateTableInterpreter {
                 });
             }
         }
-        catalog.create_table(self.build_request(stat)?).await?;
+        self.create_table_with_sequences(stat).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+
+    #[async_backtrace::framed]
+    async fn create_table_by_clone(&self, snapshot_location: &str) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+
+        if self.plan.if_not_exists
+            && catalog
+                .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await
+                .is_ok()
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        catalog
+            .create_table(self.build_request(None, &self.plan.field_default_exprs)?)
+            .await?;
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        if let Err(e) = fuse_table
+            .do_clone(self.ctx.clone(), snapshot_location)
+            .await
+        {
+            // Don't leave an empty table behind.
+            let _ = catalog
+                .drop_table_by_id(DropTableByIdReq {
+                    if_exists: true,
+                    tb_id: table.get_id(),
+                })
+                .await;
+            return Err(e);
+        }
 
         Ok(PipelineBuildResult::create())
     }
 
+    /// Create the sequences of the `AUTOINCREMENT` columns, then the table.
+    ///
+    /// The sequences are dropped if the table can not be created.
+    #[async_backtrace::framed]
+    async fn create_table_with_sequences(&self, statistics: Option<TableStatistics>) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let user_api = UserApiProvider::instance();
+
+        let mut field_default_exprs = self.plan.field_default_exprs.clone();
+        field_default_exprs.resize(self.plan.schema.num_fields(), None);
+        let mut sequences = vec![];
+        let table_exists = self.plan.if_not_exists
+            && catalog
+                .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await
+                .is_ok();
+        if !table_exists {
+            for (idx, identity) in self.plan.field_identities.iter().enumerate() {
+                if let Some(identity) = identity {
+                    let name = format!(
+                        "{}_{}_seq_{}",
+                        self.plan.table,
+                        self.plan.schema.field(idx).name(),
+                        Uuid::new_v4().simple()
+                    );
+                    let sequence =
+                        Sequence::new(&name, identity.start, identity.increment, Utc::now());
+                    user_api.add_sequence(&tenant, sequence, false).await?;
+                    field_default_exprs[idx] = Some(format!("nextval('{name}')"));
+                    sequences.push(name);
+                }
+            }
+        }
+
+        let res = match self.build_request(statistics, &field_default_exprs) {
+            Ok(req) => catalog.create_table(req).await.map(|_| ()),
+            Err(e) => Err(e),
+        };
+        if res.is_err() {
+            for name in sequences {
+                let _ = user_api.drop_sequence(&tenant, &name, true).await;
+            }
+        }
+        res
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
     /// - Update cluster key of table meta.
-    fn build_request(&self, statistics: Option<TableStatistics>) -> Result<CreateTableReq> {
+    fn build_request(
+        &self,
+        statistics: Option<TableStatistics>,
+        field_default_exprs: &[Option<String>],
+    ) -> Result<CreateTableReq> {
         let mut fields = Vec::with_capacity(self.plan.schema.num_fields());
         for (idx, field) in self.plan.schema.fields().clone().into_iter().enumerate() {
-            let field = if let Some(Some(default_expr)) = &self.plan.field_default_exprs.get(idx) {
+            let field = if let Some(Some(default_expr)) = field_default_exprs.get(idx) {
                 let field = field.with_default_expr(Some(default_expr.clone()));
                 let _ = field_default_value(self.ctx.clone(), &field)?;
                 field
@@ -206,7 +307,12 @@ impl CreateTableInterpreter {
 
             fields.push(field)
         }
-        let schema = TableSchemaRefExt::create(fields);
+        // The cloned table shares the blocks of the source table, thus keeps the column ids.
+        let schema = if self.plan.clone_source.is_some() {
+            self.plan.schema.clone()
+        } else {
+            TableSchemaRefExt::create(fields)
+        };
 
         let mut table_meta = TableMeta {
             schema,
@@ -216,6 +322,7 @@ impl CreateTableInterpreter {
             options: self.plan.options.clone(),
             default_cluster_key: None,
             field_comments: self.plan.field_comments.clone(),
+            check_constraints: self.plan.check_constraints.clone(),
             drop_on: None,
             statistics: if let Some(stat) = statistics {
                 stat
@@ -227,6 +334,11 @@ impl CreateTableInterpreter {
         if let Some(cluster_key) = &self.plan.cluster_key {
             table_meta = table_meta.push_cluster_key(cluster_key.clone());
         }
+        if let Some(source) = &self.plan.clone_source {
+            table_meta.column_mask_policy = source.column_mask_policy.clone();
+            table_meta.row_access_policy = source.row_access_policy.clone();
+            table_meta.row_access_policy_columns = source.row_access_policy_columns.clone();
+        }
 
         let req = CreateTableReq {
             if_not_exists: self.plan.if_not_exists,
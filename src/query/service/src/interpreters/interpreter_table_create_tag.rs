@@ -0,0 +1,126 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateTableTagPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_share::save_share_table_info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateTableTagInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTableTagPlan,
+}
+
+impl CreateTableTagInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTableTagPlan) -> Result<Self> {
+        Ok(CreateTableTagInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTableTagInterpreter {
+    fn name(&self) -> &str {
+        "CreateTableTagInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let table = self
+            .ctx
+            .get_catalog(catalog_name)?
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        let table_info = table.get_table_info();
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+        // Only the snapshots of fuse engine can be tagged.
+        FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support snapshot tags",
+                &self.plan.database,
+                &self.plan.table,
+                table_info.engine()
+            ))
+        })?;
+
+        if table_info.meta.snapshot_tags.contains_key(&self.plan.tag) {
+            return Err(ErrorCode::SnapshotTagAlreadyExists(format!(
+                "tag {} already exists in table {}.{}",
+                &self.plan.tag, &self.plan.database, &self.plan.table
+            )));
+        }
+
+        let tagged_table = match &self.plan.navigation {
+            Some(point) => table.navigate_to(point).await?,
+            None => table.clone(),
+        };
+        let snapshot_loc = FuseTable::try_from_table(tagged_table.as_ref())?
+            .snapshot_loc()
+            .await?
+            .ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "table {}.{} has no snapshot to tag",
+                    &self.plan.database, &self.plan.table
+                ))
+            })?;
+
+        let catalog = self.ctx.get_catalog(catalog_name)?;
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta
+            .snapshot_tags
+            .insert(self.plan.tag.clone(), snapshot_loc);
+
+        let table_id = table_info.ident.table_id;
+        let table_version = table_info.ident.seq;
+
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(table_version),
+            new_table_meta,
+            copied_files: None,
+            update_stream_metas: vec![],
+        };
+
+        let res = catalog.update_table_meta(table_info, req).await?;
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -82,6 +82,7 @@ impl Interpreter for DropTableColumnInterpreter {
             seq: MatchSeq::Exact(table_version),
             new_table_meta,
             copied_files: None,
+            update_stream_metas: vec![],
         };
 
         let res = catalog.update_table_meta(table_info, req).await?;
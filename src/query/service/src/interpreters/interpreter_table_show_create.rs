@@ -18,6 +18,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::BlockEntry;
+use common_expression::ComputedExpr;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::Scalar;
@@ -112,6 +113,11 @@ impl Interpreter for ShowCreateTableInterpreter {
                     }
                     None => "".to_string(),
                 };
+                let computed_expr = match field.computed_expr() {
+                    Some(ComputedExpr::Virtual(expr)) => format!(" AS ({expr}) VIRTUAL"),
+                    Some(ComputedExpr::Stored(expr)) => format!(" AS ({expr}) STORED"),
+                    None => "".to_string(),
+                };
                 // compatibility: creating table in the old planner will not have `fields_comments`
                 let comment = if field_comments.len() == n_fields && !field_comments[idx].is_empty()
                 {
@@ -124,15 +130,20 @@ impl Interpreter for ShowCreateTableInterpreter {
                     "".to_string()
                 };
                 let column = format!(
-                    "  `{}` {}{}{}",
+                    "  `{}` {}{}{}{}",
                     field.name(),
                     field.data_type().sql_name(),
                     default_expr,
+                    computed_expr,
                     comment
                 );
 
                 columns.push(column);
             }
+            // Append CHECK constraints after the columns.
+            for (name, expr) in table.get_table_info().meta.check_constraints.iter() {
+                columns.push(format!("  CONSTRAINT `{name}` CHECK ({expr})"));
+            }
             // Format is:
             //  (
             //      x,
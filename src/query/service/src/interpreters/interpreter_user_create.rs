@@ -14,8 +14,10 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::UserGrantSet;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserQuota;
@@ -65,6 +67,10 @@ impl Interpreter for CreateUserInterpreter {
             )));
         };
 
+        let password_update_on = match plan.auth_info {
+            AuthInfo::Password { .. } => Some(Utc::now()),
+            _ => None,
+        };
         let user_info = UserInfo {
             auth_info: plan.auth_info.clone(),
             name: plan.user.username,
@@ -72,6 +78,9 @@ impl Interpreter for CreateUserInterpreter {
             grants: UserGrantSet::empty(),
             quota: UserQuota::no_limit(),
             option: plan.user_option,
+            history_auth_infos: vec![],
+            password_fails: vec![],
+            password_update_on,
         };
         user_mgr
             .add_user(&tenant, user_info, plan.if_not_exists)
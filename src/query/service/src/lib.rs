@@ -34,6 +34,7 @@ extern crate core;
 
 pub mod api;
 pub mod auth;
+pub mod background_service;
 pub mod catalogs;
 pub mod clusters;
 pub mod databases;
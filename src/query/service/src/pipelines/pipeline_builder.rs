@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
 use async_channel::Receiver;
 use common_catalog::table::AppendMode;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::infer_table_schema;
 use common_expression::type_check::check_function;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
@@ -36,13 +38,20 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::pipe::PipeItem;
 use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
+use common_pipeline_sinks::AsyncSinker;
 use common_pipeline_sinks::EmptySink;
 use common_pipeline_sinks::Sinker;
 use common_pipeline_sinks::UnionReceiveSink;
+use common_pipeline_sources::AsyncSourcer;
+use common_pipeline_sources::OneBlockSource;
 use common_pipeline_transforms::processors::transforms::try_add_multi_sort_merge;
 use common_pipeline_transforms::processors::transforms::try_create_transform_sort_merge;
+use common_pipeline_transforms::processors::transforms::TransformDummy;
+use common_pipeline_transforms::processors::transforms::Transformer;
+use common_profile::PlanProfSpan;
 use common_profile::ProfSpanSetRef;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
@@ -50,6 +59,7 @@ use common_sql::executor::AggregateExpand;
 use common_sql::executor::AggregateFinal;
 use common_sql::executor::AggregateFunctionDesc;
 use common_sql::executor::AggregatePartial;
+use common_sql::executor::CteScan;
 use common_sql::executor::DistributedInsertSelect;
 use common_sql::executor::EvalScalar;
 use common_sql::executor::ExchangeSink;
@@ -57,6 +67,7 @@ use common_sql::executor::ExchangeSource;
 use common_sql::executor::Filter;
 use common_sql::executor::HashJoin;
 use common_sql::executor::Limit;
+use common_sql::executor::MaterializedCte;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
 use common_sql::executor::ProjectSet;
@@ -70,6 +81,9 @@ use common_sql::ColumnBinding;
 use common_sql::IndexType;
 use common_storage::DataOperator;
 use common_storages_fuse::operations::FillInternalColumnProcessor;
+use common_storages_result_cache::SubresultCache;
+use common_storages_result_cache::WriteResultCacheSink;
+use common_users::UserApiProvider;
 use petgraph::matrix_graph::Zero;
 
 use super::processors::transforms::FrameBound;
@@ -79,9 +93,12 @@ use super::processors::TransformExpandGroupingSets;
 use crate::api::DefaultExchangeInjector;
 use crate::api::ExchangeInjector;
 use crate::pipelines::processors::transforms::build_partition_bucket;
+use crate::pipelines::processors::transforms::rewrite_nextval;
 use crate::pipelines::processors::transforms::AggregateInjector;
 use crate::pipelines::processors::transforms::FinalSingleStateAggregator;
 use crate::pipelines::processors::transforms::HashJoinDesc;
+use crate::pipelines::processors::transforms::MaterializedCteSink;
+use crate::pipelines::processors::transforms::MaterializedCteSource;
 use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::RightSemiAntiJoinCompactor;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
@@ -92,8 +109,11 @@ use crate::pipelines::processors::transforms::TransformMarkJoin;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
 use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
+use crate::pipelines::processors::transforms::TransformProfileOutput;
 use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
+use crate::pipelines::processors::transforms::TransformRuntimeFilterBloom;
+use crate::pipelines::processors::transforms::TransformSequenceNextval;
 use crate::pipelines::processors::transforms::TransformWindow;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::JoinHashTable;
@@ -167,6 +187,26 @@ impl PipelineBuilder {
     }
 
     fn build_pipeline(&mut self, plan: &PhysicalPlan) -> Result<()> {
+        self.build_plan(plan)?;
+
+        if self.enable_profiling {
+            if let Some(plan_id) = plan.get_id() {
+                if self.main_pipeline.is_pulling_pipeline()? {
+                    self.main_pipeline.add_transform(|input, output| {
+                        Ok(ProcessorPtr::create(Transformer::create(
+                            input,
+                            output,
+                            TransformProfileOutput::create(plan_id, self.prof_span_set.clone()),
+                        )))
+                    })?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn build_plan(&mut self, plan: &PhysicalPlan) -> Result<()> {
         match plan {
             PhysicalPlan::TableScan(scan) => self.build_table_scan(scan),
             PhysicalPlan::Filter(filter) => self.build_filter(filter),
@@ -192,6 +232,10 @@ impl PipelineBuilder {
             PhysicalPlan::RuntimeFilterSource(runtime_filter_source) => {
                 self.build_runtime_filter_source(runtime_filter_source)
             }
+            PhysicalPlan::MaterializedCte(materialized_cte) => {
+                self.build_materialized_cte(materialized_cte)
+            }
+            PhysicalPlan::CteScan(cte_scan) => self.build_cte_scan(cte_scan),
         }
     }
 
@@ -257,6 +301,65 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    /// Add pipelines for writing the result cache by the sink from `create_sink`.
+    pub fn add_result_cache<F>(pipeline: &mut Pipeline, create_sink: F) -> Result<()>
+    where F: FnOnce(Vec<Arc<InputPort>>) -> Result<ProcessorPtr> {
+        //              ┌─────────┐ 1  ┌─────────┐ 1
+        //              │         ├───►│         ├───►Dummy───►Downstream
+        // Upstream────►│Duplicate│ 2  │         │ 3
+        //              │         ├───►│         ├───►Dummy───►Downstream
+        //              └─────────┘    │         │
+        //                             │ Shuffle │
+        //              ┌─────────┐ 3  │         │ 2  ┌─────────┐
+        //              │         ├───►│         ├───►│  Write  │
+        // Upstream────►│Duplicate│ 4  │         │ 4  │ Result  │
+        //              │         ├───►│         ├───►│  Cache  │
+        //              └─────────┘    └─────────┘    └─────────┘
+
+        // 1. Duplicate the pipes.
+        pipeline.duplicate(false)?;
+        // 2. Reorder the pipes.
+        let output_len = pipeline.output_len();
+        debug_assert!(output_len % 2 == 0);
+        let mut rule = vec![0; output_len];
+        for (i, r) in rule.iter_mut().enumerate().take(output_len) {
+            *r = if i % 2 == 0 {
+                i / 2
+            } else {
+                output_len / 2 + i / 2
+            };
+        }
+        pipeline.reorder_inputs(rule);
+
+        // `output_len` / 2 for `TransformDummy`; 1 for `WriteResultCacheSink`.
+        let mut items = Vec::with_capacity(output_len / 2 + 1);
+        // 3. Add `TransformDummy` to the front half pipes.
+        for _ in 0..output_len / 2 {
+            let input = InputPort::create();
+            let output = OutputPort::create();
+            items.push(PipeItem::create(
+                TransformDummy::create(input.clone(), output.clone()),
+                vec![input],
+                vec![output],
+            ));
+        }
+
+        // 4. Add `WriteResultCacheSink` (`AsyncMpscSinker`) to the back half pipes.
+        let mut sink_inputs = Vec::with_capacity(output_len / 2);
+        for _ in 0..output_len / 2 {
+            sink_inputs.push(InputPort::create());
+        }
+        items.push(PipeItem::create(
+            create_sink(sink_inputs.clone())?,
+            sink_inputs,
+            vec![],
+        ));
+
+        pipeline.add_pipe(Pipe::create(output_len, output_len / 2, items));
+
+        Ok(())
+    }
+
     pub fn render_result_set(
         func_ctx: &FunctionContext,
         input_schema: DataSchemaRef,
@@ -292,8 +395,15 @@ impl PipelineBuilder {
 
     fn build_table_scan(&mut self, scan: &TableScan) -> Result<()> {
         let table = self.ctx.build_table_from_source_plan(&scan.source)?;
-        self.ctx.set_partitions(scan.source.parts.clone())?;
-        table.read_data(self.ctx.clone(), &scan.source, &mut self.main_pipeline)?;
+        let ctx = match self.enable_profiling {
+            true => QueryContext::create_for_scan(
+                self.ctx.clone(),
+                PlanProfSpan::create(scan.plan_id, self.prof_span_set.clone()),
+            ),
+            false => self.ctx.clone(),
+        };
+        ctx.set_partitions(scan.source.parts.clone())?;
+        table.read_data(ctx, &scan.source, &mut self.main_pipeline)?;
 
         // Fill internal columns if needed.
         if let Some(internal_columns) = &scan.internal_column {
@@ -305,6 +415,21 @@ impl PipelineBuilder {
         }
 
         let schema = scan.source.schema();
+        // Drop the rows that can't match any build row of the hash joins.
+        if let Some(push_downs) = &scan.source.push_downs {
+            if !push_downs.runtime_filters.is_empty() {
+                self.main_pipeline.add_transform(|input, output| {
+                    TransformRuntimeFilterBloom::try_create(
+                        self.ctx.clone(),
+                        input,
+                        output,
+                        schema.clone(),
+                        &push_downs.runtime_filters,
+                    )
+                })?;
+            }
+        }
+
         let projection = scan
             .name_mapping
             .keys()
@@ -396,7 +521,7 @@ impl PipelineBuilder {
     fn build_eval_scalar(&mut self, eval_scalar: &EvalScalar) -> Result<()> {
         self.build_pipeline(&eval_scalar.input)?;
 
-        let exprs = eval_scalar
+        let mut exprs = eval_scalar
             .exprs
             .iter()
             .filter(|(scalar, idx)| {
@@ -408,19 +533,39 @@ impl PipelineBuilder {
             .map(|(scalar, _)| scalar.as_expr(&BUILTIN_FUNCTIONS))
             .collect::<Vec<_>>();
 
-        let op = BlockOperator::Map { exprs };
-
         let func_ctx = self.ctx.get_function_context()?;
 
         let num_input_columns = eval_scalar.input.output_schema()?.num_fields();
 
+        // The values of `nextval` are taken for each block and appended to the input,
+        // they are dropped after the expressions are evaluated.
+        let sequences = rewrite_nextval(&mut exprs, num_input_columns);
+        let num_exprs = exprs.len();
+        let mut ops = vec![BlockOperator::Map { exprs }];
+        if !sequences.is_empty() {
+            let first_expr = num_input_columns + sequences.len();
+            ops.push(BlockOperator::Project {
+                projection: (0..num_input_columns)
+                    .chain(first_expr..first_expr + num_exprs)
+                    .collect(),
+            });
+            self.main_pipeline.add_transform(|input, output| {
+                Ok(TransformSequenceNextval::create(
+                    self.ctx.clone(),
+                    input,
+                    output,
+                    sequences.clone(),
+                ))
+            })?;
+        }
+
         self.main_pipeline.add_transform(|input, output| {
             let transform = CompoundBlockOperator::create(
                 input,
                 output,
-                num_input_columns,
+                num_input_columns + sequences.len(),
                 func_ctx.clone(),
-                vec![op.clone()],
+                ops.clone(),
             );
 
             if self.enable_profiling {
@@ -589,6 +734,10 @@ impl PipelineBuilder {
             }
         })?;
 
+        let prof_span = self
+            .enable_profiling
+            .then(|| PlanProfSpan::create(aggregate.plan_id, self.prof_span_set.clone()));
+
         // If cluster mode, spill write will be completed in exchange serialize, because we need scatter the block data first
         if self.ctx.get_cluster().is_empty()
             && !self
@@ -607,7 +756,8 @@ impl PipelineBuilder {
                             output,
                             method,
                             operator.clone(),
-                            location_prefix.clone()
+                            location_prefix.clone(),
+                            prof_span.clone()
                         ),
                     }),
                     false => with_mappedhash_method!(|T| match method.clone() {
@@ -617,7 +767,8 @@ impl PipelineBuilder {
                             method,
                             operator.clone(),
                             params.clone(),
-                            location_prefix.clone()
+                            location_prefix.clone(),
+                            prof_span.clone()
                         ),
                     }),
                 };
@@ -641,7 +792,8 @@ impl PipelineBuilder {
                     &self.ctx,
                     tenant.clone(),
                     method,
-                    params.clone()
+                    params.clone(),
+                    prof_span.clone()
                 ),
             }),
             false => with_mappedhash_method!(|T| match method.clone() {
@@ -649,7 +801,8 @@ impl PipelineBuilder {
                     &self.ctx,
                     tenant.clone(),
                     method,
-                    params.clone()
+                    params.clone(),
+                    prof_span.clone()
                 ),
             }),
         };
@@ -658,6 +811,32 @@ impl PipelineBuilder {
     }
 
     fn build_aggregate_final(&mut self, aggregate: &AggregateFinal) -> Result<()> {
+        match self.ctx.get_subresult_cache(aggregate.plan_id) {
+            Some(SubresultCache::Hit(blocks)) => {
+                // Replay the cached result instead of executing the aggregation.
+                let block = match blocks.iter().any(|block| block.num_columns() > 0) {
+                    true => DataBlock::concat(&blocks)?,
+                    false => DataBlock::empty_with_schema(aggregate.output_schema()?),
+                };
+                self.main_pipeline
+                    .add_source(|output| OneBlockSource::create(output, block.clone()), 1)
+            }
+            Some(SubresultCache::Miss(key)) => {
+                self.build_aggregate_final_pipeline(aggregate)?;
+                let schema = infer_table_schema(&aggregate.output_schema()?)?;
+                let kv_store = UserApiProvider::instance().get_meta_store_client();
+                let ctx = self.ctx.clone();
+                Self::add_result_cache(&mut self.main_pipeline, |inputs| {
+                    WriteResultCacheSink::try_create_for_subresult(
+                        ctx, &key, schema, inputs, kv_store,
+                    )
+                })
+            }
+            None => self.build_aggregate_final_pipeline(aggregate),
+        }
+    }
+
+    fn build_aggregate_final_pipeline(&mut self, aggregate: &AggregateFinal) -> Result<()> {
         let params = Self::build_aggregator_params(
             aggregate.before_group_by_schema.clone(),
             &aggregate.group_by,
@@ -690,6 +869,9 @@ impl PipelineBuilder {
 
         let tenant = self.ctx.get_tenant();
         let old_inject = self.exchange_injector.clone();
+        let prof_span = self
+            .enable_profiling
+            .then(|| PlanProfSpan::create(aggregate.plan_id, self.prof_span_set.clone()));
 
         match params.aggregate_functions.is_empty() {
             true => with_hash_method!(|T| match method {
@@ -701,6 +883,7 @@ impl PipelineBuilder {
                             tenant,
                             v.clone(),
                             params.clone(),
+                            prof_span.clone(),
                         );
                     }
 
@@ -723,6 +906,7 @@ impl PipelineBuilder {
                             tenant,
                             v.clone(),
                             params.clone(),
+                            prof_span.clone(),
                         );
                     }
                     self.build_pipeline(&aggregate.input)?;
@@ -1188,6 +1372,77 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_materialized_cte(&mut self, materialized_cte: &MaterializedCte) -> Result<()> {
+        let state = self
+            .ctx
+            .get_materialized_cte_state(materialized_cte.cte_idx);
+        let cte_ctx = QueryContext::create_from(self.ctx.clone());
+        let pipeline_builder =
+            PipelineBuilder::create(cte_ctx, self.enable_profiling, self.prof_span_set.clone());
+        let mut build_res = pipeline_builder.finalize(&materialized_cte.left)?;
+
+        assert!(build_res.main_pipeline.is_pulling_pipeline()?);
+
+        let operator = DataOperator::instance().operator();
+        let location_prefix = format!("_materialized_cte_spill/{}", self.ctx.get_tenant());
+        let spilling_bytes_threshold = self
+            .ctx
+            .get_settings()
+            .get_materialized_cte_spilling_bytes_threshold()?;
+        let prof_span = self
+            .enable_profiling
+            .then(|| PlanProfSpan::create(materialized_cte.plan_id, self.prof_span_set.clone()));
+        build_res.main_pipeline.add_sink(|input_port| {
+            let sink = AsyncSinker::create(
+                input_port,
+                MaterializedCteSink::create(
+                    state.clone(),
+                    operator.clone(),
+                    location_prefix.clone(),
+                    spilling_bytes_threshold,
+                    prof_span.clone(),
+                ),
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    sink,
+                    materialized_cte.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(sink))
+            }
+        })?;
+
+        self.pipelines.push(build_res.main_pipeline);
+        self.pipelines
+            .extend(build_res.sources_pipelines.into_iter());
+        self.build_pipeline(&materialized_cte.right)
+    }
+
+    fn build_cte_scan(&mut self, cte_scan: &CteScan) -> Result<()> {
+        let state = self.ctx.get_materialized_cte_state(cte_scan.cte_idx);
+        let cursor = Arc::new(AtomicUsize::new(0));
+        let operator = DataOperator::instance().operator();
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+        self.main_pipeline.add_source(
+            |output| {
+                AsyncSourcer::create(
+                    self.ctx.clone(),
+                    output,
+                    MaterializedCteSource::create(
+                        state.clone(),
+                        cursor.clone(),
+                        operator.clone(),
+                        cte_scan.offsets.clone(),
+                    ),
+                )
+            },
+            max_threads,
+        )
+    }
+
     pub fn build_distributed_insert_select(
         &mut self,
         insert_select: &DistributedInsertSelect,
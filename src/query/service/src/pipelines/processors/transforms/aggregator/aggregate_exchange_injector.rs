@@ -26,6 +26,7 @@ use common_hashtable::HashtableEntryRefLike;
 use common_hashtable::HashtableLike;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
+use common_profile::PlanProfSpan;
 use common_storage::DataOperator;
 use strength_reduce::StrengthReducedU64;
 
@@ -191,6 +192,7 @@ pub struct AggregateInjector<Method: HashMethodBounds, V: Copy + Send + Sync + '
     method: Method,
     tenant: String,
     aggregator_params: Arc<AggregatorParams>,
+    prof_span: Option<PlanProfSpan>,
     _phantom: PhantomData<V>,
 }
 
@@ -200,12 +202,14 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> AggregateInjecto
         tenant: String,
         method: Method,
         params: Arc<AggregatorParams>,
+        prof_span: Option<PlanProfSpan>,
     ) -> Arc<dyn ExchangeInjector> {
         Arc::new(AggregateInjector::<Method, V> {
             method,
             tenant,
             ctx: ctx.clone(),
             aggregator_params: params,
+            prof_span,
             _phantom: Default::default(),
         })
     }
@@ -264,6 +268,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
                             method.clone(),
                             operator.clone(),
                             location_prefix.clone(),
+                            self.prof_span.clone(),
                         ),
                         false => TransformAggregateSpillWriter::create(
                             input,
@@ -272,6 +277,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
                             operator.clone(),
                             params.clone(),
                             location_prefix.clone(),
+                            self.prof_span.clone(),
                         ),
                     },
                 ))
@@ -310,6 +316,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
                         method.clone(),
                         operator.clone(),
                         location_prefix.clone(),
+                        self.prof_span.clone(),
                     ),
                     false => TransformScatterAggregateSpillWriter::create(
                         input,
@@ -318,6 +325,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
                         operator.clone(),
                         location_prefix.clone(),
                         params.clone(),
+                        self.prof_span.clone(),
                     ),
                 },
             ))
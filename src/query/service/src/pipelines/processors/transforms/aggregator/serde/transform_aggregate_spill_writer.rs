@@ -27,6 +27,8 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_profile::PlanProfSpan;
+use common_profile::ProfSpan;
 use futures_util::future::BoxFuture;
 use opendal::Operator;
 use tracing::info;
@@ -45,6 +47,7 @@ pub struct TransformAggregateSpillWriter<Method: HashMethodBounds> {
 
     operator: Operator,
     location_prefix: String,
+    prof_span: Option<PlanProfSpan>,
     output_block: Option<DataBlock>,
     spilling_meta: Option<AggregateMeta<Method, usize>>,
     spilling_future: Option<BoxFuture<'static, Result<()>>>,
@@ -58,6 +61,7 @@ impl<Method: HashMethodBounds> TransformAggregateSpillWriter<Method> {
         operator: Operator,
         params: Arc<AggregatorParams>,
         location_prefix: String,
+        prof_span: Option<PlanProfSpan>,
     ) -> Box<dyn Processor> {
         Box::new(TransformAggregateSpillWriter::<Method> {
             method,
@@ -66,6 +70,7 @@ impl<Method: HashMethodBounds> TransformAggregateSpillWriter<Method> {
             params,
             operator,
             location_prefix,
+            prof_span,
             output_block: None,
             spilling_meta: None,
             spilling_future: None,
@@ -144,6 +149,7 @@ impl<Method: HashMethodBounds> Processor for TransformAggregateSpillWriter<Metho
                     self.operator.clone(),
                     &self.method,
                     &self.location_prefix,
+                    self.prof_span.clone(),
                     &self.params,
                     payload,
                 )?;
@@ -199,6 +205,7 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
     operator: Operator,
     method: &Method,
     location_prefix: &str,
+    prof_span: Option<PlanProfSpan>,
     params: &Arc<AggregatorParams>,
     payload: HashTablePayload<Method, usize>,
 ) -> Result<(DataBlock, BoxFuture<'static, Result<()>>)> {
@@ -225,6 +232,14 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
 
             operator.write(&location, write_data).await?;
 
+            if let Some(prof_span) = prof_span {
+                prof_span.update(ProfSpan {
+                    spill_count: 1,
+                    spill_bytes: total_size as u64,
+                    ..Default::default()
+                });
+            }
+
             info!(
                 "Write aggregate spill {} successfully, elapsed: {:?}",
                 location,
@@ -27,6 +27,8 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_profile::PlanProfSpan;
+use common_profile::ProfSpan;
 use futures_util::future::BoxFuture;
 use opendal::Operator;
 use tracing::info;
@@ -43,6 +45,7 @@ pub struct TransformGroupBySpillWriter<Method: HashMethodBounds> {
 
     operator: Operator,
     location_prefix: String,
+    prof_span: Option<PlanProfSpan>,
     output_block: Option<DataBlock>,
     spilling_meta: Option<AggregateMeta<Method, ()>>,
     spilling_future: Option<BoxFuture<'static, Result<()>>>,
@@ -55,6 +58,7 @@ impl<Method: HashMethodBounds> TransformGroupBySpillWriter<Method> {
         method: Method,
         operator: Operator,
         location_prefix: String,
+        prof_span: Option<PlanProfSpan>,
     ) -> Box<dyn Processor> {
         Box::new(TransformGroupBySpillWriter::<Method> {
             method,
@@ -62,6 +66,7 @@ impl<Method: HashMethodBounds> TransformGroupBySpillWriter<Method> {
             output,
             operator,
             location_prefix,
+            prof_span,
             output_block: None,
             spilling_meta: None,
             spilling_future: None,
@@ -140,6 +145,7 @@ impl<Method: HashMethodBounds> Processor for TransformGroupBySpillWriter<Method>
                     self.operator.clone(),
                     &self.method,
                     &self.location_prefix,
+                    self.prof_span.clone(),
                     payload,
                 )?;
 
@@ -195,6 +201,7 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
     operator: Operator,
     method: &Method,
     location_prefix: &str,
+    prof_span: Option<PlanProfSpan>,
     payload: HashTablePayload<Method, ()>,
 ) -> Result<(DataBlock, BoxFuture<'static, Result<()>>)> {
     let (bucket, total_size, data) = serialize_spill_file(method, payload)?;
@@ -220,6 +227,14 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
 
             operator.write(&location, write_data).await?;
 
+            if let Some(prof_span) = prof_span {
+                prof_span.update(ProfSpan {
+                    spill_count: 1,
+                    spill_bytes: total_size as u64,
+                    ..Default::default()
+                });
+            }
+
             info!(
                 "Write aggregate spill {} successfully, elapsed: {:?}",
                 location,
@@ -22,6 +22,7 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_profile::PlanProfSpan;
 use futures_util::future::BoxFuture;
 use opendal::Operator;
 
@@ -38,6 +39,7 @@ pub struct TransformScatterAggregateSpillWriter<Method: HashMethodBounds> {
 
     operator: Operator,
     location_prefix: String,
+    prof_span: Option<PlanProfSpan>,
     params: Arc<AggregatorParams>,
     input_data_block: Option<DataBlock>,
     output_data_block: Option<DataBlock>,
@@ -52,6 +54,7 @@ impl<Method: HashMethodBounds> TransformScatterAggregateSpillWriter<Method> {
         operator: Operator,
         location_prefix: String,
         params: Arc<AggregatorParams>,
+        prof_span: Option<PlanProfSpan>,
     ) -> Box<dyn Processor> {
         Box::new(TransformScatterAggregateSpillWriter::<Method> {
             method,
@@ -60,6 +63,7 @@ impl<Method: HashMethodBounds> TransformScatterAggregateSpillWriter<Method> {
             params,
             operator,
             location_prefix,
+            prof_span,
             input_data_block: None,
             output_data_block: None,
             spilling_futures: vec![],
@@ -139,6 +143,7 @@ impl<Method: HashMethodBounds> Processor for TransformScatterAggregateSpillWrite
                                 self.operator.clone(),
                                 &self.method,
                                 &self.location_prefix,
+                                self.prof_span.clone(),
                                 &self.params,
                                 payload,
                             )?;
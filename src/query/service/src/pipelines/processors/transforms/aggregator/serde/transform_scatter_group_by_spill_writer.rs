@@ -22,6 +22,7 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_profile::PlanProfSpan;
 use futures_util::future::BoxFuture;
 use opendal::Operator;
 
@@ -37,6 +38,7 @@ pub struct TransformScatterGroupBySpillWriter<Method: HashMethodBounds> {
 
     operator: Operator,
     location_prefix: String,
+    prof_span: Option<PlanProfSpan>,
     input_data_block: Option<DataBlock>,
     output_data_block: Option<DataBlock>,
     spilling_futures: Vec<BoxFuture<'static, Result<()>>>,
@@ -49,6 +51,7 @@ impl<Method: HashMethodBounds> TransformScatterGroupBySpillWriter<Method> {
         method: Method,
         operator: Operator,
         location_prefix: String,
+        prof_span: Option<PlanProfSpan>,
     ) -> Box<dyn Processor> {
         Box::new(TransformScatterGroupBySpillWriter::<Method> {
             method,
@@ -56,6 +59,7 @@ impl<Method: HashMethodBounds> TransformScatterGroupBySpillWriter<Method> {
             output,
             operator,
             location_prefix,
+            prof_span,
             input_data_block: None,
             output_data_block: None,
             spilling_futures: vec![],
@@ -135,6 +139,7 @@ impl<Method: HashMethodBounds> Processor for TransformScatterGroupBySpillWriter<
                                 self.operator.clone(),
                                 &self.method,
                                 &self.location_prefix,
+                                self.prof_span.clone(),
                                 payload,
                             )?;
 
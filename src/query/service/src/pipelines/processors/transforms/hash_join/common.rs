@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::runtime_filter_info::RuntimeFilterBloom;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::arrow::constant_bitmap;
@@ -28,12 +32,16 @@ use common_expression::DataBlock;
 use common_expression::Evaluator;
 use common_expression::Expr;
 use common_expression::Scalar;
+use common_expression::ScalarRef;
 use common_expression::Value;
+use common_functions::aggregates::eval_aggr;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_hashtable::HashtableLike;
 use common_sql::executor::cast_expr_to_non_null_boolean;
 
+use crate::api::DataExchangeManager;
 use crate::pipelines::processors::transforms::hash_join::desc::MarkerKind;
+use crate::pipelines::processors::transforms::hash_join::row::Chunk;
 use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
 use crate::pipelines::processors::JoinHashTable;
 use crate::sql::plans::JoinType;
@@ -399,4 +407,95 @@ impl JoinHashTable {
 
         self.row_space.push_cols(data_block, build_cols)
     }
+
+    /// Publishes the runtime filters of the build keys, once all the build rows are added.
+    /// The scans read everything if the filters can't be published.
+    pub(crate) fn publish_runtime_filters(&self, chunks: &[Chunk]) -> Result<()> {
+        if self.hash_join_desc.runtime_filters.is_empty() {
+            return Ok(());
+        }
+
+        let res = self.build_runtime_filters(chunks).and_then(|filters| {
+            let query_id = self.ctx.get_id();
+            for ((_, key), filter) in self.hash_join_desc.runtime_filters.iter().zip(filters) {
+                match self.hash_join_desc.merge_runtime_filters {
+                    true => DataExchangeManager::instance()
+                        .publish_runtime_filter(&query_id, *key, filter)?,
+                    false => self
+                        .ctx
+                        .get_runtime_filter_channel(*key)
+                        .publish(Some(filter)),
+                }
+            }
+            Ok(())
+        });
+        if res.is_err() {
+            self.cancel_runtime_filters();
+        }
+        res
+    }
+
+    fn build_runtime_filters(&self, chunks: &[Chunk]) -> Result<Vec<RuntimeFilterInfo>> {
+        let max_inlist_size = self
+            .ctx
+            .get_settings()
+            .get_max_runtime_filter_inlist_size()? as usize;
+        let num_rows = chunks.iter().map(|chunk| chunk.num_rows()).sum();
+        let mut filters = Vec::with_capacity(self.hash_join_desc.runtime_filters.len());
+        for (key_index, _) in self.hash_join_desc.runtime_filters.iter() {
+            let data_type = self.hash_join_desc.build_keys[*key_index].data_type();
+            let mut min_max: Option<(Scalar, Scalar)> = None;
+            let mut inlist = Some(HashSet::new());
+            let mut bloom = RuntimeFilterBloom::with_capacity(num_rows);
+            for chunk in chunks {
+                let (column, _) = &chunk.cols[*key_index];
+                let num_rows = chunk.num_rows();
+                let (mins, _) = eval_aggr("min", vec![], &[column.clone()], num_rows)?;
+                let (maxs, _) = eval_aggr("max", vec![], &[column.clone()], num_rows)?;
+                let (min, max) = match (mins.index(0), maxs.index(0)) {
+                    (Some(min), Some(max)) if min != ScalarRef::Null => {
+                        (min.to_owned(), max.to_owned())
+                    }
+                    // All the keys of the chunk are NULL.
+                    _ => continue,
+                };
+                min_max = Some(match min_max {
+                    Some((lo, hi)) => (
+                        if min < lo { min } else { lo },
+                        if max > hi { max } else { hi },
+                    ),
+                    None => (min, max),
+                });
+
+                for value in column.iter() {
+                    if value == ScalarRef::Null {
+                        continue;
+                    }
+                    bloom.insert(RuntimeFilterBloom::hash(value.clone()));
+                    if let Some(values) = &mut inlist {
+                        values.insert(value.to_owned());
+                        if values.len() > max_inlist_size {
+                            inlist = None;
+                        }
+                    }
+                }
+            }
+
+            filters.push(RuntimeFilterInfo {
+                data_type: data_type.clone(),
+                min_max,
+                inlist: inlist.map(|values| values.into_iter().collect()),
+                bloom: Some(bloom),
+            });
+        }
+        Ok(filters)
+    }
+
+    /// Lets the scans waiting for the runtime filters read everything,
+    /// if the build side can't be completed.
+    pub(crate) fn cancel_runtime_filters(&self) {
+        for (_, key) in self.hash_join_desc.runtime_filters.iter() {
+            self.ctx.get_runtime_filter_channel(*key).publish(None);
+        }
+    }
 }
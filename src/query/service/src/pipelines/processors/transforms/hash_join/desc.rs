@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::runtime_filter_info::RuntimeFilterKey;
 use common_exception::Result;
 use common_expression::type_check::check_function;
 use common_expression::DataBlock;
@@ -67,6 +68,10 @@ pub struct HashJoinDesc {
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
     pub(crate) join_state: JoinState,
+    /// The build keys, by index, whose runtime filters are waited for by the scans of the probe side.
+    pub(crate) runtime_filters: Vec<(usize, RuntimeFilterKey)>,
+    /// Whether the runtime filters are merged with the ones of the other nodes of the cluster.
+    pub(crate) merge_runtime_filters: bool,
 }
 
 impl HashJoinDesc {
@@ -95,6 +100,17 @@ impl HashJoinDesc {
             },
             from_correlated_subquery: join.from_correlated_subquery,
             join_state: JoinState::create()?,
+            runtime_filters: join
+                .runtime_filter_keys
+                .iter()
+                .map(|key_index| {
+                    (*key_index, RuntimeFilterKey {
+                        join_id: join.plan_id,
+                        key_index: *key_index,
+                    })
+                })
+                .collect(),
+            merge_runtime_filters: join.merge_runtime_filters,
         })
     }
 
@@ -69,6 +69,7 @@ impl HashJoinState for JoinHashTable {
 
     fn interrupt(&self) {
         self.interrupt.store(true, Ordering::Release);
+        self.cancel_runtime_filters();
     }
 
     fn join_state(&self) -> &JoinState {
@@ -85,7 +86,11 @@ impl HashJoinState for JoinHashTable {
         let mut count = self.ref_count.lock().unwrap();
         *count -= 1;
         if *count == 0 {
-            self.finish()?;
+            if let Err(cause) = self.finish() {
+                // The scans waiting for the runtime filters read everything if the build fails.
+                self.cancel_runtime_filters();
+                return Err(cause);
+            }
             let mut is_finished = self.is_finished.lock().unwrap();
             *is_finished = true;
             self.finished_notify.notify_waiters();
@@ -223,7 +228,7 @@ impl HashJoinState for JoinHashTable {
                 },
             }
         }
-        Ok(())
+        self.publish_runtime_filters(&chunks)
     }
 
     #[async_backtrace::framed]
@@ -294,3 +294,10 @@ impl JoinHashTable {
         })
     }
 }
+
+impl Drop for JoinHashTable {
+    fn drop(&mut self) {
+        // No-op if the runtime filters are published.
+        self.cancel_runtime_filters();
+    }
+}
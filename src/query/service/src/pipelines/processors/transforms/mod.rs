@@ -21,6 +21,7 @@ mod transform_hash_join;
 mod transform_left_join;
 mod transform_limit;
 mod transform_mark_join;
+mod transform_materialized_cte;
 mod window;
 
 mod profile_wrapper;
@@ -32,6 +33,8 @@ mod transform_right_join;
 mod transform_right_semi_anti_join;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_runtime_filter_bloom;
+mod transform_sequence_nextval;
 
 pub use aggregator::build_partition_bucket;
 pub use aggregator::AggregateInjector;
@@ -64,6 +67,7 @@ pub use hash_join::HashTable;
 pub use hash_join::JoinHashTable;
 pub use hash_join::SerializerHashTable;
 pub use profile_wrapper::ProfileWrapper;
+pub use profile_wrapper::TransformProfileOutput;
 pub use runtime_filter::RuntimeFilterState;
 pub use transform_add_const_columns::TransformAddConstColumns;
 pub use transform_block_compact::BlockCompactor;
@@ -80,6 +84,9 @@ pub use transform_left_join::TransformLeftJoin;
 pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
+pub use transform_materialized_cte::MaterializedCteSink;
+pub use transform_materialized_cte::MaterializedCteSource;
+pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_right_join::RightJoinCompactor;
@@ -89,6 +96,10 @@ pub use transform_right_semi_anti_join::TransformRightSemiAntiJoin;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
+pub use transform_runtime_filter_bloom::TransformRuntimeFilterBloom;
+pub use transform_sequence_nextval::next_sequence_columns;
+pub use transform_sequence_nextval::rewrite_nextval;
+pub use transform_sequence_nextval::TransformSequenceNextval;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_partial::TransformSortPartial;
 pub use window::FrameBound;
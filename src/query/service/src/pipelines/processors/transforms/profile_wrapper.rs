@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
 use std::time::Instant;
 
+use common_base::runtime::MemStat;
+use common_base::runtime::ThreadTracker;
+use common_base::runtime::TrackedFuture;
 use common_exception::Result;
+use common_expression::DataBlock;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::Transform;
+use common_profile::ProfSpan;
 use common_profile::ProfSpanBuilder;
 use common_profile::ProfSpanSetRef;
 
@@ -25,6 +32,8 @@ pub struct ProfileWrapper<T> {
     prof_span_id: u32,
     prof_span_set: ProfSpanSetRef,
     prof_span_builder: ProfSpanBuilder,
+    // Created at the first processing, so that its parent is the memory stat of the query.
+    mem_stat: Option<Arc<MemStat>>,
 }
 
 impl<T> ProfileWrapper<T>
@@ -40,8 +49,16 @@ where T: Processor + 'static
             prof_span_id,
             prof_span_set,
             prof_span_builder: ProfSpanBuilder::default(),
+            mem_stat: None,
         })
     }
+
+    fn mem_stat(&mut self) -> Arc<MemStat> {
+        let prof_span_id = self.prof_span_id;
+        self.mem_stat
+            .get_or_insert_with(|| MemStat::create(format!("ProfileWrapper-{}", prof_span_id)))
+            .clone()
+    }
 }
 
 #[async_trait::async_trait]
@@ -57,8 +74,14 @@ where T: Processor + 'static
     }
 
     fn event(&mut self) -> Result<Event> {
+        self.prof_span_builder.start();
         match self.inner.event()? {
             Event::Finished => {
+                if let Some(mem_stat) = &self.mem_stat {
+                    let peak_memory_usage = mem_stat.get_peak_memory_usage().max(0) as u64;
+                    self.prof_span_builder
+                        .set_peak_memory_usage(peak_memory_usage);
+                }
                 self.prof_span_set
                     .lock()
                     .unwrap()
@@ -70,6 +93,7 @@ where T: Processor + 'static
     }
 
     fn process(&mut self) -> Result<()> {
+        let _guard = ThreadTracker::enter(Some(self.mem_stat()));
         let instant = Instant::now();
         self.inner.process()?;
         let elapsed = instant.elapsed();
@@ -80,7 +104,54 @@ where T: Processor + 'static
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        // TODO: record profile information for async process
-        self.inner.async_process().await
+        let mem_stat = self.mem_stat();
+        let instant = Instant::now();
+        TrackedFuture::create_with_mem_stat(Some(mem_stat), self.inner.async_process()).await?;
+        let elapsed = instant.elapsed();
+        self.prof_span_builder
+            .accumulate_wait_time(elapsed.as_nanos() as u64);
+        Ok(())
+    }
+}
+
+/// Counts the rows and bytes produced by a plan, it's appended to the pipeline
+/// of every plan when profiling is enabled.
+pub struct TransformProfileOutput {
+    prof_span_id: u32,
+    prof_span_set: ProfSpanSetRef,
+    output_rows: u64,
+    output_bytes: u64,
+}
+
+impl TransformProfileOutput {
+    pub fn create(prof_span_id: u32, prof_span_set: ProfSpanSetRef) -> Self {
+        TransformProfileOutput {
+            prof_span_id,
+            prof_span_set,
+            output_rows: 0,
+            output_bytes: 0,
+        }
+    }
+}
+
+impl Transform for TransformProfileOutput {
+    const NAME: &'static str = "TransformProfileOutput";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        self.output_rows += data.num_rows() as u64;
+        self.output_bytes += data.memory_size() as u64;
+        Ok(data)
+    }
+
+    fn on_finish(&mut self) -> Result<()> {
+        self.prof_span_set
+            .lock()
+            .unwrap()
+            .update(self.prof_span_id, ProfSpan {
+                output_rows: self.output_rows,
+                output_bytes: self.output_bytes,
+                ..Default::default()
+            });
+        Ok(())
     }
 }
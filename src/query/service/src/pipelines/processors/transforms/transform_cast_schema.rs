@@ -83,7 +83,9 @@ where Self: Transform
 impl Transform for TransformCastSchema {
     const NAME: &'static str = "CastSchemaTransform";
 
-    fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+    fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        // Keep the meta, e.g. the file of the rows copied.
+        let meta = data_block.take_meta();
         let mut columns = Vec::with_capacity(self.exprs.len());
         let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
         for (field, expr) in self.insert_schema.fields().iter().zip(self.exprs.iter()) {
@@ -94,6 +96,10 @@ impl Transform for TransformCastSchema {
             };
             columns.push(column);
         }
-        Ok(DataBlock::new(columns, data_block.num_rows()))
+        Ok(DataBlock::new_with_meta(
+            columns,
+            data_block.num_rows(),
+            meta,
+        ))
     }
 }
@@ -0,0 +1,305 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::tokio::sync::Notify;
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::DataBlock;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sources::AsyncSource;
+use common_profile::PlanProfSpan;
+use common_profile::ProfSpan;
+use opendal::Operator;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use tracing::info;
+use tracing::warn;
+
+#[derive(Clone)]
+enum MaterializedCteBlock {
+    InMemory(DataBlock),
+    /// The columns of the block are written to `location` one after another.
+    Spilled {
+        location: String,
+        columns_layout: Vec<usize>,
+    },
+}
+
+/// The result of a materialized CTE, shared by the sinks producing it and
+/// the sources reading it.
+///
+/// Like `DuplicateProcessor`, every consumer receives all the blocks of the CTE,
+/// but the consumers can be in different pipelines and read the blocks at their
+/// own pace, so the blocks are kept until the end of the query.
+pub struct MaterializedCteState {
+    blocks: RwLock<Vec<MaterializedCteBlock>>,
+    memory_usage: AtomicUsize,
+    spill_operator: Mutex<Option<Operator>>,
+    sinker_count: Mutex<usize>,
+    finished: Mutex<bool>,
+    finished_notify: Notify,
+}
+
+impl MaterializedCteState {
+    pub fn create() -> Arc<MaterializedCteState> {
+        Arc::new(MaterializedCteState {
+            blocks: RwLock::new(vec![]),
+            memory_usage: AtomicUsize::new(0),
+            spill_operator: Mutex::new(None),
+            sinker_count: Mutex::new(0),
+            finished: Mutex::new(false),
+            finished_notify: Notify::new(),
+        })
+    }
+
+    fn attach(&self) {
+        *self.sinker_count.lock() += 1;
+    }
+
+    fn detach(&self) {
+        let mut sinker_count = self.sinker_count.lock();
+        *sinker_count -= 1;
+        if *sinker_count == 0 {
+            *self.finished.lock() = true;
+            self.finished_notify.notify_waiters();
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn wait_finish(&self) {
+        // Register the waiter before checking the flag, so that the notification
+        // sent between the check and the wait is not lost.
+        let notified = self.finished_notify.notified();
+        if !*self.finished.lock() {
+            notified.await;
+        }
+    }
+
+    fn push_block(&self, block: MaterializedCteBlock) {
+        if let MaterializedCteBlock::InMemory(data_block) = &block {
+            self.memory_usage
+                .fetch_add(data_block.memory_size(), Ordering::Relaxed);
+        }
+        self.blocks.write().push(block);
+    }
+
+    fn get_block(&self, index: usize) -> Option<MaterializedCteBlock> {
+        self.blocks.read().get(index).cloned()
+    }
+}
+
+impl Drop for MaterializedCteState {
+    fn drop(&mut self) {
+        let operator = match self.spill_operator.lock().take() {
+            Some(operator) => operator,
+            None => return,
+        };
+        let locations = self
+            .blocks
+            .read()
+            .iter()
+            .filter_map(|block| match block {
+                MaterializedCteBlock::Spilled { location, .. } => Some(location.clone()),
+                MaterializedCteBlock::InMemory(_) => None,
+            })
+            .collect::<Vec<_>>();
+        GlobalIORuntime::instance().spawn(async move {
+            for location in locations {
+                if let Err(cause) = operator.delete(&location).await {
+                    warn!(
+                        "Cannot delete materialized cte spill file {}, cause: {:?}",
+                        location, cause
+                    );
+                }
+            }
+        });
+    }
+}
+
+/// Collects the blocks of a materialized CTE, the blocks are written to the storage
+/// once the in-memory blocks exceed `spilling_bytes_threshold`.
+pub struct MaterializedCteSink {
+    state: Arc<MaterializedCteState>,
+    operator: Operator,
+    location_prefix: String,
+    /// 0 means the blocks are never spilled.
+    spilling_bytes_threshold: usize,
+    prof_span: Option<PlanProfSpan>,
+}
+
+impl MaterializedCteSink {
+    pub fn create(
+        state: Arc<MaterializedCteState>,
+        operator: Operator,
+        location_prefix: String,
+        spilling_bytes_threshold: usize,
+        prof_span: Option<PlanProfSpan>,
+    ) -> Self {
+        state.attach();
+        MaterializedCteSink {
+            state,
+            operator,
+            location_prefix,
+            spilling_bytes_threshold,
+            prof_span,
+        }
+    }
+
+    fn need_spill(&self, data_block: &DataBlock) -> bool {
+        self.spilling_bytes_threshold != 0
+            && data_block.num_columns() != 0
+            && self.state.memory_usage.load(Ordering::Relaxed) + data_block.memory_size()
+                > self.spilling_bytes_threshold
+    }
+
+    #[async_backtrace::framed]
+    async fn spill(&self, data_block: DataBlock) -> Result<MaterializedCteBlock> {
+        let instant = Instant::now();
+        let num_rows = data_block.num_rows();
+        let mut columns_layout = Vec::with_capacity(data_block.num_columns());
+        let mut write_data = Vec::with_capacity(data_block.memory_size());
+        for entry in data_block.columns() {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let column_data = serialize_column(&column);
+            columns_layout.push(column_data.len());
+            write_data.extend(column_data);
+        }
+
+        let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+        let spill_bytes = write_data.len() as u64;
+        self.operator.write(&location, write_data).await?;
+        if let Some(prof_span) = &self.prof_span {
+            prof_span.update(ProfSpan {
+                spill_count: 1,
+                spill_bytes,
+                ..Default::default()
+            });
+        }
+        self.state
+            .spill_operator
+            .lock()
+            .get_or_insert_with(|| self.operator.clone());
+
+        info!(
+            "Write materialized cte spill {} successfully, elapsed: {:?}",
+            location,
+            instant.elapsed()
+        );
+        Ok(MaterializedCteBlock::Spilled {
+            location,
+            columns_layout,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for MaterializedCteSink {
+    const NAME: &'static str = "MaterializedCteSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        self.state.detach();
+        Ok(())
+    }
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        let block = if self.need_spill(&data_block) {
+            self.spill(data_block).await?
+        } else {
+            MaterializedCteBlock::InMemory(data_block)
+        };
+        self.state.push_block(block);
+        Ok(false)
+    }
+}
+
+/// Reads the blocks of a materialized CTE after all of them are produced.
+///
+/// The sources created for a `CteScan` share `cursor`, so that each block is read
+/// once by the scan even if it runs in several threads.
+pub struct MaterializedCteSource {
+    state: Arc<MaterializedCteState>,
+    cursor: Arc<AtomicUsize>,
+    operator: Operator,
+    offsets: Vec<usize>,
+}
+
+impl MaterializedCteSource {
+    pub fn create(
+        state: Arc<MaterializedCteState>,
+        cursor: Arc<AtomicUsize>,
+        operator: Operator,
+        offsets: Vec<usize>,
+    ) -> Self {
+        MaterializedCteSource {
+            state,
+            cursor,
+            operator,
+            offsets,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn read_spilled(&self, location: &str, columns_layout: &[usize]) -> Result<DataBlock> {
+        let data = self.operator.read(location).await?;
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(columns_layout.len());
+        for column_layout in columns_layout.iter() {
+            columns.push(deserialize_column(&data[begin..begin + column_layout]).unwrap());
+            begin += column_layout;
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for MaterializedCteSource {
+    const NAME: &'static str = "MaterializedCteSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        self.state.wait_finish().await;
+
+        let index = self.cursor.fetch_add(1, Ordering::Relaxed);
+        let data_block = match self.state.get_block(index) {
+            None => return Ok(None),
+            Some(MaterializedCteBlock::InMemory(data_block)) => data_block,
+            Some(MaterializedCteBlock::Spilled {
+                location,
+                columns_layout,
+            }) => self.read_spilled(&location, &columns_layout).await?,
+        };
+
+        let columns = self
+            .offsets
+            .iter()
+            .map(|offset| data_block.get_by_offset(*offset).clone())
+            .collect();
+        Ok(Some(DataBlock::new(columns, data_block.num_rows())))
+    }
+}
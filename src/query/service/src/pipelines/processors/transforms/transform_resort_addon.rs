@@ -16,15 +16,29 @@ use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::BlockEntry;
+use common_expression::ComputedExpr;
 use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
+use common_expression::Evaluator;
 use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
 use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
+use common_sql::parse_computed_expr;
 use common_sql::parse_exprs;
+use common_sql::sequence_of_default_expr;
 use common_storages_factory::Table;
+use common_users::UserApiProvider;
 
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
@@ -33,9 +47,21 @@ use crate::pipelines::processors::transforms::transform::Transform;
 use crate::pipelines::processors::transforms::transform::Transformer;
 use crate::sessions::QueryContext;
 
+/// A column whose default is `nextval('<sequence>')`.
+struct SequenceColumn {
+    index: usize,
+    sequence: String,
+    data_type: DataType,
+}
+
 pub struct TransformResortAddOn {
     expression_transform: CompoundBlockOperator,
     input_len: usize,
+    tenant: String,
+    func_ctx: FunctionContext,
+    sequence_columns: Vec<SequenceColumn>,
+    /// The stored computed columns and their expressions over the table columns.
+    computed_columns: Vec<(usize, Expr)>,
 }
 
 impl TransformResortAddOn
@@ -48,17 +74,51 @@ where Self: Transform
         input_schema: DataSchemaRef,
         table: Arc<dyn Table>,
     ) -> Result<ProcessorPtr> {
-        let fields = table
-            .schema()
+        let table_schema = table.schema();
+        let fields = table_schema
             .fields()
             .iter()
             .map(DataField::from)
             .collect::<Vec<_>>();
 
         let mut exprs = Vec::with_capacity(fields.len());
-        for f in fields.iter() {
+        let mut sequence_columns = vec![];
+        let mut computed_columns = vec![];
+        for (index, f) in fields.iter().enumerate() {
             let expr = if !input_schema.has_field(f.name()) {
-                if let Some(default_expr) = f.default_expr() {
+                if let Some(computed_expr) = table_schema.field(index).computed_expr() {
+                    // A placeholder, stored computed columns are evaluated after the defaults.
+                    if let ComputedExpr::Stored(expr) = computed_expr {
+                        let expr = parse_computed_expr(
+                            ctx.clone(),
+                            table_schema.clone(),
+                            expr,
+                            f.data_type(),
+                        )?;
+                        computed_columns.push((index, expr));
+                    }
+                    Expr::Constant {
+                        span: None,
+                        scalar: Scalar::default_value(f.data_type()),
+                        data_type: f.data_type().clone(),
+                    }
+                } else if let Some(sequence) = f
+                    .default_expr()
+                    .map(String::as_str)
+                    .and_then(sequence_of_default_expr)
+                {
+                    // A placeholder, replaced by the values of the sequence for each block.
+                    sequence_columns.push(SequenceColumn {
+                        index,
+                        sequence,
+                        data_type: f.data_type().clone(),
+                    });
+                    Expr::Constant {
+                        span: None,
+                        scalar: Scalar::default_value(f.data_type()),
+                        data_type: f.data_type().clone(),
+                    }
+                } else if let Some(default_expr) = f.default_expr() {
                     let mut expr = parse_exprs(ctx.clone(), table.clone(), default_expr)?;
                     let mut expr = expr.remove(0);
                     if expr.data_type() != f.data_type() {
@@ -93,27 +153,102 @@ where Self: Transform
 
         let func_ctx = ctx.get_function_context()?;
         let expression_transform = CompoundBlockOperator {
-            ctx: func_ctx,
+            ctx: func_ctx.clone(),
             operators: vec![BlockOperator::Map { exprs }],
         };
 
-        Ok(ProcessorPtr::create(Transformer::create(
-            input,
-            output,
-            Self {
-                expression_transform,
-                input_len: input_schema.num_fields(),
-            },
-        )))
+        let transform = Self {
+            expression_transform,
+            input_len: input_schema.num_fields(),
+            tenant: ctx.get_tenant(),
+            func_ctx,
+            sequence_columns,
+            computed_columns,
+        };
+        // Taking values from a sequence may need to reserve them from meta.
+        if transform.sequence_columns.is_empty() {
+            Ok(ProcessorPtr::create(Transformer::create(
+                input, output, transform,
+            )))
+        } else {
+            Ok(ProcessorPtr::create(AsyncTransformer::create(
+                input, output, transform,
+            )))
+        }
+    }
+
+    fn fill_default_values(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        block = self.expression_transform.transform(block)?;
+        let columns = block.columns()[self.input_len..].to_owned();
+        Ok(DataBlock::new(columns, block.num_rows()))
+    }
+
+    fn fill_computed_values(&self, block: DataBlock) -> Result<DataBlock> {
+        if self.computed_columns.is_empty() {
+            return Ok(block);
+        }
+        let mut columns = block.columns().to_vec();
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        for (index, expr) in &self.computed_columns {
+            columns[*index] = BlockEntry {
+                data_type: expr.data_type().clone(),
+                value: evaluator.run(expr)?,
+            };
+        }
+        Ok(DataBlock::new(columns, block.num_rows()))
+    }
+
+    fn sequence_values_entry(&self, values: Vec<u64>, data_type: &DataType) -> Result<BlockEntry> {
+        let block = DataBlock::new_from_columns(vec![UInt64Type::from_data(values)]);
+        let expr = Expr::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::ColumnRef {
+                span: None,
+                id: 0,
+                data_type: DataType::Number(NumberDataType::UInt64),
+                display_name: "nextval".to_string(),
+            }),
+            dest_type: data_type.clone(),
+        };
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&expr)?;
+        Ok(BlockEntry {
+            data_type: data_type.clone(),
+            value,
+        })
     }
 }
 
 impl Transform for TransformResortAddOn {
     const NAME: &'static str = "AddOnTransform";
 
-    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
-        block = self.expression_transform.transform(block)?;
-        let columns = block.columns()[self.input_len..].to_owned();
-        Ok(DataBlock::new(columns, block.num_rows()))
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let block = self.fill_default_values(block)?;
+        self.fill_computed_values(block)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformResortAddOn {
+    const NAME: &'static str = "AddOnTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let block = self.fill_default_values(block)?;
+        let num_rows = block.num_rows();
+        if num_rows == 0 {
+            return self.fill_computed_values(block);
+        }
+
+        let mut columns = block.columns().to_vec();
+        let user_api = UserApiProvider::instance();
+        for column in &self.sequence_columns {
+            let values = user_api
+                .next_sequence_values(&self.tenant, &column.sequence, num_rows as u64)
+                .await?;
+            columns[column.index] = self.sequence_values_entry(values, &column.data_type)?;
+        }
+        self.fill_computed_values(DataBlock::new(columns, num_rows))
     }
 }
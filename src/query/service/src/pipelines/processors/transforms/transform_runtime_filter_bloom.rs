@@ -0,0 +1,143 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::runtime_filter_info::RuntimeFilterBloom;
+use common_catalog::runtime_filter_info::RuntimeFilterTarget;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::ScalarRef;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
+use tracing::info;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::sessions::QueryContext;
+
+/// Drops the rows of a scan whose probe keys are not in the bloom filters of the hash joins
+/// the scan is the probe side of. The blocks are pruned by the other runtime filters before
+/// they are read, see `RuntimeFilterPruner`.
+pub struct TransformRuntimeFilterBloom {
+    ctx: Arc<QueryContext>,
+    func_ctx: FunctionContext,
+    /// The runtime filters and the probe keys over the columns of the scan.
+    targets: Vec<(RuntimeFilterTarget, Expr)>,
+    /// The probe keys and the bloom filters they are checked against, once published.
+    blooms: Option<Vec<(Expr, RuntimeFilterBloom)>>,
+}
+
+impl TransformRuntimeFilterBloom {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: TableSchemaRef,
+        targets: &[RuntimeFilterTarget],
+    ) -> Result<ProcessorPtr> {
+        let targets = targets
+            .iter()
+            .filter_map(|target| {
+                let expr = target.expr.as_expr(&BUILTIN_FUNCTIONS);
+                // The key columns are read by the scan for the join.
+                if expr
+                    .column_refs()
+                    .keys()
+                    .any(|name| schema.index_of(name).is_err())
+                {
+                    return None;
+                }
+                let expr = expr.project_column_ref(|name| schema.index_of(name).unwrap());
+                Some((target.clone(), expr))
+            })
+            .collect();
+
+        Ok(ProcessorPtr::create(AsyncTransformer::create(
+            input,
+            output,
+            TransformRuntimeFilterBloom {
+                func_ctx: ctx.get_function_context()?,
+                ctx,
+                targets,
+                blooms: None,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    async fn wait_blooms(&self) -> Vec<(Expr, RuntimeFilterBloom)> {
+        let mut blooms = Vec::with_capacity(self.targets.len());
+        for (target, expr) in &self.targets {
+            let info = match self.ctx.get_runtime_filter_channel(target.key).wait().await {
+                Some(info) => info,
+                None => continue,
+            };
+            // The hashes of the keys only match if they have the same type.
+            if info.data_type.remove_nullable() != expr.data_type().remove_nullable() {
+                continue;
+            }
+            if let Some(bloom) = &info.bloom {
+                blooms.push((expr.clone(), bloom.clone()));
+            }
+        }
+
+        info!(
+            "{} of {} runtime bloom filters are applied to the scan",
+            blooms.len(),
+            self.targets.len()
+        );
+        blooms
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformRuntimeFilterBloom {
+    const NAME: &'static str = "RuntimeFilterBloomTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        if self.blooms.is_none() {
+            self.blooms = Some(self.wait_blooms().await);
+        }
+        let blooms = self.blooms.as_ref().unwrap();
+        if blooms.is_empty() || block.is_empty() {
+            return Ok(block);
+        }
+
+        let num_rows = block.num_rows();
+        let mut bitmap = MutableBitmap::from_len_set(num_rows);
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        for (expr, bloom) in blooms {
+            let column = evaluator
+                .run(expr)?
+                .convert_to_full_column(expr.data_type(), num_rows);
+            for (row, key) in column.iter().enumerate() {
+                // The NULL keys can't match any build row.
+                if key == ScalarRef::Null || !bloom.contains(RuntimeFilterBloom::hash(key)) {
+                    bitmap.set(row, false);
+                }
+            }
+        }
+        DataBlock::filter_with_bitmap(block, &bitmap.into())
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
+use common_users::UserApiProvider;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::sessions::QueryContext;
+
+/// Replaces the calls of `nextval('<sequence>')` in `exprs` by references to the columns
+/// appended to the input from `first_column` on, returns the sequence of each appended column.
+pub fn rewrite_nextval(exprs: &mut [Expr], first_column: usize) -> Vec<String> {
+    let mut sequences = vec![];
+    for expr in exprs.iter_mut() {
+        rewrite_expr(expr, first_column, &mut sequences);
+    }
+    sequences
+}
+
+fn rewrite_expr(expr: &mut Expr, first_column: usize, sequences: &mut Vec<String>) {
+    match expr {
+        Expr::FunctionCall {
+            span,
+            id,
+            args,
+            return_type,
+            ..
+        } if id.name() == "nextval" => {
+            if let [
+                Expr::Constant {
+                    scalar: Scalar::String(sequence),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                let sequence = String::from_utf8_lossy(sequence).to_string();
+                let column = Expr::ColumnRef {
+                    span: *span,
+                    id: first_column + sequences.len(),
+                    data_type: return_type.clone(),
+                    display_name: format!("nextval('{sequence}')"),
+                };
+                sequences.push(sequence);
+                *expr = column;
+            }
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                rewrite_expr(arg, first_column, sequences);
+            }
+        }
+        Expr::Cast { expr, .. } => rewrite_expr(expr, first_column, sequences),
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => {}
+    }
+}
+
+/// Takes `num_rows` values from each of the sequences.
+pub async fn next_sequence_columns(
+    tenant: &str,
+    sequences: &[String],
+    num_rows: usize,
+) -> Result<Vec<BlockEntry>> {
+    let mut entries = Vec::with_capacity(sequences.len());
+    for sequence in sequences {
+        let values = match num_rows {
+            0 => vec![],
+            _ => {
+                UserApiProvider::instance()
+                    .next_sequence_values(tenant, sequence, num_rows as u64)
+                    .await?
+            }
+        };
+        entries.push(BlockEntry {
+            data_type: DataType::Number(NumberDataType::UInt64),
+            value: Value::Column(UInt64Type::from_data(values)),
+        });
+    }
+    Ok(entries)
+}
+
+/// Appends a column of values taken from each sequence, one value for each row.
+pub struct TransformSequenceNextval {
+    tenant: String,
+    sequences: Vec<String>,
+}
+
+impl TransformSequenceNextval {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        sequences: Vec<String>,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(AsyncTransformer::create(input, output, Self {
+            tenant: ctx.get_tenant(),
+            sequences,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformSequenceNextval {
+    const NAME: &'static str = "SequenceNextvalTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        let entries =
+            next_sequence_columns(&self.tenant, &self.sequences, block.num_rows()).await?;
+        for entry in entries {
+            block.add_column(entry);
+        }
+        Ok(block)
+    }
+}
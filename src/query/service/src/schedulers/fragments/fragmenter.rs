@@ -151,6 +151,8 @@ impl PhysicalPlanReplacer for Fragmenter {
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
             contain_runtime_filter: plan.contain_runtime_filter,
+            runtime_filter_keys: plan.runtime_filter_keys.clone(),
+            merge_runtime_filters: plan.merge_runtime_filters,
             stat_info: plan.stat_info.clone(),
         }))
     }
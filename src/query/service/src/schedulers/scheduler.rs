@@ -14,9 +14,7 @@
 
 use std::sync::Arc;
 
-use common_exception::ErrorCode;
 use common_exception::Result;
-use common_profile::ProfSpanSetRef;
 
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
@@ -41,12 +39,7 @@ pub async fn build_query_pipeline(
     let mut build_res = if !plan.is_distributed_plan() {
         build_local_pipeline(ctx, plan, enable_profiling).await
     } else {
-        if enable_profiling {
-            return Err(ErrorCode::Unimplemented(
-                "Query profiling is not supported in distributed mode",
-            ));
-        }
-        build_distributed_pipeline(ctx, plan).await
+        build_distributed_pipeline(ctx, plan, enable_profiling).await
     }?;
 
     let input_schema = plan.output_schema()?;
@@ -67,8 +60,7 @@ pub async fn build_local_pipeline(
     plan: &PhysicalPlan,
     enable_profiling: bool,
 ) -> Result<PipelineBuildResult> {
-    let pipeline =
-        PipelineBuilder::create(ctx.clone(), enable_profiling, ProfSpanSetRef::default());
+    let pipeline = PipelineBuilder::create(ctx.clone(), enable_profiling, ctx.get_prof_span_set());
     let mut build_res = pipeline.finalize(plan)?;
 
     let settings = ctx.get_settings();
@@ -81,11 +73,12 @@ pub async fn build_local_pipeline(
 pub async fn build_distributed_pipeline(
     ctx: &Arc<QueryContext>,
     plan: &PhysicalPlan,
+    enable_profiling: bool,
 ) -> Result<PipelineBuildResult> {
     let fragmenter = Fragmenter::try_create(ctx.clone())?;
 
     let root_fragment = fragmenter.build_fragment(plan)?;
-    let mut fragments_actions = QueryFragmentsActions::create(ctx.clone());
+    let mut fragments_actions = QueryFragmentsActions::create(ctx.clone(), enable_profiling);
     root_fragment.get_actions(ctx.clone(), &mut fragments_actions)?;
 
     let exchange_manager = ctx.get_exchange_manager();
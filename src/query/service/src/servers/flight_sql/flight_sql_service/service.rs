@@ -54,6 +54,8 @@ use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
 use common_base::base::uuid::Uuid;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchema;
 use futures::Stream;
@@ -66,7 +68,9 @@ use tonic::Status;
 use tonic::Streaming;
 
 use super::status;
+use crate::interpreters::InterpreterQueryLog;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::sessions::SessionType;
 
 fn try_unpack_any<T: ProstMessageExt>(message: Any) -> std::result::Result<T, Status> {
     message
@@ -129,7 +133,19 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         let (user, password) = FlightSqlServiceImpl::get_user_password(request.metadata())
             .map_err(Status::invalid_argument)?;
-        let session = FlightSqlServiceImpl::auth_user_password(user, password, remote_addr).await?;
+        let session =
+            FlightSqlServiceImpl::auth_user_password(user.clone(), password, remote_addr).await;
+        InterpreterQueryLog::log_login(
+            GlobalConfig::instance().query.tenant_id.clone(),
+            SessionType::FlightSQL.to_string(),
+            user,
+            remote_addr,
+            session
+                .as_ref()
+                .err()
+                .map(|status| ErrorCode::AuthenticateFailure(status.message().to_string())),
+        );
+        let session = session?;
         let token = session.get_id();
         let result = HandshakeResponse {
             protocol_version: 0,
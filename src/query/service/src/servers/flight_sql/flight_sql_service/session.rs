@@ -98,10 +98,28 @@ impl FlightSqlServiceImpl {
             .map_err(|e| status!("Could not create session", e))?;
         let tenant = session.get_current_tenant();
 
-        let user = UserApiProvider::instance()
+        let user_api = UserApiProvider::instance();
+        let user = user_api
             .get_user_with_client_ip(&tenant, &user, &client_ip)
             .await
             .map_err(|e| status!("get_user_with_client_ip fail {}", e))?;
+        let global_policy = session
+            .get_settings()
+            .get_network_policy()
+            .map_err(|e| status!("get_network_policy fail", e))?;
+        user_api
+            .check_network_policy(
+                &tenant,
+                &user,
+                Some(&global_policy),
+                remote_addr.map(|addr| addr.ip()),
+            )
+            .await
+            .map_err(|e| Status::permission_denied(e.message()))?;
+        user_api
+            .check_login_lockout(&tenant, &user)
+            .await
+            .map_err(|e| Status::unauthenticated(e.message()))?;
         let password = password.as_bytes().to_vec();
         let password = (!password.is_empty()).then_some(password);
 
@@ -116,12 +134,20 @@ impl FlightSqlServiceImpl {
                     if *h == t.hash(&p) {
                         user
                     } else {
+                        user_api
+                            .record_login_failure(&tenant, &user)
+                            .await
+                            .map_err(|e| status!("record_login_failure fail", e))?;
                         return Err(Status::unauthenticated("wrong password"));
                     }
                 }
             },
             _ => return Err(Status::unauthenticated("wrong auth type")),
         };
+        user_api
+            .record_login_success(&tenant, &user)
+            .await
+            .map_err(|e| Status::unauthenticated(e.message()))?;
         session
             .set_authed_user(user, None)
             .await
@@ -38,6 +38,7 @@ use tracing::info;
 use super::v1::HttpQueryContext;
 use crate::auth::AuthMgr;
 use crate::auth::Credential;
+use crate::interpreters::InterpreterQueryLog;
 use crate::servers::HttpHandlerKind;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
@@ -166,10 +167,33 @@ impl<E> HTTPSessionEndpoint<E> {
             let tenant_id = tenant_id.to_str().unwrap().to_string();
             session.set_current_tenant(tenant_id);
         }
+        if let Addr::SocketAddr(addr) = &req.remote_addr().0 {
+            session.set_client_host(Some(*addr));
+        }
 
-        self.auth_manager
+        let res = self
+            .auth_manager
             .auth(ctx.get_current_session(), &credential)
-            .await?;
+            .await;
+        let handler_type = match self.kind {
+            HttpHandlerKind::Query => SessionType::HTTPQuery,
+            HttpHandlerKind::Clickhouse => SessionType::ClickHouseHttpHandler,
+        };
+        let user_name = match &credential {
+            Credential::Password { name, .. } => name.clone(),
+            Credential::Jwt { .. } => session
+                .get_current_user()
+                .map(|user| user.name)
+                .unwrap_or_default(),
+        };
+        InterpreterQueryLog::log_login(
+            session.get_current_tenant(),
+            handler_type.to_string(),
+            user_name,
+            session.get_client_host(),
+            res.as_ref().err().cloned(),
+        );
+        res?;
 
         Ok(HttpQueryContext::new(session))
     }
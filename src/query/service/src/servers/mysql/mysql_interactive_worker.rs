@@ -112,8 +112,20 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         let client_addr = self.client_addr.clone();
         let info = CertifiedInfo::create(&username, auth_data, &client_addr);
 
-        let authenticate = self.base.authenticate(salt, info);
-        match authenticate.await {
+        let authenticate = self.base.authenticate(salt, info).await;
+        let session = &self.base.session;
+        InterpreterQueryLog::log_login(
+            session.get_current_tenant(),
+            session.get_type().to_string(),
+            username.to_string(),
+            session.get_client_host(),
+            match &authenticate {
+                Ok(true) => None,
+                Ok(false) => Some(ErrorCode::AuthenticateFailure("wrong password")),
+                Err(failure) => Some(failure.clone()),
+            },
+        );
+        match authenticate {
             Ok(res) => res,
             Err(failure) => {
                 error!(
@@ -256,13 +268,28 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
         let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
 
         let ctx = self.session.create_query_context().await?;
-        let user_info = UserApiProvider::instance()
-            .get_user_with_client_ip(&ctx.get_tenant(), user_name, client_ip)
+        let tenant = ctx.get_tenant();
+        let user_api = UserApiProvider::instance();
+        let user_info = user_api
+            .get_user_with_client_ip(&tenant, user_name, client_ip)
+            .await?;
+        let global_policy = self.session.get_settings().get_network_policy()?;
+        user_api
+            .check_network_policy(
+                &tenant,
+                &user_info,
+                Some(&global_policy),
+                self.session.get_client_host().map(|addr| addr.ip()),
+            )
             .await?;
+        user_api.check_login_lockout(&tenant, &user_info).await?;
 
         let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
         if authed {
+            user_api.record_login_success(&tenant, &user_info).await?;
             self.session.set_authed_user(user_info, None).await?;
+        } else {
+            user_api.record_login_failure(&tenant, &user_info).await?;
         }
         Ok(authed)
     }
@@ -0,0 +1,42 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeSet;
+
+/// The objects accessed by a query, extracted from the bound plan.
+/// Used by the query log to record which objects the query touched.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AccessedObjects {
+    pub databases: BTreeSet<String>,
+    pub tables: BTreeSet<String>,
+    pub columns: BTreeSet<String>,
+}
+
+impl AccessedObjects {
+    pub fn add_table(&mut self, catalog: &str, database: &str, table: &str) {
+        self.databases.insert(format!("{}.{}", catalog, database));
+        self.tables
+            .insert(format!("{}.{}.{}", catalog, database, table));
+    }
+
+    pub fn add_column(&mut self, catalog: &str, database: &str, table: &str, column: &str) {
+        self.columns
+            .insert(format!("{}.{}.{}.{}", catalog, database, table, column));
+    }
+
+    pub fn merge(&mut self, other: AccessedObjects) {
+        self.databases.extend(other.databases);
+        self.tables.extend(other.tables);
+        self.columns.extend(other.columns);
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod accessed_objects;
 mod query_affect;
 pub mod query_ctx;
 mod query_ctx_shared;
@@ -23,6 +24,7 @@ mod session_mgr_status;
 mod session_status;
 mod session_type;
 
+pub use accessed_objects::AccessedObjects;
 pub use common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
 pub use query_ctx::QueryContext;
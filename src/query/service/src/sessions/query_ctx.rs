@@ -35,6 +35,8 @@ use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
+use common_catalog::runtime_filter_info::RuntimeFilterChannel;
+use common_catalog::runtime_filter_info::RuntimeFilterKey;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::StageAttachment;
 use common_config::GlobalConfig;
@@ -52,7 +54,10 @@ use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_pipeline_core::InputError;
+use common_profile::PlanProfSpan;
+use common_profile::ProfSpanSetRef;
 use common_settings::ChangeValue;
 use common_settings::Settings;
 use common_storage::DataOperator;
@@ -61,6 +66,7 @@ use common_storage::StorageMetrics;
 use common_storages_fuse::TableContext;
 use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultScan;
+use common_storages_result_cache::SubresultCache;
 use common_storages_stage::StageTable;
 use common_users::UserApiProvider;
 use dashmap::mapref::multiple::RefMulti;
@@ -72,7 +78,9 @@ use crate::api::DataExchangeManager;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::MaterializedCteState;
 use crate::sessions::query_affect::QueryAffect;
+use crate::sessions::AccessedObjects;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
@@ -92,6 +100,7 @@ pub struct QueryContext {
     partition_queue: Arc<RwLock<VecDeque<PartInfoPtr>>>,
     shared: Arc<QueryContextShared>,
     fragment_id: Arc<AtomicUsize>,
+    scan_prof_span: Option<PlanProfSpan>,
 }
 
 impl QueryContext {
@@ -109,6 +118,24 @@ impl QueryContext {
             clickhouse_version: CLICKHOUSE_VERSION.to_string(),
             shared,
             fragment_id: Arc::new(AtomicUsize::new(0)),
+            scan_prof_span: None,
+        })
+    }
+
+    /// Create the context to build a profiled table scan, the storages record
+    /// the bytes they read into the span of the scan.
+    pub fn create_for_scan(
+        other: Arc<QueryContext>,
+        scan_prof_span: PlanProfSpan,
+    ) -> Arc<QueryContext> {
+        Arc::new(QueryContext {
+            partition_queue: Arc::new(RwLock::new(VecDeque::new())),
+            version: other.version.clone(),
+            mysql_version: other.mysql_version.clone(),
+            clickhouse_version: other.clickhouse_version.clone(),
+            shared: other.shared.clone(),
+            fragment_id: other.fragment_id.clone(),
+            scan_prof_span: Some(scan_prof_span),
         })
     }
 
@@ -216,6 +243,30 @@ impl QueryContext {
     pub fn get_created_time(&self) -> SystemTime {
         self.shared.created_time
     }
+
+    pub fn set_accessed_objects(&self, objects: AccessedObjects) {
+        self.shared.set_accessed_objects(objects)
+    }
+
+    pub fn set_subresult_cache(&self, plan_id: u32, cache: SubresultCache) {
+        self.shared.set_subresult_cache(plan_id, cache)
+    }
+
+    pub fn get_subresult_cache(&self, plan_id: u32) -> Option<SubresultCache> {
+        self.shared.get_subresult_cache(plan_id)
+    }
+
+    pub fn get_prof_span_set(&self) -> ProfSpanSetRef {
+        self.shared.get_prof_span_set()
+    }
+
+    pub fn get_materialized_cte_state(&self, cte_idx: usize) -> Arc<MaterializedCteState> {
+        self.shared.get_materialized_cte_state(cte_idx)
+    }
+
+    pub fn get_accessed_objects(&self) -> AccessedObjects {
+        self.shared.get_accessed_objects()
+    }
 }
 
 #[async_trait::async_trait]
@@ -269,6 +320,10 @@ impl TableContext for QueryContext {
         self.shared.result_progress.as_ref().get_values()
     }
 
+    fn get_scan_prof_span(&self) -> Option<PlanProfSpan> {
+        self.scan_prof_span.clone()
+    }
+
     fn get_status_info(&self) -> String {
         let status = self.shared.status.read();
         status.clone()
@@ -404,11 +459,18 @@ impl TableContext for QueryContext {
 
         Ok(FunctionContext {
             tz,
+            tenant: self.get_tenant(),
 
             openai_api_key: query_config.openai_api_key.clone(),
             openai_api_base_url: query_config.openai_api_base_url.clone(),
             openai_api_embedding_model: query_config.openai_api_embedding_model.clone(),
             openai_api_completion_model: query_config.openai_api_completion_model.clone(),
+            ai_provider: query_config.ai_provider.clone(),
+            ai_local_endpoint: query_config.ai_local_endpoint.clone(),
+            ai_request_batch_size: query_config.ai_request_batch_size,
+            ai_request_max_retries: query_config.ai_request_max_retries,
+            ai_requests_per_second: query_config.ai_requests_per_second,
+            ai_cache_capacity: query_config.ai_cache_capacity,
         })
     }
 
@@ -510,6 +572,28 @@ impl TableContext for QueryContext {
         self.shared.consume_precommit_blocks()
     }
 
+    fn add_consumed_stream(&self, req: UpdateStreamMetaReq) {
+        self.shared.add_consumed_stream(req)
+    }
+
+    fn get_consumed_streams(&self) -> Vec<UpdateStreamMetaReq> {
+        self.shared.get_consumed_streams()
+    }
+
+    fn get_consume_streams(&self) -> bool {
+        self.shared.consume_streams.load(Ordering::Acquire)
+    }
+
+    fn set_consume_streams(&self, consume: bool) {
+        self.shared
+            .consume_streams
+            .store(consume, Ordering::Release);
+    }
+
+    fn get_runtime_filter_channel(&self, key: RuntimeFilterKey) -> Arc<RuntimeFilterChannel> {
+        self.shared.get_runtime_filter_channel(key)
+    }
+
     #[async_backtrace::framed]
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams> {
         match StageFileFormatType::from_str(name) {
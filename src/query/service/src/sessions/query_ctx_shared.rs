@@ -22,6 +22,8 @@ use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
+use common_catalog::runtime_filter_info::RuntimeFilterChannel;
+use common_catalog::runtime_filter_info::RuntimeFilterKey;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -29,11 +31,14 @@ use common_expression::DataBlock;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_pipeline_core::InputError;
+use common_profile::ProfSpanSetRef;
 use common_settings::ChangeValue;
 use common_settings::Settings;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
+use common_storages_result_cache::SubresultCache;
 use dashmap::DashMap;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
@@ -42,7 +47,9 @@ use uuid::Uuid;
 use crate::catalogs::CatalogManager;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::MaterializedCteState;
 use crate::sessions::query_affect::QueryAffect;
+use crate::sessions::AccessedObjects;
 use crate::sessions::Session;
 use crate::storages::Table;
 
@@ -80,6 +87,19 @@ pub struct QueryContextShared {
     pub(in crate::sessions) data_operator: DataOperator,
     pub(in crate::sessions) executor: Arc<RwLock<Weak<PipelineExecutor>>>,
     pub(in crate::sessions) precommit_blocks: Arc<RwLock<Vec<DataBlock>>>,
+    /// stream id -> the new meta of the stream consumed by the query.
+    pub(in crate::sessions) consumed_streams: Arc<RwLock<HashMap<u64, UpdateStreamMetaReq>>>,
+    pub(in crate::sessions) consume_streams: Arc<AtomicBool>,
+    /// Runtime filters published by the hash joins of the query.
+    pub(in crate::sessions) runtime_filters:
+        Arc<RwLock<HashMap<RuntimeFilterKey, Arc<RuntimeFilterChannel>>>>,
+    /// cte index -> the result of the materialized CTE, shared by its producer and consumers.
+    pub(in crate::sessions) materialized_ctes:
+        Arc<RwLock<HashMap<usize, Arc<MaterializedCteState>>>>,
+    /// plan id -> the cached result of the aggregation, see `enable_aggregate_result_cache`.
+    pub(in crate::sessions) subresult_caches: Arc<RwLock<HashMap<u32, SubresultCache>>>,
+    /// The runtime statistics of the plans executed in this node, for EXPLAIN ANALYZE.
+    pub(in crate::sessions) prof_span_set: ProfSpanSetRef,
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
     pub(in crate::sessions) created_time: SystemTime,
     // DashMap<file_path, HashMap<ErrorCode::code, (ErrorCode, Number of occurrences)>>
@@ -93,6 +113,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
+    /// The objects accessed by the query, recorded into the query log.
+    pub(in crate::sessions) accessed_objects: Arc<RwLock<AccessedObjects>>,
 }
 
 impl QueryContextShared {
@@ -119,6 +141,12 @@ impl QueryContextShared {
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             precommit_blocks: Arc::new(RwLock::new(vec![])),
+            consumed_streams: Arc::new(RwLock::new(HashMap::new())),
+            consume_streams: Arc::new(AtomicBool::new(false)),
+            runtime_filters: Arc::new(RwLock::new(HashMap::new())),
+            materialized_ctes: Arc::new(RwLock::new(HashMap::new())),
+            subresult_caches: Arc::new(RwLock::new(HashMap::new())),
+            prof_span_set: ProfSpanSetRef::default(),
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
             on_error_map: Arc::new(RwLock::new(None)),
@@ -126,6 +154,7 @@ impl QueryContextShared {
             partitions_shas: Arc::new(RwLock::new(vec![])),
             cacheable: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
+            accessed_objects: Arc::new(RwLock::new(AccessedObjects::default())),
         }))
     }
 
@@ -314,6 +343,14 @@ impl QueryContextShared {
             .unwrap_or_else(|| "Unknown".to_string())
     }
 
+    pub fn set_accessed_objects(&self, objects: AccessedObjects) {
+        *self.accessed_objects.write() = objects;
+    }
+
+    pub fn get_accessed_objects(&self) -> AccessedObjects {
+        self.accessed_objects.read().clone()
+    }
+
     pub fn get_connection_id(&self) -> String {
         self.session.get_id()
     }
@@ -346,6 +383,45 @@ impl QueryContextShared {
         swapped_precommit_blocks
     }
 
+    pub fn add_consumed_stream(&self, req: UpdateStreamMetaReq) {
+        let mut streams = self.consumed_streams.write();
+        streams.insert(req.stream_id, req);
+    }
+
+    pub fn get_consumed_streams(&self) -> Vec<UpdateStreamMetaReq> {
+        self.consumed_streams.read().values().cloned().collect()
+    }
+
+    pub fn get_runtime_filter_channel(&self, key: RuntimeFilterKey) -> Arc<RuntimeFilterChannel> {
+        if let Some(channel) = self.runtime_filters.read().get(&key) {
+            return channel.clone();
+        }
+        self.runtime_filters.write().entry(key).or_default().clone()
+    }
+
+    pub fn get_prof_span_set(&self) -> ProfSpanSetRef {
+        self.prof_span_set.clone()
+    }
+
+    pub fn get_materialized_cte_state(&self, cte_idx: usize) -> Arc<MaterializedCteState> {
+        if let Some(state) = self.materialized_ctes.read().get(&cte_idx) {
+            return state.clone();
+        }
+        self.materialized_ctes
+            .write()
+            .entry(cte_idx)
+            .or_insert_with(MaterializedCteState::create)
+            .clone()
+    }
+
+    pub fn set_subresult_cache(&self, plan_id: u32, cache: SubresultCache) {
+        self.subresult_caches.write().insert(plan_id, cache);
+    }
+
+    pub fn get_subresult_cache(&self, plan_id: u32) -> Option<SubresultCache> {
+        self.subresult_caches.read().get(&plan_id).cloned()
+    }
+
     pub fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.stage_attachment.read().clone()
     }
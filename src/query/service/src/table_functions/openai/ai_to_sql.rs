@@ -25,7 +25,6 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_function::TableFunction;
-use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::StringType;
@@ -34,10 +33,12 @@ use common_expression::FromData;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
+use common_functions::scalars::llm_config;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
-use common_openai::OpenAI;
+use common_openai::CompletionMode;
+use common_openai::LLMClient;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
@@ -216,18 +217,9 @@ impl AsyncSource for GPT2SQLSource {
         info!("openai request prompt: {}", prompt);
 
         // Response.
-        let api_base = GlobalConfig::instance().query.openai_api_base_url.clone();
-        let api_key = GlobalConfig::instance().query.openai_api_key.clone();
-        let api_embedding_model = GlobalConfig::instance()
-            .query
-            .openai_api_embedding_model
-            .clone();
-        let api_completion_model = GlobalConfig::instance()
-            .query
-            .openai_api_completion_model
-            .clone();
-        let openai = OpenAI::create(api_base, api_key, api_embedding_model, api_completion_model);
-        let (sql, _) = openai.completion_sql_request(prompt)?;
+        let func_ctx = self.ctx.get_function_context()?;
+        let client = LLMClient::create(&llm_config(&func_ctx))?;
+        let sql = client.completion(CompletionMode::Sql, prompt)?;
 
         let sql = format!("SELECT {}", sql);
         info!("openai response sql: {}", sql);
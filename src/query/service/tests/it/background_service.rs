@@ -0,0 +1,69 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::BlockThresholds;
+use databend_query::background_service::compact_reason;
+use databend_query::background_service::recluster_reason;
+use databend_query::background_service::MaintenanceJobType;
+use storages_common_table_meta::meta::Statistics;
+
+#[test]
+fn test_compact_reason() {
+    let thresholds = BlockThresholds::new(1000, 800, 100 * 1024 * 1024);
+    let summary = |row_count, block_count, perfect_block_count| Statistics {
+        row_count,
+        block_count,
+        perfect_block_count,
+        uncompressed_byte_size: row_count * 8,
+        ..Default::default()
+    };
+
+    // the blocks are full.
+    assert!(compact_reason(&summary(10000, 10, 10), 1, thresholds, 1000).is_none());
+    // a few small blocks are tolerated.
+    assert!(compact_reason(&summary(10100, 12, 10), 1, thresholds, 1000).is_none());
+    // only one block is not full.
+    assert!(compact_reason(&summary(10, 1, 0), 1, thresholds, 1000).is_none());
+    // many small blocks.
+    assert!(compact_reason(&summary(10, 10, 0), 1, thresholds, 1000).is_some());
+    assert!(compact_reason(&summary(2500, 30, 2), 1, thresholds, 1000).is_some());
+    // many small segments.
+    assert!(compact_reason(&summary(10000, 10, 10), 10, thresholds, 1000).is_some());
+    assert!(compact_reason(&summary(10000, 10, 10), 2, thresholds, 5).is_none());
+}
+
+#[test]
+fn test_recluster_reason() {
+    assert!(recluster_reason(10, 1.0, 0.01).is_none());
+    assert!(recluster_reason(10, 1.5, 0.01).is_some());
+    assert!(recluster_reason(1000, 9.0, 0.01).is_none());
+    assert!(recluster_reason(1000, 12.0, 0.01).is_some());
+}
+
+#[test]
+fn test_maintenance_job_sql() {
+    assert_eq!(
+        MaintenanceJobType::Compact.sql("db", "t"),
+        "OPTIMIZE TABLE `db`.`t` COMPACT"
+    );
+    assert_eq!(
+        MaintenanceJobType::Recluster.sql("db", "t"),
+        "ALTER TABLE `db`.`t` RECLUSTER"
+    );
+    assert_eq!(
+        MaintenanceJobType::Purge.sql("db", "t"),
+        "OPTIMIZE TABLE `db`.`t` PURGE"
+    );
+    assert_eq!(MaintenanceJobType::Recluster.to_string(), "recluster");
+}
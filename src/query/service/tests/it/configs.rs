@@ -18,6 +18,7 @@ use std::fs;
 use std::io::Write;
 
 use common_config::CacheConfig;
+use common_config::CacheEvictionPolicyInnerConfig;
 use common_config::CacheStorageTypeConfig;
 use common_config::CatalogConfig;
 use common_config::CatalogHiveConfig;
@@ -705,6 +706,11 @@ rpc_tls_query_service_domain_name = "localhost"
 table_engine_memory_enabled = true
 wait_timeout_mills = 5000
 max_query_log_size = 10000
+audit_log_table = ""
+audit_log_flush_rows = 1000
+audit_log_flush_interval_secs = 10
+enable_background_service = false
+background_service_interval_secs = 60
 management_mode = false
 jwt_key_file = ""
 users = []
@@ -984,3 +990,38 @@ fn test_env_cache_config_and_defaults() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_env_cache_eviction_policy_overrides() -> Result<()> {
+    temp_env::with_vars(
+        vec![(
+            "CACHE_EVICTION_POLICY_OVERRIDES",
+            Some("segment_info_cache=s3fifo, table_data_cache=s3fifo"),
+        )],
+        || {
+            let configured = InnerConfig::load_for_test().expect("must success");
+            assert_eq!(
+                configured.cache.eviction_policy_of("table_data_cache"),
+                CacheEvictionPolicyInnerConfig::S3Fifo
+            );
+            assert_eq!(
+                configured.cache.eviction_policy_of("table_snapshot_cache"),
+                CacheEvictionPolicyInnerConfig::Lru
+            );
+        },
+    );
+
+    // the names not in `system.caches` are rejected
+    temp_env::with_vars(
+        vec![(
+            "CACHE_EVICTION_POLICY_OVERRIDES",
+            Some("segment_cache=s3fifo"),
+        )],
+        || {
+            let r = InnerConfig::load_for_test();
+            assert!(r.is_err(), "expecting `Err`, but got `Ok`");
+            assert_eq!(r.unwrap_err().code(), ErrorCode::INVALID_CONFIG)
+        },
+    );
+    Ok(())
+}
@@ -19,6 +19,7 @@ extern crate core;
 
 mod api;
 mod auth;
+mod background_service;
 mod catalogs;
 mod clusters;
 mod configs;
@@ -92,6 +92,7 @@ fn test_format() {
         tab1,
         None,
         None,
+        None,
     );
     let col2 = metadata.add_base_table_column(
         "col2".to_string(),
@@ -99,6 +100,7 @@ fn test_format() {
         tab1,
         None,
         None,
+        None,
     );
 
     let s_expr = SExpr::create_binary(
@@ -173,6 +175,7 @@ fn test_format() {
                     limit: None,
                     order_by: None,
                     prewhere: None,
+                    vector_top_k: None,
                     statistics: Statistics {
                         statistics: None,
                         col_stats: Default::default(),
@@ -189,6 +192,7 @@ fn test_format() {
                 limit: None,
                 order_by: None,
                 prewhere: None,
+                vector_top_k: None,
                 statistics: Statistics {
                     statistics: None,
                     col_stats: Default::default(),
@@ -16,6 +16,7 @@ use common_arrow::parquet::metadata::FileMetaData;
 use common_arrow::parquet::metadata::ThriftFileMetaData;
 use common_base::base::tokio;
 use common_cache::Cache;
+use common_cache::EvictionPolicy;
 use common_expression::types::Int32Type;
 use common_expression::types::NumberDataType;
 use common_expression::DataBlock;
@@ -67,7 +68,10 @@ async fn test_index_meta_cache_size_file_meta_data() -> common_exception::Result
         scenario, pid, base_memory_usage
     );
 
-    let cache = InMemoryCacheBuilder::new_item_cache::<FileMetaData>(cache_number as u64);
+    let cache = InMemoryCacheBuilder::new_item_cache::<FileMetaData>(
+        cache_number as u64,
+        EvictionPolicy::Lru,
+    );
 
     populate_cache(&cache, meta, cache_number);
     show_memory_usage(scenario, base_memory_usage, cache_number);
@@ -97,7 +101,10 @@ async fn test_index_meta_cache_size_bloom_meta() -> common_exception::Result<()>
         scenario, pid, base_memory_usage
     );
 
-    let cache = InMemoryCacheBuilder::new_item_cache::<BloomIndexMeta>(cache_number as u64);
+    let cache = InMemoryCacheBuilder::new_item_cache::<BloomIndexMeta>(
+        cache_number as u64,
+        EvictionPolicy::Lru,
+    );
     populate_cache(&cache, bloom_index_meta, cache_number);
     show_memory_usage("BloomIndexMeta(Mini)", base_memory_usage, cache_number);
 
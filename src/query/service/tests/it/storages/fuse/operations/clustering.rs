@@ -13,6 +13,8 @@
 // limitations under the License.
 //
 
+use std::collections::BTreeMap;
+
 use common_ast::ast::Engine;
 use common_base::base::tokio;
 use common_sql::plans::AlterTableClusterKeyPlan;
@@ -54,8 +56,11 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         .into(),
         field_default_exprs: vec![],
         field_comments: vec![],
+        field_identities: vec![],
+        check_constraints: BTreeMap::new(),
         as_select: None,
         cluster_key: None,
+        clone_source: None,
     };
 
     // create test table
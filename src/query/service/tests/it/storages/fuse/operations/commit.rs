@@ -27,6 +27,8 @@ use common_catalog::database::Database;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
+use common_catalog::runtime_filter_info::RuntimeFilterChannel;
+use common_catalog::runtime_filter_info::RuntimeFilterKey;
 use common_catalog::table::Table;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
@@ -65,12 +67,14 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::MetaId;
 use common_pipeline_core::InputError;
+use common_profile::PlanProfSpan;
 use common_settings::ChangeValue;
 use common_settings::Settings;
 use common_storage::DataOperator;
@@ -407,6 +411,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_scan_prof_span(&self) -> Option<PlanProfSpan> {
+        None
+    }
+
     fn get_status_info(&self) -> String {
         "".to_string()
     }
@@ -570,6 +578,26 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn add_consumed_stream(&self, _req: UpdateStreamMetaReq) {
+        todo!()
+    }
+
+    fn get_consumed_streams(&self) -> Vec<UpdateStreamMetaReq> {
+        self.ctx.get_consumed_streams()
+    }
+
+    fn get_consume_streams(&self) -> bool {
+        todo!()
+    }
+
+    fn set_consume_streams(&self, _consume: bool) {
+        todo!()
+    }
+
+    fn get_runtime_filter_channel(&self, key: RuntimeFilterKey) -> Arc<RuntimeFilterChannel> {
+        self.ctx.get_runtime_filter_channel(key)
+    }
+
     async fn get_file_format(&self, _name: &str) -> Result<FileFormatParams> {
         todo!()
     }
@@ -52,6 +52,7 @@ fn expected_data_block(
             block_location: block_meta.block_location.clone(),
             segment_location: block_meta.segment_location.clone(),
             snapshot_location: block_meta.snapshot_location.clone().unwrap(),
+            is_deleted: block_meta.is_deleted,
         };
         for internal_column in internal_columns {
             let column = internal_column.generate_column_values(&internal_column_meta, num_rows);
@@ -64,6 +64,7 @@ fn test_to_partitions() -> Result<()> {
         leaf_indices: vec![field_index],
         leaf_column_ids: vec![field_index as ColumnId],
         children: None,
+        legacy_columns: vec![],
     };
 
     // generates fake data.
@@ -138,6 +139,8 @@ fn test_to_partitions() -> Result<()> {
         order_by: vec![],
         prewhere: None,
         virtual_columns: None,
+        runtime_filters: vec![],
+        vector_top_k: None,
     });
 
     let (stats, parts) =
@@ -181,6 +184,8 @@ async fn test_fuse_table_exact_statistic() -> Result<()> {
             limit: None,
             order_by: vec![],
             virtual_columns: None,
+            runtime_filters: vec![],
+            vector_top_k: None,
         };
         let (stats, parts) = table.read_partitions(ctx.clone(), Some(push_downs)).await?;
         assert_eq!(stats.read_rows, num_blocks * rows_per_block);
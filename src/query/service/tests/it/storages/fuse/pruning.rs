@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_ast::ast::Engine;
@@ -99,8 +100,11 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_default_exprs: vec![],
         field_comments: vec![],
+        field_identities: vec![],
+        check_constraints: BTreeMap::new(),
         as_select: None,
         cluster_key: None,
+        clone_source: None,
     };
 
     let interpreter = CreateTableInterpreter::try_create(ctx.clone(), create_table_plan)?;
@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::VecDeque;
 use std::str;
 use std::sync::Arc;
@@ -174,8 +175,11 @@ impl TestFixture {
             .into(),
             field_default_exprs: vec![],
             field_comments: vec![],
+            field_identities: vec![],
+            check_constraints: BTreeMap::new(),
             as_select: None,
             cluster_key: Some("(id)".to_string()),
+            clone_source: None,
         }
     }
 
@@ -198,8 +202,11 @@ impl TestFixture {
             .into(),
             field_default_exprs: vec![],
             field_comments: vec![],
+            field_identities: vec![],
+            check_constraints: BTreeMap::new(),
             as_select: None,
             cluster_key: None,
+            clone_source: None,
         }
     }
 
@@ -140,6 +140,16 @@ impl DefaultSettings {
                     desc: "Enables runtime filter optimization for JOIN.",
                     possible_values: None,
                 }),
+                ("enable_runtime_filter_pruning", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables skipping the blocks and the rows of the probe side of a JOIN by the keys of the build side.",
+                    possible_values: None,
+                }),
+                ("max_runtime_filter_inlist_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1024),
+                    desc: "Sets the maximum number of distinct keys of the build side of a JOIN to prune the probe side by the values, more keys are only pruned by the range and a bloom filter.",
+                    possible_values: None,
+                }),
                 ("max_execute_time", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
@@ -165,6 +175,41 @@ impl DefaultSettings {
                     desc: "Enables broadcast join.",
                     possible_values: None,
                 }),
+                ("enable_cost_based_join_distribution", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Chooses between a broadcast and a hash shuffle JOIN by the estimated cost, when broadcast joins are enabled by prefer_broadcast_join.",
+                    possible_values: None,
+                }),
+                ("cost_factor_compute_per_row", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the estimated cost of processing a row by a simple operator, such as a filter, in thousandths, in the cost model of the optimizer.",
+                    possible_values: None,
+                }),
+                ("cost_factor_hash_table_per_row", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10000),
+                    desc: "Sets the estimated cost of inserting a row into the hash table of a JOIN, in thousandths, in the cost model of the optimizer.",
+                    possible_values: None,
+                }),
+                ("cost_factor_aggregate_per_row", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(5000),
+                    desc: "Sets the estimated cost of aggregating a row, in thousandths, in the cost model of the optimizer.",
+                    possible_values: None,
+                }),
+                ("cost_factor_io_per_kb", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(2000),
+                    desc: "Sets the estimated cost of reading a kilobyte from the storage, in thousandths, in the cost model of the optimizer.",
+                    possible_values: None,
+                }),
+                ("cost_factor_network_per_kb", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(8000),
+                    desc: "Sets the estimated cost of sending a kilobyte to another node of the cluster, in thousandths, in the cost model of the optimizer.",
+                    possible_values: None,
+                }),
+                ("cost_factor_memory_pressure", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(4000),
+                    desc: "Sets the factor of the estimated cost of the hash tables larger than max_memory_usage, in thousandths, in the cost model of the optimizer.",
+                    possible_values: None,
+                }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
@@ -185,6 +230,11 @@ impl DefaultSettings {
                     desc: "Injects a custom 'sandbox_tenant' into this session. This is only for testing purposes and will take effect only when 'internal_enable_sandbox_tenant' is turned on.",
                     possible_values: None,
                 }),
+                ("network_policy", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_string()),
+                    desc: "Sets the network policy that restricts the client ip of the users without their own network policy.",
+                    possible_values: None,
+                }),
                 ("parquet_uncompressed_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2 * 1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading Parquet files.",
@@ -215,6 +265,11 @@ impl DefaultSettings {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend will return cached query results that are inconsistent with the underlying data.",
                     possible_values: None,
+                }),
+                ("enable_aggregate_result_cache", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables caching the results of aggregations, so that queries which only differ in the filters above an aggregation can reuse them. Takes effect only when enable_query_result_cache is enabled.",
+                    possible_values: None,
                 }),
                     #[cfg(feature = "hive")]
                 ("enable_hive_parquet_predict_pushdown", DefaultSettingValue {
@@ -233,6 +288,21 @@ impl DefaultSettings {
                     desc: "Sets the maximum amount of memory in bytes that an aggregator can use before spilling data to storage during query execution.",
                     possible_values: None,
                 }),
+                ("enable_materialized_cte", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables materializing common table expressions that are referenced more than once, so they are computed only once per query. In cluster mode, only the expressions declared with MATERIALIZED are materialized.",
+                    possible_values: None,
+                }),
+                ("materialized_cte_spilling_bytes_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(max_memory_usage / 4),
+                    desc: "Sets the maximum amount of memory in bytes that a materialized common table expression can use before spilling data to storage, 0 disables spilling. Defaults to a quarter of max_memory_usage.",
+                    possible_values: None,
+                }),
+                ("enable_query_profiling", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables collecting the runtime statistics of every operator of a query into system.query_profile.",
+                    possible_values: None,
+                }),
                 ("group_by_shuffle_mode", DefaultSettingValue {
                     value: UserSettingValue::String(String::from("before_merge")),
                     desc: "Group by shuffle mode, 'before_partial' is more balanced, but more data needs to exchange.",
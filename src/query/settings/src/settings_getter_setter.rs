@@ -241,10 +241,46 @@ impl Settings {
         self.try_set_u64("enable_runtime_filter", u64::from(val))
     }
 
+    pub fn get_enable_runtime_filter_pruning(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_runtime_filter_pruning")? != 0)
+    }
+
+    pub fn get_max_runtime_filter_inlist_size(&self) -> Result<u64> {
+        self.try_get_u64("max_runtime_filter_inlist_size")
+    }
+
     pub fn get_prefer_broadcast_join(&self) -> Result<bool> {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
 
+    pub fn get_enable_cost_based_join_distribution(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_cost_based_join_distribution")? != 0)
+    }
+
+    pub fn get_cost_factor_compute_per_row(&self) -> Result<u64> {
+        self.try_get_u64("cost_factor_compute_per_row")
+    }
+
+    pub fn get_cost_factor_hash_table_per_row(&self) -> Result<u64> {
+        self.try_get_u64("cost_factor_hash_table_per_row")
+    }
+
+    pub fn get_cost_factor_aggregate_per_row(&self) -> Result<u64> {
+        self.try_get_u64("cost_factor_aggregate_per_row")
+    }
+
+    pub fn get_cost_factor_io_per_kb(&self) -> Result<u64> {
+        self.try_get_u64("cost_factor_io_per_kb")
+    }
+
+    pub fn get_cost_factor_network_per_kb(&self) -> Result<u64> {
+        self.try_get_u64("cost_factor_network_per_kb")
+    }
+
+    pub fn get_cost_factor_memory_pressure(&self) -> Result<u64> {
+        self.try_get_u64("cost_factor_memory_pressure")
+    }
+
     pub fn set_prefer_broadcast_join(&self, val: bool) -> Result<()> {
         self.try_set_u64("join_distribution_type", u64::from(val))
     }
@@ -284,6 +320,10 @@ impl Settings {
         self.try_get_string("sandbox_tenant")
     }
 
+    pub fn get_network_policy(&self) -> Result<String> {
+        self.try_get_string("network_policy")
+    }
+
     pub fn get_hide_options_in_show_create_table(&self) -> Result<bool> {
         Ok(self.try_get_u64("hide_options_in_show_create_table")? != 0)
     }
@@ -304,6 +344,10 @@ impl Settings {
         Ok(self.try_get_u64("query_result_cache_allow_inconsistent")? != 0)
     }
 
+    pub fn get_enable_aggregate_result_cache(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_aggregate_result_cache")? != 0)
+    }
+
     pub fn get_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
         Ok(self.try_get_u64("spilling_bytes_threshold_per_proc")? as usize)
     }
@@ -312,6 +356,18 @@ impl Settings {
         self.try_set_u64("spilling_bytes_threshold_per_proc", value as u64)
     }
 
+    pub fn get_enable_materialized_cte(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_cte")? != 0)
+    }
+
+    pub fn get_materialized_cte_spilling_bytes_threshold(&self) -> Result<usize> {
+        Ok(self.try_get_u64("materialized_cte_spilling_bytes_threshold")? as usize)
+    }
+
+    pub fn get_enable_query_profiling(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_query_profiling")? != 0)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
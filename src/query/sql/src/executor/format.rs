@@ -16,6 +16,7 @@ use common_ast::ast::FormatTreeNode;
 use common_catalog::plan::PartStatistics;
 use common_exception::Result;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_profile::ProfSpanSet;
 use common_profile::ProfSpanSetRef;
 use itertools::Itertools;
 
@@ -36,10 +37,12 @@ use super::TableScan;
 use super::UnionAll;
 use super::WindowFunction;
 use crate::executor::explain::PlanStatsInfo;
+use crate::executor::CteScan;
 use crate::executor::DistributedInsertSelect;
 use crate::executor::ExchangeSink;
 use crate::executor::ExchangeSource;
 use crate::executor::FragmentKind;
+use crate::executor::MaterializedCte;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Window;
 use crate::planner::MetadataRef;
@@ -89,6 +92,18 @@ impl PhysicalPlan {
                     children,
                 ))
             }
+            PhysicalPlan::Exchange(plan) => {
+                let kind = match plan.kind {
+                    FragmentKind::Init => "Init-Partition",
+                    FragmentKind::Normal => "Hash",
+                    FragmentKind::Expansive => "Broadcast",
+                    FragmentKind::Merge => "Merge",
+                };
+                Ok(FormatTreeNode::with_children(
+                    format!("Exchange: {}", kind),
+                    vec![plan.input.format_join(metadata)?],
+                ))
+            }
             other => {
                 let children = other
                     .children()
@@ -114,7 +129,7 @@ fn to_format_tree(
     prof_span_set: &ProfSpanSetRef,
 ) -> Result<FormatTreeNode<String>> {
     match plan {
-        PhysicalPlan::TableScan(plan) => table_scan_to_format_tree(plan, metadata),
+        PhysicalPlan::TableScan(plan) => table_scan_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Filter(plan) => filter_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Project(plan) => project_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::EvalScalar(plan) => eval_scalar_to_format_tree(plan, metadata, prof_span_set),
@@ -144,12 +159,17 @@ fn to_format_tree(
         PhysicalPlan::RuntimeFilterSource(plan) => {
             runtime_filter_source_to_format_tree(plan, metadata, prof_span_set)
         }
+        PhysicalPlan::MaterializedCte(plan) => {
+            materialized_cte_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::CteScan(plan) => cte_scan_to_format_tree(plan, prof_span_set),
     }
 }
 
 fn table_scan_to_format_tree(
     plan: &TableScan,
     metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
 ) -> Result<FormatTreeNode<String>> {
     if plan.table_index == DUMMY_TABLE_INDEX {
         return Ok(FormatTreeNode::new("DummyTableScan".to_string()));
@@ -202,6 +222,17 @@ fn table_scan_to_format_tree(
         }
     };
     children.push(FormatTreeNode::new(push_downs));
+    if let Some(top_k) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.vector_top_k.as_ref())
+    {
+        children.push(FormatTreeNode::new(format!(
+            "vector top k: [{}({}), limit: {}]",
+            top_k.func_name, top_k.column, top_k.limit
+        )));
+    }
 
     let output_columns = plan.source.output_schema.fields();
 
@@ -219,6 +250,8 @@ fn table_scan_to_format_tree(
         children.extend(items);
     }
 
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[]));
+
     Ok(FormatTreeNode::with_children(
         "TableScan".to_string(),
         children,
@@ -242,12 +275,9 @@ fn filter_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.input,
+    ]));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -276,12 +306,9 @@ fn project_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.input,
+    ]));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -309,12 +336,9 @@ fn eval_scalar_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.input,
+    ]));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -361,12 +385,9 @@ fn aggregate_expand_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.input,
+    ]));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -407,12 +428,9 @@ fn aggregate_partial_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.input,
+    ]));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -459,12 +477,9 @@ fn aggregate_final_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.input,
+    ]));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -513,12 +528,9 @@ fn window_to_format_tree(
         FormatTreeNode::new(format!("frame: [{frame}]")),
     ];
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.input,
+    ]));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -559,12 +571,9 @@ fn sort_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.input,
+    ]));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -590,12 +599,9 @@ fn limit_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.input,
+    ]));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -644,12 +650,10 @@ fn hash_join_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.build,
+        &plan.probe,
+    ]));
 
     children.push(build_child);
     children.push(probe_child);
@@ -695,12 +699,10 @@ fn union_all_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.left,
+        &plan.right,
+    ]));
 
     children.extend(vec![
         to_format_tree(&plan.left, metadata, prof_span_set)?,
@@ -713,6 +715,50 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn materialized_cte_to_format_tree(
+    plan: &MaterializedCte,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![];
+
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.right,
+    ]));
+
+    children.extend(vec![
+        to_format_tree(&plan.left, metadata, prof_span_set)?,
+        to_format_tree(&plan.right, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        format!("MaterializedCte: {}", plan.cte_idx),
+        children,
+    ))
+}
+
+fn cte_scan_to_format_tree(
+    plan: &CteScan,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        plan.schema.fields().iter().map(|f| f.name()).join(", ")
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[]));
+
+    Ok(FormatTreeNode::with_children(
+        format!("CteScan: {}", plan.cte_idx),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -743,6 +789,76 @@ fn plan_stats_info_to_format_tree(info: &PlanStatsInfo) -> Vec<FormatTreeNode<St
     ))]
 }
 
+/// The runtime statistics of a plan collected by `EXPLAIN ANALYZE`.
+fn prof_span_to_format_tree(
+    prof_span_set: &ProfSpanSetRef,
+    plan_id: u32,
+    inputs: &[&PhysicalPlan],
+) -> Vec<FormatTreeNode<String>> {
+    let prof_span_set = prof_span_set.lock().unwrap();
+    let prof_span = match prof_span_set.get(&plan_id) {
+        Some(prof_span) => prof_span,
+        None => return vec![],
+    };
+
+    let mut items = vec![];
+    if !inputs.is_empty() {
+        let input_rows: u64 = inputs
+            .iter()
+            .map(|input| output_rows(input, &prof_span_set))
+            .sum();
+        items.push(FormatTreeNode::new(format!("input rows: {input_rows}")));
+    }
+    items.extend([
+        FormatTreeNode::new(format!("output rows: {}", prof_span.output_rows)),
+        FormatTreeNode::new(format!("output bytes: {}", prof_span.output_bytes)),
+        // milliseconds
+        FormatTreeNode::new(format!(
+            "total process time: {}ms",
+            prof_span.process_time / 1000 / 1000
+        )),
+        FormatTreeNode::new(format!(
+            "total wait time: {}ms",
+            prof_span.wait_time / 1000 / 1000
+        )),
+        FormatTreeNode::new(format!(
+            "wall time: {}ms",
+            prof_span.wall_time / 1000 / 1000
+        )),
+        FormatTreeNode::new(format!(
+            "peak memory usage: {}",
+            prof_span.peak_memory_usage
+        )),
+    ]);
+    if prof_span.scan_bytes > 0 {
+        items.push(FormatTreeNode::new(format!(
+            "bytes scanned: {}",
+            prof_span.scan_bytes
+        )));
+    }
+    if prof_span.spill_count > 0 {
+        items.push(FormatTreeNode::new(format!(
+            "spilled: [files: {}, bytes: {}]",
+            prof_span.spill_count, prof_span.spill_bytes
+        )));
+    }
+    items
+}
+
+/// The rows produced by a plan, the plans without id(e.g. exchanges) pass through
+/// the rows of their inputs.
+fn output_rows(plan: &PhysicalPlan, prof_span_set: &ProfSpanSet) -> u64 {
+    match plan.get_id() {
+        Some(plan_id) => prof_span_set
+            .get(&plan_id)
+            .map_or(0, |prof_span| prof_span.output_rows),
+        None => plan
+            .children()
+            .map(|child| output_rows(child, prof_span_set))
+            .sum(),
+    }
+}
+
 fn exchange_source_to_format_tree(plan: &ExchangeSource) -> Result<FormatTreeNode<String>> {
     let mut children = vec![];
 
@@ -802,12 +918,9 @@ fn project_set_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.input,
+    ]));
 
     children.extend(vec![FormatTreeNode::new(format!(
         "set returning functions: {}",
@@ -831,10 +944,12 @@ fn runtime_filter_source_to_format_tree(
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
 ) -> Result<FormatTreeNode<String>> {
-    let children = vec![
-        to_format_tree(&plan.left_side, metadata, prof_span_set)?,
-        to_format_tree(&plan.right_side, metadata, prof_span_set)?,
-    ];
+    let mut children = prof_span_to_format_tree(prof_span_set, plan.plan_id, &[
+        &plan.left_side,
+        &plan.right_side,
+    ]);
+    children.push(to_format_tree(&plan.left_side, metadata, prof_span_set)?);
+    children.push(to_format_tree(&plan.right_side, metadata, prof_span_set)?);
     Ok(FormatTreeNode::with_children(
         "RuntimeFilterSource".to_string(),
         children,
@@ -401,6 +401,12 @@ pub struct HashJoin {
 
     // It means that join has a corresponding runtime filter
     pub contain_runtime_filter: bool,
+    /// Indices of the keys whose runtime filters are pushed down to the
+    /// table scans of the probe side, see `RuntimeFilterTarget`.
+    pub runtime_filter_keys: Vec<usize>,
+    /// Whether the build rows are shuffled to the nodes of the cluster, so the runtime
+    /// filters of the nodes are merged before the scans use them.
+    pub merge_runtime_filters: bool,
 
     /// Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -569,6 +575,46 @@ impl UnionAll {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MaterializedCte {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_idx: IndexType,
+    /// Produces the CTE.
+    pub left: Box<PhysicalPlan>,
+    /// Reads the CTE.
+    pub right: Box<PhysicalPlan>,
+}
+
+impl MaterializedCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.right.output_schema()
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_idx: IndexType,
+    /// Offsets of the output columns in the blocks produced by the CTE.
+    pub offsets: Vec<usize>,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl CteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct DistributedInsertSelect {
     pub input: Box<PhysicalPlan>,
@@ -627,6 +673,8 @@ pub enum PhysicalPlan {
     Exchange(Exchange),
     UnionAll(UnionAll),
     RuntimeFilterSource(RuntimeFilterSource),
+    MaterializedCte(MaterializedCte),
+    CteScan(CteScan),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
@@ -665,6 +713,8 @@ impl PhysicalPlan {
             PhysicalPlan::DistributedInsertSelect(plan) => plan.output_schema(),
             PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::RuntimeFilterSource(plan) => plan.output_schema(),
+            PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
+            PhysicalPlan::CteScan(plan) => plan.output_schema(),
         }
     }
 
@@ -688,6 +738,34 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSink(_) => "Exchange Sink".to_string(),
             PhysicalPlan::ProjectSet(_) => "Unnest".to_string(),
             PhysicalPlan::RuntimeFilterSource(_) => "RuntimeFilterSource".to_string(),
+            PhysicalPlan::MaterializedCte(_) => "MaterializedCte".to_string(),
+            PhysicalPlan::CteScan(_) => "CteScan".to_string(),
+        }
+    }
+
+    /// The id of the plan, the plans synthesized for the exchanges have no id.
+    pub fn get_id(&self) -> Option<u32> {
+        match self {
+            PhysicalPlan::TableScan(plan) => Some(plan.plan_id),
+            PhysicalPlan::Filter(plan) => Some(plan.plan_id),
+            PhysicalPlan::Project(plan) => Some(plan.plan_id),
+            PhysicalPlan::EvalScalar(plan) => Some(plan.plan_id),
+            PhysicalPlan::ProjectSet(plan) => Some(plan.plan_id),
+            PhysicalPlan::AggregateExpand(plan) => Some(plan.plan_id),
+            PhysicalPlan::AggregatePartial(plan) => Some(plan.plan_id),
+            PhysicalPlan::AggregateFinal(plan) => Some(plan.plan_id),
+            PhysicalPlan::Window(plan) => Some(plan.plan_id),
+            PhysicalPlan::Sort(plan) => Some(plan.plan_id),
+            PhysicalPlan::Limit(plan) => Some(plan.plan_id),
+            PhysicalPlan::HashJoin(plan) => Some(plan.plan_id),
+            PhysicalPlan::UnionAll(plan) => Some(plan.plan_id),
+            PhysicalPlan::RuntimeFilterSource(plan) => Some(plan.plan_id),
+            PhysicalPlan::MaterializedCte(plan) => Some(plan.plan_id),
+            PhysicalPlan::CteScan(plan) => Some(plan.plan_id),
+            PhysicalPlan::Exchange(_)
+            | PhysicalPlan::DistributedInsertSelect(_)
+            | PhysicalPlan::ExchangeSource(_)
+            | PhysicalPlan::ExchangeSink(_) => None,
         }
     }
 
@@ -720,6 +798,10 @@ impl PhysicalPlan {
                 std::iter::once(plan.left_side.as_ref())
                     .chain(std::iter::once(plan.right_side.as_ref())),
             ),
+            PhysicalPlan::MaterializedCte(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
+            PhysicalPlan::CteScan(_) => Box::new(std::iter::empty()),
         }
     }
 }
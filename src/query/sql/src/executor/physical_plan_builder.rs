@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -21,20 +22,27 @@ use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::PrewhereInfo;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::VectorTopK;
 use common_catalog::plan::VirtualColumnInfo;
+use common_catalog::runtime_filter_info::RuntimeFilterKey;
+use common_catalog::runtime_filter_info::RuntimeFilterTarget;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::check_function;
 use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
+use common_expression::types::NumberColumn;
+use common_expression::Column;
 use common_expression::ConstantFolder;
 use common_expression::DataBlock;
 use common_expression::DataField;
+use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::Expr;
 use common_expression::FunctionContext;
 use common_expression::RemoteExpr;
+use common_expression::Scalar;
 use common_expression::TableSchema;
 use common_functions::BUILTIN_FUNCTIONS;
 
@@ -55,8 +63,10 @@ use super::WindowFunction;
 use crate::binder::wrap_cast;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::table_read_plan::ToReadDataSourcePlan;
+use crate::executor::CteScan;
 use crate::executor::EvalScalar;
 use crate::executor::FragmentKind;
+use crate::executor::MaterializedCte;
 use crate::executor::PhysicalPlan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::SortDesc;
@@ -66,6 +76,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::AggregateMode;
+use crate::plans::CastExpr;
 use crate::plans::Exchange;
 use crate::plans::FunctionCall;
 use crate::plans::JoinType;
@@ -76,6 +87,7 @@ use crate::plans::WindowFuncType;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
+use crate::IndexType;
 use crate::Metadata;
 use crate::MetadataRef;
 use crate::TableInternalColumn;
@@ -90,6 +102,8 @@ pub struct PhysicalPlanBuilder {
     func_ctx: FunctionContext,
 
     next_plan_id: u32,
+    /// Output schemas of the materialized CTEs built so far.
+    cte_schemas: HashMap<IndexType, DataSchemaRef>,
 }
 
 impl PhysicalPlanBuilder {
@@ -100,6 +114,7 @@ impl PhysicalPlanBuilder {
             ctx,
             next_plan_id: 0,
             func_ctx,
+            cte_schemas: HashMap::new(),
         }
     }
 
@@ -311,7 +326,7 @@ impl PhysicalPlanBuilder {
             }
             RelOperator::Join(join) => {
                 let build_side = self.build(s_expr.child(1)?).await?;
-                let probe_side = self.build(s_expr.child(0)?).await?;
+                let mut probe_side = self.build(s_expr.child(0)?).await?;
 
                 let build_schema = match join.join_type {
                     JoinType::Left | JoinType::Full => {
@@ -390,8 +405,16 @@ impl PhysicalPlanBuilder {
                         right_join_conditions.push(right_join_condition.clone());
                     }
                 }
+                let plan_id = self.next_plan_id();
+                let (runtime_filter_keys, merge_runtime_filters) = self.push_down_runtime_filters(
+                    plan_id,
+                    &join.join_type,
+                    &left_join_conditions,
+                    &build_side,
+                    &mut probe_side,
+                )?;
                 Ok(PhysicalPlan::HashJoin(HashJoin {
-                    plan_id: self.next_plan_id(),
+                    plan_id,
                     build: Box::new(build_side),
                     probe: Box::new(probe_side),
                     join_type: join.join_type.clone(),
@@ -439,6 +462,8 @@ impl PhysicalPlanBuilder {
                     from_correlated_subquery: join.from_correlated_subquery,
 
                     contain_runtime_filter: join.contain_runtime_filter,
+                    runtime_filter_keys,
+                    merge_runtime_filters,
                     stat_info: Some(stat_info),
                 }))
             }
@@ -930,6 +955,50 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::MaterializedCte(cte) => {
+                // The CTE is built first, its schema is needed by the `CteScan`s reading it.
+                let left = self.build(s_expr.child(0)?).await?;
+                self.cte_schemas.insert(cte.cte_idx, left.output_schema()?);
+                let right = self.build(s_expr.child(1)?).await?;
+                Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
+                    plan_id: self.next_plan_id(),
+                    cte_idx: cte.cte_idx,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }))
+            }
+
+            RelOperator::CteScan(cte_scan) => {
+                let cte_schema = self
+                    .cte_schemas
+                    .get(&cte_scan.cte_idx)
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "Materialized CTE {} is read before it is built",
+                            cte_scan.cte_idx
+                        ))
+                    })?;
+                let mut offsets = Vec::with_capacity(cte_scan.columns.len());
+                let mut fields = Vec::with_capacity(cte_scan.columns.len());
+                for (column, source) in cte_scan.columns.iter().zip(cte_scan.source_columns.iter())
+                {
+                    let offset = cte_schema.index_of(&source.to_string())?;
+                    offsets.push(offset);
+                    fields.push(DataField::new(
+                        &column.index.to_string(),
+                        cte_schema.field(offset).data_type().clone(),
+                    ));
+                }
+                Ok(PhysicalPlan::CteScan(CteScan {
+                    plan_id: self.next_plan_id(),
+                    cte_idx: cte_scan.cte_idx,
+                    offsets,
+                    schema: DataSchemaRefExt::create(fields),
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             _ => Err(ErrorCode::Internal(format!(
                 "Unsupported physical plan: {:?}",
                 s_expr.plan()
@@ -1126,6 +1195,7 @@ impl PhysicalPlanBuilder {
             .transpose()?;
 
         let virtual_columns = self.build_virtual_columns(&scan.columns);
+        let vector_top_k = self.build_vector_top_k(&metadata, scan)?;
 
         Ok(PushDownInfo {
             projection: Some(projection),
@@ -1135,9 +1205,124 @@ impl PhysicalPlanBuilder {
             limit: scan.limit,
             order_by: order_by.unwrap_or_default(),
             virtual_columns,
+            runtime_filters: vec![],
+            vector_top_k,
         })
     }
 
+    /// Evaluates the query vector of the nearest neighbours search pushed down to the scan,
+    /// the search isn't pushed down if the vector is not a constant of non-null numbers.
+    fn build_vector_top_k(&self, metadata: &Metadata, scan: &Scan) -> Result<Option<VectorTopK>> {
+        let top_k = match &scan.vector_top_k {
+            Some(top_k) => top_k,
+            None => return Ok(None),
+        };
+        let column = match metadata.column(top_k.column) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                column_name,
+                path_indices: None,
+                ..
+            }) => column_name.clone(),
+            _ => return Ok(None),
+        };
+
+        let expr = top_k.query.as_expr_with_col_name()?;
+        let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let query = match expr {
+            Expr::Constant {
+                scalar: Scalar::Array(Column::Number(NumberColumn::Float32(values))),
+                ..
+            } => values.to_vec(),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(VectorTopK {
+            column,
+            func_name: top_k.func_name.clone(),
+            query,
+            limit: top_k.limit,
+        }))
+    }
+
+    /// Pushes the runtime filters of the keys of a hash join down to the table scans of
+    /// the probe side. Returns the indices of the keys pushed down, and whether the filters
+    /// of the nodes of the cluster have to be merged.
+    ///
+    /// The scans only skip the rows that can't match any build row, which doesn't change
+    /// the result of the join types dropping such probe rows. The filter a scan uses has to
+    /// cover all the build rows: for the hash shuffle joins, each node only sees its share of
+    /// the build rows, so the nodes send their filters to each other and merge them. The scans
+    /// behind a hash shuffle exchange run on all the nodes, like the join, so they can wait for
+    /// the filters of the join of their node. The joins reading a merge exchange only run on
+    /// the coordinator, the scans of their probe side behind an exchange are not filtered.
+    fn push_down_runtime_filters(
+        &self,
+        join_id: u32,
+        join_type: &JoinType,
+        probe_keys: &[ScalarExpr],
+        build_side: &PhysicalPlan,
+        probe_side: &mut PhysicalPlan,
+    ) -> Result<(Vec<usize>, bool)> {
+        if !self
+            .ctx
+            .get_settings()
+            .get_enable_runtime_filter_pruning()?
+            || !matches!(
+                join_type,
+                JoinType::Inner | JoinType::LeftSemi | JoinType::RightSemi | JoinType::Right
+            )
+        {
+            return Ok((vec![], false));
+        }
+
+        let on_all_nodes = !reads_merge_exchange(build_side) && !reads_merge_exchange(probe_side);
+        let merge = !is_build_side_complete(build_side);
+        if merge && !on_all_nodes {
+            return Ok((vec![], false));
+        }
+
+        let mut runtime_filter_keys = vec![];
+        for (key_index, probe_key) in probe_keys.iter().enumerate() {
+            let column = match probe_key {
+                ScalarExpr::BoundColumnRef(column_ref) => &column_ref.column,
+                ScalarExpr::CastExpr(CastExpr {
+                    argument: box ScalarExpr::BoundColumnRef(column_ref),
+                    ..
+                }) => &column_ref.column,
+                _ => continue,
+            };
+            let (table_index, column_name) = match self.metadata.read().column(column.index) {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    table_index,
+                    column_name,
+                    path_indices: None,
+                    virtual_computed_expr: None,
+                    ..
+                }) => (*table_index, column_name.clone()),
+                _ => continue,
+            };
+            let scan = match find_runtime_filter_scan(probe_side, table_index, on_all_nodes) {
+                Some(scan) => scan,
+                None => continue,
+            };
+
+            let expr = probe_key
+                .as_expr_with_col_name()?
+                .project_column_ref(|_| column_name.clone());
+            let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            scan.source
+                .push_downs
+                .get_or_insert_with(PushDownInfo::default)
+                .runtime_filters
+                .push(RuntimeFilterTarget {
+                    key: RuntimeFilterKey { join_id, key_index },
+                    expr: expr.as_remote_expr(),
+                });
+            runtime_filter_keys.push(key_index);
+        }
+        Ok((runtime_filter_keys, merge))
+    }
+
     fn build_plan_stat_info(&self, s_expr: &SExpr) -> Result<PlanStatsInfo> {
         let rel_expr = RelExpr::with_s_expr(s_expr);
         let prop = rel_expr.derive_relational_prop()?;
@@ -1147,3 +1332,66 @@ impl PhysicalPlanBuilder {
         })
     }
 }
+
+/// Whether every node running the fragment of a hash join sees all the rows of the
+/// build side, which is not the case if they are shuffled by the keys.
+fn is_build_side_complete(plan: &PhysicalPlan) -> bool {
+    match plan {
+        PhysicalPlan::Exchange(exchange) => exchange.kind != FragmentKind::Normal,
+        _ => plan.children().all(is_build_side_complete),
+    }
+}
+
+/// Whether the fragment of the plan reads an exchange merging the rows to the coordinator,
+/// in which case it only runs on the coordinator.
+fn reads_merge_exchange(plan: &PhysicalPlan) -> bool {
+    match plan {
+        PhysicalPlan::Exchange(exchange) => exchange.kind == FragmentKind::Merge,
+        _ => plan.children().any(reads_merge_exchange),
+    }
+}
+
+/// Finds the scan of the table, whose rows can be skipped if they can't match any build row
+/// without changing the result of the join. The scans behind a hash shuffle exchange are
+/// only looked for if `through_exchanges`.
+fn find_runtime_filter_scan(
+    plan: &mut PhysicalPlan,
+    table_index: IndexType,
+    through_exchanges: bool,
+) -> Option<&mut TableScan> {
+    match plan {
+        PhysicalPlan::TableScan(scan) if scan.table_index == table_index => Some(scan),
+        PhysicalPlan::Filter(plan) => {
+            find_runtime_filter_scan(&mut plan.input, table_index, through_exchanges)
+        }
+        PhysicalPlan::Project(plan) => {
+            find_runtime_filter_scan(&mut plan.input, table_index, through_exchanges)
+        }
+        PhysicalPlan::EvalScalar(plan) => {
+            find_runtime_filter_scan(&mut plan.input, table_index, through_exchanges)
+        }
+        PhysicalPlan::RuntimeFilterSource(plan) => {
+            find_runtime_filter_scan(&mut plan.left_side, table_index, through_exchanges)
+        }
+        PhysicalPlan::Exchange(exchange)
+            if through_exchanges && exchange.kind == FragmentKind::Normal =>
+        {
+            find_runtime_filter_scan(&mut exchange.input, table_index, through_exchanges)
+        }
+        PhysicalPlan::HashJoin(join) => match join.join_type {
+            JoinType::Inner => {
+                if let Some(scan) =
+                    find_runtime_filter_scan(&mut join.probe, table_index, through_exchanges)
+                {
+                    return Some(scan);
+                }
+                find_runtime_filter_scan(&mut join.build, table_index, through_exchanges)
+            }
+            JoinType::LeftSemi | JoinType::Right => {
+                find_runtime_filter_scan(&mut join.probe, table_index, through_exchanges)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -30,6 +30,8 @@ use super::Project;
 use super::ProjectSet;
 use super::Sort;
 use super::TableScan;
+use crate::executor::CteScan;
+use crate::executor::MaterializedCte;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::UnionAll;
 use crate::executor::Window;
@@ -55,6 +57,8 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::DistributedInsertSelect(plan) => self.replace_insert_select(plan),
             PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
             PhysicalPlan::RuntimeFilterSource(plan) => self.replace_runtime_filter_source(plan),
+            PhysicalPlan::MaterializedCte(plan) => self.replace_materialized_cte(plan),
+            PhysicalPlan::CteScan(plan) => self.replace_cte_scan(plan),
         }
     }
 
@@ -164,6 +168,8 @@ pub trait PhysicalPlanReplacer {
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
             contain_runtime_filter: plan.contain_runtime_filter,
+            runtime_filter_keys: plan.runtime_filter_keys.clone(),
+            merge_runtime_filters: plan.merge_runtime_filters,
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -273,6 +279,21 @@ pub trait PhysicalPlanReplacer {
             right_runtime_filters: plan.right_runtime_filters.clone(),
         }))
     }
+
+    fn replace_materialized_cte(&mut self, plan: &MaterializedCte) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+        Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
+            plan_id: plan.plan_id,
+            cte_idx: plan.cte_idx,
+            left: Box::new(left),
+            right: Box::new(right),
+        }))
+    }
+
+    fn replace_cte_scan(&mut self, plan: &CteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::CteScan(plan.clone()))
+    }
 }
 
 impl PhysicalPlan {
@@ -338,6 +359,11 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left_side, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right_side, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::MaterializedCte(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::CteScan(_) => {}
             }
             post_visit(plan);
         }
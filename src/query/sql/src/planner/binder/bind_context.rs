@@ -31,6 +31,7 @@ use dashmap::DashMap;
 use super::AggregateInfo;
 use super::INTERNAL_COLUMN_FACTORY;
 use crate::binder::window::WindowInfo;
+use crate::binder::MaterializedCteInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::ScalarExpr;
@@ -164,12 +165,18 @@ pub struct BindContext {
     pub srfs: DashMap<String, ScalarExpr>,
 
     pub expr_context: ExprContext,
+
+    /// If true, masking and row access policies are not applied to the tables
+    /// bound in current context, e.g. the target table of `DELETE` and `UPDATE`.
+    pub ignore_security_policies: bool,
 }
 
 #[derive(Clone, Debug)]
 pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
+    /// Set if the CTE is computed once and shared by all its references.
+    pub materialized: Option<MaterializedCteInfo>,
 }
 
 impl BindContext {
@@ -185,6 +192,7 @@ impl BindContext {
             view_info: None,
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
+            ignore_security_policies: false,
         }
     }
 
@@ -200,6 +208,7 @@ impl BindContext {
             view_info: None,
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
+            ignore_security_policies: false,
         }
     }
 
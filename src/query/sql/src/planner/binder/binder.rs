@@ -29,13 +29,27 @@ use common_meta_app::principal::UserDefinedFunction;
 
 use crate::normalize_identifier;
 use crate::planner::udf_validator::UDFValidator;
+use crate::plans::AlterNetworkPolicyPlan;
+use crate::plans::AlterPasswordPolicyPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::CallPlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::CreatePasswordPolicyPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateUDFPlan;
+use crate::plans::DescMaskingPolicyPlan;
+use crate::plans::DescNetworkPolicyPlan;
+use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
 use crate::plans::DropFileFormatPlan;
+use crate::plans::DropMaskingPolicyPlan;
+use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::DropPasswordPolicyPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::DropSequencePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
@@ -43,6 +57,7 @@ use crate::plans::Plan;
 use crate::plans::RewriteKind;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
+use crate::plans::ShowPasswordPoliciesPlan;
 use crate::plans::ShowRolesPlan;
 use crate::plans::UseDatabasePlan;
 use crate::BindContext;
@@ -193,6 +208,25 @@ impl<'a> Binder {
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
+            Statement::CreateVectorIndex(stmt) => self.bind_create_vector_index(stmt).await?,
+            Statement::DropVectorIndex(stmt) => self.bind_drop_vector_index(stmt).await?,
+
+            // Sequences
+            Statement::CreateSequence(stmt) => Plan::CreateSequence(Box::new(CreateSequencePlan {
+                if_not_exists: stmt.if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                name: stmt.name.clone(),
+                start: stmt.start.unwrap_or(1),
+                increment: stmt.increment.unwrap_or(1),
+                comment: stmt.comment.clone().unwrap_or_default(),
+            })),
+            Statement::DropSequence(stmt) => Plan::DropSequence(Box::new(DropSequencePlan {
+                if_exists: stmt.if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: stmt.name.clone(),
+            })),
 
             // Users
             Statement::CreateUser(stmt) => self.bind_create_user(stmt).await?,
@@ -253,6 +287,75 @@ impl<'a> Binder {
             })),
             Statement::Revoke(stmt) => self.bind_revoke(stmt).await?,
 
+            // Password policies
+            Statement::CreatePasswordPolicy(stmt) => Plan::CreatePasswordPolicy(Box::new(CreatePasswordPolicyPlan {
+                if_not_exists: stmt.if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                name: stmt.name.clone(),
+                set_options: stmt.set_options.clone(),
+            })),
+            Statement::AlterPasswordPolicy(stmt) => Plan::AlterPasswordPolicy(Box::new(AlterPasswordPolicyPlan {
+                if_exists: stmt.if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: stmt.name.clone(),
+                action: stmt.action.clone(),
+            })),
+            Statement::DropPasswordPolicy(stmt) => Plan::DropPasswordPolicy(Box::new(DropPasswordPolicyPlan {
+                if_exists: stmt.if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: stmt.name.clone(),
+            })),
+            Statement::DescPasswordPolicy(stmt) => Plan::DescPasswordPolicy(Box::new(DescPasswordPolicyPlan {
+                name: stmt.name.clone(),
+            })),
+            Statement::ShowPasswordPolicies => Plan::ShowPasswordPolicies(Box::new(ShowPasswordPoliciesPlan {})),
+
+            // Network policies
+            Statement::CreateNetworkPolicy(stmt) => Plan::CreateNetworkPolicy(Box::new(CreateNetworkPolicyPlan {
+                if_not_exists: stmt.if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                name: stmt.name.clone(),
+                allowed_ip_list: stmt.allowed_ip_list.clone(),
+                blocked_ip_list: stmt.blocked_ip_list.clone(),
+                comment: stmt.comment.clone().unwrap_or_default(),
+            })),
+            Statement::AlterNetworkPolicy(stmt) => Plan::AlterNetworkPolicy(Box::new(AlterNetworkPolicyPlan {
+                if_exists: stmt.if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: stmt.name.clone(),
+                allowed_ip_list: stmt.allowed_ip_list.clone(),
+                blocked_ip_list: stmt.blocked_ip_list.clone(),
+                comment: stmt.comment.clone(),
+            })),
+            Statement::DropNetworkPolicy(stmt) => Plan::DropNetworkPolicy(Box::new(DropNetworkPolicyPlan {
+                if_exists: stmt.if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: stmt.name.clone(),
+            })),
+            Statement::DescNetworkPolicy(stmt) => Plan::DescNetworkPolicy(Box::new(DescNetworkPolicyPlan {
+                name: stmt.name.clone(),
+            })),
+
+            // Data security policies
+            Statement::CreateMaskingPolicy(stmt) => self.bind_create_masking_policy(stmt).await?,
+            Statement::DropMaskingPolicy(stmt) => Plan::DropMaskingPolicy(Box::new(DropMaskingPolicyPlan {
+                if_exists: stmt.if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: stmt.name.clone(),
+            })),
+            Statement::DescMaskingPolicy(stmt) => Plan::DescMaskingPolicy(Box::new(DescMaskingPolicyPlan {
+                name: stmt.name.clone(),
+            })),
+            Statement::CreateRowAccessPolicy(stmt) => self.bind_create_row_access_policy(stmt).await?,
+            Statement::DropRowAccessPolicy(stmt) => Plan::DropRowAccessPolicy(Box::new(DropRowAccessPolicyPlan {
+                if_exists: stmt.if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: stmt.name.clone(),
+            })),
+            Statement::DescRowAccessPolicy(stmt) => Plan::DescRowAccessPolicy(Box::new(DescRowAccessPolicyPlan {
+                name: stmt.name.clone(),
+            })),
+
             // File Formats
             Statement::CreateFileFormat{  if_not_exists, name, file_format_options} =>  Plan::CreateFileFormat(Box::new(CreateFileFormatPlan {
                 if_not_exists: *if_not_exists,
@@ -242,15 +242,17 @@ impl<'a> Binder {
             pattern: stmt.pattern.clone(),
         };
 
+        // Computed columns are not read from the files.
+        let schema = Arc::new(table.schema().remove_computed_fields());
         let from = DataSourcePlan {
             catalog: dst_catalog_name.to_string(),
             source_info: DataSourceInfo::StageSource(StageTableInfo {
-                schema: table.schema(),
+                schema: schema.clone(),
                 stage_info,
                 files_info,
                 files_to_copy: None,
             }),
-            output_schema: table.schema(),
+            output_schema: schema.clone(),
             parts: Partitions::default(),
             statistics: Default::default(),
             description: "".to_string(),
@@ -264,7 +266,7 @@ impl<'a> Binder {
             database_name: dst_database_name.to_string(),
             table_name: dst_table_name.to_string(),
             table_id: table.get_id(),
-            schema: table.schema(),
+            schema,
             from: Box::new(from),
             validation_mode,
             force: stmt.force,
@@ -307,15 +309,17 @@ impl<'a> Binder {
             pattern: stmt.pattern.clone(),
         };
 
+        // Computed columns are not read from the files.
+        let schema = Arc::new(table.schema().remove_computed_fields());
         let from = DataSourcePlan {
             catalog: dst_catalog_name.to_string(),
             source_info: DataSourceInfo::StageSource(StageTableInfo {
-                schema: table.schema(),
+                schema: schema.clone(),
                 stage_info,
                 files_info,
                 files_to_copy: None,
             }),
-            output_schema: table.schema(),
+            output_schema: schema.clone(),
             parts: Partitions::default(),
             statistics: Default::default(),
             description: "".to_string(),
@@ -329,7 +333,7 @@ impl<'a> Binder {
             database_name: dst_database_name.to_string(),
             table_name: dst_table_name.to_string(),
             table_id: table.get_id(),
-            schema: table.schema(),
+            schema,
             from: Box::new(from),
             validation_mode,
             force: stmt.force,
@@ -0,0 +1,207 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Query;
+use common_ast::ast::SetExpr;
+use common_ast::ast::TableReference;
+use common_ast::ast::With;
+use common_ast::ast::CTE;
+use common_ast::walk_table_reference;
+use common_ast::Visitor;
+use common_exception::Result;
+use parking_lot::RwLock;
+
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::CteScan;
+use crate::plans::MaterializedCte;
+use crate::BindContext;
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::IndexType;
+use crate::NameResolutionContext;
+
+/// A common table expression that is computed once and shared by all its references.
+#[derive(Clone, Debug)]
+pub struct MaterializedCteInfo {
+    pub cte_idx: IndexType,
+    /// Shared by all the copies of the `CteInfo`, so that the CTE is bound only once.
+    pub binding: Arc<RwLock<CteBinding>>,
+}
+
+#[derive(Clone, Debug)]
+pub enum CteBinding {
+    /// The CTE hasn't been referenced yet.
+    Unbound,
+    /// The CTE has been bound, every reference reads from a `CteScan`.
+    Bound {
+        s_expr: SExpr,
+        columns: Vec<ColumnBinding>,
+        cardinality: f64,
+    },
+    /// The CTE references outer columns, so it's inlined at every reference.
+    Inlined,
+}
+
+/// Count the references to a CTE in a query.
+struct CteReferenceCounter<'a> {
+    cte_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    count: usize,
+}
+
+impl<'a, 'ast> Visitor<'ast> for CteReferenceCounter<'a> {
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table,
+            ..
+        } = table
+        {
+            if normalize_identifier(table, self.name_resolution_ctx).name == self.cte_name {
+                self.count += 1;
+            }
+        }
+        walk_table_reference(self, table);
+    }
+}
+
+/// A CTE reading a single table without aggregation is cheap to recompute,
+/// and inlining it allows pushing filters and limits down to the table scan.
+fn is_simple_query(query: &Query) -> bool {
+    if query.with.is_some() || !query.order_by.is_empty() || !query.limit.is_empty() {
+        return false;
+    }
+    match &query.body {
+        SetExpr::Select(stmt) => {
+            !stmt.distinct
+                && stmt.group_by.is_none()
+                && stmt.having.is_none()
+                && stmt.window_list.is_none()
+                && matches!(stmt.from.as_slice(), [TableReference::Table { .. }])
+        }
+        _ => false,
+    }
+}
+
+impl Binder {
+    /// Decide whether a CTE of `query` is materialized or inlined at every reference.
+    pub(super) fn materialize_cte(
+        &self,
+        bind_context: &BindContext,
+        query: &Query,
+        with: &With,
+        cte: &CTE,
+    ) -> Result<Option<MaterializedCteInfo>> {
+        // Materialized CTEs are only supported at the top level of a statement,
+        // and a recursive CTE must be evaluated at every reference.
+        if bind_context.parent.is_some() || with.recursive {
+            return Ok(None);
+        }
+        let materialized = match cte.materialized {
+            Some(materialized) => materialized,
+            None => {
+                // A materialized CTE is kept by the local node, which disables the distributed
+                // execution of the query. In cluster mode, only the CTEs declared with
+                // `MATERIALIZED` are materialized.
+                if !self.ctx.get_settings().get_enable_materialized_cte()?
+                    || !self.ctx.get_cluster().is_empty()
+                    || is_simple_query(&cte.query)
+                {
+                    return Ok(None);
+                }
+                let mut counter = CteReferenceCounter {
+                    cte_name: &cte.alias.name.name,
+                    name_resolution_ctx: &self.name_resolution_ctx,
+                    count: 0,
+                };
+                counter.visit_query(query);
+                counter.count > 1
+            }
+        };
+        if !materialized {
+            return Ok(None);
+        }
+        Ok(Some(MaterializedCteInfo {
+            cte_idx: self.metadata.write().add_materialized_cte(),
+            binding: Arc::new(RwLock::new(CteBinding::Unbound)),
+        }))
+    }
+
+    /// Create a `CteScan` reading from a bound materialized CTE, the output columns
+    /// are new columns so that every reference can be optimized independently.
+    pub(super) fn bind_cte_scan(
+        &mut self,
+        bind_context: &BindContext,
+        cte_idx: IndexType,
+        cte_columns: &[ColumnBinding],
+        cardinality: f64,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut columns = Vec::with_capacity(cte_columns.len());
+        for column in cte_columns.iter() {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column.column_name.clone(), *column.data_type.clone());
+            columns.push(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                table_index: None,
+                column_name: column.column_name.clone(),
+                index,
+                data_type: column.data_type.clone(),
+                visibility: column.visibility.clone(),
+            });
+        }
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        new_bind_context.columns = columns.clone();
+        let cte_scan = CteScan {
+            cte_idx,
+            columns,
+            source_columns: cte_columns.iter().map(|column| column.index).collect(),
+            cardinality: cardinality as u64,
+        };
+        Ok((SExpr::create_leaf(cte_scan.into()), new_bind_context))
+    }
+
+    /// Put the materialized CTEs of a query below it, the CTEs are produced in
+    /// declaration order since a CTE can read the ones declared before it.
+    pub(super) fn wrap_materialized_ctes(
+        &self,
+        s_expr: SExpr,
+        materialized_ctes: &[MaterializedCteInfo],
+    ) -> SExpr {
+        let mut s_expr = s_expr;
+        for cte in materialized_ctes.iter().rev() {
+            let binding = cte.binding.read().clone();
+            if let CteBinding::Bound {
+                s_expr: cte_s_expr,
+                columns,
+                ..
+            } = binding
+            {
+                let materialized_cte = MaterializedCte {
+                    cte_idx: cte.cte_idx,
+                    cte_columns: columns,
+                };
+                s_expr = SExpr::create_binary(materialized_cte.into(), cte_s_expr, s_expr);
+            }
+        }
+        s_expr
+    }
+}
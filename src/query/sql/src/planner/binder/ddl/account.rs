@@ -53,24 +53,24 @@ impl Binder {
             AccountMgrSource::ALL { level } => {
                 // ALL PRIVILEGES have different available privileges set on different grant objects
                 // Now in this case all is always true.
-                let grant_object = self.convert_to_grant_object(level);
-                let priv_types = grant_object.available_privileges();
+                let grant_objects = self.convert_to_grant_objects(level);
+                let priv_types = grant_objects[0].available_privileges();
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: grant_objects,
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
             }
             AccountMgrSource::Privs { privileges, level } => {
-                let grant_object = self.convert_to_grant_object(level);
+                let grant_objects = self.convert_to_grant_objects(level);
                 let mut priv_types = UserPrivilegeSet::empty();
                 for x in privileges {
                     priv_types.set_privilege(*x);
                 }
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: grant_objects,
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -96,24 +96,24 @@ impl Binder {
             AccountMgrSource::ALL { level } => {
                 // ALL PRIVILEGES have different available privileges set on different grant objects
                 // Now in this case all is always true.
-                let grant_object = self.convert_to_grant_object(level);
-                let priv_types = grant_object.available_privileges();
+                let grant_objects = self.convert_to_grant_objects(level);
+                let priv_types = grant_objects[0].available_privileges();
                 let plan = RevokePrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: grant_objects,
                     priv_types,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
             }
             AccountMgrSource::Privs { privileges, level } => {
-                let grant_object = self.convert_to_grant_object(level);
+                let grant_objects = self.convert_to_grant_objects(level);
                 let mut priv_types = UserPrivilegeSet::empty();
                 for x in privileges {
                     priv_types.set_privilege(*x);
                 }
                 let plan = RevokePrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: grant_objects,
                     priv_types,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
@@ -121,25 +121,47 @@ impl Binder {
         }
     }
 
-    pub(in crate::planner::binder) fn convert_to_grant_object(
+    // Convert the privilege level to grant objects, column privileges
+    // are granted on each column separately.
+    pub(in crate::planner::binder) fn convert_to_grant_objects(
         &self,
         source: &AccountMgrLevel,
-    ) -> GrantObject {
+    ) -> Vec<GrantObject> {
         // TODO fetch real catalog
         let catalog_name = self.ctx.get_current_catalog();
         match source {
-            AccountMgrLevel::Global => GrantObject::Global,
+            AccountMgrLevel::Global => vec![GrantObject::Global],
             AccountMgrLevel::Table(database_name, table_name) => {
                 let database_name = database_name
                     .clone()
                     .unwrap_or_else(|| self.ctx.get_current_database());
-                GrantObject::Table(catalog_name, database_name, table_name.clone())
+                vec![GrantObject::Table(
+                    catalog_name,
+                    database_name,
+                    table_name.clone(),
+                )]
+            }
+            AccountMgrLevel::Column(database_name, table_name, columns) => {
+                let database_name = database_name
+                    .clone()
+                    .unwrap_or_else(|| self.ctx.get_current_database());
+                columns
+                    .iter()
+                    .map(|column| {
+                        GrantObject::Column(
+                            catalog_name.clone(),
+                            database_name.clone(),
+                            table_name.clone(),
+                            column.clone(),
+                        )
+                    })
+                    .collect()
             }
             AccountMgrLevel::Database(database_name) => {
                 let database_name = database_name
                     .clone()
                     .unwrap_or_else(|| self.ctx.get_current_database());
-                GrantObject::Database(catalog_name, database_name)
+                vec![GrantObject::Database(catalog_name, database_name)]
             }
         }
     }
@@ -159,6 +181,17 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        UserApiProvider::instance()
+            .verify_password(
+                &self.ctx.get_tenant(),
+                &user_option,
+                auth_option.password.as_ref(),
+                None,
+            )
+            .await?;
+        UserApiProvider::instance()
+            .verify_network_policy(&self.ctx.get_tenant(), user_option.network_policy())
+            .await?;
         let plan = CreateUserPlan {
             user: user.clone(),
             auth_info: AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?,
@@ -205,6 +238,20 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        let new_password = auth_option
+            .as_ref()
+            .and_then(|auth_option| auth_option.password.as_ref());
+        UserApiProvider::instance()
+            .verify_password(
+                &self.ctx.get_tenant(),
+                &user_option,
+                new_password,
+                Some(&user_info),
+            )
+            .await?;
+        UserApiProvider::instance()
+            .verify_network_policy(&self.ctx.get_tenant(), user_option.network_policy())
+            .await?;
         let new_user_option = if user_option == user_info.option {
             None
         } else {
@@ -17,7 +17,10 @@ mod catalog;
 mod column;
 mod database;
 mod role;
+mod security_policy;
 mod share;
 mod stage;
+mod stream;
 mod table;
+mod vector_index;
 mod view;
@@ -0,0 +1,176 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateMaskingPolicyStmt;
+use common_ast::ast::CreateRowAccessPolicyStmt;
+use common_ast::ast::Identifier;
+use common_ast::ast::TypeName;
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::binder::ColumnBinding;
+use crate::binder::ScalarBinder;
+use crate::binder::Visibility;
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::resolve_type_name;
+use crate::plans::CreateMaskingPolicyPlan;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::BindContext;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_masking_policy(
+        &mut self,
+        stmt: &CreateMaskingPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateMaskingPolicyStmt {
+            if_not_exists,
+            name,
+            args,
+            return_type,
+            body,
+            comment,
+        } = stmt;
+
+        let (args, arg_bindings) = self.analyze_policy_args(args)?;
+        let return_data_type = DataType::from(&resolve_type_name(return_type)?);
+        if arg_bindings[0].data_type.remove_nullable() != return_data_type.remove_nullable() {
+            return Err(ErrorCode::IllegalMaskingPolicy(format!(
+                "the return type of masking policy '{}' must be the same as the type of its first argument",
+                name
+            )));
+        }
+
+        // Make sure the body can be bound with the arguments.
+        let body = body.to_string();
+        self.bind_policy_body(&body, arg_bindings).await?;
+
+        Ok(Plan::CreateMaskingPolicy(Box::new(
+            CreateMaskingPolicyPlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                name: name.clone(),
+                args,
+                return_type: return_type.to_string(),
+                body,
+                comment: comment.clone().unwrap_or_default(),
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            if_not_exists,
+            name,
+            args,
+            body,
+            comment,
+        } = stmt;
+
+        let (args, arg_bindings) = self.analyze_policy_args(args)?;
+        let body = body.to_string();
+        let (_, data_type) = self.bind_policy_body(&body, arg_bindings).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "the body of row access policy '{}' must be a boolean expression, but got {}",
+                name, data_type
+            )));
+        }
+
+        Ok(Plan::CreateRowAccessPolicy(Box::new(
+            CreateRowAccessPolicyPlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                name: name.clone(),
+                args,
+                body,
+                comment: comment.clone().unwrap_or_default(),
+            },
+        )))
+    }
+
+    /// Bind the body of a masking policy or row access policy.
+    ///
+    /// The arguments of the policy are resolved to `arg_bindings`, whose column names
+    /// must be the argument names.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_policy_body(
+        &mut self,
+        body: &str,
+        arg_bindings: Vec<ColumnBinding>,
+    ) -> Result<(ScalarExpr, DataType)> {
+        let mut bind_context = BindContext::new();
+        for arg_binding in arg_bindings {
+            bind_context.add_column_binding(arg_binding);
+        }
+
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(body)?;
+        let expr = parse_expr(&tokens, sql_dialect)?;
+
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        scalar_binder.bind(&expr).await
+    }
+
+    // Normalize the arguments of a policy to (name, type) pairs,
+    // and create a column binding for each of them to check the body.
+    fn analyze_policy_args(
+        &mut self,
+        args: &[(Identifier, TypeName)],
+    ) -> Result<(Vec<(String, String)>, Vec<ColumnBinding>)> {
+        let mut normalized_args = Vec::with_capacity(args.len());
+        let mut arg_bindings = Vec::with_capacity(args.len());
+        for (arg_name, arg_type) in args {
+            let arg_name = normalize_identifier(arg_name, &self.name_resolution_ctx).name;
+            if normalized_args.iter().any(|(name, _)| name == &arg_name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicate argument name '{}'",
+                    arg_name
+                )));
+            }
+            let data_type = DataType::from(&resolve_type_name(arg_type)?);
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(arg_name.clone(), data_type.clone());
+            arg_bindings.push(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                table_index: None,
+                column_name: arg_name.clone(),
+                index,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+            });
+            normalized_args.push((arg_name, arg_type.to_string()));
+        }
+        Ok((normalized_args, arg_bindings))
+    }
+}
@@ -19,8 +19,10 @@ use std::sync::Arc;
 use common_ast::ast::AlterTableAction;
 use common_ast::ast::AlterTableStmt;
 use common_ast::ast::AnalyzeTableStmt;
+use common_ast::ast::CheckConstraint;
 use common_ast::ast::ColumnDefinition;
 use common_ast::ast::CompactTarget;
+use common_ast::ast::ComputedExpr as AstComputedExpr;
 use common_ast::ast::CreateTableSource;
 use common_ast::ast::CreateTableStmt;
 use common_ast::ast::DescribeTableStmt;
@@ -30,6 +32,7 @@ use common_ast::ast::ExistsTableStmt;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::Literal;
+use common_ast::ast::ModifyColumnAction;
 use common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use common_ast::ast::OptimizeTableStmt;
 use common_ast::ast::RenameTableStmt;
@@ -52,6 +55,7 @@ use common_exception::Result;
 use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
 use common_expression::types::DataType;
+use common_expression::ComputedExpr;
 use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
@@ -63,8 +67,11 @@ use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::OPT_KEY_CLONE_SOURCES;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use tracing::debug;
@@ -76,26 +83,38 @@ use crate::binder::Visibility;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
+use crate::planner::parse_check_constraint;
+use crate::planner::parse_computed_expr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
+use crate::planner::semantic::resolve_type_name_by_str;
 use crate::planner::semantic::IdentifierNormalizer;
+use crate::planner::sequence_of_default_expr;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CastExpr;
+use crate::plans::CloneSource;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableTagPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
+use crate::plans::DropTableTagPlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::ModifyTableColumnTypePlan;
 use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
 use crate::plans::ReclusterTablePlan;
+use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
+use crate::plans::SetTableColumnMaskingPolicyPlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
@@ -367,8 +386,87 @@ impl Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // `CREATE TABLE ... CLONE ...` shares the segments and blocks of the source table
+        let clone_source = match source {
+            Some(CreateTableSource::Clone {
+                catalog: source_catalog,
+                database: source_database,
+                table: source_table,
+                travel_point,
+            }) => {
+                if as_query.is_some() || uri_location.is_some() || engine != Engine::Fuse {
+                    return Err(ErrorCode::BadArguments(
+                        "CLONE can not be used with AS SELECT, location or non-FUSE engine",
+                    ));
+                }
+                let (source_catalog, source_database, source_table) = self
+                    .normalize_object_identifier_triple(
+                        source_catalog,
+                        source_database,
+                        source_table,
+                    );
+                let table = self
+                    .ctx
+                    .get_table(&source_catalog, &source_database, &source_table)
+                    .await?;
+                if table.engine().to_uppercase() != "FUSE" {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Only FUSE table can be cloned, but table {} is of engine {}",
+                        source_table,
+                        table.engine()
+                    )));
+                }
+                let source_id = table.get_id();
+                let table = match travel_point {
+                    Some(point) => {
+                        let point = self
+                            .resolve_data_travel_point(&mut BindContext::new(), point)
+                            .await?;
+                        table.navigate_to(&point).await?
+                    }
+                    None => table,
+                };
+
+                let table_info = table.get_table_info();
+                for (key, value) in table_info.options() {
+                    if !is_reserved_opt_key(key) && key != OPT_KEY_SNAPSHOT_LOCATION {
+                        options
+                            .entry(key.to_owned())
+                            .or_insert_with(|| value.to_owned());
+                    }
+                }
+                // Record the whole lineage, so that gc of any ancestor can find this table.
+                let clone_sources = match table_info.options().get(OPT_KEY_CLONE_SOURCES) {
+                    Some(sources) => format!("{},{}", sources, source_id),
+                    None => source_id.to_string(),
+                };
+                options.insert(OPT_KEY_CLONE_SOURCES.to_owned(), clone_sources);
+                Some((source_catalog, source_database, source_table, table))
+            }
+            _ => None,
+        };
+
+        let field_identities = match &source {
+            Some(CreateTableSource::Columns(columns, _)) => columns
+                .iter()
+                .map(|column| column.identity.clone())
+                .collect(),
+            _ => vec![],
+        };
+
         // Build table schema
         let (schema, field_default_exprs, field_comments) = match (&source, &as_query) {
+            (Some(CreateTableSource::Clone { .. }), None) => {
+                // Keep the column ids of the source table, which are referenced by its blocks
+                let (_, _, _, table) = clone_source.as_ref().unwrap();
+                let schema = table.schema();
+                let field_comments = if table.field_comments().len() == schema.num_fields() {
+                    table.field_comments().clone()
+                } else {
+                    vec![]
+                };
+                (schema, vec![], field_comments)
+            }
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 self.analyze_create_table_schema(source).await?
@@ -418,6 +516,14 @@ impl Binder {
             ))?,
         };
 
+        let check_constraints = match (&source, &clone_source) {
+            (Some(CreateTableSource::Columns(_, check_constraints)), _) => {
+                self.analyze_check_constraints(check_constraints, &schema)?
+            }
+            (_, Some((_, _, _, table))) => table.get_table_info().meta.check_constraints.clone(),
+            _ => BTreeMap::new(),
+        };
+
         if engine == Engine::Fuse {
             // Currently, [Table] can not accesses its database id yet, thus
             // here we keep the db id AS an entry of `table_meta.options`.
@@ -480,13 +586,36 @@ impl Binder {
             let keys = self
                 .analyze_cluster_keys(cluster_by, schema.clone())
                 .await?;
-            if keys.is_empty() {
-                None
-            } else {
+            if !keys.is_empty() {
                 Some(format!("({})", keys.join(", ")))
+            } else if let Some((_, _, _, table)) = &clone_source {
+                table.get_table_info().meta.default_cluster_key.clone()
+            } else {
+                None
             }
         };
 
+        let (storage_params, part_prefix, clone_source) = match clone_source {
+            Some((source_catalog, source_database, source_table, table)) => {
+                let table_info = table.get_table_info();
+                let clone_source = CloneSource {
+                    catalog: source_catalog,
+                    database: source_database,
+                    table: source_table,
+                    snapshot_location: table_info.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned(),
+                    column_mask_policy: table_info.meta.column_mask_policy.clone(),
+                    row_access_policy: table_info.meta.row_access_policy.clone(),
+                    row_access_policy_columns: table_info.meta.row_access_policy_columns.clone(),
+                };
+                (
+                    table_info.meta.storage_params.clone(),
+                    table_info.meta.part_prefix.clone(),
+                    Some(clone_source),
+                )
+            }
+            None => (storage_params, part_prefix, None),
+        };
+
         let plan = CreateTablePlan {
             if_not_exists: *if_not_exists,
             tenant: self.ctx.get_tenant(),
@@ -500,7 +629,10 @@ impl Binder {
             options,
             field_default_exprs,
             field_comments,
+            field_identities,
+            check_constraints,
             cluster_key,
+            clone_source,
             as_select: if let Some(query) = as_query {
                 let mut bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -607,9 +739,40 @@ impl Binder {
                 })))
             }
             AlterTableAction::AddColumn { column } => {
+                if column.identity.is_some() {
+                    return Err(ErrorCode::SemanticError(
+                        "AUTOINCREMENT column can not be added to an existing table",
+                    ));
+                }
                 let (schema, field_default_exprs, field_comments) = self
                     .analyze_create_table_schema_by_columns(&[column.clone()])
                     .await?;
+                if field_default_exprs
+                    .iter()
+                    .flatten()
+                    .any(|expr| sequence_of_default_expr(expr).is_some())
+                {
+                    return Err(ErrorCode::SemanticError(
+                        "column with a sequence as default can not be added to an existing table",
+                    ));
+                }
+                if let Some(computed_expr) = schema.field(0).computed_expr() {
+                    // The existing rows have no value for a stored computed column.
+                    if matches!(computed_expr, ComputedExpr::Stored(_)) {
+                        return Err(ErrorCode::SemanticError(
+                            "STORED computed column can not be added to an existing table",
+                        ));
+                    }
+                    let mut table_schema = self
+                        .ctx
+                        .get_table(&catalog, &database, &table)
+                        .await?
+                        .schema()
+                        .as_ref()
+                        .clone();
+                    table_schema.add_columns(schema.fields())?;
+                    self.validate_computed_exprs(&Arc::new(table_schema))?;
+                }
                 Ok(Plan::AddTableColumn(Box::new(AddTableColumnPlan {
                     catalog,
                     database,
@@ -619,7 +782,26 @@ impl Binder {
                     field_comments,
                 })))
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                let old_column = normalize_identifier(old_column, &self.name_resolution_ctx).name;
+                let new_column = normalize_identifier(new_column, &self.name_resolution_ctx).name;
+                self.check_column_not_referenced(&catalog, &database, &table, &old_column)
+                    .await?;
+                Ok(Plan::RenameTableColumn(Box::new(RenameTableColumnPlan {
+                    catalog,
+                    database,
+                    table,
+                    old_column,
+                    new_column,
+                })))
+            }
             AlterTableAction::DropColumn { column } => {
+                let column_name = normalize_identifier(column, &self.name_resolution_ctx).name;
+                self.check_column_not_referenced(&catalog, &database, &table, &column_name)
+                    .await?;
                 Ok(Plan::DropTableColumn(Box::new(DropTableColumnPlan {
                     catalog,
                     database,
@@ -657,6 +839,7 @@ impl Binder {
                 is_final,
                 selection,
             } => {
+                bind_context.ignore_security_policies = true;
                 let (_, mut context) = self
                     .bind_table_reference(bind_context, table_reference)
                     .await?;
@@ -696,6 +879,124 @@ impl Binder {
                     point,
                 })))
             }
+            AlterTableAction::ModifyColumn { column, action } => {
+                let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                let field = schema.field_with_name(&column)?;
+                let policy = match action {
+                    ModifyColumnAction::SetMaskingPolicy(policy) => {
+                        let policy = normalize_identifier(policy, &self.name_resolution_ctx).name;
+                        let masking_policy = UserApiProvider::instance()
+                            .get_masking_policy(&tenant, &policy)
+                            .await?;
+                        let return_type = resolve_type_name_by_str(&masking_policy.return_type)?;
+                        if return_type.remove_nullable() != field.data_type().remove_nullable() {
+                            return Err(ErrorCode::IllegalMaskingPolicy(format!(
+                                "masking policy '{}' returns {}, which does not match the type {} of column '{}'",
+                                policy,
+                                masking_policy.return_type,
+                                field.data_type(),
+                                column
+                            )));
+                        }
+                        for (arg, _) in masking_policy.args.iter().skip(1) {
+                            schema.field_with_name(arg)?;
+                        }
+                        Some(policy)
+                    }
+                    ModifyColumnAction::UnsetMaskingPolicy => None,
+                    ModifyColumnAction::SetDataType(type_name) => {
+                        let data_type = resolve_type_name(type_name)?;
+                        return Ok(Plan::ModifyTableColumnType(Box::new(
+                            ModifyTableColumnTypePlan {
+                                catalog,
+                                database,
+                                table,
+                                column,
+                                data_type,
+                            },
+                        )));
+                    }
+                };
+                Ok(Plan::SetTableColumnMaskingPolicy(Box::new(
+                    SetTableColumnMaskingPolicyPlan {
+                        catalog,
+                        database,
+                        table,
+                        column,
+                        policy,
+                    },
+                )))
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let policy = normalize_identifier(policy, &self.name_resolution_ctx).name;
+                let columns = columns
+                    .iter()
+                    .map(|c| normalize_identifier(c, &self.name_resolution_ctx).name)
+                    .collect::<Vec<_>>();
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                let row_access_policy = UserApiProvider::instance()
+                    .get_row_access_policy(&tenant, &policy)
+                    .await?;
+                if row_access_policy.args.len() != columns.len() {
+                    return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                        "row access policy '{}' requires {} columns, but got {}",
+                        policy,
+                        row_access_policy.args.len(),
+                        columns.len()
+                    )));
+                }
+                for column in columns.iter() {
+                    schema.field_with_name(column)?;
+                }
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        catalog,
+                        database,
+                        table,
+                        policy,
+                        columns,
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy => Ok(Plan::DropTableRowAccessPolicy(Box::new(
+                DropTableRowAccessPolicyPlan {
+                    catalog,
+                    database,
+                    table,
+                },
+            ))),
+            AlterTableAction::CreateTag { tag, travel_point } => {
+                let tag = normalize_identifier(tag, &self.name_resolution_ctx).name;
+                let navigation = match travel_point {
+                    Some(point) => Some(self.resolve_data_travel_point(bind_context, point).await?),
+                    None => None,
+                };
+                Ok(Plan::CreateTableTag(Box::new(CreateTableTagPlan {
+                    catalog,
+                    database,
+                    table,
+                    tag,
+                    navigation,
+                })))
+            }
+            AlterTableAction::DropTag { tag } => {
+                let tag = normalize_identifier(tag, &self.name_resolution_ctx).name;
+                Ok(Plan::DropTableTag(Box::new(DropTableTagPlan {
+                    catalog,
+                    database,
+                    table,
+                    tag,
+                })))
+            }
         }
     }
 
@@ -875,9 +1176,47 @@ impl Binder {
             let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
             let schema_data_type = resolve_type_name(&column.data_type)?;
 
-            fields.push(TableField::new(&name, schema_data_type.clone()));
+            if let Some(computed_expr) = &column.computed_expr {
+                if column.default_expr.is_some() || column.identity.is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "computed column {name} can not have DEFAULT or AUTOINCREMENT"
+                    )));
+                }
+                let computed_expr = match computed_expr {
+                    AstComputedExpr::Virtual(expr) => ComputedExpr::Virtual(expr.to_string()),
+                    AstComputedExpr::Stored(expr) => ComputedExpr::Stored(expr.to_string()),
+                };
+                fields.push(
+                    TableField::new(&name, schema_data_type.clone())
+                        .with_computed_expr(Some(computed_expr)),
+                );
+            } else {
+                fields.push(TableField::new(&name, schema_data_type.clone()));
+            }
+            if column.identity.is_some() {
+                if column.default_expr.is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "column {name} can not have both DEFAULT and AUTOINCREMENT"
+                    )));
+                }
+                if !DataType::from(&schema_data_type.remove_nullable()).is_integer() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "AUTOINCREMENT column {name} must be of an integer type"
+                    )));
+                }
+            }
             fields_default_expr.push({
-                if let Some(default_expr) = &column.default_expr {
+                if let Some(sequence) = column
+                    .default_expr
+                    .as_ref()
+                    .and_then(|expr| sequence_of_default_expr(&expr.to_string()))
+                {
+                    // Filled by the sequence when rows are inserted, don't take a value now.
+                    UserApiProvider::instance()
+                        .get_sequence(&self.ctx.get_tenant(), &sequence)
+                        .await?;
+                    Some(format!("nextval('{sequence}')"))
+                } else if let Some(default_expr) = &column.default_expr {
                     let (expr, _) = scalar_binder.bind(default_expr).await?;
                     let is_try = schema_data_type.is_nullable();
                     let cast_expr_to_field_type = ScalarExpr::CastExpr(CastExpr {
@@ -917,14 +1256,111 @@ impl Binder {
         Ok((schema, fields_default_expr, fields_comments))
     }
 
+    /// Computed expressions can only reference the columns that are not computed.
+    fn validate_computed_exprs(&self, schema: &TableSchemaRef) -> Result<()> {
+        let source_schema = Arc::new(schema.remove_computed_fields());
+        for field in schema.fields() {
+            if let Some(computed_expr) = field.computed_expr() {
+                parse_computed_expr(
+                    self.ctx.clone(),
+                    source_schema.clone(),
+                    computed_expr.expr(),
+                    &DataType::from(field.data_type()),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn analyze_check_constraints(
+        &self,
+        check_constraints: &[CheckConstraint],
+        schema: &TableSchemaRef,
+    ) -> Result<BTreeMap<String, String>> {
+        // CHECK constraints are evaluated on the written rows, which never carry virtual
+        // computed columns, so they can only refer to the stored columns.
+        let source_schema = Arc::new(schema.remove_virtual_computed_fields());
+        let mut constraints = BTreeMap::new();
+        for (i, constraint) in check_constraints.iter().enumerate() {
+            let name = match &constraint.name {
+                Some(name) => normalize_identifier(name, &self.name_resolution_ctx).name,
+                None => format!("check_{}", i + 1),
+            };
+            let expr = constraint.expr.to_string();
+            parse_check_constraint(self.ctx.clone(), source_schema.clone(), &expr)?;
+            if constraints.insert(name.clone(), expr).is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated check constraint {name}"
+                )));
+            }
+        }
+        Ok(constraints)
+    }
+
+    #[async_backtrace::framed]
+    async fn check_column_not_referenced(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        column: &str,
+    ) -> Result<()> {
+        let table = self.ctx.get_table(catalog, database, table).await?;
+        let schema = table.schema();
+        for field in schema.fields() {
+            if let Some(computed_expr) = field.computed_expr() {
+                let expr = parse_computed_expr(
+                    self.ctx.clone(),
+                    schema.clone(),
+                    computed_expr.expr(),
+                    &DataType::from(field.data_type()),
+                )?;
+                if expr
+                    .column_refs()
+                    .keys()
+                    .any(|index| schema.field(*index).name() == column)
+                {
+                    return Err(ErrorCode::AlterColumnError(format!(
+                        "column {column} is referenced by computed column {}",
+                        field.name()
+                    )));
+                }
+            }
+        }
+        for (name, check_expr) in table.get_table_info().meta.check_constraints.iter() {
+            let expr = parse_check_constraint(self.ctx.clone(), schema.clone(), check_expr)?;
+            if expr
+                .column_refs()
+                .keys()
+                .any(|index| schema.field(*index).name() == column)
+            {
+                return Err(ErrorCode::AlterColumnError(format!(
+                    "column {column} is referenced by check constraint {name}"
+                )));
+            }
+        }
+        // The vector indexes are defined as `<method>:<distance>:<column>`.
+        for (name, definition) in table.get_table_info().meta.vector_indexes.iter() {
+            if definition.splitn(3, ':').nth(2) == Some(column) {
+                return Err(ErrorCode::AlterColumnError(format!(
+                    "column {column} is referenced by vector index {name}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     async fn analyze_create_table_schema(
         &self,
         source: &CreateTableSource,
     ) -> Result<(TableSchemaRef, Vec<Option<String>>, Vec<String>)> {
         match source {
-            CreateTableSource::Columns(columns) => {
-                self.analyze_create_table_schema_by_columns(columns).await
+            CreateTableSource::Columns(columns, _) => {
+                let (schema, field_default_exprs, field_comments) =
+                    self.analyze_create_table_schema_by_columns(columns).await?;
+                self.validate_computed_exprs(&schema)?;
+                Ok((schema, field_default_exprs, field_comments))
             }
             CreateTableSource::Like {
                 catalog,
@@ -944,6 +1380,9 @@ impl Binder {
                     Ok((table.schema(), vec![], table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone { .. } => Err(ErrorCode::BadArguments(
+                "CLONE can not be used with AS SELECT",
+            )),
         }
     }
 
@@ -985,7 +1424,7 @@ impl Binder {
     #[async_backtrace::framed]
     async fn analyze_cluster_keys(
         &mut self,
-        cluster_by: &[Expr],
+        cluster_by_exprs: &[Expr],
         schema: TableSchemaRef,
     ) -> Result<Vec<String>> {
         // Build a temporary BindContext to resolve the expr
@@ -1010,8 +1449,8 @@ impl Binder {
             &[],
         );
 
-        let mut cluster_keys = Vec::with_capacity(cluster_by.len());
-        for cluster_by in cluster_by.iter() {
+        let mut cluster_keys = Vec::with_capacity(cluster_by_exprs.len());
+        for cluster_by in cluster_by_exprs.iter() {
             let (cluster_key, _) = scalar_binder.bind(cluster_by).await?;
             let expr = cluster_key.as_expr_with_col_index()?;
             if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
@@ -1020,6 +1459,20 @@ impl Binder {
                     cluster_by
                 )));
             }
+            // The key of a space-filling curve covers all the clustered columns,
+            // `CLUSTER BY HILBERT(a, b)` rather than `CLUSTER BY (HILBERT(a, b), c)`.
+            if cluster_by_exprs.len() > 1
+                && matches!(
+                    &expr,
+                    common_expression::Expr::FunctionCall { function, .. }
+                        if matches!(function.signature.name.as_str(), "hilbert" | "zorder")
+                )
+            {
+                return Err(ErrorCode::InvalidClusterKeys(format!(
+                    "Cluster by expression `{:#}` is a space-filling curve, which must be the only cluster key",
+                    cluster_by
+                )));
+            }
             let mut cluster_by = cluster_by.clone();
             walk_expr_mut(
                 &mut IdentifierNormalizer {
@@ -0,0 +1,127 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use common_ast::ast::CreateVectorIndexStmt;
+use common_ast::ast::DropVectorIndexStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::ComputedExpr;
+use common_expression::TableDataType;
+use common_vector::DistanceType;
+use common_vector::VectorIndexMethod;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::plans::CreateVectorIndexPlan;
+use crate::plans::DropVectorIndexPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_vector_index(
+        &mut self,
+        stmt: &CreateVectorIndexStmt,
+    ) -> Result<Plan> {
+        let CreateVectorIndexStmt {
+            if_not_exists,
+            index,
+            catalog,
+            database,
+            table,
+            column,
+            method,
+            distance,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index, &self.name_resolution_ctx).name;
+        let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+        let method = VectorIndexMethod::from_str(&method.name)?;
+        let distance = match distance {
+            Some(distance) => DistanceType::from_str(distance)?,
+            None => DistanceType::Cosine,
+        };
+
+        // Only the vectors of Float32 can be indexed, same as the arguments of
+        // the distance functions.
+        let schema = self
+            .ctx
+            .get_table(&catalog, &database, &table)
+            .await?
+            .schema();
+        let field = schema.field_with_name(&column)?;
+        let vector_type =
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Float32)));
+        if field.data_type().remove_nullable() != vector_type {
+            return Err(ErrorCode::SemanticError(format!(
+                "vector index can only be created on column of type ARRAY(FLOAT32), but column {} is {}",
+                column,
+                field.data_type()
+            )));
+        }
+        // The virtual computed columns are not stored, there are no vectors to index.
+        if let Some(ComputedExpr::Virtual(_)) = field.computed_expr() {
+            return Err(ErrorCode::SemanticError(format!(
+                "vector index can't be created on virtual computed column {}",
+                column
+            )));
+        }
+
+        let plan = CreateVectorIndexPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            table,
+            index_name,
+            column,
+            method,
+            distance,
+        };
+        Ok(Plan::CreateVectorIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_vector_index(
+        &mut self,
+        stmt: &DropVectorIndexStmt,
+    ) -> Result<Plan> {
+        let DropVectorIndexStmt {
+            if_exists,
+            index,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index, &self.name_resolution_ctx).name;
+        let plan = DropVectorIndexPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            table,
+            index_name,
+        };
+        Ok(Plan::DropVectorIndex(Box::new(plan)))
+    }
+}
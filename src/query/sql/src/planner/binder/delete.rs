@@ -50,9 +50,14 @@ impl<'a> Binder {
             ));
         };
 
+        // The selection is bound to the raw columns of the table, the masked columns are read
+        // through their masking policies and only the rows visible through the row access policy
+        // are deleted.
+        bind_context.ignore_security_policies = true;
         let (table_expr, mut context) = self
             .bind_table_reference(bind_context, table_reference)
             .await?;
+        let policies = self.bind_dml_security_policies(&context).await?;
 
         let mut scalar_binder = ScalarBinder::new(
             &mut context,
@@ -63,10 +68,12 @@ impl<'a> Binder {
         );
 
         let (selection, input_expr) = if let Some(expr) = filter {
-            let (scalar, _) = scalar_binder.bind(expr).await?;
-            if let ScalarExpr::SubqueryExpr(_) = scalar {
+            let (mut scalar, _) = scalar_binder.bind(expr).await?;
+            if let ScalarExpr::SubqueryExpr(subquery) = &mut scalar {
+                policies.mask_subquery(subquery)?;
+                let predicates = policies.row_filter().into_iter().chain([scalar]).collect();
                 let filter = Filter {
-                    predicates: vec![scalar],
+                    predicates,
                     is_having: false,
                 };
                 let filter_expr = SExpr::create_unary(filter.into(), table_expr);
@@ -74,10 +81,11 @@ impl<'a> Binder {
                 let filter_expr = rewriter.rewrite(&filter_expr)?;
                 (None, Some(filter_expr))
             } else {
-                (Some(scalar), None)
+                policies.mask(&mut scalar);
+                (policies.filter(Some(scalar)), None)
             }
         } else {
-            (None, None)
+            (policies.filter(None), None)
         };
 
         let plan = DeletePlan {
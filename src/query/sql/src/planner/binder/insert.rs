@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_ast::ast::InsertSource;
 use common_ast::ast::InsertStmt;
 use common_ast::ast::Statement;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::principal::FileFormatOptionsAst;
@@ -54,17 +55,23 @@ impl Binder {
         let table_id = table.get_id();
 
         let schema = if columns.is_empty() {
-            table.schema()
+            // Computed columns are not written directly.
+            Arc::new(table.schema().remove_computed_fields())
         } else {
             let schema = table.schema();
             let fields = columns
                 .iter()
                 .map(|ident| {
-                    schema
-                        .field_with_name(
-                            &normalize_identifier(ident, &self.name_resolution_ctx).name,
-                        )
-                        .map(|v| v.clone())
+                    let field = schema.field_with_name(
+                        &normalize_identifier(ident, &self.name_resolution_ctx).name,
+                    )?;
+                    if field.computed_expr().is_some() {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "The value specified for computed column '{}' is not allowed",
+                            field.name()
+                        )));
+                    }
+                    Ok(field.clone())
                 })
                 .collect::<Result<Vec<_>>>()?;
             TableSchemaRefExt::create(fields)
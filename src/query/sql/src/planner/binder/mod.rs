@@ -19,6 +19,7 @@ mod binder;
 /// SQL builders;
 mod builders;
 mod copy;
+mod cte;
 mod ddl;
 mod delete;
 mod distinct;
@@ -50,6 +51,8 @@ pub use bind_context::*;
 pub use binder::Binder;
 pub use builders::*;
 pub use copy::parse_stage_location;
+pub use cte::CteBinding;
+pub use cte::MaterializedCteInfo;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::parse_uri_location;
 pub use scalar::ScalarBinder;
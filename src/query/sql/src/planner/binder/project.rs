@@ -35,6 +35,7 @@ use crate::planner::binder::ColumnBinding;
 use crate::planner::semantic::compare_table_name;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::GroupingChecker;
+use crate::planner::semantic::TypeChecker;
 use crate::plans::BoundColumnRef;
 use crate::plans::EvalScalar;
 use crate::plans::ScalarExpr;
@@ -259,6 +260,22 @@ impl Binder {
                 }
             }
         }
+
+        // Virtual computed columns expanded from the wildcard are replaced by their expressions.
+        let mut type_checker = TypeChecker::new(
+            input_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        for item in output.items.iter_mut() {
+            if let ScalarExpr::BoundColumnRef(BoundColumnRef { span, column }) = &item.scalar {
+                let (span, column) = (*span, column.clone());
+                let box (scalar, _) = type_checker.resolve_column_binding(span, &column).await?;
+                item.scalar = scalar;
+            }
+        }
         Ok(output)
     }
 
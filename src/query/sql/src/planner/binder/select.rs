@@ -255,6 +255,7 @@ impl Binder {
         bind_context: &mut BindContext,
         query: &Query,
     ) -> Result<(SExpr, BindContext)> {
+        let mut materialized_ctes = vec![];
         if let Some(with) = &query.with {
             for cte in with.ctes.iter() {
                 let table_name = cte.alias.name.name.clone();
@@ -263,9 +264,14 @@ impl Binder {
                         "duplicate cte {table_name}"
                     )));
                 }
+                let materialized = self.materialize_cte(bind_context, query, with, cte)?;
+                if let Some(materialized) = &materialized {
+                    materialized_ctes.push(materialized.clone());
+                }
                 let cte_info = CteInfo {
                     columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
                     query: cte.query.clone(),
+                    materialized,
                 };
                 bind_context.ctes_map.insert(table_name, cte_info);
             }
@@ -308,6 +314,10 @@ impl Binder {
                 .await?;
         }
 
+        if !materialized_ctes.is_empty() {
+            s_expr = self.wrap_materialized_ctes(s_expr, &materialized_ctes);
+        }
+
         Ok((s_expr, bind_context))
     }
 
@@ -59,20 +59,35 @@ use common_storages_result_cache::ResultScan;
 use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
+use storages_common_table_meta::table::OPT_KEY_SOURCE_DATABASE_NAME;
+use storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_NAME;
 
 use crate::binder::copy::parse_file_location;
 use crate::binder::scalar::ScalarBinder;
+use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::table_args::bind_table_args;
 use crate::binder::Binder;
 use crate::binder::ColumnBinding;
+use crate::binder::CteBinding;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
 use crate::binder::Visibility;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
+use crate::plans::SubqueryExpr;
+use crate::plans::WindowFuncType;
 use crate::BaseTableColumn;
 use crate::BindContext;
 use crate::ColumnEntry;
@@ -270,9 +285,17 @@ impl Binder {
                             bind_context.view_info.is_some(),
                         );
 
-                        let (s_expr, mut bind_context) = self
+                        let ignore_security_policies = bind_context.ignore_security_policies;
+                        let (mut s_expr, mut bind_context) = self
                             .bind_base_table(bind_context, database.as_str(), table_index)
                             .await?;
+                        self.bind_check_constraints(&bind_context, table_index)
+                            .await?;
+                        if !ignore_security_policies {
+                            s_expr = self
+                                .apply_security_policies(&mut bind_context, table_index, s_expr)
+                                .await?;
+                        }
                         if let Some(alias) = alias {
                             bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                         }
@@ -584,6 +607,56 @@ impl Binder {
         table_name: &str,
         alias: &Option<TableAlias>,
         cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        if let Some(materialized) = &cte_info.materialized {
+            let binding = materialized.binding.read().clone();
+            let (s_expr, mut new_bind_context) = match binding {
+                CteBinding::Unbound => {
+                    let (s_expr, cte_bind_context) =
+                        self.bind_cte_query(bind_context, cte_info).await?;
+                    let prop = RelExpr::with_s_expr(&s_expr).derive_relational_prop()?;
+                    if prop.outer_columns.is_empty() {
+                        let cte_scan = self.bind_cte_scan(
+                            bind_context,
+                            materialized.cte_idx,
+                            &cte_bind_context.columns,
+                            prop.cardinality,
+                        )?;
+                        *materialized.binding.write() = CteBinding::Bound {
+                            s_expr,
+                            columns: cte_bind_context.columns,
+                            cardinality: prop.cardinality,
+                        };
+                        cte_scan
+                    } else {
+                        // A correlated CTE can't be computed only once.
+                        *materialized.binding.write() = CteBinding::Inlined;
+                        (s_expr, cte_bind_context)
+                    }
+                }
+                CteBinding::Bound {
+                    columns,
+                    cardinality,
+                    ..
+                } => {
+                    self.bind_cte_scan(bind_context, materialized.cte_idx, &columns, cardinality)?
+                }
+                CteBinding::Inlined => self.bind_cte_query(bind_context, cte_info).await?,
+            };
+            self.rename_cte_columns(span, table_name, alias, cte_info, &mut new_bind_context)?;
+            return Ok((s_expr, new_bind_context));
+        }
+
+        let (s_expr, mut new_bind_context) = self.bind_cte_query(bind_context, cte_info).await?;
+        self.rename_cte_columns(span, table_name, alias, cte_info, &mut new_bind_context)?;
+        Ok((s_expr, new_bind_context))
+    }
+
+    #[async_backtrace::framed]
+    async fn bind_cte_query(
+        &mut self,
+        bind_context: &BindContext,
+        cte_info: &CteInfo,
     ) -> Result<(SExpr, BindContext)> {
         let mut new_bind_context = BindContext {
             parent: Some(Box::new(bind_context.clone())),
@@ -596,10 +669,20 @@ impl Binder {
             view_info: None,
             srfs: Default::default(),
             expr_context: ExprContext::default(),
+            ignore_security_policies: false,
         };
-        let (s_expr, mut new_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        self.bind_query(&mut new_bind_context, &cte_info.query)
+            .await
+    }
+
+    fn rename_cte_columns(
+        &self,
+        span: Span,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        cte_info: &CteInfo,
+        new_bind_context: &mut BindContext,
+    ) -> Result<()> {
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -630,7 +713,7 @@ impl Binder {
         for (index, column_name) in cols_alias.iter().enumerate() {
             new_bind_context.columns[index].column_name = column_name.clone();
         }
-        Ok((s_expr, new_bind_context))
+        Ok(())
     }
 
     #[async_backtrace::framed]
@@ -717,6 +800,7 @@ impl Binder {
                         col_stats,
                     },
                     prewhere: None,
+                    vector_top_k: None,
                 }
                 .into(),
             ),
@@ -724,6 +808,260 @@ impl Binder {
         ))
     }
 
+    // Bind the CHECK constraints of the table to its columns, the optimizer
+    // can assume that they hold for every row of the table.
+    #[async_backtrace::framed]
+    async fn bind_check_constraints(
+        &mut self,
+        bind_context: &BindContext,
+        table_index: IndexType,
+    ) -> Result<()> {
+        let table = self.metadata.read().table(table_index).table();
+        let check_constraints = &table.get_table_info().meta.check_constraints;
+        if check_constraints.is_empty() {
+            return Ok(());
+        }
+
+        let column_bindings = bind_context
+            .columns
+            .iter()
+            .filter(|column| column.table_index == Some(table_index))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut scalars = Vec::with_capacity(check_constraints.len());
+        for check_expr in check_constraints.values() {
+            let (scalar, _) = self
+                .bind_policy_body(check_expr, column_bindings.clone())
+                .await?;
+            scalars.push(scalar);
+        }
+        self.metadata
+            .write()
+            .set_table_check_constraints(table_index, scalars);
+        Ok(())
+    }
+
+    /// Inject the data security policies attached to the table into the scan.
+    ///
+    /// The rows are filtered by the row access policy, and each masked column is
+    /// replaced with a derived column evaluated by its masking policy, so the
+    /// policies are transparent to the rest of the query.
+    #[async_backtrace::framed]
+    async fn apply_security_policies(
+        &mut self,
+        bind_context: &mut BindContext,
+        table_index: IndexType,
+        mut s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let policies = self
+            .bind_security_policies(bind_context, table_index)
+            .await?;
+
+        if let Some(predicate) = &policies.row_filter {
+            let filter = Filter {
+                predicates: split_conjunctions(predicate),
+                is_having: false,
+            };
+            s_expr = SExpr::create_unary(filter.into(), s_expr);
+        }
+
+        if !policies.masks.is_empty() {
+            let mut items = Vec::with_capacity(policies.masks.len());
+            let mut masked_columns = Vec::with_capacity(policies.masks.len());
+            for mask in policies.masks {
+                let column = &bind_context.columns[mask.position];
+                let index = self
+                    .metadata
+                    .write()
+                    .add_derived_column(column.column_name.clone(), *column.data_type.clone());
+                items.push(ScalarItem {
+                    scalar: mask.scalar,
+                    index,
+                });
+                masked_columns.push((mask.position, index));
+            }
+            s_expr = SExpr::create_unary(EvalScalar { items }.into(), s_expr);
+            for (position, index) in masked_columns {
+                bind_context.columns[position].index = index;
+            }
+        }
+        Ok(s_expr)
+    }
+
+    /// Bind the data security policies of the target table of UPDATE or DELETE, which is
+    /// bound to its raw columns with `ignore_security_policies`.
+    ///
+    /// The statement shall read the masked columns through [`SecurityPolicies::mask`], and
+    /// only touch the rows selected by [`SecurityPolicies::filter`].
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_dml_security_policies(
+        &mut self,
+        bind_context: &BindContext,
+    ) -> Result<SecurityPolicies> {
+        match bind_context
+            .columns
+            .iter()
+            .find_map(|column| column.table_index)
+        {
+            Some(table_index) => self.bind_security_policies(bind_context, table_index).await,
+            None => Ok(SecurityPolicies::default()),
+        }
+    }
+
+    // Bind the policies attached to the table to its raw columns in `bind_context`.
+    #[async_backtrace::framed]
+    async fn bind_security_policies(
+        &mut self,
+        bind_context: &BindContext,
+        table_index: IndexType,
+    ) -> Result<SecurityPolicies> {
+        let table = self.metadata.read().table(table_index).table();
+        // The changes read from a stream are protected by the policies of its source table.
+        let table = self.stream_source_table(table).await?;
+        let table_meta = &table.get_table_info().meta;
+        let mut policies = SecurityPolicies::default();
+        if table_meta.row_access_policy.is_none() && table_meta.column_mask_policy.is_empty() {
+            return Ok(policies);
+        }
+
+        // The result depends on the current role.
+        self.ctx.set_cacheable(false);
+        let tenant = self.ctx.get_tenant();
+
+        // Both kinds of policies are bound to the raw columns.
+        if let Some(policy) = &table_meta.row_access_policy {
+            let policy = UserApiProvider::instance()
+                .get_row_access_policy(&tenant, policy)
+                .await?;
+            let arg_bindings = Self::policy_arg_bindings(
+                bind_context,
+                &policy.args,
+                &table_meta.row_access_policy_columns,
+            )?;
+            let (mut predicate, _) = self.bind_policy_body(&policy.body, arg_bindings).await?;
+            Self::restore_policy_columns(bind_context, &mut predicate);
+            policies.row_filter = Some(predicate);
+        }
+
+        for (column, policy) in table_meta.column_mask_policy.iter() {
+            let position = match bind_context
+                .columns
+                .iter()
+                .position(|c| &c.column_name == column && c.visibility == Visibility::Visible)
+            {
+                Some(position) => position,
+                None => continue,
+            };
+            let policy = UserApiProvider::instance()
+                .get_masking_policy(&tenant, policy)
+                .await?;
+            // The first argument is the masked column, and the others are
+            // the columns with the same names.
+            let columns = std::iter::once(column.clone())
+                .chain(policy.args.iter().skip(1).map(|(arg, _)| arg.clone()))
+                .collect::<Vec<_>>();
+            let arg_bindings = Self::policy_arg_bindings(bind_context, &policy.args, &columns)?;
+            let (mut scalar, data_type) = self.bind_policy_body(&policy.body, arg_bindings).await?;
+            Self::restore_policy_columns(bind_context, &mut scalar);
+
+            let column_type = bind_context.columns[position].data_type.clone();
+            if data_type != *column_type {
+                scalar = CastExpr {
+                    span: None,
+                    is_try: false,
+                    argument: Box::new(scalar),
+                    target_type: column_type,
+                }
+                .into();
+            }
+            policies.masks.push(ColumnMask {
+                position,
+                column_index: bind_context.columns[position].index,
+                scalar,
+            });
+        }
+        Ok(policies)
+    }
+
+    // The source table of a stream, other tables are returned as is.
+    #[async_backtrace::framed]
+    async fn stream_source_table(&self, table: Arc<dyn Table>) -> Result<Arc<dyn Table>> {
+        if table.engine() != "STREAM" {
+            return Ok(table);
+        }
+        let options = table.options();
+        let source = match (
+            options.get(OPT_KEY_SOURCE_DATABASE_NAME),
+            options.get(OPT_KEY_SOURCE_TABLE_NAME),
+        ) {
+            (Some(database), Some(name)) => {
+                self.ctx
+                    .get_table(&table.get_table_info().meta.catalog, database, name)
+                    .await?
+            }
+            _ => {
+                return Err(ErrorCode::IllegalStream(format!(
+                    "Invalid stream {}, the source table is unknown",
+                    table.name()
+                )));
+            }
+        };
+        if options.get(OPT_KEY_SOURCE_TABLE_ID) != Some(&source.get_id().to_string()) {
+            return Err(ErrorCode::IllegalStream(format!(
+                "The source table of stream {} has been dropped or recreated",
+                table.name()
+            )));
+        }
+        Ok(source)
+    }
+
+    // Bind the references to the policy arguments in a bound policy body back to the columns
+    // of the table, the DML statements resolve the columns by names.
+    fn restore_policy_columns(bind_context: &BindContext, scalar: &mut ScalarExpr) {
+        replace_column_refs(scalar, &|column_ref| {
+            bind_context
+                .columns
+                .iter()
+                .find(|c| c.index == column_ref.column.index)
+                .map(|column| {
+                    BoundColumnRef {
+                        span: column_ref.span,
+                        column: column.clone(),
+                    }
+                    .into()
+                })
+        });
+    }
+
+    // Bind the arguments of a policy to the columns of the table, renamed as the arguments.
+    fn policy_arg_bindings(
+        bind_context: &BindContext,
+        args: &[(String, String)],
+        columns: &[String],
+    ) -> Result<Vec<ColumnBinding>> {
+        args.iter()
+            .zip(columns.iter())
+            .map(|((arg, _), column)| {
+                let column_binding = bind_context
+                    .columns
+                    .iter()
+                    .find(|c| &c.column_name == column)
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(format!(
+                            "column '{}' referenced by policy argument '{}' does not exist",
+                            column, arg
+                        ))
+                    })?;
+                Ok(ColumnBinding {
+                    database_name: None,
+                    table_name: None,
+                    column_name: arg.clone(),
+                    ..column_binding.clone()
+                })
+            })
+            .collect()
+    }
+
     #[async_backtrace::framed]
     async fn resolve_data_source(
         &self,
@@ -751,6 +1089,9 @@ impl Binder {
     ) -> Result<NavigationPoint> {
         match travel_point {
             TimeTravelPoint::Snapshot(s) => Ok(NavigationPoint::SnapshotID(s.to_owned())),
+            TimeTravelPoint::Tag(tag) => Ok(NavigationPoint::Tag(
+                normalize_identifier(tag, &self.name_resolution_ctx).name,
+            )),
             TimeTravelPoint::Timestamp(expr) => {
                 let mut type_checker = TypeChecker::new(
                     bind_context,
@@ -801,3 +1142,118 @@ pub fn parse_result_scan_args(table_args: &TableArgs) -> Result<String> {
     let args = table_args.expect_all_positioned("RESULT_SCAN", Some(1))?;
     string_value(&args[0])
 }
+
+/// The data security policies of a table bound to its raw columns.
+#[derive(Default)]
+pub(in crate::planner::binder) struct SecurityPolicies {
+    // the predicate of the row access policy
+    row_filter: Option<ScalarExpr>,
+    masks: Vec<ColumnMask>,
+}
+
+struct ColumnMask {
+    // the position of the masked column in the bind context
+    position: usize,
+    // the index of the raw masked column
+    column_index: IndexType,
+    scalar: ScalarExpr,
+}
+
+impl SecurityPolicies {
+    /// Read the masked columns referenced by `scalar` through their masking policies.
+    pub fn mask(&self, scalar: &mut ScalarExpr) {
+        replace_column_refs(scalar, &|column_ref| {
+            self.masks
+                .iter()
+                .find(|mask| mask.column_index == column_ref.column.index)
+                .map(|mask| mask.scalar.clone())
+        });
+    }
+
+    /// Read the masked columns compared with the result of `subquery` through their masking
+    /// policies, the subquery can't be correlated with a masked column.
+    pub fn mask_subquery(&self, subquery: &mut SubqueryExpr) -> Result<()> {
+        if self
+            .masks
+            .iter()
+            .any(|mask| subquery.outer_columns.contains(&mask.column_index))
+        {
+            return Err(ErrorCode::SemanticError(
+                "subquery can't be correlated with a masked column",
+            )
+            .set_span(subquery.span));
+        }
+        if let Some(child_expr) = subquery.child_expr.as_mut() {
+            self.mask(child_expr);
+        }
+        Ok(())
+    }
+
+    /// The predicate of the row access policy.
+    pub fn row_filter(&self) -> Option<ScalarExpr> {
+        self.row_filter.clone()
+    }
+
+    /// Restrict the `selection` of the statement to the rows visible through the row access
+    /// policy, `None` selects all the rows.
+    pub fn filter(&self, selection: Option<ScalarExpr>) -> Option<ScalarExpr> {
+        match (&self.row_filter, selection) {
+            (Some(row_filter), Some(selection)) => Some(
+                FunctionCall {
+                    span: None,
+                    func_name: "and".to_string(),
+                    params: vec![],
+                    arguments: vec![row_filter.clone(), selection],
+                }
+                .into(),
+            ),
+            (Some(row_filter), None) => Some(row_filter.clone()),
+            (None, selection) => selection,
+        }
+    }
+}
+
+// Replace the column references in `scalar` with the results of `f`, the replacements are not
+// visited again.
+fn replace_column_refs(
+    scalar: &mut ScalarExpr,
+    f: &impl Fn(&BoundColumnRef) -> Option<ScalarExpr>,
+) {
+    match scalar {
+        ScalarExpr::BoundColumnRef(column_ref) => {
+            if let Some(replacement) = f(column_ref) {
+                *scalar = replacement;
+            }
+        }
+        ScalarExpr::WindowFunction(expr) => {
+            if let WindowFuncType::Aggregate(agg) = &mut expr.func {
+                for arg in agg.args.iter_mut() {
+                    replace_column_refs(arg, f);
+                }
+            }
+            for arg in expr.partition_by.iter_mut() {
+                replace_column_refs(arg, f);
+            }
+            for arg in expr.order_by.iter_mut() {
+                replace_column_refs(&mut arg.expr, f);
+            }
+        }
+        ScalarExpr::AggregateFunction(expr) => {
+            for arg in expr.args.iter_mut() {
+                replace_column_refs(arg, f);
+            }
+        }
+        ScalarExpr::FunctionCall(expr) => {
+            for arg in expr.arguments.iter_mut() {
+                replace_column_refs(arg, f);
+            }
+        }
+        ScalarExpr::CastExpr(expr) => {
+            replace_column_refs(&mut expr.argument, f);
+        }
+        // The policies and the statements reject subqueries.
+        ScalarExpr::BoundInternalColumnRef(_)
+        | ScalarExpr::ConstantExpr(_)
+        | ScalarExpr::SubqueryExpr(_) => {}
+    }
+}
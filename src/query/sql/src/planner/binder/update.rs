@@ -63,7 +63,12 @@ impl Binder {
             ));
         };
 
+        // The selection and update list are bound to the raw columns of the table, the masked
+        // columns are read through their masking policies and only the rows visible through the
+        // row access policy are updated.
+        bind_context.ignore_security_policies = true;
         let (_, mut context) = self.bind_table_reference(bind_context, table).await?;
+        let policies = self.bind_dml_security_policies(&context).await?;
 
         let table = self
             .ctx
@@ -82,6 +87,12 @@ impl Binder {
         for update_expr in update_list {
             let col_name = normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
             let index = schema.index_of(&col_name)?;
+            if schema.field(index).computed_expr().is_some() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The value specified for computed column '{}' is not allowed",
+                    col_name
+                )));
+            }
             if update_columns.contains_key(&index) {
                 return Err(ErrorCode::BadArguments(format!(
                     "Multiple assignments in the single statement to column `{}`",
@@ -90,22 +101,24 @@ impl Binder {
             }
 
             // TODO(zhyass): selection and update_list support subquery.
-            let (scalar, _) = scalar_binder.bind(&update_expr.expr).await?;
+            let (mut scalar, _) = scalar_binder.bind(&update_expr.expr).await?;
             if matches!(scalar, ScalarExpr::SubqueryExpr(_)) {
                 return Err(ErrorCode::Internal(
                     "Update does not support subquery temporarily",
                 ));
             }
+            policies.mask(&mut scalar);
             update_columns.insert(index, scalar);
         }
 
         let push_downs = if let Some(expr) = selection {
-            let (scalar, _) = scalar_binder.bind(expr).await?;
+            let (mut scalar, _) = scalar_binder.bind(expr).await?;
             if matches!(scalar, ScalarExpr::SubqueryExpr(_)) {
                 return Err(ErrorCode::Internal(
                     "Update does not support subquery temporarily",
                 ));
             }
+            policies.mask(&mut scalar);
             Some(scalar)
         } else {
             None
@@ -116,7 +129,7 @@ impl Binder {
             database: database_name,
             table: table_name,
             update_list: update_columns,
-            selection: push_downs,
+            selection: policies.filter(push_downs),
             bind_context: Box::new(context.clone()),
         };
         Ok(Plan::Update(Box::new(plan)))
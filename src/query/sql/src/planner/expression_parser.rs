@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::Expr as AExpr;
+use common_ast::ast::Literal;
 use common_ast::parser::parse_comma_separated_exprs;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
@@ -24,6 +26,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::type_check::check_cast;
 use common_expression::types::DataType;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
@@ -32,6 +35,7 @@ use common_expression::FunctionContext;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableField;
+use common_expression::TableSchemaRef;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableInfo;
 use common_settings::Settings;
@@ -137,6 +141,107 @@ pub fn parse_to_remote_string_expr(
     }
 }
 
+/// Parses the expression of a computed field of `schema`, the column references of the
+/// returned expression are the indices of the fields in `schema`, and the expression is
+/// cast to `data_type`.
+pub fn parse_computed_expr(
+    ctx: Arc<dyn TableContext>,
+    schema: TableSchemaRef,
+    sql: &str,
+    data_type: &DataType,
+) -> Result<Expr> {
+    let expr = parse_schema_expr(ctx, schema, sql)?;
+    if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "computed expression {sql} is not deterministic"
+        )));
+    }
+    if expr.data_type() == data_type {
+        Ok(expr)
+    } else {
+        check_cast(
+            None,
+            data_type.is_nullable(),
+            expr,
+            data_type,
+            &BUILTIN_FUNCTIONS,
+        )
+    }
+}
+
+/// Parses the expression of a CHECK constraint over `schema`, the column references of the
+/// returned expression are the indices of the fields in `schema`. The expression must be a
+/// deterministic boolean predicate.
+pub fn parse_check_constraint(
+    ctx: Arc<dyn TableContext>,
+    schema: TableSchemaRef,
+    sql: &str,
+) -> Result<Expr> {
+    let expr = parse_schema_expr(ctx, schema, sql)?;
+    if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "check constraint {sql} is not deterministic"
+        )));
+    }
+    if expr.data_type().remove_nullable() != DataType::Boolean {
+        return Err(ErrorCode::SemanticError(format!(
+            "check constraint {sql} must be a boolean expression, but got {}",
+            expr.data_type()
+        )));
+    }
+    Ok(expr)
+}
+
+fn parse_schema_expr(
+    ctx: Arc<dyn TableContext>,
+    schema: TableSchemaRef,
+    sql: &str,
+) -> Result<Expr> {
+    let settings = Settings::create("".to_string());
+    let mut bind_context = BindContext::new();
+    let mut metadata = Metadata::default();
+    for (index, field) in schema.fields().iter().enumerate() {
+        bind_context.add_column_binding(ColumnBinding {
+            database_name: None,
+            table_name: None,
+            table_index: None,
+            column_name: field.name().clone(),
+            index,
+            data_type: Box::new(field.data_type().into()),
+            visibility: Visibility::Visible,
+        });
+        metadata.add_base_table_column(
+            field.name().clone(),
+            field.data_type().clone(),
+            0,
+            None,
+            None,
+            None,
+        );
+    }
+
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut type_checker = TypeChecker::new(
+        &mut bind_context,
+        ctx,
+        &name_resolution_ctx,
+        Arc::new(RwLock::new(metadata)),
+        &[],
+    );
+
+    let tokens = tokenize_sql(sql)?;
+    let mut ast_exprs = parse_comma_separated_exprs(&tokens, Dialect::MySQL)?;
+    if ast_exprs.len() != 1 {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "Expected single expr, but got {}",
+            ast_exprs.len()
+        )));
+    }
+    let ast = ast_exprs.remove(0);
+    let (scalar, _) = *block_in_place(|| Handle::current().block_on(type_checker.resolve(&ast)))?;
+    scalar.as_expr_with_col_index()
+}
+
 #[derive(Default)]
 struct DummyTable {
     info: TableInfo,
@@ -151,11 +256,39 @@ impl Table for DummyTable {
     }
 }
 
+/// Returns the sequence name if the default expr is `nextval('<sequence>')`.
+///
+/// Such a column is filled with the values of the sequence when rows are inserted,
+/// instead of a constant.
+pub fn sequence_of_default_expr(default_expr: &str) -> Option<String> {
+    let tokens = tokenize_sql(default_expr).ok()?;
+    let exprs = parse_comma_separated_exprs(&tokens, Dialect::MySQL).ok()?;
+    match exprs.as_slice() {
+        [AExpr::FunctionCall { name, args, .. }] if name.name.eq_ignore_ascii_case("nextval") => {
+            match args.as_slice() {
+                [
+                    AExpr::Literal {
+                        lit: Literal::String(sequence),
+                        ..
+                    },
+                ] => Some(sequence.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Result<Scalar> {
     let data_type = field.data_type();
     let data_type = DataType::from(data_type);
 
     match field.default_expr() {
+        // The values of a sequence are generated when rows are inserted,
+        // existing rows get the default value of the type.
+        Some(default_expr) if sequence_of_default_expr(default_expr).is_some() => {
+            Ok(Scalar::default_value(&data_type))
+        }
         Some(default_expr) => {
             let table: Arc<dyn Table> = Arc::new(DummyTable::default());
             let mut expr = parse_exprs(ctx.clone(), table.clone(), default_expr)?;
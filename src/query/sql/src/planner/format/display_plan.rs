@@ -59,6 +59,13 @@ impl Plan {
             Plan::RenameTable(rename_table) => Ok(format!("{:?}", rename_table)),
             Plan::AddTableColumn(add_table_column) => Ok(format!("{:?}", add_table_column)),
             Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
+            Plan::RenameTableColumn(p) => Ok(format!("{:?}", p)),
+            Plan::ModifyTableColumnType(p) => Ok(format!("{:?}", p)),
+            Plan::SetTableColumnMaskingPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::AddTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::CreateTableTag(p) => Ok(format!("{:?}", p)),
+            Plan::DropTableTag(p) => Ok(format!("{:?}", p)),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
             }
@@ -75,6 +82,12 @@ impl Plan {
             Plan::CreateView(create_view) => Ok(format!("{:?}", create_view)),
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
+            Plan::CreateVectorIndex(p) => Ok(format!("{:?}", p)),
+            Plan::DropVectorIndex(p) => Ok(format!("{:?}", p)),
+            Plan::CreateSequence(create_sequence) => Ok(format!("{:?}", create_sequence)),
+            Plan::DropSequence(drop_sequence) => Ok(format!("{:?}", drop_sequence)),
 
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
@@ -92,6 +105,25 @@ impl Plan {
             Plan::DropFileFormat(drop_file_format) => Ok(format!("{:?}", drop_file_format)),
             Plan::ShowFileFormats(show_file_formats) => Ok(format!("{:?}", show_file_formats)),
 
+            // Password policy
+            Plan::CreatePasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::AlterPasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropPasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescPasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::ShowPasswordPolicies(p) => Ok(format!("{:?}", p)),
+
+            // Network policy
+            Plan::CreateNetworkPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::AlterNetworkPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropNetworkPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescNetworkPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::CreateMaskingPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropMaskingPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescMaskingPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::CreateRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+
             // Account
             Plan::GrantRole(grant_role) => Ok(format!("{:?}", grant_role)),
             Plan::GrantPriv(grant_priv) => Ok(format!("{:?}", grant_priv)),
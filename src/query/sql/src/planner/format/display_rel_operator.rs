@@ -76,6 +76,8 @@ impl Display for FormatContext {
                 RelOperator::RuntimeFilterSource(_) => write!(f, "RuntimeFilterSource"),
                 RelOperator::Window(_) => write!(f, "WindowFunc"),
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
+                RelOperator::MaterializedCte(op) => write!(f, "MaterializedCte: {}", op.cte_idx),
+                RelOperator::CteScan(op) => write!(f, "CteScan: {}", op.cte_idx),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
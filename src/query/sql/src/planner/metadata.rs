@@ -23,11 +23,14 @@ use common_ast::ast::Literal;
 use common_catalog::plan::InternalColumn;
 use common_catalog::table::Table;
 use common_expression::types::DataType;
+use common_expression::ComputedExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use parking_lot::RwLock;
 
+use crate::ScalarExpr;
+
 /// Planner use [`usize`] as it's index type.
 ///
 /// This type will be used across the whole planner.
@@ -52,6 +55,8 @@ pub type MetadataRef = Arc<RwLock<Metadata>>;
 pub struct Metadata {
     tables: Vec<TableEntry>,
     columns: Vec<ColumnEntry>,
+    /// Number of materialized common table expressions in the query.
+    materialized_ctes: usize,
 }
 
 impl Metadata {
@@ -63,6 +68,17 @@ impl Metadata {
         self.tables.as_slice()
     }
 
+    pub fn set_table_check_constraints(
+        &mut self,
+        index: IndexType,
+        check_constraints: Vec<ScalarExpr>,
+    ) {
+        self.tables
+            .get_mut(index)
+            .expect("metadata must contain table")
+            .check_constraints = check_constraints;
+    }
+
     pub fn table_index_by_column_indexes(&self, column_indexes: &ColumnSet) -> Option<IndexType> {
         self.columns.iter().find_map(|v| match v {
             ColumnEntry::BaseTableColumn(BaseTableColumn {
@@ -139,6 +155,7 @@ impl Metadata {
         table_index: IndexType,
         path_indices: Option<Vec<IndexType>>,
         leaf_index: Option<IndexType>,
+        virtual_computed_expr: Option<String>,
     ) -> IndexType {
         let column_index = self.columns.len();
         let column_entry = ColumnEntry::BaseTableColumn(BaseTableColumn {
@@ -148,6 +165,7 @@ impl Metadata {
             table_index,
             path_indices,
             leaf_index,
+            virtual_computed_expr,
         });
         self.columns.push(column_entry);
         column_index
@@ -164,6 +182,13 @@ impl Metadata {
         column_index
     }
 
+    /// Allocate an index for a materialized common table expression.
+    pub fn add_materialized_cte(&mut self) -> IndexType {
+        let cte_idx = self.materialized_ctes;
+        self.materialized_ctes += 1;
+        cte_idx
+    }
+
     pub fn add_internal_column(
         &mut self,
         table_index: IndexType,
@@ -221,6 +246,7 @@ impl Metadata {
             table: table_meta.clone(),
             alias_name: table_alias_name,
             source_of_view,
+            check_constraints: vec![],
         };
         self.tables.push(table_entry);
         let mut fields = VecDeque::new();
@@ -237,6 +263,11 @@ impl Metadata {
                 None
             };
 
+            let virtual_computed_expr = match field.computed_expr() {
+                Some(ComputedExpr::Virtual(expr)) => Some(expr.clone()),
+                _ => None,
+            };
+
             // TODO handle Tuple inside Array.
             if let TableDataType::Tuple {
                 fields_name,
@@ -249,6 +280,7 @@ impl Metadata {
                     table_index,
                     path_indices,
                     None,
+                    virtual_computed_expr,
                 );
 
                 let mut i = fields_type.len();
@@ -270,6 +302,7 @@ impl Metadata {
                     table_index,
                     path_indices,
                     Some(leaf_index),
+                    virtual_computed_expr,
                 );
                 leaf_index += 1;
             }
@@ -297,6 +330,8 @@ pub struct TableEntry {
     alias_name: Option<String>,
     index: IndexType,
     source_of_view: bool,
+    /// The CHECK constraints of the table bound to its columns, they hold for every row.
+    check_constraints: Vec<ScalarExpr>,
 
     table: Arc<dyn Table>,
 }
@@ -329,6 +364,7 @@ impl TableEntry {
             table,
             alias_name,
             source_of_view: false,
+            check_constraints: vec![],
         }
     }
 
@@ -366,6 +402,11 @@ impl TableEntry {
     pub fn is_source_of_view(&self) -> bool {
         self.source_of_view
     }
+
+    /// Get the bound CHECK constraints of this table entry.
+    pub fn check_constraints(&self) -> &[ScalarExpr] {
+        &self.check_constraints
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -380,6 +421,9 @@ pub struct BaseTableColumn {
     /// Leaf index is the primitive column index in Parquet, constructed in DFS order.
     /// None if the data type of column is struct.
     pub leaf_index: Option<usize>,
+    /// The expression of a virtual computed column, which is not stored and
+    /// replaced by the expression when the column is referenced.
+    pub virtual_computed_expr: Option<String>,
 }
 
 #[derive(Clone, Debug)]
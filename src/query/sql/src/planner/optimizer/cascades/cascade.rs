@@ -55,7 +55,7 @@ impl CascadesOptimizer {
         };
         Ok(CascadesOptimizer {
             memo: Memo::create(),
-            cost_model: Box::new(DefaultCostModel),
+            cost_model: Box::new(DefaultCostModel::try_create(&ctx)?),
             best_cost_map: HashMap::new(),
             explore_rule_set,
             metadata,
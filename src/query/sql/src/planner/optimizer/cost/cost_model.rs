@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -19,91 +22,334 @@ use super::Cost;
 use super::CostModel;
 use crate::optimizer::MExpr;
 use crate::optimizer::Memo;
+use crate::optimizer::RelationalProperty;
+use crate::plans::Exchange;
 use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::plans::Window;
+
+/// Estimated size in bytes of a value of a column, for the columns without statistics.
+const ESTIMATED_COLUMN_WIDTH: f64 = 8.0;
 
-static COST_FACTOR_COMPUTE_PER_ROW: f64 = 1.0;
-static COST_FACTOR_HASH_TABLE_PER_ROW: f64 = 10.0;
-static COST_FACTOR_AGGREGATE_PER_ROW: f64 = 5.0;
+/// The `cost_factor_*` settings are in thousandths, to set fractional factors.
+const COST_FACTOR_SCALE: f64 = 1000.0;
 
-#[derive(Default)]
-pub struct DefaultCostModel;
+/// Cost constants of `DefaultCostModel`, relative to the cost of processing a row
+/// by a simple operator. They are read from the `cost_factor_*` settings.
+#[derive(Clone, Debug)]
+pub struct CostFactors {
+    pub compute_per_row: f64,
+    pub hash_table_per_row: f64,
+    pub aggregate_per_row: f64,
+    pub io_per_kb: f64,
+    pub network_per_kb: f64,
+    /// Factor of the cost of the hash tables that don't fit in memory, which have to spill.
+    pub memory_pressure: f64,
+}
+
+impl Default for CostFactors {
+    fn default() -> Self {
+        CostFactors {
+            compute_per_row: 1.0,
+            hash_table_per_row: 10.0,
+            aggregate_per_row: 5.0,
+            io_per_kb: 2.0,
+            network_per_kb: 8.0,
+            memory_pressure: 4.0,
+        }
+    }
+}
+
+impl CostFactors {
+    pub fn try_create(ctx: &Arc<dyn TableContext>) -> Result<Self> {
+        let settings = ctx.get_settings();
+        Ok(CostFactors {
+            compute_per_row: settings.get_cost_factor_compute_per_row()? as f64 / COST_FACTOR_SCALE,
+            hash_table_per_row: settings.get_cost_factor_hash_table_per_row()? as f64
+                / COST_FACTOR_SCALE,
+            aggregate_per_row: settings.get_cost_factor_aggregate_per_row()? as f64
+                / COST_FACTOR_SCALE,
+            io_per_kb: settings.get_cost_factor_io_per_kb()? as f64 / COST_FACTOR_SCALE,
+            network_per_kb: settings.get_cost_factor_network_per_kb()? as f64 / COST_FACTOR_SCALE,
+            memory_pressure: settings.get_cost_factor_memory_pressure()? as f64 / COST_FACTOR_SCALE,
+        })
+    }
+}
+
+/// Cost model accounting for the CPU, the bytes read from the storage, the bytes sent
+/// through the network by `Exchange`s, and the hash tables exceeding the memory limit.
+pub struct DefaultCostModel {
+    factors: CostFactors,
+    /// Number of nodes running the query.
+    cluster_nodes: usize,
+    /// Memory usage in bytes above which hash tables spill.
+    memory_limit: f64,
+}
 
 impl CostModel for DefaultCostModel {
     fn compute_cost(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
-        compute_cost_impl(memo, m_expr)
+        self.compute_cost_impl(memo, m_expr)
     }
 }
 
-fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
-    match &m_expr.plan {
-        RelOperator::Scan(plan) => compute_cost_scan(memo, m_expr, plan),
-        RelOperator::DummyTableScan(_) => Ok(Cost(0.0)),
-        RelOperator::Join(plan) => compute_cost_join(memo, m_expr, plan),
-        RelOperator::UnionAll(_) => compute_cost_union_all(memo, m_expr),
-        RelOperator::Aggregate(_) => compute_aggregate(memo, m_expr),
+impl DefaultCostModel {
+    pub fn new(factors: CostFactors, cluster_nodes: usize, memory_limit: u64) -> Self {
+        DefaultCostModel {
+            factors,
+            cluster_nodes: cluster_nodes.max(1),
+            memory_limit: memory_limit as f64,
+        }
+    }
 
-        RelOperator::EvalScalar(_)
-        | RelOperator::Filter(_)
-        | RelOperator::Window(_)
-        | RelOperator::Sort(_)
-        | RelOperator::ProjectSet(_)
-        | RelOperator::Limit(_) => compute_cost_unary_common_operator(memo, m_expr),
+    pub fn try_create(ctx: &Arc<dyn TableContext>) -> Result<Self> {
+        Ok(Self::new(
+            CostFactors::try_create(ctx)?,
+            ctx.get_cluster().nodes.len(),
+            ctx.get_settings().get_max_memory_usage()?,
+        ))
+    }
 
-        _ => Err(ErrorCode::Internal("Cannot compute cost from logical plan")),
+    fn compute_cost_impl(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+        match &m_expr.plan {
+            RelOperator::Scan(plan) => self.compute_cost_scan(memo, m_expr, plan),
+            RelOperator::DummyTableScan(_) => Ok(Cost(0.0)),
+            RelOperator::Join(plan) => self.compute_cost_join(memo, m_expr, plan),
+            RelOperator::UnionAll(_) => self.compute_cost_union_all(memo, m_expr),
+            RelOperator::Aggregate(_) => self.compute_cost_aggregate(memo, m_expr),
+            RelOperator::Sort(plan) => self.compute_cost_sort(memo, m_expr, plan),
+            RelOperator::Window(plan) => self.compute_cost_window(memo, m_expr, plan),
+            RelOperator::Exchange(plan) => self.compute_cost_exchange(memo, m_expr, plan),
+            RelOperator::MaterializedCte(_) => self.compute_cost_materialized_cte(memo, m_expr),
+            RelOperator::CteScan(_) => self.compute_cost_cte_scan(memo, m_expr),
+
+            RelOperator::EvalScalar(_)
+            | RelOperator::Filter(_)
+            | RelOperator::ProjectSet(_)
+            | RelOperator::RuntimeFilterSource(_)
+            | RelOperator::Limit(_) => self.compute_cost_unary_common_operator(memo, m_expr),
+
+            RelOperator::Pattern(_) => {
+                Err(ErrorCode::Internal("Cannot compute cost from pattern plan"))
+            }
+        }
     }
-}
 
-fn compute_cost_scan(memo: &Memo, m_expr: &MExpr, _plan: &Scan) -> Result<Cost> {
-    // Since we don't have alternations(e.g. index scan) for table scan for now, we just ignore
-    // the I/O cost and treat `PhysicalScan` as normal computation.
-    let group = memo.group(m_expr.group_index)?;
-    let prop = &group.relational_prop;
-    let cost = prop.cardinality * COST_FACTOR_COMPUTE_PER_ROW;
-    Ok(Cost(cost))
-}
+    fn compute_cost_scan(&self, memo: &Memo, m_expr: &MExpr, plan: &Scan) -> Result<Cost> {
+        let group = memo.group(m_expr.group_index)?;
+        let prop = &group.relational_prop;
+        let cost = prop.cardinality * self.factors.compute_per_row
+            + scan_bytes(plan) / 1024.0 * self.factors.io_per_kb;
+        Ok(Cost(cost))
+    }
+
+    fn compute_cost_join(&self, memo: &Memo, m_expr: &MExpr, plan: &Join) -> Result<Cost> {
+        let build_prop = &m_expr.child_group(memo, 1)?.relational_prop;
+        let probe_prop = &m_expr.child_group(memo, 0)?.relational_prop;
+        Ok(Cost(self.hash_join_cost(
+            probe_prop,
+            build_prop,
+            &plan.join_type,
+        )))
+    }
+
+    /// Cost of building the hash table of a join and probing it, on a single node.
+    pub fn hash_join_cost(
+        &self,
+        probe_prop: &RelationalProperty,
+        build_prop: &RelationalProperty,
+        join_type: &JoinType,
+    ) -> f64 {
+        let build_card = build_prop.cardinality;
+        let probe_card = probe_prop.cardinality;
+
+        let hash_table_cost = build_card
+            * self.factors.hash_table_per_row
+            * self.memory_pressure(build_card * row_width(build_prop));
+        let mut cost = hash_table_cost + probe_card * self.factors.compute_per_row;
 
-fn compute_cost_join(memo: &Memo, m_expr: &MExpr, plan: &Join) -> Result<Cost> {
-    let build_group = m_expr.child_group(memo, 1)?;
-    let probe_group = m_expr.child_group(memo, 0)?;
-    let build_card = build_group.relational_prop.cardinality;
-    let probe_card = probe_group.relational_prop.cardinality;
+        if matches!(join_type, JoinType::RightAnti | JoinType::RightSemi) {
+            // Due to implementation reasons, right semi join is more expensive than left semi join
+            // So if join type is right anti or right semi, cost needs multiply three (an approximate value)
+            cost *= 3.0;
+        }
+        cost
+    }
 
-    let mut cost =
-        build_card * COST_FACTOR_HASH_TABLE_PER_ROW + probe_card * COST_FACTOR_COMPUTE_PER_ROW;
+    /// Compute cost for the unary operators that perform simple computation(e.g. `Project`, `Filter`, `EvalScalar`).
+    fn compute_cost_unary_common_operator(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+        let group = m_expr.child_group(memo, 0)?;
+        let card = group.relational_prop.cardinality;
+        let cost = card * self.factors.compute_per_row;
+        Ok(Cost(cost))
+    }
 
-    if matches!(plan.join_type, JoinType::RightAnti | JoinType::RightSemi) {
-        // Due to implementation reasons, right semi join is more expensive than left semi join
-        // So if join type is right anti or right semi, cost needs multiply three (an approximate value)
-        cost *= 3.0;
+    fn compute_cost_union_all(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+        let left_group = m_expr.child_group(memo, 0)?;
+        let right_group = m_expr.child_group(memo, 1)?;
+        let card = left_group.relational_prop.cardinality + right_group.relational_prop.cardinality;
+        let cost = card * self.factors.compute_per_row;
+        Ok(Cost(cost))
+    }
+
+    /// The CTE is computed once and buffered for all its consumers.
+    fn compute_cost_materialized_cte(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+        let cte_prop = &m_expr.child_group(memo, 0)?.relational_prop;
+        let cost = cte_prop.cardinality
+            * self.factors.compute_per_row
+            * self.memory_pressure(cte_prop.cardinality * row_width(cte_prop));
+        Ok(Cost(cost))
+    }
+
+    fn compute_cost_cte_scan(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+        let prop = &memo.group(m_expr.group_index)?.relational_prop;
+        Ok(Cost(prop.cardinality * self.factors.compute_per_row))
+    }
+
+    /// TODO(leiysky): Since we don't have alternation for `Aggregate` for now, we just
+    /// treat `Aggregate` as normal computation, its hash table is sized by the groups.
+    fn compute_cost_aggregate(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+        let input_card = m_expr.child_group(memo, 0)?.relational_prop.cardinality;
+        let prop = &memo.group(m_expr.group_index)?.relational_prop;
+        let cost = input_card
+            * self.factors.aggregate_per_row
+            * self.memory_pressure(prop.cardinality * row_width(prop));
+        Ok(Cost(cost))
+    }
+
+    fn compute_cost_sort(&self, memo: &Memo, m_expr: &MExpr, plan: &Sort) -> Result<Cost> {
+        let card = m_expr.child_group(memo, 0)?.relational_prop.cardinality;
+        // A sort with limit only keeps the top rows in a heap.
+        let sorted = match plan.limit {
+            Some(limit) => card.min(limit as f64),
+            None => card,
+        };
+        Ok(Cost(card * log2(sorted) * self.factors.compute_per_row))
+    }
+
+    fn compute_cost_window(&self, memo: &Memo, m_expr: &MExpr, plan: &Window) -> Result<Cost> {
+        let card = m_expr.child_group(memo, 0)?.relational_prop.cardinality;
+        let mut cost = card * self.factors.compute_per_row;
+        if !plan.partition_by.is_empty() || !plan.order_by.is_empty() {
+            // The rows are sorted by the partitions and the order of the window.
+            cost += card * log2(card) * self.factors.compute_per_row;
+        }
+        Ok(Cost(cost))
+    }
+
+    fn compute_cost_exchange(&self, memo: &Memo, m_expr: &MExpr, plan: &Exchange) -> Result<Cost> {
+        let prop = &m_expr.child_group(memo, 0)?.relational_prop;
+        let bytes = prop.cardinality * row_width(prop);
+        Ok(Cost(self.exchange_cost(plan, bytes)))
+    }
+
+    /// Network cost of an exchange of `bytes`, which are spread over all the nodes.
+    pub fn exchange_cost(&self, exchange: &Exchange, bytes: f64) -> f64 {
+        let nodes = self.cluster_nodes as f64;
+        let sent_bytes = match exchange {
+            // Each node sends its rows to all the other nodes.
+            Exchange::Broadcast => bytes * (nodes - 1.0),
+            // Only the rows of the other nodes are received.
+            Exchange::Random | Exchange::Hash(_) | Exchange::Merge => bytes * (nodes - 1.0) / nodes,
+        };
+        sent_bytes / 1024.0 * self.factors.network_per_kb
+    }
+
+    /// Whether broadcasting the build side of a join costs less than shuffling both sides
+    /// by the join keys.
+    pub fn prefer_broadcast_join(
+        &self,
+        probe_prop: &RelationalProperty,
+        build_prop: &RelationalProperty,
+    ) -> bool {
+        self.broadcast_join_cost(build_prop)
+            <= self.shuffle_join_cost(probe_prop, build_prop, false, false)
+    }
+
+    /// Cost of a broadcast join besides probing, the build side is received and inserted
+    /// into a hash table by every node.
+    pub fn broadcast_join_cost(&self, build_prop: &RelationalProperty) -> f64 {
+        let nodes = self.cluster_nodes as f64;
+        let build_bytes = build_prop.cardinality * row_width(build_prop);
+        let hash_table_cost = build_prop.cardinality * self.factors.hash_table_per_row;
+        self.exchange_cost(&Exchange::Broadcast, build_bytes)
+            + hash_table_cost * nodes * self.memory_pressure(build_bytes)
+    }
+
+    /// Cost of a shuffle join besides probing, the hash table is split over the nodes.
+    /// The sides already partitioned by the join keys are not shuffled again.
+    pub fn shuffle_join_cost(
+        &self,
+        probe_prop: &RelationalProperty,
+        build_prop: &RelationalProperty,
+        probe_partitioned: bool,
+        build_partitioned: bool,
+    ) -> f64 {
+        let nodes = self.cluster_nodes as f64;
+        let probe_bytes = probe_prop.cardinality * row_width(probe_prop);
+        let build_bytes = build_prop.cardinality * row_width(build_prop);
+        let hash_table_cost = build_prop.cardinality * self.factors.hash_table_per_row;
+
+        let mut cost = hash_table_cost * self.memory_pressure(build_bytes / nodes);
+        if !probe_partitioned {
+            cost += self.exchange_cost(&Exchange::Hash(vec![]), probe_bytes);
+        }
+        if !build_partitioned {
+            cost += self.exchange_cost(&Exchange::Hash(vec![]), build_bytes);
+        }
+        cost
+    }
+
+    fn memory_pressure(&self, bytes: f64) -> f64 {
+        if bytes > self.memory_limit {
+            self.factors.memory_pressure
+        } else {
+            1.0
+        }
     }
-    Ok(Cost(cost))
 }
 
-/// Compute cost for the unary operators that perform simple computation(e.g. `Project`, `Filter`, `EvalScalar`).
-///
-/// TODO(leiysky): Since we don't have alternation for `Aggregate` for now, we just
-/// treat `Aggregate` as normal computation.
-fn compute_cost_unary_common_operator(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
-    let group = m_expr.child_group(memo, 0)?;
-    let card = group.relational_prop.cardinality;
-    let cost = card * COST_FACTOR_COMPUTE_PER_ROW;
-    Ok(Cost(cost))
+/// Estimated size in bytes of the rows of a relational expression, from the sizes of the
+/// columns in the block statistics.
+fn row_width(prop: &RelationalProperty) -> f64 {
+    if prop.output_columns.is_empty() {
+        return ESTIMATED_COLUMN_WIDTH;
+    }
+    prop.output_columns
+        .iter()
+        .map(|column| {
+            prop.statistics
+                .column_stats
+                .get(column)
+                .and_then(|stat| stat.avg_size)
+                .unwrap_or(ESTIMATED_COLUMN_WIDTH)
+        })
+        .sum()
 }
 
-fn compute_cost_union_all(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
-    let left_group = m_expr.child_group(memo, 0)?;
-    let right_group = m_expr.child_group(memo, 0)?;
-    let card = left_group.relational_prop.cardinality + right_group.relational_prop.cardinality;
-    let cost = card * COST_FACTOR_COMPUTE_PER_ROW;
-    Ok(Cost(cost))
+fn log2(card: f64) -> f64 {
+    card.max(2.0).log2()
 }
 
-fn compute_aggregate(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
-    let group = m_expr.child_group(memo, 0)?;
-    let card = group.relational_prop.cardinality;
-    let cost = card * COST_FACTOR_AGGREGATE_PER_ROW;
-    Ok(Cost(cost))
+/// Estimated bytes read by a scan, from the sizes of the columns in the block statistics
+/// scaled by the compression ratio of the table.
+pub fn scan_bytes(plan: &Scan) -> f64 {
+    let table_stats = plan.statistics.statistics.as_ref();
+    let num_rows = table_stats.and_then(|s| s.num_rows).unwrap_or(0) as f64;
+    let compression_ratio = match table_stats.map(|s| (s.data_size, s.data_size_compressed)) {
+        Some((Some(size), Some(compressed))) if size > 0 => compressed as f64 / size as f64,
+        _ => 1.0,
+    };
+
+    plan.columns
+        .iter()
+        .map(|column| match plan.statistics.col_stats.get(column) {
+            Some(Some(stat)) if stat.in_memory_size > 0 => {
+                stat.in_memory_size as f64 * compression_ratio
+            }
+            _ => num_rows * ESTIMATED_COLUMN_WIDTH,
+        })
+        .sum()
 }
@@ -19,4 +19,6 @@ mod cost_model;
 pub use cost::Cost;
 pub use cost::CostContext;
 pub use cost::CostModel;
+pub use cost_model::scan_bytes;
+pub use cost_model::CostFactors;
 pub use cost_model::DefaultCostModel;
@@ -421,6 +421,7 @@ impl SubqueryRewriter {
                         col_stats: HashMap::new(),
                     },
                     prewhere: None,
+                    vector_top_k: None,
                 }
                 .into(),
             );
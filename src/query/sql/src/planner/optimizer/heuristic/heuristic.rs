@@ -52,6 +52,7 @@ pub static DEFAULT_REWRITE_RULES: Lazy<Vec<RuleID>> = Lazy::new(|| {
         RuleID::PushDownFilterScan,
         RuleID::PushDownPrewhere, /* PushDownPrwhere should be after all rules except PushDownFilterScan */
         RuleID::PushDownSortScan, // PushDownFilterScan should be after PushDownPrewhere
+        RuleID::PushDownVectorTopKScan,
     ]
 });
 
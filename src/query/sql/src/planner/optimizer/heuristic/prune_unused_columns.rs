@@ -19,6 +19,7 @@ use crate::optimizer::util::contains_project_set;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::SExpr;
 use crate::plans::Aggregate;
+use crate::plans::CteScan;
 use crate::plans::EvalScalar;
 use crate::plans::RelOperator;
 use crate::plans::WindowFuncType;
@@ -247,6 +248,29 @@ impl UnusedColumnPruner {
 
             RelOperator::DummyTableScan(_) => Ok(expr.clone()),
 
+            RelOperator::MaterializedCte(p) => Ok(SExpr::create_binary(
+                RelOperator::MaterializedCte(p.clone()),
+                // All the columns of the CTE are kept, they are shared by every consumer.
+                self.keep_required_columns(expr.child(0)?, p.used_columns()?)?,
+                self.keep_required_columns(expr.child(1)?, required)?,
+            )),
+
+            RelOperator::CteScan(p) => {
+                let (columns, source_columns) = p
+                    .columns
+                    .iter()
+                    .zip(p.source_columns.iter())
+                    .filter(|(column, _)| required.contains(&column.index))
+                    .map(|(column, source)| (column.clone(), *source))
+                    .unzip();
+                Ok(SExpr::create_leaf(RelOperator::CteScan(CteScan {
+                    cte_idx: p.cte_idx,
+                    columns,
+                    source_columns,
+                    cardinality: p.cardinality,
+                })))
+            }
+
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
             )),
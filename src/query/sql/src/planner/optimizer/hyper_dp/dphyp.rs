@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
 
 use crate::optimizer::hyper_dp::join_node::JoinNode;
@@ -25,6 +26,11 @@ use crate::optimizer::hyper_dp::query_graph::QueryGraph;
 use crate::optimizer::hyper_dp::util::intersect;
 use crate::optimizer::hyper_dp::util::union;
 use crate::optimizer::rule::TransformResult;
+use crate::optimizer::CostFactors;
+use crate::optimizer::DefaultCostModel;
+use crate::optimizer::Distribution;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -36,16 +42,19 @@ use crate::IndexType;
 use crate::MetadataRef;
 use crate::ScalarExpr;
 
-static COST_FACTOR_COMPUTE_PER_ROW: f64 = 1.0;
-static COST_FACTOR_HASH_TABLE_PER_ROW: f64 = 10.0;
-
 const MOCK_NUMBER: usize = 1000;
 
 // The join reorder algorithm follows the paper: Dynamic Programming Strikes Back
 // See the paper for more details.
 // Current, we only support inner join
 pub struct DPhpy {
+    ctx: Arc<dyn TableContext>,
     metadata: MetadataRef,
+    cost_factors: CostFactors,
+    // Set if the query is distributed and the distribution of the joins is chosen by cost,
+    // to account for the exchanges in the cost of the join orders.
+    distributed_cost_model: Option<DefaultCostModel>,
+    prefer_broadcast_join: bool,
     join_relations: Vec<JoinRelation>,
     // base table index -> index of join_relations
     table_index_map: HashMap<IndexType, IndexType>,
@@ -57,8 +66,23 @@ pub struct DPhpy {
 }
 
 impl DPhpy {
-    pub fn new(metadata: MetadataRef) -> Self {
-        Self {
+    pub fn new(
+        ctx: Arc<dyn TableContext>,
+        metadata: MetadataRef,
+        distributed: bool,
+    ) -> Result<Self> {
+        let settings = ctx.get_settings();
+        let distributed_cost_model =
+            if distributed && settings.get_enable_cost_based_join_distribution()? {
+                Some(DefaultCostModel::try_create(&ctx)?)
+            } else {
+                None
+            };
+        Ok(Self {
+            cost_factors: CostFactors::try_create(&ctx)?,
+            distributed_cost_model,
+            prefer_broadcast_join: settings.get_prefer_broadcast_join()?,
+            ctx,
             metadata,
             join_relations: vec![],
             table_index_map: Default::default(),
@@ -67,7 +91,7 @@ impl DPhpy {
             query_graph: QueryGraph::new(),
             relation_set_tree: Default::default(),
             filters: HashSet::new(),
-        }
+        })
     }
 
     // Traverse the s_expr and get all base relations and join conditions
@@ -86,7 +110,11 @@ impl DPhpy {
 
         if is_subquery {
             // If it's a subquery, start a new dphyp
-            let mut dphyp = DPhpy::new(self.metadata.clone());
+            let mut dphyp = DPhpy::new(
+                self.ctx.clone(),
+                self.metadata.clone(),
+                self.distributed_cost_model.is_some(),
+            )?;
             let (res, optimized) = dphyp.optimize(s_expr.clone())?;
             if optimized {
                 let key = self.subquery_table_index_map.len() + MOCK_NUMBER;
@@ -190,7 +218,9 @@ impl DPhpy {
             RelOperator::Window(_)
             | RelOperator::UnionAll(_)
             | RelOperator::DummyTableScan(_)
-            | RelOperator::RuntimeFilterSource(_) => Ok(false),
+            | RelOperator::RuntimeFilterSource(_)
+            | RelOperator::MaterializedCte(_)
+            | RelOperator::CteScan(_) => Ok(false),
         }
     }
 
@@ -296,6 +326,7 @@ impl DPhpy {
                 join_conditions: vec![],
                 cost: relation.cardinality()?,
                 cardinality: Some(relation.cardinality()?),
+                distribution: Distribution::Random,
             };
             let _ = self.dp_table.insert(nodes, join);
         }
@@ -422,32 +453,53 @@ impl DPhpy {
                 std::mem::swap(&mut join_condition.0, &mut join_condition.1);
             }
         }
-        let parent_node = self.dp_table.get(&parent_set);
         let join_node = if !join_conditions.is_empty() {
+            let (probe_join, build_join, probe_cardinality) =
+                if left_cardinality < right_cardinality {
+                    (right_join, left_join, right_cardinality)
+                } else {
+                    (left_join, right_join, left_cardinality)
+                };
+            let (cost, distribution) = match &self.distributed_cost_model {
+                Some(cost_model) => self.distributed_join_cost(
+                    cost_model,
+                    &probe_join,
+                    &build_join,
+                    probe_cardinality,
+                    &join_conditions,
+                )?,
+                None => (
+                    left_cardinality * self.cost_factors.compute_per_row
+                        + right_cardinality * self.cost_factors.hash_table_per_row,
+                    Distribution::Random,
+                ),
+            };
             JoinNode {
                 join_type: JoinType::Inner,
                 leaves: parent_set.clone(),
-                children: if left_cardinality < right_cardinality {
-                    vec![right_join, left_join]
-                } else {
-                    vec![left_join, right_join]
-                },
-                cost: left_cardinality * COST_FACTOR_COMPUTE_PER_ROW
-                    + right_cardinality * COST_FACTOR_HASH_TABLE_PER_ROW,
+                children: vec![probe_join, build_join],
+                cost,
                 join_conditions,
                 cardinality: None,
+                distribution,
             }
         } else {
+            let mut cost = left_cardinality * right_cardinality;
+            if self.distributed_cost_model.is_some() {
+                cost += left_join.cost + right_join.cost;
+            }
             JoinNode {
                 join_type: JoinType::Cross,
                 leaves: parent_set.clone(),
-                children: vec![left_join.clone(), right_join.clone()],
-                cost: left_cardinality * right_cardinality,
+                children: vec![left_join, right_join],
+                cost,
                 join_conditions: vec![],
                 cardinality: None,
+                distribution: Distribution::Random,
             }
         };
 
+        let parent_node = self.dp_table.get(&parent_set);
         if parent_node.is_none() || parent_node.unwrap().cost > join_node.cost {
             // Update `dp_table`
             self.dp_table.insert(parent_set, join_node);
@@ -455,6 +507,49 @@ impl DPhpy {
         Ok(true)
     }
 
+    // Cost of the join of `probe` and `build` in a distributed query, including the cost of the
+    // children, and the distribution of its output.
+    // The build side is broadcast if it's cheaper than shuffling the sides by the join keys,
+    // the sides already partitioned by the join keys by a former join are not shuffled again.
+    fn distributed_join_cost(
+        &self,
+        cost_model: &DefaultCostModel,
+        probe: &JoinNode,
+        build: &JoinNode,
+        probe_cardinality: f64,
+        join_conditions: &[(ScalarExpr, ScalarExpr)],
+    ) -> Result<(f64, Distribution)> {
+        let probe_keys: Vec<ScalarExpr> = join_conditions.iter().map(|(l, _)| l.clone()).collect();
+        let build_keys: Vec<ScalarExpr> = join_conditions.iter().map(|(_, r)| r.clone()).collect();
+        let probe_prop = self.relational_prop(probe)?;
+        let build_prop = self.relational_prop(build)?;
+
+        let shuffle_cost = cost_model.shuffle_join_cost(
+            &probe_prop,
+            &build_prop,
+            probe.distribution == Distribution::Hash(probe_keys.clone()),
+            build.distribution == Distribution::Hash(build_keys),
+        );
+        let broadcast_cost = cost_model.broadcast_join_cost(&build_prop);
+        let (join_cost, distribution) =
+            if self.prefer_broadcast_join && broadcast_cost <= shuffle_cost {
+                (broadcast_cost, Distribution::Random)
+            } else {
+                (shuffle_cost, Distribution::Hash(probe_keys))
+            };
+
+        let cost = probe.cost
+            + build.cost
+            + probe_cardinality * self.cost_factors.compute_per_row
+            + join_cost;
+        Ok((cost, distribution))
+    }
+
+    fn relational_prop(&self, join_node: &JoinNode) -> Result<RelationalProperty> {
+        let s_expr = self.s_expr(join_node);
+        RelExpr::with_s_expr(&s_expr).derive_relational_prop()
+    }
+
     // The second parameter is a set which is connected and must be extended until a valid csg-cmp-pair is reached.
     // Therefore, it considers the neighborhood of right.
     fn enumerate_cmp_rec(
@@ -15,6 +15,7 @@
 use common_exception::Result;
 
 use crate::optimizer::hyper_dp::DPhpy;
+use crate::optimizer::Distribution;
 use crate::optimizer::RelExpr;
 use crate::plans::JoinType;
 use crate::IndexType;
@@ -29,6 +30,8 @@ pub struct JoinNode {
     pub cost: f64,
     // Cache cardinality after computing.
     pub cardinality: Option<f64>,
+    // How the output rows are partitioned over the nodes of a distributed query.
+    pub distribution: Distribution,
 }
 
 impl JoinNode {
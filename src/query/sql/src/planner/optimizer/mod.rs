@@ -30,6 +30,9 @@ mod runtime_filter;
 mod s_expr;
 mod util;
 
+pub use cost::scan_bytes;
+pub use cost::CostFactors;
+pub use cost::DefaultCostModel;
 pub use heuristic::HeuristicOptimizer;
 pub use heuristic::SubqueryRewriter;
 pub use heuristic::DEFAULT_REWRITE_RULES;
@@ -28,10 +28,12 @@ use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::runtime_filter::try_add_runtime_filter_nodes;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::util::contains_materialized_cte;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::SExpr;
 use crate::plans::CopyPlan;
 use crate::plans::Plan;
+use crate::plans::RelOperator;
 use crate::BindContext;
 use crate::IndexType;
 use crate::MetadataRef;
@@ -146,12 +148,48 @@ pub fn optimize_query(
     bind_context: Box<BindContext>,
     s_expr: SExpr,
 ) -> Result<SExpr> {
+    if let RelOperator::MaterializedCte(cte) = s_expr.plan() {
+        // The CTE and the query reading it are optimized separately, both of them
+        // are executed in the local node which keeps the materialized CTE.
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: false,
+        }));
+        let mut cte_bind_context = BindContext::new();
+        cte_bind_context.columns = cte.cte_columns.clone();
+        let cte_s_expr = optimize_query(
+            ctx.clone(),
+            opt_ctx.clone(),
+            metadata.clone(),
+            Box::new(cte_bind_context),
+            s_expr.child(0)?.clone(),
+        )?;
+        let query_s_expr = optimize_query(
+            ctx,
+            opt_ctx,
+            metadata,
+            bind_context,
+            s_expr.child(1)?.clone(),
+        )?;
+        return Ok(SExpr::create_binary(
+            s_expr.plan().clone(),
+            cte_s_expr,
+            query_s_expr,
+        ));
+    }
+
     let contains_local_table_scan = contains_local_table_scan(&s_expr, &metadata);
+    // So far, we don't have ability to execute distributed query
+    // with reading data from local tales(e.g. system tables) or materialized CTEs.
+    let enable_distributed_query = opt_ctx.config.enable_distributed_optimization
+        && !contains_local_table_scan
+        && !contains_materialized_cte(&s_expr);
 
     let mut heuristic = HeuristicOptimizer::new(ctx.clone(), bind_context, metadata.clone());
     let mut result = heuristic.optimize(s_expr)?;
     if ctx.get_settings().get_enable_dphyp()? {
-        let (dp_res, optimized) = DPhpy::new(metadata.clone()).optimize(result.clone())?;
+        let (dp_res, optimized) =
+            DPhpy::new(ctx.clone(), metadata.clone(), enable_distributed_query)?
+                .optimize(result.clone())?;
         result = dp_res;
         if !optimized {
             // Callback to CascadesOptimizer
@@ -162,10 +200,6 @@ pub fn optimize_query(
         let mut cascades = CascadesOptimizer::create(ctx.clone(), metadata)?;
         result = cascades.optimize(result)?;
     }
-    // So far, we don't have ability to execute distributed query
-    // with reading data from local tales(e.g. system tables).
-    let enable_distributed_query =
-        opt_ctx.config.enable_distributed_optimization && !contains_local_table_scan;
     // Add runtime filter related nodes after cbo
     // Because cbo may change join order and we don't want to
     // break optimizer due to new added nodes by runtime filter.
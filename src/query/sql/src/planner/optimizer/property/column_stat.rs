@@ -37,6 +37,9 @@ pub struct ColumnStat {
 
     /// Histogram of column
     pub histogram: Option<Histogram>,
+
+    /// Average size in bytes of the values of the column, from the block statistics
+    pub avg_size: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
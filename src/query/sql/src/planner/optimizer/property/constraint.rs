@@ -18,6 +18,7 @@ use common_expression::FunctionContext;
 use common_expression::Scalar;
 use common_expression::Value;
 use common_functions::BUILTIN_FUNCTIONS;
+use z3::ast::Ast;
 use z3::ast::Bool;
 use z3::ast::Dynamic;
 use z3::ast::Int;
@@ -102,6 +103,36 @@ impl ConstraintSet {
             false
         }
     }
+
+    /// Check if the constraints can never be satisfied by the rows of a table, given the
+    /// CHECK constraints of the table, which never evaluate to FALSE for any row.
+    ///
+    /// The unsupported CHECK constraints are ignored, so this check may return `false`
+    /// for an unsatisfiable set, but never returns `true` for a satisfiable one.
+    pub fn is_unsatisfiable_with(&self, check_constraints: &[ScalarExpr]) -> bool {
+        let context = Context::new(&Config::new());
+        let z3_asts = self
+            .constraints
+            .iter()
+            .map(|c| as_z3_ast(&context, c))
+            .collect::<Option<Vec<Dynamic>>>();
+        let z3_asts = match z3_asts {
+            Some(z3_asts) => z3_asts,
+            None => return false,
+        };
+
+        let solver = Solver::new(&context);
+        for z3_ast in z3_asts.iter() {
+            solver.assert(&is_true(&context, z3_ast));
+        }
+        for check_constraint in check_constraints.iter() {
+            if let Some(z3_ast) = as_z3_ast(&context, check_constraint) {
+                solver.assert(&z3_ast._eq(&false_bool(&context)).not());
+            }
+        }
+
+        matches!(solver.check(), SatResult::Unsat)
+    }
 }
 
 /// Transform a logical expression into a z3 ast.
@@ -36,14 +36,19 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// constructed from NDV(number of distinct values) and the total number
 /// of rows instead of maintaining a real histogram for each column,
 /// which brings the assumption that the data is uniformly distributed.
+///
+/// If the table has been analyzed, the histogram of a numeric column is
+/// built from the sampled data and marked as `accuracy`.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
+    /// Whether the histogram is built from real data instead of NDV.
+    pub accuracy: bool,
 }
 
 impl Histogram {
-    pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+    pub fn new(buckets: Vec<HistogramBucket>, accuracy: bool) -> Self {
+        Self { buckets, accuracy }
     }
 
     /// Get number of buckets
@@ -94,7 +99,10 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram {
+                buckets: vec![],
+                accuracy: false,
+            })
         };
     }
 
@@ -149,7 +157,10 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram {
+        buckets,
+        accuracy: false,
+    })
 }
 
 #[derive(Debug, Clone)]
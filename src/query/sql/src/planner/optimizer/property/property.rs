@@ -89,11 +89,12 @@ impl Distribution {
             | (Distribution::Serial, Distribution::Serial)
             | (Distribution::Broadcast, Distribution::Broadcast) => true,
 
-            // TODO(leiysky): this is actually broken by https://github.com/datafuselabs/databend/pull/7451
-            // , would be fixed later.
-            // (Distribution::Hash(ref keys), Distribution::Hash(ref other_keys)) => keys
-            //     .iter()
-            //     .all(|key| other_keys.iter().any(|other_key| key == other_key)),
+            // The rows are only colocated the same way if they are hashed by the same keys,
+            // in the same order. The operators deriving `Hash` must make sure that the rows
+            // are hashed by the keys themselves, not by a value computed from them.
+            (Distribution::Hash(keys), Distribution::Hash(other_keys)) => {
+                !keys.is_empty() && keys == other_keys
+            }
             _ => false,
         }
     }
@@ -251,6 +251,20 @@ fn compare_equal(datum: &Option<Datum>, col_hist: &Histogram, column_stat: &Colu
             {
                 return 0.0;
             }
+            // The histogram built from real data can estimate the frequency of the value.
+            if col_hist.accuracy {
+                let num_values = col_hist.num_values();
+                for bucket in col_hist.buckets_iter() {
+                    if let Ok(ord) = bucket.upper_bound().compare(constant_datum) {
+                        if ord != Ordering::Less {
+                            if num_values == 0.0 || bucket.num_distinct() == 0.0 {
+                                break;
+                            }
+                            return bucket.num_values() / bucket.num_distinct() / num_values;
+                        }
+                    }
+                }
+            }
         }
     }
 
@@ -13,14 +13,17 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_expression::Scalar;
 
 use crate::optimizer::rule::Rule;
 use crate::optimizer::rule::TransformResult;
+use crate::optimizer::ConstraintSet;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
 use crate::plans::AggregateFunction;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::PatternPlan;
@@ -31,6 +34,7 @@ use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
+use crate::IndexType;
 use crate::MetadataRef;
 use crate::ScalarExpr;
 use crate::TableEntry;
@@ -201,6 +205,22 @@ impl RulePushDownFilterScan {
         }
     }
 
+    fn contradicts_check_constraints(
+        &self,
+        predicates: &[ScalarExpr],
+        table_index: IndexType,
+    ) -> bool {
+        let metadata = self.metadata.read();
+        let check_constraints = metadata.table(table_index).check_constraints();
+        if check_constraints.is_empty() {
+            return false;
+        }
+        match ConstraintSet::new(predicates) {
+            Some(constraint_set) => constraint_set.is_unsatisfiable_with(check_constraints),
+            None => false,
+        }
+    }
+
     fn find_push_down_predicates(&self, predicates: &[ScalarExpr]) -> Result<Vec<ScalarExpr>> {
         let metadata = self.metadata.read();
         let column_entries = metadata.columns();
@@ -247,9 +267,18 @@ impl Rule for RulePushDownFilterScan {
     }
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
-        let filter: Filter = s_expr.plan().clone().try_into()?;
+        let mut filter: Filter = s_expr.plan().clone().try_into()?;
         let mut get: Scan = s_expr.child(0)?.plan().clone().try_into()?;
 
+        // The rows of the table always satisfy its CHECK constraints, the filter
+        // can't be satisfied if it contradicts them.
+        if self.contradicts_check_constraints(&filter.predicates, get.table_index) {
+            filter.predicates = vec![ScalarExpr::ConstantExpr(ConstantExpr {
+                span: None,
+                value: Scalar::Boolean(false),
+            })];
+        }
+
         let add_filters = self.find_push_down_predicates(&filter.predicates)?;
 
         match get.push_down_predicates.as_mut() {
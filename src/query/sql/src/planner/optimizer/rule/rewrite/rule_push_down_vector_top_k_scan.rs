@@ -0,0 +1,162 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_vector::DistanceType;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
+use crate::plans::ScanVectorTopK;
+use crate::plans::Sort;
+
+/// Input:  Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///             LogicalGet
+///
+/// Output:
+///         Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///             LogicalGet(padding vector_top_k)
+///
+/// Only applies to a sort by the distance between a column and a constant vector,
+/// the sort re-ranks the rows of the candidate blocks found by the vector indexes.
+pub struct RulePushDownVectorTopKScan {
+    id: RuleID,
+    patterns: Vec<SExpr>,
+}
+
+impl RulePushDownVectorTopKScan {
+    pub fn new() -> Self {
+        Self {
+            id: RuleID::PushDownVectorTopKScan,
+            patterns: vec![SExpr::create_unary(
+                PatternPlan {
+                    plan_type: RelOp::Sort,
+                }
+                .into(),
+                SExpr::create_unary(
+                    PatternPlan {
+                        plan_type: RelOp::EvalScalar,
+                    }
+                    .into(),
+                    SExpr::create_leaf(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ),
+                ),
+            )],
+        }
+    }
+
+    fn vector_top_k(sort: &Sort, eval_scalar: &EvalScalar, scan: &Scan) -> Option<ScanVectorTopK> {
+        let limit = sort.limit?;
+        let first = sort.items.first()?;
+        let item = eval_scalar
+            .items
+            .iter()
+            .find(|item| item.index == first.index)?;
+        let func = match &item.scalar {
+            ScalarExpr::FunctionCall(func) if func.params.is_empty() => func,
+            _ => return None,
+        };
+        let distance = DistanceType::from_func_name(&func.func_name)?;
+        if func.arguments.len() != 2 || first.asc != distance.is_ascending() {
+            return None;
+        }
+
+        // The distance functions are symmetric, the column can be either argument.
+        let (column, query) = match (&func.arguments[0], &func.arguments[1]) {
+            (ScalarExpr::BoundColumnRef(column), query)
+            | (query, ScalarExpr::BoundColumnRef(column))
+                if query.used_columns().is_empty() =>
+            {
+                (column, query)
+            }
+            _ => return None,
+        };
+        if column.column.table_index != Some(scan.table_index)
+            || !scan.columns.contains(&column.column.index)
+        {
+            return None;
+        }
+
+        Some(ScanVectorTopK {
+            column: column.column.index,
+            func_name: func.func_name.clone(),
+            query: ScalarExpr::CastExpr(CastExpr {
+                span: None,
+                is_try: false,
+                argument: Box::new(query.clone()),
+                target_type: Box::new(DataType::Array(Box::new(DataType::Number(
+                    NumberDataType::Float32,
+                )))),
+            }),
+            limit,
+        })
+    }
+}
+
+impl Rule for RulePushDownVectorTopKScan {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut get: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+
+        // The indexes are built over all the rows, filtered scans fall back to the full scan.
+        if get.vector_top_k.is_some()
+            || get.push_down_predicates.is_some()
+            || get.prewhere.is_some()
+        {
+            return Ok(());
+        }
+        let vector_top_k = match Self::vector_top_k(&sort, &eval_scalar, &get) {
+            Some(vector_top_k) => vector_top_k,
+            None => return Ok(()),
+        };
+        get.vector_top_k = Some(vector_top_k);
+
+        let get = SExpr::create_leaf(RelOperator::Scan(get));
+        let eval_scalar_expr = eval_scalar_expr.replace_children(vec![get]);
+        let mut result = s_expr.replace_children(vec![eval_scalar_expr]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
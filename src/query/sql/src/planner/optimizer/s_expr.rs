@@ -233,6 +233,8 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::RuntimeFilterSource(_)
+        | RelOperator::MaterializedCte(_)
+        | RelOperator::CteScan(_)
         | RelOperator::Pattern(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
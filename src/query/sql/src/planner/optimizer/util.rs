@@ -31,6 +31,12 @@ pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool
         }
 }
 
+/// Check if a query contains materialized CTEs, which are kept in the memory of the local node.
+pub fn contains_materialized_cte(s_expr: &SExpr) -> bool {
+    matches!(s_expr.plan(), RelOperator::MaterializedCte(_))
+        || s_expr.children().iter().any(contains_materialized_cte)
+}
+
 /// Check the expr contains ProjectSet op.
 pub fn contains_project_set(s_expr: &SExpr) -> bool {
     if let Some(child) = s_expr.children().iter().next() {
@@ -27,6 +27,7 @@ use crate::optimizer::RequiredProperty;
 use crate::optimizer::Statistics;
 use crate::plans::Operator;
 use crate::plans::RelOp;
+use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
 use crate::IndexType;
 
@@ -70,6 +71,21 @@ impl Aggregate {
         Ok(used_columns)
     }
 
+    /// Whether the rows of each group are on the same node if they are partitioned
+    /// by `distribution`.
+    fn is_partitioned_by(&self, distribution: &Distribution) -> bool {
+        match distribution {
+            Distribution::Hash(keys) => {
+                !keys.is_empty()
+                    && self.grouping_sets.is_empty()
+                    && keys
+                        .iter()
+                        .all(|key| self.group_items.iter().any(|item| &item.scalar == key))
+            }
+            _ => false,
+        }
+    }
+
     pub fn group_columns(&self) -> Result<ColumnSet> {
         let mut col_set = ColumnSet::new();
         for group_item in self.group_items.iter() {
@@ -86,7 +102,21 @@ impl Operator for Aggregate {
     }
 
     fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
-        rel_expr.derive_physical_prop_child(0)
+        let mut prop = rel_expr.derive_physical_prop_child(0)?;
+        if let Distribution::Hash(_) = prop.distribution {
+            // The exchange of the aggregation may be changed to hash the `_group_by_key`,
+            // and the grouping sets output the rows with NULL keys from every node.
+            let shuffled = match rel_expr {
+                RelExpr::SExpr { expr } => {
+                    matches!(expr.child(0)?.plan(), RelOperator::Exchange(_))
+                }
+                RelExpr::MExpr { .. } => true,
+            };
+            if shuffled || !self.grouping_sets.is_empty() {
+                prop.distribution = Distribution::Random;
+            }
+        }
+        Ok(prop)
     }
 
     fn compute_required_prop_child(
@@ -108,6 +138,10 @@ impl Operator for Aggregate {
                 if self.group_items.is_empty() {
                     // Scalar aggregation
                     required.distribution = Distribution::Any;
+                } else if self.is_partitioned_by(&child_physical_prop.distribution) {
+                    // The rows of a group are already on the same node, e.g. the aggregation
+                    // is grouped by the keys of a shuffle join, no need to shuffle them again.
+                    required.distribution = child_physical_prop.distribution;
                 } else {
                     let settings = ctx.get_settings();
 
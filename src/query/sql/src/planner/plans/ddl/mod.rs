@@ -15,16 +15,30 @@ mod account;
 mod catalog;
 mod database;
 mod file_format;
+mod masking_policy;
+mod network_policy;
+mod password_policy;
+mod row_access_policy;
+mod sequence;
 mod stage;
+mod stream;
 mod table;
 mod udf;
+mod vector_index;
 mod view;
 
 pub use account::*;
 pub use catalog::*;
 pub use database::*;
 pub use file_format::*;
+pub use masking_policy::*;
+pub use network_policy::*;
+pub use password_policy::*;
+pub use row_access_policy::*;
+pub use sequence::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
 pub use udf::*;
+pub use vector_index::*;
 pub use view::*;
@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use common_ast::ast::ColumnIdentity;
 use common_ast::ast::Engine;
 use common_catalog::table::NavigationPoint;
 use common_expression::types::DataType;
@@ -23,6 +24,7 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::TableDataType;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
@@ -47,10 +49,30 @@ pub struct CreateTablePlan {
     pub options: TableOptions,
     pub field_default_exprs: Vec<Option<String>>,
     pub field_comments: Vec<String>,
+    /// `AUTOINCREMENT` columns, a sequence is created for each of them along with the table.
+    pub field_identities: Vec<Option<ColumnIdentity>>,
+    /// Constraint name -> expression of the `CHECK` constraints.
+    pub check_constraints: BTreeMap<String, String>,
     pub cluster_key: Option<String>,
+    /// Source table of `CREATE TABLE ... CLONE ...`
+    pub clone_source: Option<CloneSource>,
     pub as_select: Option<Box<Plan>>,
 }
 
+/// The source table of `CREATE TABLE ... CLONE ...`
+#[derive(Clone, Debug)]
+pub struct CloneSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// Snapshot to clone, `None` if the source table is empty.
+    pub snapshot_location: Option<String>,
+    // The policies of the source table also protect the cloned data.
+    pub column_mask_policy: BTreeMap<String, String>,
+    pub row_access_policy: Option<String>,
+    pub row_access_policy_columns: Vec<String>,
+}
+
 impl CreateTablePlan {
     pub fn schema(&self) -> DataSchemaRef {
         DataSchemaRefExt::create(vec![])
@@ -178,6 +200,117 @@ impl DropTableColumnPlan {
     }
 }
 
+// Table rename column
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenameTableColumnPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub old_column: String,
+    pub new_column: String,
+}
+
+impl RenameTableColumnPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table modify the data type of a column
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModifyTableColumnTypePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub column: String,
+    pub data_type: TableDataType,
+}
+
+impl ModifyTableColumnTypePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table set or unset the masking policy of a column
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetTableColumnMaskingPolicyPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub column: String,
+    // None to unset the masking policy.
+    pub policy: Option<String>,
+}
+
+impl SetTableColumnMaskingPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table add row access policy
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddTableRowAccessPolicyPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl AddTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table drop row access policy
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableRowAccessPolicyPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl DropTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table create snapshot tag
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTableTagPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub tag: String,
+    /// The snapshot to be tagged, the current snapshot if not specified.
+    pub navigation: Option<NavigationPoint>,
+}
+
+impl CreateTableTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table drop snapshot tag
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableTagPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub tag: String,
+}
+
+impl DropTableTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Show.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ShowCreateTablePlan {
@@ -23,6 +23,7 @@ use common_exception::Result;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
 use crate::optimizer::Datum;
+use crate::optimizer::DefaultCostModel;
 use crate::optimizer::Distribution;
 use crate::optimizer::Histogram;
 use crate::optimizer::InterleavedBucket;
@@ -157,6 +158,38 @@ impl Default for Join {
 }
 
 impl Join {
+    fn prefer_broadcast_join(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        rel_expr: &RelExpr,
+        probe_physical_prop: &PhysicalProperty,
+        build_physical_prop: &PhysicalProperty,
+    ) -> Result<bool> {
+        let settings = ctx.get_settings();
+        if !settings.get_prefer_broadcast_join()? {
+            return Ok(false);
+        }
+        if !settings.get_enable_cost_based_join_distribution()? {
+            return Ok(true);
+        }
+
+        // The sides already partitioned by the join keys are joined without being shuffled.
+        let probe_partitioned =
+            probe_physical_prop.distribution == Distribution::Hash(self.left_conditions.clone());
+        let build_partitioned =
+            build_physical_prop.distribution == Distribution::Hash(self.right_conditions.clone());
+        let cost_model = DefaultCostModel::try_create(&ctx)?;
+        let probe_prop = rel_expr.derive_relational_prop_child(0)?;
+        let build_prop = rel_expr.derive_relational_prop_child(1)?;
+        Ok(cost_model.broadcast_join_cost(&build_prop)
+            <= cost_model.shuffle_join_cost(
+                &probe_prop,
+                &build_prop,
+                probe_partitioned,
+                build_partitioned,
+            ))
+    }
+
     pub fn used_columns(&self) -> Result<ColumnSet> {
         let mut used_columns = ColumnSet::new();
         for cond in self
@@ -363,17 +396,32 @@ impl Operator for Join {
         let probe_prop = rel_expr.derive_physical_prop_child(0)?;
         let build_prop = rel_expr.derive_physical_prop_child(1)?;
 
-        match (&probe_prop.distribution, &build_prop.distribution) {
+        let distribution = match (&probe_prop.distribution, &build_prop.distribution) {
+            (Distribution::Serial, _) | (_, Distribution::Serial) => Distribution::Serial,
+            // The probe side is split over the nodes, and so is the result of a broadcast join.
+            (_, Distribution::Broadcast) => Distribution::Random,
+            // The unmatched rows of the build side are output with NULL probe keys,
+            // on the node their build keys are hashed to.
+            _ if matches!(
+                self.join_type,
+                JoinType::Right | JoinType::Full | JoinType::RightMark
+            ) =>
+            {
+                Distribution::Random
+            }
+            // Only the columns of the build side are output.
+            _ if matches!(self.join_type, JoinType::RightSemi | JoinType::RightAnti) => {
+                build_prop.distribution.clone()
+            }
             // If the distribution of probe side is Random, we will pass through
             // the distribution of build side.
-            (Distribution::Random, _) => Ok(PhysicalProperty {
-                distribution: build_prop.distribution.clone(),
-            }),
+            (Distribution::Random, _) if self.join_type == JoinType::Inner => {
+                build_prop.distribution.clone()
+            }
             // Otherwise pass through probe side.
-            _ => Ok(PhysicalProperty {
-                distribution: probe_prop.distribution.clone(),
-            }),
-        }
+            _ => probe_prop.distribution.clone(),
+        };
+        Ok(PhysicalProperty { distribution })
     }
 
     fn compute_required_prop_child(
@@ -394,16 +442,19 @@ impl Operator for Join {
         {
             // TODO(leiysky): we can enforce redistribution here
             required.distribution = Distribution::Serial;
-        } else if ctx.get_settings().get_prefer_broadcast_join()?
-            && !matches!(
-                self.join_type,
-                JoinType::Right
-                    | JoinType::Full
-                    | JoinType::RightAnti
-                    | JoinType::RightSemi
-                    | JoinType::RightMark
-            )
-            && probe_physical_prop.distribution != Distribution::Broadcast
+        } else if self.prefer_broadcast_join(
+            ctx.clone(),
+            rel_expr,
+            &probe_physical_prop,
+            &build_physical_prop,
+        )? && !matches!(
+            self.join_type,
+            JoinType::Right
+                | JoinType::Full
+                | JoinType::RightAnti
+                | JoinType::RightSemi
+                | JoinType::RightMark
+        ) && probe_physical_prop.distribution != Distribution::Broadcast
             && build_physical_prop.distribution != Distribution::Broadcast
         {
             required.distribution = Distribution::Broadcast;
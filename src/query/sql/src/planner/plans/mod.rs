@@ -15,6 +15,7 @@
 mod aggregate;
 mod call;
 mod copy;
+mod cte_scan;
 mod ddl;
 mod delete;
 mod dummy_table_scan;
@@ -26,6 +27,7 @@ mod join;
 mod kill;
 mod limit;
 mod list;
+mod materialized_cte;
 mod operator;
 mod pattern;
 mod plan;
@@ -47,6 +49,7 @@ mod window;
 pub use aggregate::*;
 pub use call::CallPlan;
 pub use copy::*;
+pub use cte_scan::CteScan;
 pub use ddl::*;
 pub use delete::DeletePlan;
 pub use dummy_table_scan::DummyTableScan;
@@ -59,6 +62,7 @@ pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
 pub use list::ListPlan;
+pub use materialized_cte::MaterializedCte;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use plan::Plan::*;
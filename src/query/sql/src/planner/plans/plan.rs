@@ -39,6 +39,9 @@ use crate::plans::share::ShowGrantTenantsOfSharePlan;
 use crate::plans::share::ShowObjectGrantPrivilegesPlan;
 use crate::plans::share::ShowSharesPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
+use crate::plans::AlterNetworkPolicyPlan;
+use crate::plans::AlterPasswordPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
@@ -48,44 +51,69 @@ use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateMaskingPolicyPlan;
+use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::CreatePasswordPolicyPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableTagPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
+use crate::plans::CreateVectorIndexPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DeletePlan;
+use crate::plans::DescMaskingPolicyPlan;
+use crate::plans::DescNetworkPolicyPlan;
+use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::DropFileFormatPlan;
+use crate::plans::DropMaskingPolicyPlan;
+use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::DropPasswordPolicyPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::DropSequencePlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
+use crate::plans::DropTableTagPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
+use crate::plans::DropVectorIndexPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
+use crate::plans::ModifyTableColumnTypePlan;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
+use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::Replace;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
 use crate::plans::SetRolePlan;
+use crate::plans::SetTableColumnMaskingPolicyPlan;
 use crate::plans::SettingPlan;
 use crate::plans::ShowCreateCatalogPlan;
 use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
+use crate::plans::ShowPasswordPoliciesPlan;
 use crate::plans::ShowRolesPlan;
 use crate::plans::ShowShareEndpointPlan;
 use crate::plans::TruncateTablePlan;
@@ -152,6 +180,13 @@ pub enum Plan {
     RenameTable(Box<RenameTablePlan>),
     AddTableColumn(Box<AddTableColumnPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
+    RenameTableColumn(Box<RenameTableColumnPlan>),
+    ModifyTableColumnType(Box<ModifyTableColumnTypePlan>),
+    SetTableColumnMaskingPolicy(Box<SetTableColumnMaskingPolicyPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
+    CreateTableTag(Box<CreateTableTagPlan>),
+    DropTableTag(Box<DropTableTagPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
@@ -172,6 +207,18 @@ pub enum Plan {
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
+
+    // Vector indexes
+    CreateVectorIndex(Box<CreateVectorIndexPlan>),
+    DropVectorIndex(Box<DropVectorIndexPlan>),
+
+    // Sequences
+    CreateSequence(Box<CreateSequencePlan>),
+    DropSequence(Box<DropSequencePlan>),
+
     // Account
     AlterUser(Box<AlterUserPlan>),
     CreateUser(Box<CreateUserPlan>),
@@ -198,6 +245,27 @@ pub enum Plan {
     DropFileFormat(Box<DropFileFormatPlan>),
     ShowFileFormats(Box<ShowFileFormatsPlan>),
 
+    // Password policy
+    CreatePasswordPolicy(Box<CreatePasswordPolicyPlan>),
+    AlterPasswordPolicy(Box<AlterPasswordPolicyPlan>),
+    DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
+    DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
+    ShowPasswordPolicies(Box<ShowPasswordPoliciesPlan>),
+
+    // Network policy
+    CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
+    AlterNetworkPolicy(Box<AlterNetworkPolicyPlan>),
+    DropNetworkPolicy(Box<DropNetworkPolicyPlan>),
+    DescNetworkPolicy(Box<DescNetworkPolicyPlan>),
+
+    // Data security policy
+    CreateMaskingPolicy(Box<CreateMaskingPolicyPlan>),
+    DropMaskingPolicy(Box<DropMaskingPolicyPlan>),
+    DescMaskingPolicy(Box<DescMaskingPolicyPlan>),
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
+
     // Stages
     CreateStage(Box<CreateStagePlan>),
     DropStage(Box<DropStagePlan>),
@@ -273,6 +341,13 @@ impl Display for Plan {
             Plan::RenameTable(_) => write!(f, "RenameTable"),
             Plan::AddTableColumn(_) => write!(f, "AddTableColumn"),
             Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
+            Plan::RenameTableColumn(_) => write!(f, "RenameTableColumn"),
+            Plan::ModifyTableColumnType(_) => write!(f, "ModifyTableColumnType"),
+            Plan::SetTableColumnMaskingPolicy(_) => write!(f, "SetTableColumnMaskingPolicy"),
+            Plan::AddTableRowAccessPolicy(_) => write!(f, "AddTableRowAccessPolicy"),
+            Plan::DropTableRowAccessPolicy(_) => write!(f, "DropTableRowAccessPolicy"),
+            Plan::CreateTableTag(_) => write!(f, "CreateTableTag"),
+            Plan::DropTableTag(_) => write!(f, "DropTableTag"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
             Plan::ReclusterTable(_) => write!(f, "ReclusterTable"),
@@ -283,6 +358,12 @@ impl Display for Plan {
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
+            Plan::CreateVectorIndex(_) => write!(f, "CreateVectorIndex"),
+            Plan::DropVectorIndex(_) => write!(f, "DropVectorIndex"),
+            Plan::CreateSequence(_) => write!(f, "CreateSequence"),
+            Plan::DropSequence(_) => write!(f, "DropSequence"),
             Plan::AlterUser(_) => write!(f, "AlterUser"),
             Plan::CreateUser(_) => write!(f, "CreateUser"),
             Plan::DropUser(_) => write!(f, "DropUser"),
@@ -293,6 +374,21 @@ impl Display for Plan {
             Plan::CreateFileFormat(_) => write!(f, "CreateFileFormat"),
             Plan::DropFileFormat(_) => write!(f, "DropFileFormat"),
             Plan::ShowFileFormats(_) => write!(f, "ShowFileFormats"),
+            Plan::CreatePasswordPolicy(_) => write!(f, "CreatePasswordPolicy"),
+            Plan::AlterPasswordPolicy(_) => write!(f, "AlterPasswordPolicy"),
+            Plan::DropPasswordPolicy(_) => write!(f, "DropPasswordPolicy"),
+            Plan::DescPasswordPolicy(_) => write!(f, "DescPasswordPolicy"),
+            Plan::ShowPasswordPolicies(_) => write!(f, "ShowPasswordPolicies"),
+            Plan::CreateNetworkPolicy(_) => write!(f, "CreateNetworkPolicy"),
+            Plan::AlterNetworkPolicy(_) => write!(f, "AlterNetworkPolicy"),
+            Plan::DropNetworkPolicy(_) => write!(f, "DropNetworkPolicy"),
+            Plan::DescNetworkPolicy(_) => write!(f, "DescNetworkPolicy"),
+            Plan::CreateMaskingPolicy(_) => write!(f, "CreateMaskingPolicy"),
+            Plan::DropMaskingPolicy(_) => write!(f, "DropMaskingPolicy"),
+            Plan::DescMaskingPolicy(_) => write!(f, "DescMaskingPolicy"),
+            Plan::CreateRowAccessPolicy(_) => write!(f, "CreateRowAccessPolicy"),
+            Plan::DropRowAccessPolicy(_) => write!(f, "DropRowAccessPolicy"),
+            Plan::DescRowAccessPolicy(_) => write!(f, "DescRowAccessPolicy"),
             Plan::RemoveStage(_) => write!(f, "RemoveStage"),
             Plan::GrantRole(_) => write!(f, "GrantRole"),
             Plan::GrantPriv(_) => write!(f, "GrantPriv"),
@@ -367,6 +463,13 @@ impl Plan {
             Plan::RenameTable(plan) => plan.schema(),
             Plan::AddTableColumn(plan) => plan.schema(),
             Plan::DropTableColumn(plan) => plan.schema(),
+            Plan::RenameTableColumn(plan) => plan.schema(),
+            Plan::ModifyTableColumnType(plan) => plan.schema(),
+            Plan::SetTableColumnMaskingPolicy(plan) => plan.schema(),
+            Plan::AddTableRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropTableRowAccessPolicy(plan) => plan.schema(),
+            Plan::CreateTableTag(plan) => plan.schema(),
+            Plan::DropTableTag(plan) => plan.schema(),
             Plan::AlterTableClusterKey(plan) => plan.schema(),
             Plan::DropTableClusterKey(plan) => plan.schema(),
             Plan::ReclusterTable(plan) => plan.schema(),
@@ -377,6 +480,12 @@ impl Plan {
             Plan::CreateView(plan) => plan.schema(),
             Plan::AlterView(plan) => plan.schema(),
             Plan::DropView(plan) => plan.schema(),
+            Plan::CreateStream(plan) => plan.schema(),
+            Plan::DropStream(plan) => plan.schema(),
+            Plan::CreateVectorIndex(plan) => plan.schema(),
+            Plan::DropVectorIndex(plan) => plan.schema(),
+            Plan::CreateSequence(plan) => plan.schema(),
+            Plan::DropSequence(plan) => plan.schema(),
             Plan::AlterUser(plan) => plan.schema(),
             Plan::CreateUser(plan) => plan.schema(),
             Plan::DropUser(plan) => plan.schema(),
@@ -392,6 +501,21 @@ impl Plan {
             Plan::CreateFileFormat(plan) => plan.schema(),
             Plan::DropFileFormat(plan) => plan.schema(),
             Plan::ShowFileFormats(plan) => plan.schema(),
+            Plan::CreatePasswordPolicy(plan) => plan.schema(),
+            Plan::AlterPasswordPolicy(plan) => plan.schema(),
+            Plan::DropPasswordPolicy(plan) => plan.schema(),
+            Plan::DescPasswordPolicy(plan) => plan.schema(),
+            Plan::ShowPasswordPolicies(plan) => plan.schema(),
+            Plan::CreateNetworkPolicy(plan) => plan.schema(),
+            Plan::AlterNetworkPolicy(plan) => plan.schema(),
+            Plan::DropNetworkPolicy(plan) => plan.schema(),
+            Plan::DescNetworkPolicy(plan) => plan.schema(),
+            Plan::CreateMaskingPolicy(plan) => plan.schema(),
+            Plan::DropMaskingPolicy(plan) => plan.schema(),
+            Plan::DescMaskingPolicy(plan) => plan.schema(),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
             Plan::RevokePriv(_) => Arc::new(DataSchema::empty()),
             Plan::RevokeRole(_) => Arc::new(DataSchema::empty()),
             Plan::CreateUDF(_) => Arc::new(DataSchema::empty()),
@@ -435,6 +559,11 @@ impl Plan {
                 | Plan::ShowCreateDatabase(_)
                 | Plan::ShowCreateTable(_)
                 | Plan::ShowFileFormats(_)
+                | Plan::DescPasswordPolicy(_)
+                | Plan::ShowPasswordPolicies(_)
+                | Plan::DescNetworkPolicy(_)
+                | Plan::DescMaskingPolicy(_)
+                | Plan::DescRowAccessPolicy(_)
                 | Plan::ShowRoles(_)
                 | Plan::DescShare(_)
                 | Plan::ShowShares(_)
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::table::ColumnHistogramBucket;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
@@ -27,6 +28,8 @@ use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
 use crate::optimizer::Datum;
 use crate::optimizer::Distribution;
+use crate::optimizer::Histogram;
+use crate::optimizer::HistogramBucket;
 use crate::optimizer::PhysicalProperty;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
@@ -51,6 +54,18 @@ pub struct Prewhere {
     pub predicates: Vec<ScalarExpr>,
 }
 
+/// Nearest neighbours search of `ORDER BY <distance>(column, query) LIMIT limit`,
+/// the scan probes the vector indexes of the column for the candidate blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanVectorTopK {
+    pub column: IndexType,
+    /// The distance function, such as `cosine_distance`
+    pub func_name: String,
+    /// The constant query vector, casted to `Array(Float32)`
+    pub query: ScalarExpr,
+    pub limit: usize,
+}
+
 #[derive(Clone, Debug)]
 pub struct Statistics {
     // statistics will be ignored in comparison and hashing
@@ -67,6 +82,7 @@ pub struct Scan {
     pub limit: Option<usize>,
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
+    pub vector_top_k: Option<ScanVectorTopK>,
 
     pub statistics: Statistics,
 }
@@ -92,6 +108,7 @@ impl Scan {
                 col_stats,
             },
             prewhere,
+            vector_top_k: self.vector_top_k.clone(),
         }
     }
 }
@@ -153,19 +170,24 @@ impl Operator for Scan {
                 let min_datum = Datum::from_scalar(&min);
                 let max_datum = Datum::from_scalar(&max);
                 if let (Some(min), Some(max)) = (min_datum, max_datum) {
-                    let histogram = histogram_from_ndv(
-                        col_stat.number_of_distinct_values,
-                        num_rows,
-                        Some((min.clone(), max.clone())),
-                        DEFAULT_HISTOGRAM_BUCKETS,
-                    )
-                    .ok();
+                    let histogram = match histogram_from_buckets(col_stat.histogram.as_ref()) {
+                        Some(histogram) => Some(histogram),
+                        None => histogram_from_ndv(
+                            col_stat.number_of_distinct_values,
+                            num_rows,
+                            Some((min.clone(), max.clone())),
+                            DEFAULT_HISTOGRAM_BUCKETS,
+                        )
+                        .ok(),
+                    };
                     let column_stat = ColumnStat {
                         min,
                         max,
                         ndv: col_stat.number_of_distinct_values as f64,
                         null_count: col_stat.null_count,
                         histogram,
+                        avg_size: (num_rows > 0 && col_stat.in_memory_size > 0)
+                            .then(|| col_stat.in_memory_size as f64 / num_rows as f64),
                     };
                     column_stats.insert(*k as IndexType, column_stat);
                 }
@@ -234,3 +256,19 @@ impl Operator for Scan {
         unreachable!()
     }
 }
+
+// Converts the histogram collected by `ANALYZE TABLE` to the optimizer's histogram.
+fn histogram_from_buckets(buckets: Option<&Vec<ColumnHistogramBucket>>) -> Option<Histogram> {
+    let buckets = buckets?
+        .iter()
+        .map(|bucket| {
+            Datum::from_scalar(&bucket.upper_bound).map(|upper_bound| {
+                HistogramBucket::new(upper_bound, bucket.num_values, bucket.num_distinct)
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if buckets.is_empty() {
+        return None;
+    }
+    Some(Histogram::new(buckets, true))
+}
@@ -151,6 +151,90 @@ impl<'a> TypeChecker<'a> {
         Ok((scalar.clone(), data_type.clone()))
     }
 
+    /// Resolve a column binding, virtual computed columns are replaced by their expressions.
+    #[async_backtrace::framed]
+    pub async fn resolve_column_binding(
+        &mut self,
+        span: Span,
+        column: &ColumnBinding,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if let Some(virtual_computed_expr) = self.virtual_computed_expr_of_column(column) {
+            return self
+                .resolve_virtual_computed_column(span, column, &virtual_computed_expr)
+                .await;
+        }
+        let data_type = *column.data_type.clone();
+        Ok(Box::new((
+            BoundColumnRef {
+                span,
+                column: column.clone(),
+            }
+            .into(),
+            data_type,
+        )))
+    }
+
+    fn virtual_computed_expr_of_column(&self, column: &ColumnBinding) -> Option<String> {
+        column.table_index?;
+        match self.metadata.read().column(column.index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                virtual_computed_expr,
+                ..
+            }) => virtual_computed_expr.clone(),
+            _ => None,
+        }
+    }
+
+    /// Virtual computed columns are not stored, resolve the computed expression
+    /// against the other columns of the same table instead.
+    #[async_backtrace::framed]
+    async fn resolve_virtual_computed_column(
+        &mut self,
+        span: Span,
+        column: &ColumnBinding,
+        virtual_computed_expr: &str,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let mut table_context = BindContext::new();
+        let mut bind_context: Option<&BindContext> = Some(&*self.bind_context);
+        while let Some(current) = bind_context {
+            for binding in current.columns.iter() {
+                if binding.table_index == column.table_index
+                    && binding.index != column.index
+                    && table_context
+                        .columns
+                        .iter()
+                        .all(|bound| bound.index != binding.index)
+                {
+                    table_context.add_column_binding(binding.clone());
+                }
+            }
+            bind_context = current.parent.as_deref();
+        }
+
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(virtual_computed_expr)?;
+        let expr = parse_expr(&tokens, sql_dialect)?;
+        let mut type_checker = TypeChecker::new(
+            &mut table_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let box (scalar, scalar_type) = type_checker.resolve(&expr).await.map_err(|e| {
+            e.set_span(span).add_message_back(format!(
+                " (while resolving virtual computed column {})",
+                column.column_name
+            ))
+        })?;
+        let data_type = *column.data_type.clone();
+        if scalar_type == data_type {
+            Ok(Box::new((scalar, data_type)))
+        } else {
+            Ok(Box::new((wrap_cast(&scalar, &data_type), data_type)))
+        }
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn resolve(&mut self, expr: &Expr) -> Result<Box<(ScalarExpr, DataType)>> {
@@ -189,6 +273,17 @@ impl<'a> TypeChecker<'a> {
                 )?;
                 let (scalar, data_type) = match result {
                     NameResolutionResult::Column(column) => {
+                        if let Some(virtual_computed_expr) =
+                            self.virtual_computed_expr_of_column(&column)
+                        {
+                            return self
+                                .resolve_virtual_computed_column(
+                                    *span,
+                                    &column,
+                                    &virtual_computed_expr,
+                                )
+                                .await;
+                        }
                         let data_type = *column.data_type.clone();
                         (
                             BoundColumnRef {
@@ -1673,6 +1768,7 @@ impl<'a> TypeChecker<'a> {
             "current_role",
             "connection_id",
             "timezone",
+            "nextval",
             "nullif",
             "ifnull",
             "is_null",
@@ -1745,6 +1841,25 @@ impl<'a> TypeChecker<'a> {
                     .await,
                 )
             }
+            (
+                "nextval",
+                &[
+                    Expr::Literal {
+                        lit: Literal::String(sequence),
+                        ..
+                    },
+                ],
+            ) => {
+                // nextval('seq') is evaluated per row when the expression is executed,
+                // only check that the sequence exists here and bind it as a normal function.
+                let sequence = UserApiProvider::instance()
+                    .get_sequence(&self.ctx.get_tenant(), sequence)
+                    .await;
+                match sequence {
+                    Ok(_) => None,
+                    Err(e) => Some(Err(e.set_span(span))),
+                }
+            }
             ("nullif", &[arg_x, arg_y]) => {
                 // Rewrite nullif(x, y) to if(x = y, null, x)
                 Some(
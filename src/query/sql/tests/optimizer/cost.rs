@@ -0,0 +1,184 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_catalog::table::ColumnStatistics;
+use common_catalog::table::TableStatistics;
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
+use common_sql::optimizer::scan_bytes;
+use common_sql::optimizer::ColumnStat;
+use common_sql::optimizer::CostFactors;
+use common_sql::optimizer::Datum;
+use common_sql::optimizer::DefaultCostModel;
+use common_sql::optimizer::RelationalProperty;
+use common_sql::plans::Exchange;
+use common_sql::plans::Scan;
+use common_sql::plans::Statistics;
+
+// Relations shaped like the tables of TPC-H at scale factor 1, by number of rows and columns.
+fn relation(cardinality: f64, columns: usize) -> RelationalProperty {
+    RelationalProperty {
+        output_columns: (0..columns).collect(),
+        cardinality,
+        ..Default::default()
+    }
+}
+
+fn lineitem() -> RelationalProperty {
+    relation(6_000_000.0, 16)
+}
+
+fn orders() -> RelationalProperty {
+    relation(1_500_000.0, 9)
+}
+
+fn customer() -> RelationalProperty {
+    relation(150_000.0, 8)
+}
+
+fn supplier() -> RelationalProperty {
+    relation(10_000.0, 7)
+}
+
+fn nation() -> RelationalProperty {
+    relation(25.0, 4)
+}
+
+fn cost_model(cluster_nodes: usize, memory_limit: u64) -> DefaultCostModel {
+    DefaultCostModel::new(CostFactors::default(), cluster_nodes, memory_limit)
+}
+
+#[test]
+fn test_join_distribution() {
+    let cluster = cost_model(3, u64::MAX);
+
+    // Dimension tables are broadcast.
+    assert!(cluster.prefer_broadcast_join(&lineitem(), &nation()));
+    assert!(cluster.prefer_broadcast_join(&lineitem(), &supplier()));
+    assert!(cluster.prefer_broadcast_join(&orders(), &supplier()));
+
+    // Large build sides are shuffled with the probe side.
+    assert!(!cluster.prefer_broadcast_join(&lineitem(), &orders()));
+    assert!(!cluster.prefer_broadcast_join(&orders(), &customer()));
+
+    // Nothing is sent through the network on a single node.
+    let standalone = cost_model(1, u64::MAX);
+    assert!(standalone.prefer_broadcast_join(&lineitem(), &orders()));
+    assert_eq!(
+        standalone.exchange_cost(&Exchange::Broadcast, 1024.0 * 1024.0),
+        0.0
+    );
+}
+
+#[test]
+fn test_join_distribution_memory_pressure() {
+    // The broadcast hash table of supplier doesn't fit in memory, but a third of it does.
+    let cluster = cost_model(3, 200 * 1024);
+    assert!(!cluster.prefer_broadcast_join(&orders(), &supplier()));
+}
+
+#[test]
+fn test_exchange_cost() {
+    let cluster = cost_model(4, u64::MAX);
+    let bytes = 1024.0 * 1024.0;
+    let broadcast = cluster.exchange_cost(&Exchange::Broadcast, bytes);
+    let shuffle = cluster.exchange_cost(&Exchange::Hash(vec![]), bytes);
+    assert_eq!(shuffle, cluster.exchange_cost(&Exchange::Merge, bytes));
+    assert_eq!(broadcast, shuffle * 4.0);
+}
+
+#[test]
+fn test_scan_bytes() {
+    let column_stats = |in_memory_size| ColumnStatistics {
+        min: Scalar::Number(NumberScalar::UInt64(0)),
+        max: Scalar::Number(NumberScalar::UInt64(100)),
+        null_count: 0,
+        number_of_distinct_values: 100,
+        in_memory_size,
+        histogram: None,
+    };
+    let scan = Scan {
+        table_index: 0,
+        columns: [0, 1, 2].into_iter().collect(),
+        push_down_predicates: None,
+        limit: None,
+        order_by: None,
+        prewhere: None,
+        vector_top_k: None,
+        statistics: Statistics {
+            statistics: Some(TableStatistics {
+                num_rows: Some(1000),
+                data_size: Some(20000),
+                data_size_compressed: Some(5000),
+                index_size: None,
+            }),
+            col_stats: HashMap::from([
+                (0, Some(column_stats(8000))),
+                (1, Some(column_stats(4000))),
+                (2, None),
+            ]),
+        },
+    };
+
+    // Compressed sizes of the columns 0 and 1, and an estimation for the column 2.
+    assert_eq!(scan_bytes(&scan), 2000.0 + 1000.0 + 8000.0);
+}
+
+#[test]
+fn test_co_partitioned_join_distribution() {
+    let cluster = cost_model(3, u64::MAX);
+    assert!(cluster.prefer_broadcast_join(&lineitem(), &supplier()));
+
+    // The probe side is already partitioned by the join keys, only the build side is shuffled.
+    let broadcast = cluster.broadcast_join_cost(&supplier());
+    let shuffle = cluster.shuffle_join_cost(&lineitem(), &supplier(), true, false);
+    assert!(shuffle < broadcast);
+
+    // Nothing is sent through the network for co-partitioned sides.
+    let co_partitioned = cluster.shuffle_join_cost(&lineitem(), &supplier(), true, true);
+    assert!(co_partitioned < shuffle);
+    let standalone = cost_model(1, u64::MAX);
+    assert_eq!(
+        co_partitioned,
+        standalone.shuffle_join_cost(&lineitem(), &supplier(), false, false)
+    );
+}
+
+// Set the average size of the values of all the columns, as derived from the block statistics.
+fn with_column_size(mut prop: RelationalProperty, avg_size: f64) -> RelationalProperty {
+    for column in prop.output_columns.iter() {
+        prop.statistics.column_stats.insert(*column, ColumnStat {
+            min: Datum::Int(0),
+            max: Datum::Int(100),
+            ndv: 100.0,
+            null_count: 0,
+            histogram: None,
+            avg_size: Some(avg_size),
+        });
+    }
+    prop
+}
+
+#[test]
+fn test_row_width_from_column_sizes() {
+    let cluster = cost_model(3, u64::MAX);
+    assert!(!cluster.prefer_broadcast_join(&orders(), &customer()));
+
+    // The rows of orders are much wider than the 8 bytes per column assumed without
+    // statistics, shuffling them costs more than broadcasting customer.
+    let wide_orders = with_column_size(orders(), 64.0);
+    assert!(cluster.prefer_broadcast_join(&wide_orders, &customer()));
+}
@@ -17,9 +17,9 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use common_base::base::GlobalInstance;
-use common_cache::CountableMeter;
-use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
 use common_config::CacheConfig;
+use common_config::CacheEvictionPolicyInnerConfig;
 use common_config::CacheStorageTypeInnerConfig;
 use common_exception::Result;
 use storages_common_cache::InMemoryCacheBuilder;
@@ -28,6 +28,7 @@ use storages_common_cache::Named;
 use storages_common_cache::NamedCache;
 use storages_common_cache::TableDataCache;
 use storages_common_cache::TableDataCacheBuilder;
+use storages_common_cache::TieredCache;
 
 use crate::caches::BloomIndexFilterCache;
 use crate::caches::BloomIndexMetaCache;
@@ -36,6 +37,7 @@ use crate::caches::FileMetaDataCache;
 use crate::caches::SegmentInfoCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
+use crate::ColumnArrayCodec;
 use crate::ColumnArrayMeter;
 use crate::PrunePartitionsCache;
 
@@ -52,6 +54,7 @@ pub struct CacheManager {
     file_meta_data_cache: Option<FileMetaDataCache>,
     table_data_cache: Option<TableDataCache>,
     table_column_array_cache: Option<ColumnArrayCache>,
+    table_column_array_demotion: bool,
 }
 
 impl CacheManager {
@@ -62,23 +65,33 @@ impl CacheManager {
             match config.data_cache_storage {
                 CacheStorageTypeInnerConfig::None => None,
                 CacheStorageTypeInnerConfig::Disk => {
+                    // bump the version whenever the format of the cached items changes,
+                    // e.g. v2 compresses the demoted column arrays
                     let real_disk_cache_root = PathBuf::from(&config.disk_cache_config.path)
                         .join(tenant_id.into())
-                        .join("v1");
+                        .join("v2");
                     Self::new_block_data_cache(
                         &real_disk_cache_root,
                         config.table_data_cache_population_queue_size,
                         config.disk_cache_config.max_bytes,
+                        Self::eviction_policy(config, "table_data_cache"),
                     )?
                 }
             }
         };
 
-        // setup in-memory table column cache
-        let table_column_array_cache = Self::new_in_memory_cache(
+        // setup in-memory table column cache, demoting the evicted columns to disk if enabled
+        let table_column_array_demotion = config.enable_table_column_array_demotion
+            && config.table_data_deserialized_data_bytes > 0
+            && table_data_cache.is_some();
+        let table_column_array_cache = Self::new_column_array_cache(
             config.table_data_deserialized_data_bytes,
-            ColumnArrayMeter,
-            "table_data_column_array",
+            Self::eviction_policy(config, "table_column_array_cache"),
+            if table_column_array_demotion {
+                table_data_cache.clone()
+            } else {
+                None
+            },
         );
 
         // setup in-memory table meta cache
@@ -93,25 +106,45 @@ impl CacheManager {
                 table_statistic_cache: None,
                 table_data_cache,
                 table_column_array_cache,
+                table_column_array_demotion,
             }));
         } else {
-            let table_snapshot_cache =
-                Self::new_item_cache(config.table_meta_snapshot_count, "table_snapshot");
-            let table_statistic_cache =
-                Self::new_item_cache(config.table_meta_statistic_count, "table_statistics");
-            let segment_info_cache =
-                Self::new_item_cache(config.table_meta_segment_count, "segment_info");
-            let bloom_index_filter_cache =
-                Self::new_item_cache(config.table_bloom_index_filter_count, "bloom_index_filter");
+            let table_snapshot_cache = Self::new_item_cache(
+                config.table_meta_snapshot_count,
+                Self::eviction_policy(config, "table_snapshot_cache"),
+                "table_snapshot",
+            );
+            let table_statistic_cache = Self::new_item_cache(
+                config.table_meta_statistic_count,
+                Self::eviction_policy(config, "table_snapshot_statistic_cache"),
+                "table_statistics",
+            );
+            let segment_info_cache = Self::new_item_cache(
+                config.table_meta_segment_count,
+                Self::eviction_policy(config, "segment_info_cache"),
+                "segment_info",
+            );
+            let bloom_index_filter_cache = Self::new_item_cache(
+                config.table_bloom_index_filter_count,
+                Self::eviction_policy(config, "bloom_index_filter_cache"),
+                "bloom_index_filter",
+            );
             let bloom_index_meta_cache = Self::new_item_cache(
                 config.table_bloom_index_meta_count,
+                Self::eviction_policy(config, "bloom_index_meta_cache"),
                 "bloom_index_file_meta_data",
             );
-            let prune_partitions_cache =
-                Self::new_item_cache(config.table_prune_partitions_count, "prune_partitions");
+            let prune_partitions_cache = Self::new_item_cache(
+                config.table_prune_partitions_count,
+                Self::eviction_policy(config, "prune_partitions_cache"),
+                "prune_partitions",
+            );
 
-            let file_meta_data_cache =
-                Self::new_item_cache(DEFAULT_FILE_META_DATA_CACHE_ITEMS, "parquet_file_meta");
+            let file_meta_data_cache = Self::new_item_cache(
+                DEFAULT_FILE_META_DATA_CACHE_ITEMS,
+                Self::eviction_policy(config, "file_meta_data_cache"),
+                "parquet_file_meta",
+            );
             GlobalInstance::set(Arc::new(Self {
                 table_snapshot_cache,
                 segment_info_cache,
@@ -122,6 +155,7 @@ impl CacheManager {
                 table_statistic_cache,
                 table_data_cache,
                 table_column_array_cache,
+                table_column_array_demotion,
             }));
         }
 
@@ -164,35 +198,50 @@ impl CacheManager {
         self.table_data_cache.clone()
     }
 
+    /// Whether the column arrays evicted from the table column array cache are demoted to the
+    /// table data cache, in which case their raw data needn't be cached there as well.
+    pub fn is_table_column_array_demoted(&self) -> bool {
+        self.table_column_array_demotion
+    }
+
     pub fn get_table_data_array_cache(&self) -> Option<ColumnArrayCache> {
         self.table_column_array_cache.clone()
     }
 
+    // the eviction policy of the cache named `name` in `system.caches`
+    fn eviction_policy(config: &CacheConfig, name: &str) -> EvictionPolicy {
+        match config.eviction_policy_of(name) {
+            CacheEvictionPolicyInnerConfig::Lru => EvictionPolicy::Lru,
+            CacheEvictionPolicyInnerConfig::S3Fifo => EvictionPolicy::S3Fifo,
+        }
+    }
+
     // create cache that meters size by `Count`
     fn new_item_cache<V>(
         capacity: u64,
+        policy: EvictionPolicy,
         name: impl Into<String>,
     ) -> Option<NamedCache<InMemoryItemCacheHolder<V>>> {
         if capacity > 0 {
-            Some(InMemoryCacheBuilder::new_item_cache(capacity).name_with(name.into()))
+            Some(InMemoryCacheBuilder::new_item_cache(capacity, policy).name_with(name.into()))
         } else {
             None
         }
     }
 
-    // create cache that meters size by `meter`
-    fn new_in_memory_cache<V, M>(
+    // create column array cache that meters size by `ColumnArrayMeter`, and demotes the
+    // evicted column arrays to `disk` if any
+    fn new_column_array_cache(
         capacity: u64,
-        meter: M,
-        name: &str,
-    ) -> Option<NamedCache<InMemoryItemCacheHolder<V, DefaultHashBuilder, M>>>
-    where
-        M: CountableMeter<String, Arc<V>>,
-    {
+        policy: EvictionPolicy,
+        disk: Option<TableDataCache>,
+    ) -> Option<ColumnArrayCache> {
         if capacity > 0 {
+            let memory =
+                InMemoryCacheBuilder::new_in_memory_cache(capacity, ColumnArrayMeter, policy);
             Some(
-                InMemoryCacheBuilder::new_in_memory_cache(capacity, meter)
-                    .name_with(name.to_owned()),
+                TieredCache::new(memory, disk, Arc::new(ColumnArrayCodec))
+                    .name_with("table_data_column_array"),
             )
         } else {
             None
@@ -203,12 +252,14 @@ impl CacheManager {
         path: &PathBuf,
         population_queue_size: u32,
         disk_cache_bytes_size: u64,
+        policy: EvictionPolicy,
     ) -> Result<Option<TableDataCache>> {
         if disk_cache_bytes_size > 0 {
             let cache_holder = TableDataCacheBuilder::new_table_data_disk_cache(
                 path,
                 population_queue_size,
                 disk_cache_bytes_size,
+                policy,
             )?;
             Ok(Some(cache_holder))
         } else {
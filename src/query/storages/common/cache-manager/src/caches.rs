@@ -13,16 +13,29 @@
 // limitations under the License.
 
 use std::borrow::Borrow;
+use std::io::Cursor;
 use std::sync::Arc;
 
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema;
+use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::FileReader;
+use common_arrow::arrow::io::ipc::write::Compression;
+use common_arrow::arrow::io::ipc::write::FileWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
 use common_arrow::parquet::metadata::FileMetaData;
 use common_cache::DefaultHashBuilder;
 use common_cache::Meter;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::InMemoryItemCacheHolder;
 use storages_common_cache::NamedCache;
+use storages_common_cache::TierCodec;
+use storages_common_cache::TieredCache;
 use storages_common_index::filters::Xor8Filter;
 use storages_common_index::BloomIndexMeta;
 use storages_common_table_meta::meta::SegmentInfo;
@@ -47,9 +60,9 @@ pub type FileMetaDataCache = NamedCache<InMemoryItemCacheHolder<FileMetaData>>;
 
 pub type PrunePartitionsCache = NamedCache<InMemoryItemCacheHolder<(PartStatistics, Partitions)>>;
 
-/// In memory object cache of table column array
+/// In memory object cache of table column array, optionally backed by the table data disk cache
 pub type ColumnArrayCache =
-    NamedCache<InMemoryItemCacheHolder<SizedColumnArray, DefaultHashBuilder, ColumnArrayMeter>>;
+    NamedCache<TieredCache<SizedColumnArray, DefaultHashBuilder, ColumnArrayMeter>>;
 pub type ArrayRawDataUncompressedSize = usize;
 pub type SizedColumnArray = (
     Box<dyn common_arrow::arrow::array::Array>,
@@ -124,3 +137,42 @@ impl<K, V> Meter<K, Arc<(V, usize)>> for ColumnArrayMeter {
         v.1
     }
 }
+
+/// Encodes the column arrays demoted to the table data disk cache as LZ4 compressed arrow IPC
+/// files, prefixed by the uncompressed size of the raw data (le u64).
+pub struct ColumnArrayCodec;
+
+impl TierCodec<SizedColumnArray> for ColumnArrayCodec {
+    fn encode(&self, value: &SizedColumnArray) -> Result<Vec<u8>> {
+        let (array, size) = value;
+        let mut buffer = (*size as u64).to_le_bytes().to_vec();
+        let schema = Schema::from(vec![Field::new("", array.data_type().clone(), true)]);
+        let options = WriteOptions {
+            compression: Some(Compression::LZ4),
+        };
+        let mut writer = FileWriter::new(&mut buffer, schema, None, options);
+        writer.start()?;
+        writer.write(&Chunk::new(vec![array.clone()]), None)?;
+        writer.finish()?;
+        Ok(buffer)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<SizedColumnArray> {
+        if bytes.len() < 8 {
+            return Err(ErrorCode::StorageOther(format!(
+                "invalid demoted column array, length {}",
+                bytes.len()
+            )));
+        }
+        let size = u64::from_le_bytes(bytes[0..8].try_into().unwrap()) as usize;
+        let mut cursor = Cursor::new(&bytes[8..]);
+        let metadata = read_file_metadata(&mut cursor)?;
+        let mut reader = FileReader::new(cursor, metadata, None, None);
+        match reader.next() {
+            Some(chunk) => Ok((chunk?.into_arrays().remove(0), size)),
+            None => Err(ErrorCode::StorageOther(
+                "invalid demoted column array, no array found",
+            )),
+        }
+    }
+}
@@ -16,9 +16,11 @@ use std::hash::BuildHasher;
 use std::hash::Hash;
 use std::sync::Arc;
 
+use common_cache::CacheStatistics;
 use common_cache::Count;
 use common_cache::CountableMeter;
 use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
 
 use crate::metrics_inc_cache_access_count;
 use crate::metrics_inc_cache_hit_count;
@@ -40,6 +42,10 @@ where
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+    /// The eviction policy, and the hits, misses and evictions of the cache if tracked.
+    fn statistics(&self) -> Option<(EvictionPolicy, CacheStatistics)> {
+        None
+    }
 }
 
 /// Helper trait to convert a Cache into NamedCache
@@ -110,4 +116,8 @@ where
     fn contains_key(&self, k: &str) -> bool {
         self.cache.contains_key(k)
     }
+
+    fn statistics(&self) -> Option<(EvictionPolicy, CacheStatistics)> {
+        self.cache.statistics()
+    }
 }
@@ -17,16 +17,20 @@ use std::fs::File;
 use std::hash::Hasher;
 use std::io::IoSlice;
 use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use common_cache::Cache;
+use common_cache::CacheStatistics;
 use common_cache::Count;
 use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
 use common_cache::FileSize;
-use common_cache::LruCache;
+use common_cache::PolicyCache;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use parking_lot::RwLock;
@@ -85,7 +89,52 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
             cache: C::with_meter_and_hasher(size, FileSize, DefaultHashBuilder::default()),
             root: PathBuf::from(path),
         }
-        .init()
+        .init(|_, _| true)
+    }
+
+    /// Create an `DiskCache` like [`DiskCache::new`], except that the existing files in `path`
+    /// rejected by `is_intact`, given the path and the size of the file, are removed instead of
+    /// being stored, e.g. the files left truncated by a crash.
+    pub fn new_with_validator<T, F>(path: T, size: u64, is_intact: F) -> self::result::Result<Self>
+    where
+        PathBuf: From<T>,
+        F: Fn(&Path, u64) -> bool,
+    {
+        DiskCache {
+            cache: C::with_meter_and_hasher(size, FileSize, DefaultHashBuilder::default()),
+            root: PathBuf::from(path),
+        }
+        .init(is_intact)
+    }
+}
+
+impl DiskCache<PolicyCache<String, u64, DefaultHashBuilder, FileSize>> {
+    /// Create an `DiskCache` like [`DiskCache::new_with_validator`], except that the files are
+    /// evicted by `policy` instead of the LRU.
+    pub fn new_with_policy<T, F>(
+        path: T,
+        size: u64,
+        policy: EvictionPolicy,
+        is_intact: F,
+    ) -> self::result::Result<Self>
+    where
+        PathBuf: From<T>,
+        F: Fn(&Path, u64) -> bool,
+    {
+        DiskCache {
+            cache: PolicyCache::with_policy(policy, size, FileSize, DefaultHashBuilder::default()),
+            root: PathBuf::from(path),
+        }
+        .init(is_intact)
+    }
+
+    pub fn policy(&self) -> EvictionPolicy {
+        self.cache.policy()
+    }
+
+    /// Return the hits, misses and evictions of the files since the cache was created.
+    pub fn statistics(&self) -> CacheStatistics {
+        self.cache.statistics()
     }
 }
 
@@ -121,11 +170,17 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
         self.root.join(rel_path)
     }
 
-    /// Scan `self.root` for existing files and store them.
-    fn init(mut self) -> self::result::Result<Self> {
+    /// Scan `self.root` for existing files and store the intact ones.
+    fn init<F>(mut self, is_intact: F) -> self::result::Result<Self>
+    where F: Fn(&Path, u64) -> bool {
         fs::create_dir_all(&self.root)?;
         for (file, size) in get_all_files(&self.root) {
-            if !self.can_store(size) {
+            if !is_intact(&file, size) {
+                warn!("removing corrupted disk cache file `{:?}`", file);
+                fs::remove_file(&file).unwrap_or_else(|e| {
+                    error!("Error removing corrupted file `{:?}`: {}", file, e)
+                });
+            } else if !self.can_store(size) {
                 fs::remove_file(file).unwrap_or_else(|e| {
                     error!(
                         "Error removing file `{}` which is too large for the cache ({} bytes)",
@@ -194,17 +249,19 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
             return Err(Error::FileTooLarge);
         }
 
-        // check eviction
-        if self.cache.size() + bytes_len > self.cache.capacity() {
-            if let Some((rel_path, _)) = self.cache.pop_by_policy() {
-                let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
-                fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
-                    error!(
-                        "Error removing file from cache: `{:?}`: {}",
-                        cached_item_path, e
-                    )
-                });
-            }
+        // check eviction, the files are removed here so that `put` evicts nothing
+        while self.cache.size() + bytes_len > self.cache.capacity() {
+            let (rel_path, _) = self
+                .cache
+                .pop_by_policy()
+                .expect("Unexpectedly empty cache!");
+            let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
+            fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
+                error!(
+                    "Error removing file from cache: `{:?}`: {}",
+                    cached_item_path, e
+                )
+            });
         }
 
         let cache_key = self.cache_key(key.as_ref());
@@ -299,9 +356,7 @@ pub mod result {
 
 use result::*;
 
-impl CacheAccessor<String, Vec<u8>, common_cache::DefaultHashBuilder, Count>
-    for LruDiskCacheHolder
-{
+impl CacheAccessor<String, Vec<u8>, common_cache::DefaultHashBuilder, Count> for DiskCacheHolder {
     fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<Vec<u8>>> {
         let k = k.as_ref();
         {
@@ -333,9 +388,9 @@ impl CacheAccessor<String, Vec<u8>, common_cache::DefaultHashBuilder, Count>
                         }
                         None
                     } else {
-                        // trim the checksum bytes and return
+                        // trim the trailer bytes and return
                         let total_len = bytes.len();
-                        let body_len = total_len - 4;
+                        let body_len = total_len - TRAILER_LEN;
                         bytes.truncate(body_len);
                         let item = Arc::new(bytes);
                         Some(item)
@@ -350,10 +405,9 @@ impl CacheAccessor<String, Vec<u8>, common_cache::DefaultHashBuilder, Count>
     }
 
     fn put(&self, key: String, value: Arc<Vec<u8>>) {
-        let crc = crc32fast::hash(value.as_slice());
-        let crc_bytes = crc.to_le_bytes();
+        let trailer = encode_trailer(value.as_slice());
         let mut cache = self.write();
-        if let Err(e) = cache.insert_bytes(&key, &[value.as_slice(), &crc_bytes]) {
+        if let Err(e) = cache.insert_bytes(&key, &[value.as_slice(), &trailer]) {
             error!("put disk cache item failed {}", e);
         }
     }
@@ -384,27 +438,79 @@ impl CacheAccessor<String, Vec<u8>, common_cache::DefaultHashBuilder, Count>
         let cache = self.read();
         cache.len()
     }
+
+    fn statistics(&self) -> Option<(EvictionPolicy, CacheStatistics)> {
+        let cache = self.read();
+        Some((cache.policy(), cache.statistics()))
+    }
+}
+
+// Each item is stored as `body | crc32 of body (le u32) | length of body (le u64)`.
+//
+// Although parquet page has built-in crc, but it is optional (and not generated in parquet2).
+// The length lets `DiskCache::init` tell the files truncated by a crash without reading them
+// through, while the crc is verified each time the item is read.
+const TRAILER_LEN: usize = 12;
+
+fn encode_trailer(body: &[u8]) -> [u8; TRAILER_LEN] {
+    let mut trailer = [0; TRAILER_LEN];
+    trailer[0..4].copy_from_slice(&crc32fast::hash(body).to_le_bytes());
+    trailer[4..].copy_from_slice(&(body.len() as u64).to_le_bytes());
+    trailer
 }
 
-/// The crc32 checksum is stored at the end of `bytes` and encoded as le u32.
-// Although parquet page has built-in crc, but it is optional (and not generated in parquet2)
+/// Validates the trailer stored at the end of `bytes`, see [`TRAILER_LEN`].
 fn validate_checksum(bytes: &[u8]) -> Result<()> {
     let total_len = bytes.len();
-    if total_len <= 4 {
-        Err(ErrorCode::StorageOther(format!(
+    if total_len < TRAILER_LEN {
+        return Err(ErrorCode::StorageOther(format!(
             "crc checksum validation failure: invalid file length {total_len}"
-        )))
+        )));
+    }
+
+    let body_len = total_len - TRAILER_LEN;
+    // total_len >= TRAILER_LEN is ensured
+    let crc_bytes: [u8; 4] = bytes[body_len..body_len + 4].try_into().unwrap();
+    let len_bytes: [u8; 8] = bytes[body_len + 4..].try_into().unwrap();
+    let len_provided = u64::from_le_bytes(len_bytes);
+    if len_provided != body_len as u64 {
+        return Err(ErrorCode::StorageOther(format!(
+            "crc checksum validation failure: length not match, length provided {len_provided}, actual length {body_len}"
+        )));
+    }
+
+    let crc_provided = u32::from_le_bytes(crc_bytes);
+    let crc_calculated = crc32fast::hash(&bytes[0..body_len]);
+    if crc_provided == crc_calculated {
+        Ok(())
     } else {
-        // total_len > 4 is ensured
-        let crc_bytes: [u8; 4] = bytes[total_len - 4..].try_into().unwrap();
-        let crc_provided = u32::from_le_bytes(crc_bytes);
-        let crc_calculated = crc32fast::hash(&bytes[0..total_len - 4]);
-        if crc_provided == crc_calculated {
-            Ok(())
-        } else {
-            Err(ErrorCode::StorageOther(format!(
-                "crc checksum validation failure, key : crc checksum not match, crc provided {crc_provided}, crc calculated {crc_calculated}"
-            )))
+        Err(ErrorCode::StorageOther(format!(
+            "crc checksum validation failure, key : crc checksum not match, crc provided {crc_provided}, crc calculated {crc_calculated}"
+        )))
+    }
+}
+
+/// Returns `true` if the length recorded in the trailer of the file at `path` matches its
+/// `size`, the crc is left to be verified when the item is read.
+fn validate_length(path: &Path, size: u64) -> bool {
+    if size < TRAILER_LEN as u64 {
+        return false;
+    }
+    let read_length = || {
+        let mut file = File::open(path)?;
+        file.seek(SeekFrom::End(-8))?;
+        let mut len_bytes = [0; 8];
+        file.read_exact(&mut len_bytes)?;
+        Ok::<_, std::io::Error>(u64::from_le_bytes(len_bytes))
+    };
+    match read_length() {
+        Ok(len) => len + TRAILER_LEN as u64 == size,
+        Err(e) => {
+            warn!(
+                "failed to read the trailer of disk cache file `{:?}`. {e}",
+                path
+            );
+            false
         }
     }
 }
@@ -426,17 +532,19 @@ fn get_all_files<P: AsRef<Path>>(path: P) -> impl Iterator<Item = (PathBuf, u64)
         })
 }
 
-pub type LruDiskCache = DiskCache<LruCache<String, u64, DefaultHashBuilder, FileSize>>;
-pub type LruDiskCacheHolder = Arc<RwLock<LruDiskCache>>;
+pub type PolicyDiskCache = DiskCache<PolicyCache<String, u64, DefaultHashBuilder, FileSize>>;
+pub type DiskCacheHolder = Arc<RwLock<PolicyDiskCache>>;
 
-pub struct LruDiskCacheBuilder;
-impl LruDiskCacheBuilder {
+pub struct DiskCacheBuilder;
+impl DiskCacheBuilder {
     pub fn new_disk_cache(
         path: &PathBuf,
         disk_cache_bytes_size: u64,
-    ) -> Result<LruDiskCacheHolder> {
-        let external_cache = DiskCache::new(path, disk_cache_bytes_size)
-            .map_err(|e| ErrorCode::StorageOther(format!("create disk cache failed, {e}")))?;
+        policy: EvictionPolicy,
+    ) -> Result<DiskCacheHolder> {
+        let external_cache =
+            DiskCache::new_with_policy(path, disk_cache_bytes_size, policy, validate_length)
+                .map_err(|e| ErrorCode::StorageOther(format!("create disk cache failed, {e}")))?;
         Ok(Arc::new(RwLock::new(external_cache)))
     }
 }
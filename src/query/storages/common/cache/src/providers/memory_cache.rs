@@ -16,15 +16,15 @@ use std::hash::BuildHasher;
 use std::sync::Arc;
 
 use common_cache::BytesMeter;
-use common_cache::Cache;
 use common_cache::Count;
 use common_cache::CountableMeter;
 use common_cache::DefaultHashBuilder;
-use common_cache::LruCache;
+use common_cache::EvictionPolicy;
+use common_cache::PolicyCache;
 use parking_lot::RwLock;
 
-pub type ImMemoryCache<V, S, M> = LruCache<String, Arc<V>, S, M>;
-pub type BytesCache = LruCache<String, Arc<Vec<u8>>, DefaultHashBuilder, BytesMeter>;
+pub type ImMemoryCache<V, S, M> = PolicyCache<String, Arc<V>, S, M>;
+pub type BytesCache = PolicyCache<String, Arc<Vec<u8>>, DefaultHashBuilder, BytesMeter>;
 
 pub type InMemoryItemCacheHolder<T, S = DefaultHashBuilder, M = Count> =
     Arc<RwLock<ImMemoryCache<T, S, M>>>;
@@ -36,24 +36,25 @@ impl InMemoryCacheBuilder {
     pub fn new_in_memory_cache<V, M>(
         capacity: u64,
         meter: M,
+        policy: EvictionPolicy,
     ) -> InMemoryItemCacheHolder<V, DefaultHashBuilder, M>
     where
         M: CountableMeter<String, Arc<V>>,
     {
-        let cache = LruCache::with_meter_and_hasher(capacity, meter, DefaultHashBuilder::new());
+        let cache = PolicyCache::with_policy(policy, capacity, meter, DefaultHashBuilder::new());
         Arc::new(RwLock::new(cache))
     }
 
     // new cache that caches `V` and meter by counting
-    pub fn new_item_cache<V>(capacity: u64) -> InMemoryItemCacheHolder<V> {
-        let cache = LruCache::new(capacity);
+    pub fn new_item_cache<V>(capacity: u64, policy: EvictionPolicy) -> InMemoryItemCacheHolder<V> {
+        let cache = PolicyCache::with_policy(policy, capacity, Count, DefaultHashBuilder::new());
         Arc::new(RwLock::new(cache))
     }
 
     // new cache that cache `Vec<u8>`, and metered by byte size
-    pub fn new_bytes_cache(capacity: u64) -> InMemoryBytesCacheHolder {
+    pub fn new_bytes_cache(capacity: u64, policy: EvictionPolicy) -> InMemoryBytesCacheHolder {
         let cache =
-            LruCache::with_meter_and_hasher(capacity, BytesMeter, DefaultHashBuilder::new());
+            PolicyCache::with_policy(policy, capacity, BytesMeter, DefaultHashBuilder::new());
         Arc::new(RwLock::new(cache))
     }
 }
@@ -62,17 +63,18 @@ impl InMemoryCacheBuilder {
 mod impls {
     use std::sync::Arc;
 
+    use common_cache::Cache;
+    use common_cache::CacheStatistics;
     use parking_lot::RwLock;
 
     use super::*;
     use crate::cache::CacheAccessor;
 
-    // Wrap a Cache with RwLock, and impl CacheAccessor for it
-    impl<V, C, S, M> CacheAccessor<String, V, S, M> for Arc<RwLock<C>>
+    // Wrap a PolicyCache with RwLock, and impl CacheAccessor for it
+    impl<V, S, M> CacheAccessor<String, V, S, M> for Arc<RwLock<PolicyCache<String, Arc<V>, S, M>>>
     where
-        C: Cache<String, Arc<V>, S, M>,
         M: CountableMeter<String, Arc<V>>,
-        S: BuildHasher,
+        S: BuildHasher + Clone,
     {
         fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<V>> {
             let mut guard = self.write();
@@ -103,6 +105,11 @@ mod impls {
             let guard = self.read();
             guard.len()
         }
+
+        fn statistics(&self) -> Option<(EvictionPolicy, CacheStatistics)> {
+            let guard = self.read();
+            Some((guard.policy(), guard.statistics()))
+        }
     }
 
     // Wrap an Option<CacheAccessor>, and impl CacheAccessor for it
@@ -153,5 +160,9 @@ mod impls {
                 0
             }
         }
+
+        fn statistics(&self) -> Option<(EvictionPolicy, CacheStatistics)> {
+            self.as_ref().and_then(|cache| cache.statistics())
+        }
     }
 }
@@ -15,13 +15,14 @@
 mod disk_cache;
 mod memory_cache;
 mod table_data_cache;
+mod tiered_cache;
 pub use disk_cache::result::Error as DiskCacheError;
 pub use disk_cache::result::Result as DiskCacheResult;
 pub use disk_cache::DiskCache;
+pub use disk_cache::DiskCacheBuilder;
+pub use disk_cache::DiskCacheHolder;
 pub use disk_cache::DiskCacheKey;
-pub use disk_cache::LruDiskCache;
-pub use disk_cache::LruDiskCacheBuilder;
-pub use disk_cache::LruDiskCacheHolder;
+pub use disk_cache::PolicyDiskCache;
 pub use memory_cache::BytesCache;
 pub use memory_cache::ImMemoryCache;
 pub use memory_cache::InMemoryBytesCacheHolder;
@@ -30,3 +31,5 @@ pub use memory_cache::InMemoryItemCacheHolder;
 pub use table_data_cache::TableDataCache;
 pub use table_data_cache::TableDataCacheBuilder;
 pub use table_data_cache::TableDataCacheKey;
+pub use tiered_cache::TierCodec;
+pub use tiered_cache::TieredCache;
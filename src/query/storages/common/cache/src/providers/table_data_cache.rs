@@ -16,8 +16,10 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::thread::JoinHandle;
 
+use common_cache::CacheStatistics;
 use common_cache::Count;
 use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use crossbeam_channel::TrySendError;
@@ -29,9 +31,9 @@ use crate::metrics_inc_cache_hit_count;
 use crate::metrics_inc_cache_miss_count;
 use crate::metrics_inc_cache_population_overflow_count;
 use crate::metrics_inc_cache_population_pending_count;
-use crate::providers::LruDiskCacheHolder;
+use crate::providers::DiskCacheHolder;
 use crate::CacheAccessor;
-use crate::LruDiskCacheBuilder;
+use crate::DiskCacheBuilder;
 
 struct CacheItem {
     key: String,
@@ -64,7 +66,7 @@ impl AsRef<str> for TableDataCacheKey {
 }
 
 #[derive(Clone)]
-pub struct TableDataCache<T = LruDiskCacheHolder> {
+pub struct TableDataCache<T = DiskCacheHolder> {
     external_cache: T,
     population_queue: crossbeam_channel::Sender<CacheItem>,
     _cache_populator: DiskCachePopulator,
@@ -78,8 +80,9 @@ impl TableDataCacheBuilder {
         path: &PathBuf,
         population_queue_size: u32,
         disk_cache_bytes_size: u64,
-    ) -> Result<TableDataCache<LruDiskCacheHolder>> {
-        let disk_cache = LruDiskCacheBuilder::new_disk_cache(path, disk_cache_bytes_size)?;
+        policy: EvictionPolicy,
+    ) -> Result<TableDataCache<DiskCacheHolder>> {
+        let disk_cache = DiskCacheBuilder::new_disk_cache(path, disk_cache_bytes_size, policy)?;
         let (rx, tx) = crossbeam_channel::bounded(population_queue_size as usize);
         let num_population_thread = 1;
         Ok(TableDataCache {
@@ -138,6 +141,10 @@ impl CacheAccessor<String, Vec<u8>, DefaultHashBuilder, Count> for TableDataCach
     fn len(&self) -> usize {
         self.external_cache.len()
     }
+
+    fn statistics(&self) -> Option<(EvictionPolicy, CacheStatistics)> {
+        self.external_cache.statistics()
+    }
 }
 
 struct CachePopulationWorker<T> {
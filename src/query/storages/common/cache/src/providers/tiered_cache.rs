@@ -0,0 +1,154 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::BuildHasher;
+use std::sync::Arc;
+
+use common_cache::CacheStatistics;
+use common_cache::Count;
+use common_cache::CountableMeter;
+use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
+use common_exception::Result;
+use tracing::warn;
+
+use crate::providers::InMemoryItemCacheHolder;
+use crate::CacheAccessor;
+use crate::TableDataCache;
+
+/// Encodes the items demoted from the memory tier of a [`TieredCache`] to its disk tier, and
+/// decodes them while being promoted back.
+pub trait TierCodec<V>: Send + Sync {
+    fn encode(&self, value: &V) -> Result<Vec<u8>>;
+    fn decode(&self, bytes: &[u8]) -> Result<V>;
+}
+
+/// An in-memory cache backed by a disk cache: the items evicted from memory are demoted to
+/// disk instead of being dropped, and promoted back to memory once hit on disk.
+pub struct TieredCache<V, S = DefaultHashBuilder, M = Count, D = TableDataCache> {
+    memory: InMemoryItemCacheHolder<V, S, M>,
+    disk: Option<D>,
+    codec: Arc<dyn TierCodec<V>>,
+}
+
+impl<V, S, M, D> TieredCache<V, S, M, D> {
+    /// Without the `disk` tier, the evicted items are dropped as usual.
+    pub fn new(
+        memory: InMemoryItemCacheHolder<V, S, M>,
+        disk: Option<D>,
+        codec: Arc<dyn TierCodec<V>>,
+    ) -> Self {
+        Self {
+            memory,
+            disk,
+            codec,
+        }
+    }
+}
+
+impl<V, S, M, D: Clone> Clone for TieredCache<V, S, M, D> {
+    fn clone(&self) -> Self {
+        Self {
+            memory: self.memory.clone(),
+            disk: self.disk.clone(),
+            codec: self.codec.clone(),
+        }
+    }
+}
+
+// the demoted items are keyed apart from the raw column data kept in the same disk cache
+fn demoted_key(k: &str) -> String {
+    format!("{k}-demoted")
+}
+
+impl<V, S, M, D> CacheAccessor<String, V, S, M> for TieredCache<V, S, M, D>
+where
+    M: CountableMeter<String, Arc<V>>,
+    S: BuildHasher + Clone,
+    D: CacheAccessor<String, Vec<u8>, DefaultHashBuilder, Count>,
+{
+    fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<V>> {
+        let k = k.as_ref();
+        if let Some(item) = self.memory.get(k) {
+            return Some(item);
+        }
+
+        let disk = self.disk.as_ref()?;
+        let key = demoted_key(k);
+        let bytes = disk.get(&key)?;
+        match self.codec.decode(&bytes) {
+            Ok(item) => {
+                // the disk copy is kept, so that it needn't be written again once demoted
+                let item = Arc::new(item);
+                self.put(k.to_owned(), item.clone());
+                Some(item)
+            }
+            Err(e) => {
+                warn!("failed to decode demoted cache item, key {k}. {e}");
+                disk.evict(&key);
+                None
+            }
+        }
+    }
+
+    fn put(&self, k: String, v: Arc<V>) {
+        let (_, evicted) = {
+            let mut guard = self.memory.write();
+            guard.put_and_evict(k, v)
+        };
+
+        if let Some(disk) = &self.disk {
+            for (k, v) in evicted {
+                let key = demoted_key(&k);
+                if disk.contains_key(&key) {
+                    continue;
+                }
+                match self.codec.encode(&v) {
+                    Ok(bytes) => disk.put(key, Arc::new(bytes)),
+                    Err(e) => warn!("failed to encode evicted cache item, key {k}. {e}"),
+                }
+            }
+        }
+    }
+
+    fn evict(&self, k: &str) -> bool {
+        let in_memory = self.memory.evict(k);
+        let on_disk = self
+            .disk
+            .as_ref()
+            .map_or(false, |disk| disk.evict(&demoted_key(k)));
+        in_memory || on_disk
+    }
+
+    fn contains_key(&self, k: &str) -> bool {
+        self.memory.contains_key(k)
+            || self
+                .disk
+                .as_ref()
+                .map_or(false, |disk| disk.contains_key(&demoted_key(k)))
+    }
+
+    // the disk tier is reported as the `table_data_cache` on its own
+    fn size(&self) -> u64 {
+        self.memory.size()
+    }
+
+    fn len(&self) -> usize {
+        self.memory.len()
+    }
+
+    fn statistics(&self) -> Option<(EvictionPolicy, CacheStatistics)> {
+        self.memory.statistics()
+    }
+}
@@ -20,11 +20,16 @@ use std::io::Read;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
 
+use common_cache::CacheStatistics;
+use common_cache::EvictionPolicy;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache::DiskCacheBuilder;
 use storages_common_cache::DiskCacheError;
 use storages_common_cache::DiskCacheKey;
 use storages_common_cache::DiskCacheResult;
-use storages_common_cache::LruDiskCache as DiskCache;
+use storages_common_cache::PolicyDiskCache as DiskCache;
 use tempfile::TempDir;
 
 struct TestFixture {
@@ -188,6 +193,26 @@ fn test_add_get_lru() {
     }
 }
 
+#[test]
+fn test_policy_statistics() {
+    let f = TestFixture::new();
+    let mut c =
+        DiskCache::new_with_policy(f.tmp(), 25, EvictionPolicy::S3Fifo, |_, _| true).unwrap();
+    assert_eq!(c.policy(), EvictionPolicy::S3Fifo);
+    c.insert_single_slice("file1", &[1; 10]).unwrap();
+    c.insert_single_slice("file2", &[2; 10]).unwrap();
+    assert!(c.get_cache_path("file1").is_some());
+    assert!(c.get_cache_path("file3").is_none());
+    // Adding this third file should put the cache above the limit.
+    c.insert_single_slice("file3", &[3; 10]).unwrap();
+    assert_eq!(c.size(), 20);
+    assert_eq!(c.statistics(), CacheStatistics {
+        hits: 1,
+        misses: 1,
+        evictions: 1,
+    });
+}
+
 #[test]
 fn test_insert_bytes_too_large() {
     let f = TestFixture::new();
@@ -197,3 +222,50 @@ fn test_insert_bytes_too_large() {
         x => panic!("Unexpected result: {x:?}"),
     }
 }
+
+#[test]
+fn test_checksum_mismatch() {
+    let f = TestFixture::new();
+    let cache =
+        DiskCacheBuilder::new_disk_cache(&f.tmp().to_owned(), 1024, EvictionPolicy::Lru).unwrap();
+    cache.put("file1".to_owned(), Arc::new(vec![1; 10]));
+    assert_eq!(cache.get("file1"), Some(Arc::new(vec![1; 10])));
+
+    // Flip a byte of the stored item, the corrupted item shall be evicted once read.
+    let path = cache.write().get_cache_path("file1").unwrap();
+    let mut bytes = fs::read(&path).unwrap();
+    bytes[0] ^= 0xff;
+    fs::write(&path, bytes).unwrap();
+    assert_eq!(cache.get("file1"), None);
+    assert!(!cache.contains_key("file1"));
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_truncated_files_removed_on_init() {
+    let f = TestFixture::new();
+    let root = f.tmp().to_owned();
+    let truncated_path = {
+        let cache = DiskCacheBuilder::new_disk_cache(&root, 1024, EvictionPolicy::Lru).unwrap();
+        cache.put("file1".to_owned(), Arc::new(vec![1; 10]));
+        cache.put("file2".to_owned(), Arc::new(vec![2; 10]));
+        let path = cache.write().get_cache_path("file2").unwrap();
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(15)
+            .unwrap();
+        path
+    };
+
+    // Files not written by the cache are not trusted either.
+    let test_path = PathBuf::from(&DiskCacheKey::from("file3"));
+    f.create_file(test_path, 10);
+
+    let cache = DiskCacheBuilder::new_disk_cache(&root, 1024, EvictionPolicy::Lru).unwrap();
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.get("file1"), Some(Arc::new(vec![1; 10])));
+    assert!(!cache.contains_key("file2"));
+    assert!(!truncated_path.exists());
+}
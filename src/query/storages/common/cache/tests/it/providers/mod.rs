@@ -14,3 +14,4 @@
 //
 
 mod disk_cache;
+mod tiered_cache;
@@ -0,0 +1,85 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_cache::Count;
+use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache::DiskCacheBuilder;
+use storages_common_cache::DiskCacheHolder;
+use storages_common_cache::InMemoryCacheBuilder;
+use storages_common_cache::TierCodec;
+use storages_common_cache::TieredCache;
+use tempfile::TempDir;
+
+struct StringCodec;
+
+impl TierCodec<String> for StringCodec {
+    fn encode(&self, value: &String) -> Result<Vec<u8>> {
+        Ok(value.as_bytes().to_vec())
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<String> {
+        String::from_utf8(bytes.to_vec()).map_err(|e| ErrorCode::StorageOther(e.to_string()))
+    }
+}
+
+fn new_tiered_cache(
+    capacity: u64,
+    disk: Option<DiskCacheHolder>,
+) -> TieredCache<String, DefaultHashBuilder, Count, DiskCacheHolder> {
+    let memory = InMemoryCacheBuilder::new_item_cache(capacity, EvictionPolicy::Lru);
+    TieredCache::new(memory, disk, Arc::new(StringCodec))
+}
+
+#[test]
+fn test_demotion_and_promotion() {
+    let tempdir = TempDir::new().unwrap();
+    let disk =
+        DiskCacheBuilder::new_disk_cache(&tempdir.path().to_owned(), 1024, EvictionPolicy::Lru)
+            .unwrap();
+    let cache = new_tiered_cache(2, Some(disk.clone()));
+
+    cache.put("k1".to_owned(), Arc::new("v1".to_owned()));
+    cache.put("k2".to_owned(), Arc::new("v2".to_owned()));
+    assert_eq!(disk.len(), 0);
+
+    // k1 is demoted to disk instead of being dropped
+    cache.put("k3".to_owned(), Arc::new("v3".to_owned()));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(disk.len(), 1);
+    assert!(cache.contains_key("k1"));
+
+    // and promoted back to memory once hit, demoting k2 in turn
+    assert_eq!(cache.get("k1"), Some(Arc::new("v1".to_owned())));
+    assert_eq!(disk.len(), 2);
+    assert_eq!(cache.get("k2"), Some(Arc::new("v2".to_owned())));
+
+    assert!(cache.evict("k1"));
+    assert!(!cache.contains_key("k1"));
+    assert_eq!(cache.get("k1"), None);
+}
+
+#[test]
+fn test_without_disk_tier() {
+    let cache = new_tiered_cache(1, None);
+    cache.put("k1".to_owned(), Arc::new("v1".to_owned()));
+    cache.put("k2".to_owned(), Arc::new("v2".to_owned()));
+    assert_eq!(cache.get("k1"), None);
+    assert_eq!(cache.get("k2"), Some(Arc::new("v2".to_owned())));
+}
@@ -25,7 +25,6 @@ use common_expression::RemoteExpr;
 use common_expression::TableDataType;
 use common_expression::TableSchemaRef;
 use storages_common_table_meta::meta::BlockMeta;
-use storages_common_table_meta::meta::ColumnStatistics;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
 pub struct BlockMetaIndex {
@@ -40,6 +39,8 @@ pub struct BlockMetaIndex {
     pub segment_id: usize,
     pub segment_location: String,
     pub snapshot_location: Option<String>,
+    // the block is removed from the table, set by streams only.
+    pub is_deleted: bool,
 }
 
 #[typetag::serde(name = "block_meta_index")]
@@ -118,32 +119,27 @@ impl TopNPrunner {
             return Ok(metas);
         };
 
-        let sort_idx = if let Ok(index) = self.schema.index_of(column.as_str()) {
-            index as u32
+        let field = if let Ok(field) = self.schema.field_with_name(column.as_str()) {
+            field
         } else {
             return Ok(metas);
         };
 
         // String Type min/max is truncated
-        if matches!(
-            self.schema.field(sort_idx as usize).data_type(),
-            TableDataType::String
-        ) {
+        if matches!(field.data_type(), TableDataType::String) {
             return Ok(metas);
         }
 
-        let mut id_stats = metas
-            .iter()
-            .map(|(id, meta)| {
-                let stat = meta.col_stats.get(&sort_idx).ok_or_else(|| {
-                    ErrorCode::UnknownException(format!(
-                        "Unable to get the colStats by ColumnId: {}",
-                        sort_idx
-                    ))
-                })?;
-                Ok((id.clone(), stat.clone(), meta.clone()))
-            })
-            .collect::<Result<Vec<(BlockMetaIndex, ColumnStatistics, Arc<BlockMeta>)>>>()?;
+        // Blocks written before the column was added or its type was modified
+        // have no statistics of the column, and can not be ordered.
+        let column_id = field.column_id();
+        let mut id_stats = Vec::with_capacity(metas.len());
+        for (id, meta) in metas.iter() {
+            match meta.col_stats.get(&column_id) {
+                Some(stat) => id_stats.push((id.clone(), stat.clone(), meta.clone())),
+                None => return Ok(metas),
+            }
+        }
 
         id_stats.sort_by(|a, b| {
             if a.1.null_count + b.1.null_count != 0 && *nulls_first {
@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 pub use v0::ColumnMeta as SingleColumnMeta;
+pub use v1::HistogramBucket;
 pub use v1::TableSnapshotStatistics;
 pub use v2::BlockMeta;
 pub use v2::ColumnMeta;
@@ -27,7 +27,9 @@ pub use current::*;
 pub use statistics::ClusterKey;
 pub use statistics::ClusterStatistics;
 pub use statistics::ColumnStatistics;
+pub use statistics::HyperLogLog;
 pub use statistics::Location;
+pub use statistics::NdvSketchesOfColumns;
 pub use statistics::SnapshotId;
 pub use statistics::Statistics;
 pub use statistics::StatisticsOfColumns;
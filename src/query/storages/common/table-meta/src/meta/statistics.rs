@@ -27,6 +27,8 @@ pub type Location = (String, FormatVersion);
 pub type ClusterKey = (u32, String);
 
 pub type StatisticsOfColumns = HashMap<ColumnId, ColumnStatistics>;
+/// The NDV sketches of the columns of a segment, see [`HyperLogLog`].
+pub type NdvSketchesOfColumns = HashMap<ColumnId, HyperLogLog>;
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct ColumnStatistics {
@@ -38,6 +40,65 @@ pub struct ColumnStatistics {
     pub distinct_of_values: Option<u64>,
 }
 
+/// Number of bits of the hash value used to index the registers.
+const HLL_PRECISION: u32 = 10;
+const HLL_NUM_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// A mergeable HyperLogLog sketch to estimate the number of distinct values.
+///
+/// A sketch takes 1 KiB (about 3.3% standard error), one sketch is persisted for each
+/// column of each segment in a binary file besides the segment.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HyperLogLog {
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; HLL_NUM_REGISTERS],
+        }
+    }
+
+    /// Add a value by its 64 bits hash.
+    pub fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        // The guard bit bounds the rank to `64 - HLL_PRECISION + 1`.
+        let rank = ((hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1))).leading_zeros() + 1;
+        let register = &mut self.registers[index];
+        *register = (*register).max(rank as u8);
+    }
+
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        for (l, r) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *l = (*l).max(*r);
+        }
+    }
+
+    /// Estimate the number of distinct values.
+    pub fn count(&self) -> u64 {
+        let m = HLL_NUM_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self.registers.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            // Use linear counting for small cardinalities.
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct ClusterStatistics {
     pub cluster_key_id: u32,
@@ -126,3 +187,69 @@ impl Statistics {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::HyperLogLog;
+
+    // A well mixed 64 bits hash of `v` (splitmix64).
+    fn hash(v: u64) -> u64 {
+        let mut z = v.wrapping_add(0x9e3779b97f4a7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn sketch_of(values: impl Iterator<Item = u64>) -> HyperLogLog {
+        let mut sketch = HyperLogLog::new();
+        for v in values {
+            sketch.add_hash(hash(v));
+        }
+        sketch
+    }
+
+    fn assert_close(estimate: u64, expected: u64) {
+        let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+        assert!(
+            error < 0.1,
+            "estimate {} is too far from {}",
+            estimate,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_hyper_log_log_count() {
+        assert_eq!(HyperLogLog::new().count(), 0);
+
+        // small cardinalities are counted almost exactly
+        let sketch = sketch_of((0..10).chain(0..10));
+        assert!((9..=11).contains(&sketch.count()));
+
+        for ndv in [1_000, 10_000, 100_000] {
+            // duplicates do not count
+            let sketch = sketch_of((0..ndv).chain(0..ndv / 2));
+            assert_close(sketch.count(), ndv);
+        }
+    }
+
+    #[test]
+    fn test_hyper_log_log_merge() {
+        let mut left = sketch_of(0..30_000);
+        let right = sketch_of(20_000..50_000);
+        left.merge(&right);
+        assert_close(left.count(), 50_000);
+        // merging is the same as adding all the values into one sketch
+        assert_eq!(left, sketch_of(0..50_000));
+
+        // merging is idempotent
+        let mut sketch = sketch_of(0..10_000);
+        let count = sketch.count();
+        sketch.merge(&sketch.clone());
+        assert_eq!(sketch.count(), count);
+
+        let mut empty = HyperLogLog::new();
+        empty.merge(&right);
+        assert_eq!(empty, right);
+    }
+}
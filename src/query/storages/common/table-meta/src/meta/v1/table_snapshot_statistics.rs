@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use common_expression::ColumnId;
+use common_expression::Scalar;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -31,14 +32,34 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// equi-height histograms of columns, built from the sampled blocks.
+    #[serde(default)]
+    pub column_histograms: HashMap<ColumnId, Vec<HistogramBucket>>,
+}
+
+/// A bucket of an equi-height histogram.
+///
+/// Values of the bucket are greater than the upper bound of the previous bucket,
+/// and less than or equal to the upper bound of the bucket. The first bucket only
+/// records the min value of the column.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HistogramBucket {
+    pub upper_bound: Scalar,
+    pub num_values: f64,
+    pub num_distinct: f64,
 }
 
 impl TableSnapshotStatistics {
-    pub fn new(column_distinct_values: HashMap<ColumnId, u64>) -> Self {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        column_histograms: HashMap<ColumnId, Vec<HistogramBucket>>,
+    ) -> Self {
         Self {
             format_version: TableSnapshotStatistics::VERSION,
             snapshot_id: SnapshotId::new_v4(),
             column_distinct_values,
+            column_histograms,
         }
     }
 
@@ -49,4 +70,8 @@ impl TableSnapshotStatistics {
     pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
         &self.column_distinct_values
     }
+
+    pub fn get_column_histograms(&self) -> &HashMap<ColumnId, Vec<HistogramBucket>> {
+        &self.column_histograms
+    }
 }
@@ -20,6 +20,16 @@ pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 pub const OPT_KEY_STORAGE_FORMAT: &str = "storage_format";
 pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
+/// Comma separated ids of the tables that a cloned table (transitively) shares data with
+pub const OPT_KEY_CLONE_SOURCES: &str = "clone_sources";
+/// Comma separated ids of the tables (transitively) cloned from a table, the reverse of `OPT_KEY_CLONE_SOURCES`
+pub const OPT_KEY_CLONED_BY: &str = "cloned_by";
+
+// The source table of a stream.
+pub const OPT_KEY_SOURCE_DATABASE_ID: &str = "source_database_id";
+pub const OPT_KEY_SOURCE_DATABASE_NAME: &str = "source_database_name";
+pub const OPT_KEY_SOURCE_TABLE_ID: &str = "source_table_id";
+pub const OPT_KEY_SOURCE_TABLE_NAME: &str = "source_table_name";
 
 /// Legacy table snapshot location key
 ///
@@ -38,6 +48,8 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_CLONE_SOURCES);
+    r.insert(OPT_KEY_CLONED_BY);
     r
 });
 
@@ -46,6 +58,8 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_CLONE_SOURCES);
+    r.insert(OPT_KEY_CLONED_BY);
     r
 });
 
@@ -17,11 +17,17 @@ pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+// Opt in the table to the maintenance jobs of the background service.
+pub const FUSE_OPT_KEY_AUTO_COMPACT: &str = "auto_compact";
+pub const FUSE_OPT_KEY_AUTO_RECLUSTER: &str = "auto_recluster";
+pub const FUSE_OPT_KEY_AUTO_PURGE: &str = "auto_purge";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
 pub const FUSE_TBL_XOR_BLOOM_INDEX_PREFIX: &str = "_i_b_v2";
+pub const FUSE_TBL_VECTOR_INDEX_PREFIX: &str = "_i_v";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
+pub const FUSE_TBL_SEGMENT_STATISTICS_PREFIX: &str = "_sg_s";
 pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 pub const FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX: &str = "_ts";
 pub const FUSE_TBL_LAST_SNAPSHOT_HINT: &str = "last_snapshot_location_hint";
@@ -94,6 +94,15 @@ impl FusePartInfo {
         }
     }
 
+    /// The bytes of the column data to read from the storage.
+    pub fn read_bytes(&self) -> u64 {
+        let range = self.range().cloned();
+        self.columns_meta
+            .values()
+            .map(|meta| meta.read_bytes(&range))
+            .sum()
+    }
+
     pub fn range(&self) -> Option<&Range<usize>> {
         self.block_meta_index
             .as_ref()
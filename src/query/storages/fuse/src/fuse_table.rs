@@ -25,6 +25,7 @@ use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::AppendMode;
+use common_catalog::table::ColumnHistogramBucket;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_catalog::table::CompactTarget;
@@ -55,6 +56,7 @@ use opendal::Operator;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
+use storages_common_table_meta::meta::HistogramBucket;
 use storages_common_table_meta::meta::Statistics as FuseStatistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
@@ -67,10 +69,12 @@ use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use tracing::error;
+use tracing::warn;
 use uuid::Uuid;
 
 use crate::io::MetaReaders;
 use crate::io::TableMetaLocationGenerator;
+use crate::io::VectorIndexDef;
 use crate::io::WriteSettings;
 use crate::operations::AppendOperationLogEntry;
 use crate::pipelines::Pipeline;
@@ -237,7 +241,19 @@ impl FuseTable {
                         put_cache: true,
                     };
 
-                    Ok(Some(reader.read(&load_params).await?))
+                    // The statistics of the snapshots within the retention period may have been
+                    // purged, they are only used to estimate the plan.
+                    match reader.read(&load_params).await {
+                        Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                            warn!(
+                                "table statistics of location {} not found. table: {}, ident {}",
+                                loc, self.table_info.desc, self.table_info.ident,
+                            );
+                            Ok(None)
+                        }
+                        Err(e) => Err(e),
+                        Ok(statistics) => Ok(Some(statistics)),
+                    }
                 } else {
                     Ok(None)
                 }
@@ -327,6 +343,15 @@ impl FuseTable {
     pub fn cluster_key_str(&self) -> Option<&String> {
         self.cluster_key_meta.as_ref().map(|(_, key)| key)
     }
+
+    pub fn vector_indexes(&self) -> Result<Vec<VectorIndexDef>> {
+        self.table_info
+            .meta
+            .vector_indexes
+            .iter()
+            .map(|(name, value)| VectorIndexDef::parse(name, value))
+            .collect()
+    }
 }
 
 #[async_trait::async_trait]
@@ -577,12 +602,14 @@ impl Table for FuseTable {
                     row_count: snapshot.summary.row_count,
                     // save row count first
                     column_distinct_values: Some(table_statistics.column_distinct_values.clone()),
+                    column_histograms: table_statistics.column_histograms.clone(),
                 }
             } else {
                 FuseTableColumnStatisticsProvider {
                     column_stats: stats.clone(),
                     row_count: snapshot.summary.row_count,
                     column_distinct_values: None,
+                    column_histograms: HashMap::new(),
                 }
             }
         } else {
@@ -601,6 +628,7 @@ impl Table for FuseTable {
             NavigationPoint::TimePoint(time_point) => {
                 Ok(self.navigate_to_time_point(*time_point).await?)
             }
+            NavigationPoint::Tag(tag) => Ok(self.navigate_to_tag(tag).await?),
         }
     }
 
@@ -703,6 +731,7 @@ impl FromStr for FuseStorageFormat {
 struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, FuseColumnStatistics>,
     pub column_distinct_values: Option<HashMap<ColumnId, u64>>,
+    pub column_histograms: HashMap<ColumnId, Vec<HistogramBucket>>,
     pub row_count: u64,
 }
 
@@ -717,6 +746,17 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
                 .column_distinct_values
                 .as_ref()
                 .map_or(self.row_count, |map| map.get(&column_id).map_or(0, |v| *v)),
+            in_memory_size: s.in_memory_size,
+            histogram: self.column_histograms.get(&column_id).map(|buckets| {
+                buckets
+                    .iter()
+                    .map(|bucket| ColumnHistogramBucket {
+                        upper_bound: bucket.upper_bound.clone(),
+                        num_values: bucket.num_values,
+                        num_distinct: bucket.num_distinct,
+                    })
+                    .collect()
+            }),
         })
     }
 }
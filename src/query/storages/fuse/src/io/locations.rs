@@ -15,6 +15,7 @@
 use std::marker::PhantomData;
 
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::DataBlock;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
@@ -25,9 +26,12 @@ use uuid::Uuid;
 
 use crate::constants::FUSE_TBL_BLOCK_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
+use crate::constants::FUSE_TBL_SEGMENT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
+use crate::constants::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::index::filters::BlockFilter;
+use crate::io::VectorIndexDef;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
@@ -102,6 +106,45 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    /// The vector index of a segment is named after the segment, the index
+    /// definition and the indexed column, so it's rebuilt after the index is
+    /// re-created differently or the column is replaced.
+    pub fn segment_vector_index_location(
+        &self,
+        segment_location: &str,
+        def: &VectorIndexDef,
+        column_id: ColumnId,
+    ) -> String {
+        let file_name = segment_location
+            .rsplit('/')
+            .next()
+            .unwrap_or(segment_location);
+        let segment_uuid = file_name.split('_').next().unwrap_or(file_name);
+        format!(
+            "{}/{}/{}_{}_{}_{}.idx",
+            &self.prefix,
+            FUSE_TBL_VECTOR_INDEX_PREFIX,
+            segment_uuid,
+            def.method.to_string().to_lowercase(),
+            def.distance,
+            column_id,
+        )
+    }
+
+    /// The NDV sketches of the columns of a segment are named after the segment,
+    /// they are written with the segment.
+    pub fn segment_ndv_sketches_location(&self, segment_location: &str) -> String {
+        let file_name = segment_location
+            .rsplit('/')
+            .next()
+            .unwrap_or(segment_location);
+        let segment_uuid = file_name.split('_').next().unwrap_or(file_name);
+        format!(
+            "{}/{}/{}_ndv.bin",
+            &self.prefix, FUSE_TBL_SEGMENT_STATISTICS_PREFIX, segment_uuid,
+        )
+    }
+
     pub fn snapshot_location_from_uuid(&self, id: &Uuid, version: u64) -> Result<String> {
         let snapshot_version = SnapshotVersion::try_from(version)?;
         Ok(snapshot_version.create(id, &self.prefix))
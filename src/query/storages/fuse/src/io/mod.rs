@@ -21,6 +21,8 @@ mod write;
 
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
+pub use read::read_ndv_sketches;
+pub use read::read_vector_index;
 pub use read::BlockReader;
 pub use read::BloomBlockFilterReader;
 pub use read::MergeIOReadResult;
@@ -35,6 +37,7 @@ pub use segments::SegmentsIO;
 pub use snapshots::ListSnapshotLiteOption;
 pub use snapshots::SnapshotLiteListExtended;
 pub use snapshots::SnapshotsIO;
+pub use write::remove_virtual_computed_columns;
 pub use write::serialize_block;
 pub use write::write_data;
 pub use write::BlockBuilder;
@@ -42,4 +45,6 @@ pub use write::BlockSerialization;
 pub use write::CachedMetaWriter;
 pub use write::MetaWriter;
 pub use write::SegmentWriter;
+pub use write::VectorIndexBuilder;
+pub use write::VectorIndexDef;
 pub use write::WriteSettings;
@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use common_arrow::arrow::array::Array;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use common_arrow::parquet::metadata::SchemaDescriptor;
@@ -23,13 +24,21 @@ use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
 use common_expression::ColumnId;
+use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchema;
+use common_expression::Evaluator;
+use common_expression::Expr;
 use common_expression::FieldIndex;
+use common_expression::FunctionContext;
 use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::field_default_value;
 use common_storage::ColumnNode;
 use common_storage::ColumnNodes;
@@ -45,6 +54,7 @@ pub struct BlockReader {
     pub(crate) project_column_nodes: Vec<ColumnNode>,
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
+    pub(crate) func_ctx: FunctionContext,
     pub query_internal_columns: bool,
 }
 
@@ -114,6 +124,7 @@ impl BlockReader {
             }
         };
 
+        let func_ctx = ctx.get_function_context()?;
         let arrow_schema = schema.to_arrow();
         let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
 
@@ -134,6 +145,7 @@ impl BlockReader {
             project_column_nodes,
             parquet_schema_descriptor,
             default_vals,
+            func_ctx,
             query_internal_columns,
         }))
     }
@@ -160,6 +172,56 @@ impl BlockReader {
         indices
     }
 
+    // The column ids to read from a block, including the legacy columns of the projected
+    // columns, which only exist in blocks written before the column type was modified.
+    pub(crate) fn project_column_ids(&self) -> Vec<ColumnId> {
+        let legacy_column_ids = self
+            .project_column_nodes
+            .iter()
+            .flat_map(|column_node| column_node.legacy_columns.iter())
+            .map(|legacy_column| legacy_column.column_id);
+        self.project_indices
+            .values()
+            .map(|(column_id, ..)| *column_id)
+            .chain(legacy_column_ids)
+            .collect()
+    }
+
+    /// Cast the array of a legacy column to the current type of the column.
+    pub(crate) fn cast_legacy_array(
+        &self,
+        array: Box<dyn Array>,
+        legacy_type: &DataType,
+        column_node: &ColumnNode,
+    ) -> Result<Box<dyn Array>> {
+        let field: TableField = (&column_node.field).into();
+        let data_type: DataType = field.data_type().into();
+        let num_rows = array.len();
+        let block = DataBlock::new(
+            vec![BlockEntry {
+                data_type: legacy_type.clone(),
+                value: Value::Column(Column::from_arrow(array.as_ref(), legacy_type)),
+            }],
+            num_rows,
+        );
+        let expr = Expr::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::ColumnRef {
+                span: None,
+                id: 0,
+                data_type: legacy_type.clone(),
+                display_name: field.name().clone(),
+            }),
+            dest_type: data_type.clone(),
+        };
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&expr)?;
+        Ok(value
+            .convert_to_full_column(&data_type, num_rows)
+            .as_arrow())
+    }
+
     pub fn query_internal_columns(&self) -> bool {
         self.query_internal_columns
     }
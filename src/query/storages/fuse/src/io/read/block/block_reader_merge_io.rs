@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -54,6 +55,8 @@ pub struct MergeIOReadResult {
     pub cached_column_data: CachedColumnData,
     pub cached_column_array: CachedColumnArray,
     table_data_cache: Option<TableDataCache>,
+    // the columns kept in the table data cache as demoted arrays instead of raw data
+    demoted_columns: HashSet<ColumnId>,
 }
 
 pub enum DataItem<'a> {
@@ -75,9 +78,15 @@ impl MergeIOReadResult {
             cached_column_data: vec![],
             cached_column_array: vec![],
             table_data_cache,
+            demoted_columns: HashSet::new(),
         }
     }
 
+    pub fn with_demoted_columns(mut self, demoted_columns: HashSet<ColumnId>) -> Self {
+        self.demoted_columns = demoted_columns;
+        self
+    }
+
     pub fn columns_chunks(&self) -> Result<HashMap<ColumnId, DataItem>> {
         let mut res = HashMap::with_capacity(self.columns_chunk_offsets.len());
 
@@ -110,7 +119,11 @@ impl MergeIOReadResult {
         column_id: ColumnId,
         range: Range<usize>,
     ) {
-        if let Some(table_data_cache) = &self.table_data_cache {
+        let table_data_cache = self
+            .table_data_cache
+            .as_ref()
+            .filter(|_| !self.demoted_columns.contains(&column_id));
+        if let Some(table_data_cache) = table_data_cache {
             // populate raw column data cache (compressed raw bytes)
             if let Ok(chunk_data) = self.get_chunk(chunk_index, &self.block_path) {
                 let cache_key = TableDataCacheKey::new(&self.block_path, column_id);
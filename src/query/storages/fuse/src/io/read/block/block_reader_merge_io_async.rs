@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Range;
 use std::time::Instant;
 
@@ -47,6 +48,7 @@ impl BlockReader {
         op: Operator,
         location: &str,
         raw_ranges: Vec<(ColumnId, Range<u64>)>,
+        demoted_columns: HashSet<ColumnId>,
     ) -> Result<MergeIOReadResult> {
         if raw_ranges.is_empty() {
             // shortcut
@@ -97,7 +99,8 @@ impl BlockReader {
             raw_ranges.len(),
             location.to_string(),
             table_data_cache,
-        );
+        )
+        .with_demoted_columns(demoted_columns);
 
         // Perf.
         {
@@ -144,7 +147,7 @@ impl BlockReader {
         let column_array_cache = CacheManager::instance().get_table_data_array_cache();
         let mut cached_column_data = vec![];
         let mut cached_column_array = vec![];
-        for (_index, (column_id, ..)) in self.project_indices.iter() {
+        for column_id in self.project_column_ids().iter() {
             let column_cache_key = TableDataCacheKey::new(location, *column_id);
 
             // first, check column array object cache
@@ -172,14 +175,36 @@ impl BlockReader {
             }
         }
 
-        let mut merge_io_read_res =
-            Self::merge_io_read(settings, self.operator.clone(), location, ranges).await?;
+        // the raw data of the columns demoted to the table data cache as arrays needn't be cached
+        let demoted_columns = if CacheManager::instance().is_table_column_array_demoted() {
+            self.array_cached_column_ids()
+        } else {
+            HashSet::new()
+        };
+        let mut merge_io_read_res = Self::merge_io_read(
+            settings,
+            self.operator.clone(),
+            location,
+            ranges,
+            demoted_columns,
+        )
+        .await?;
         // TODO set
         merge_io_read_res.cached_column_data = cached_column_data;
         merge_io_read_res.cached_column_array = cached_column_array;
         Ok(merge_io_read_res)
     }
 
+    // the columns put into the column array cache once deserialized, i.e. the columns not
+    // nested, see `DeserializedArray::Deserialized`
+    fn array_cached_column_ids(&self) -> HashSet<ColumnId> {
+        self.project_column_nodes
+            .iter()
+            .filter(|column_node| !column_node.has_children())
+            .map(|column_node| column_node.leaf_column_ids[0])
+            .collect()
+    }
+
     #[inline]
     #[async_backtrace::framed]
     pub async fn read_range(
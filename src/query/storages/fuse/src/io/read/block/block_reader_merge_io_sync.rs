@@ -106,7 +106,7 @@ impl BlockReader {
 
         let mut ranges = vec![];
         let mut cached_column_array = vec![];
-        for (_index, (column_id, ..)) in self.project_indices.iter() {
+        for column_id in self.project_column_ids().iter() {
             // first, check column array object cache
             let block_path = &part.location;
             let column_cache_key = TableDataCacheKey::new(block_path, *column_id);
@@ -18,8 +18,10 @@ use std::time::Instant;
 
 use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::read::column_iter_to_arrays;
 use common_arrow::arrow::io::parquet::read::ArrayIter;
+use common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use common_arrow::parquet::compression::Compression as ParquetCompression;
 use common_arrow::parquet::metadata::ColumnDescriptor;
 use common_arrow::parquet::read::PageMetaData;
@@ -27,8 +29,10 @@ use common_arrow::parquet::read::PageReader;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
+use common_expression::TableField;
 use common_storage::ColumnNode;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::TableDataCacheKey;
@@ -107,7 +111,13 @@ impl BlockReader {
             uncompressed_buffer: &uncompressed_buffer,
         };
         for column_node in &self.project_column_nodes {
-            match self.deserialize_field(&field_deserialization_ctx, column_node)? {
+            let deserialized = match self
+                .deserialize_field(&field_deserialization_ctx, column_node)?
+            {
+                None => self.deserialize_legacy_field(&field_deserialization_ctx, column_node)?,
+                v => v,
+            };
+            match deserialized {
                 None => {
                     need_to_fill_default_val = true;
                     need_default_vals.push(true);
@@ -312,6 +322,61 @@ impl BlockReader {
         }
     }
 
+    /// Deserialize the column from the data of its legacy column, if the block was written
+    /// before the type of the column was modified.
+    fn deserialize_legacy_field<'a>(
+        &self,
+        deserialization_context: &'a FieldDeserializationContext,
+        column: &ColumnNode,
+    ) -> Result<Option<DeserializedArray<'a>>> {
+        for legacy_column in column.legacy_columns.iter().rev() {
+            let column_id = legacy_column.column_id;
+            let column_meta = match deserialization_context.column_metas.get(&column_id) {
+                Some(column_meta) => column_meta,
+                None => continue,
+            };
+            let array = match deserialization_context.column_chunks.get(&column_id) {
+                Some(DataItem::RawData(data)) => {
+                    let legacy_field: Field =
+                        (&TableField::new(&column.field.name, legacy_column.data_type.clone()))
+                            .into();
+                    let legacy_schema =
+                        to_parquet_schema(&ArrowSchema::from(vec![legacy_field.clone()]))?;
+                    let mut array_iter = Self::chunks_to_parquet_array_iter(
+                        vec![column_meta],
+                        vec![*data],
+                        deserialization_context.num_rows,
+                        vec![&legacy_schema.columns()[0]],
+                        legacy_field,
+                        deserialization_context.compression,
+                        deserialization_context
+                            .uncompressed_buffer
+                            .clone()
+                            .unwrap_or_else(|| UncompressedBuffer::new(0)),
+                    )?;
+                    array_iter.next().transpose()?.ok_or_else(|| {
+                        ErrorCode::StorageOther(format!(
+                            "unexpected deserialization error, no array found for legacy column {column_id}"
+                        ))
+                    })?
+                }
+                // cached before the type of the column was modified
+                Some(DataItem::ColumnArray(column_array)) => column_array.0.clone(),
+                None => {
+                    return Err(ErrorCode::StorageOther(
+                        "unexpected: legacy column data not found",
+                    ));
+                }
+            };
+
+            // the array is cast to the current type, it is not cached under the legacy column id
+            let legacy_type: DataType = (&legacy_column.data_type).into();
+            let array = self.cast_legacy_array(array, &legacy_type, column)?;
+            return Ok(Some(DeserializedArray::NoNeedToCache(array)));
+        }
+        Ok(None)
+    }
+
     fn to_parquet_compression(meta_compression: &Compression) -> Result<ParquetCompression> {
         match meta_compression {
             Compression::Lz4 => {
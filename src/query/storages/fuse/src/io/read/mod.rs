@@ -15,8 +15,10 @@
 mod block;
 mod bloom;
 mod meta;
+mod ndv_sketches_reader;
 mod read_settings;
 mod snapshot_history_reader;
+mod vector_index_reader;
 
 pub use block::BlockReader;
 pub use block::MergeIOReadResult;
@@ -26,5 +28,7 @@ pub use bloom::BloomBlockFilterReader;
 pub use meta::MetaReaders;
 pub use meta::SegmentInfoReader;
 pub use meta::TableSnapshotReader;
+pub use ndv_sketches_reader::read_ndv_sketches;
 pub use read_settings::ReadSettings;
 pub use snapshot_history_reader::SnapshotHistoryReader;
+pub use vector_index_reader::read_vector_index;
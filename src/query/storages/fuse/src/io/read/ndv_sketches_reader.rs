@@ -0,0 +1,34 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_exception::Result;
+use common_io::prelude::deserialize_from_slice;
+use opendal::Operator;
+use storages_common_table_meta::meta::NdvSketchesOfColumns;
+
+/// Read the NDV sketches of a segment, returns None if the segment has no sketches,
+/// e.g. it's written by a version not building them, or by a mutation.
+#[async_backtrace::framed]
+pub async fn read_ndv_sketches(
+    dal: &Operator,
+    location: &str,
+) -> Result<Option<NdvSketchesOfColumns>> {
+    let data = match dal.read(location).await {
+        Ok(data) => data,
+        Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let sketches = deserialize_from_slice(&mut data.as_slice())?;
+    Ok(Some(sketches))
+}
@@ -35,6 +35,28 @@ use crate::operations::util;
 use crate::operations::BloomIndexState;
 use crate::statistics::gen_columns_statistics;
 
+/// Virtual computed columns are not stored, remove them from the schema and the block.
+pub fn remove_virtual_computed_columns(
+    schema: &TableSchemaRef,
+    block: DataBlock,
+) -> (TableSchemaRef, DataBlock) {
+    if !schema.fields().iter().any(|f| f.is_virtual_computed()) {
+        return (schema.clone(), block);
+    }
+    let num_rows = block.num_rows();
+    let columns = block
+        .columns()
+        .iter()
+        .zip(schema.fields())
+        .filter(|(_, f)| !f.is_virtual_computed())
+        .map(|(entry, _)| entry.clone())
+        .collect();
+    (
+        Arc::new(schema.remove_virtual_computed_fields()),
+        DataBlock::new(columns, num_rows),
+    )
+}
+
 // TODO rename this, it is serialization, or pass in a writer(if not rename)
 pub fn serialize_block(
     write_settings: &WriteSettings,
@@ -107,12 +129,14 @@ impl BlockBuilder {
         // TODO cluster stats
         // let (cluster_stats, block) = self.cluster_stats_gen.gen_stats_for_append(data_block)?;
 
+        let (source_schema, data_block) =
+            remove_virtual_computed_columns(&self.source_schema, data_block);
         let (block_location, block_id) = self.meta_locations.gen_block_location();
 
         let bloom_index_location = self.meta_locations.block_bloom_index_location(&block_id);
         let bloom_index_state = BloomIndexState::try_create(
             self.ctx.clone(),
-            self.source_schema.clone(),
+            source_schema.clone(),
             &data_block,
             bloom_index_location,
         )?;
@@ -125,13 +149,12 @@ impl BlockBuilder {
 
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
-        let col_stats =
-            gen_columns_statistics(&data_block, column_distinct_count, &self.source_schema)?;
+        let col_stats = gen_columns_statistics(&data_block, column_distinct_count, &source_schema)?;
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
         let (file_size, col_metas) = serialize_block(
             &self.write_settings,
-            &self.source_schema,
+            &source_schema,
             data_block,
             &mut buffer,
         )?;
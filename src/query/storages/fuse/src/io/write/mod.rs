@@ -15,8 +15,10 @@
 mod block_writer;
 mod meta_writer;
 mod segment_writer;
+mod vector_index_writer;
 mod write_settings;
 
+pub use block_writer::remove_virtual_computed_columns;
 pub use block_writer::serialize_block;
 pub use block_writer::write_data;
 pub use block_writer::BlockBuilder;
@@ -24,4 +26,6 @@ pub use block_writer::BlockSerialization;
 pub use meta_writer::CachedMetaWriter;
 pub use meta_writer::MetaWriter;
 pub use segment_writer::SegmentWriter;
+pub use vector_index_writer::VectorIndexBuilder;
+pub use vector_index_writer::VectorIndexDef;
 pub use write_settings::WriteSettings;
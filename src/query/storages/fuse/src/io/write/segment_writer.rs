@@ -13,12 +13,17 @@
 //  limitations under the License.
 
 use common_exception::Result;
+use common_io::prelude::serialize_into_buf;
 use opendal::Operator;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::NdvSketchesOfColumns;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Versioned;
+use tracing::warn;
 
 use super::meta_writer::MetaWriter;
+use crate::io::read_ndv_sketches;
+use crate::io::write_data;
 use crate::io::CachedMetaWriter;
 use crate::io::TableMetaLocationGenerator;
 
@@ -57,6 +62,41 @@ impl<'a> SegmentWriter<'a> {
         Ok(location)
     }
 
+    /// Writes the NDV sketches of a segment merged from the ones of the segments it is
+    /// compacted from. Nothing is written if any of them has no sketches, the NDV of the
+    /// segment is then estimated from the statistics of its blocks by ANALYZE TABLE.
+    #[async_backtrace::framed]
+    pub async fn write_merged_ndv_sketches(
+        &self,
+        segment_location: &str,
+        merged_from: &[Location],
+    ) -> Result<()> {
+        let mut merged = NdvSketchesOfColumns::new();
+        for (location, _) in merged_from {
+            let location = self
+                .location_generator
+                .segment_ndv_sketches_location(location);
+            let sketches = match read_ndv_sketches(self.data_accessor, &location).await {
+                Ok(Some(sketches)) => sketches,
+                Ok(None) => return Ok(()),
+                Err(e) => {
+                    warn!("cannot read the ndv sketches {}: {}", location, e);
+                    return Ok(());
+                }
+            };
+            for (column_id, sketch) in sketches.iter() {
+                merged.entry(*column_id).or_default().merge(sketch);
+            }
+        }
+
+        let mut data = vec![];
+        serialize_into_buf(&mut data, &merged)?;
+        let location = self
+            .location_generator
+            .segment_ndv_sketches_location(segment_location);
+        write_data(data, self.data_accessor, &location).await
+    }
+
     fn generate_location(&self) -> Location {
         let path = self.location_generator.gen_segment_info_location();
         (path, SegmentInfo::VERSION)
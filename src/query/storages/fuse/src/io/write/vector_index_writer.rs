@@ -0,0 +1,162 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::str::FromStr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::ArrayType;
+use common_expression::types::Float32Type;
+use common_expression::types::NullableType;
+use common_expression::types::ValueType;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::FieldIndex;
+use common_expression::TableSchema;
+use common_io::prelude::serialize_into_buf;
+use common_vector::DistanceType;
+use common_vector::VectorIndex;
+use common_vector::VectorIndexMethod;
+
+use crate::io::TableMetaLocationGenerator;
+
+/// The definition of a vector index, kept in the table meta as
+/// `<method>:<distance>:<column>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexDef {
+    pub name: String,
+    pub method: VectorIndexMethod,
+    pub distance: DistanceType,
+    pub column: String,
+}
+
+impl VectorIndexDef {
+    pub fn parse(name: &str, value: &str) -> Result<Self> {
+        let parts: Vec<&str> = value.splitn(3, ':').collect();
+        if parts.len() != 3 {
+            return Err(ErrorCode::Internal(format!(
+                "invalid definition of vector index {}: {}",
+                name, value
+            )));
+        }
+        Ok(VectorIndexDef {
+            name: name.to_string(),
+            method: VectorIndexMethod::from_str(parts[0])?,
+            distance: DistanceType::from_str(parts[1])?,
+            column: parts[2].to_string(),
+        })
+    }
+
+    pub fn meta_value(&self) -> String {
+        format!("{}:{}:{}", self.method, self.distance, self.column)
+    }
+}
+
+/// Collects the vectors of an indexed column from the blocks of a segment,
+/// the label of a vector is the index of its block in the segment.
+pub struct VectorIndexBuilder {
+    def: VectorIndexDef,
+    column_id: ColumnId,
+    field_index: FieldIndex,
+    dimension: Option<usize>,
+    vectors: Vec<f32>,
+    labels: Vec<u32>,
+    // Rows which can't be indexed, e.g. NULL or of a different dimension.
+    // Such a segment is left without index and is always scanned.
+    unindexable: bool,
+}
+
+impl VectorIndexBuilder {
+    /// `schema` is the schema of the blocks to be added.
+    pub fn try_create(def: VectorIndexDef, schema: &TableSchema) -> Result<Self> {
+        let field_index = schema.index_of(&def.column)?;
+        let column_id = schema.column_id_of_index(field_index)?;
+        Ok(VectorIndexBuilder {
+            def,
+            column_id,
+            field_index,
+            dimension: None,
+            vectors: vec![],
+            labels: vec![],
+            unindexable: false,
+        })
+    }
+
+    pub fn add_block(&mut self, block: &DataBlock, block_idx: usize) -> Result<()> {
+        if self.unindexable {
+            return Ok(());
+        }
+
+        let entry = block.get_by_offset(self.field_index);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, block.num_rows());
+        let vectors = match NullableType::<ArrayType<Float32Type>>::try_downcast_column(&column) {
+            Some(nullable) => {
+                if nullable.validity.unset_bits() > 0 {
+                    self.unindexable = true;
+                    return Ok(());
+                }
+                nullable.column
+            }
+            None => ArrayType::<Float32Type>::try_downcast_column(&column).ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "column {} of vector index {} is not an array of float32",
+                    self.def.column, self.def.name
+                ))
+            })?,
+        };
+
+        for vector in vectors.iter() {
+            let dimension = *self.dimension.get_or_insert(vector.len());
+            if vector.is_empty() || vector.len() != dimension {
+                self.unindexable = true;
+                return Ok(());
+            }
+            self.vectors.extend(vector.iter().map(|v| v.0));
+            self.labels.push(block_idx as u32);
+        }
+        Ok(())
+    }
+
+    /// Build the index of the blocks added, returns the location and the data
+    /// of the index file of the segment. The builder is reset for the next segment.
+    pub fn finish(
+        &mut self,
+        locations: &TableMetaLocationGenerator,
+        segment_location: &str,
+    ) -> Result<Option<(String, Vec<u8>)>> {
+        let dimension = self.dimension.take();
+        let vectors = std::mem::take(&mut self.vectors);
+        let labels = std::mem::take(&mut self.labels);
+        let unindexable = std::mem::replace(&mut self.unindexable, false);
+
+        let dimension = match dimension {
+            Some(dimension) if !unindexable => dimension,
+            _ => return Ok(None),
+        };
+        let index = VectorIndex::build(
+            self.def.method,
+            self.def.distance,
+            dimension,
+            vectors,
+            labels,
+        )?;
+        let mut data = vec![];
+        serialize_into_buf(&mut data, &index)?;
+        let location =
+            locations.segment_vector_index_location(segment_location, &self.def, self.column_id);
+        Ok(Some((location, data)))
+    }
+}
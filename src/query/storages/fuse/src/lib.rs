@@ -26,6 +26,7 @@ pub mod io;
 pub mod operations;
 pub mod pruning;
 pub mod statistics;
+mod stream_table;
 pub mod table_functions;
 
 mod metrics;
@@ -40,6 +41,9 @@ pub use fuse_part::FusePartInfo;
 pub use fuse_table::FuseStorageFormat;
 pub use fuse_table::FuseTable;
 pub use io::MergeIOReadResult;
+pub use stream_table::StreamTable;
+pub use stream_table::OPT_KEY_STREAM_OFFSET;
+pub use stream_table::STREAM_ENGINE;
 
 pub use crate::metrics::metrics_reset;
 
@@ -15,17 +15,39 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_io::prelude::serialize_into_buf;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::HistogramBucket;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::NdvSketchesOfColumns;
+use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use tracing::warn;
 
+use crate::io::read_ndv_sketches;
+use crate::io::write_data;
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
+use crate::statistics::calc_column_ndv_sketch;
+use crate::statistics::has_ndv_sketch;
 use crate::FuseTable;
 
+// Max number of blocks sampled to build the histograms.
+const HISTOGRAM_SAMPLE_BLOCKS: usize = 16;
+// Number of buckets of the histograms.
+const HISTOGRAM_BUCKETS: usize = 100;
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
@@ -47,46 +69,30 @@ impl FuseTable {
 
         if let Some(snapshot) = snapshot_opt {
             // 2. Iterator segments and blocks to estimate statistics.
-            let mut sum_map = HashMap::new();
-            let mut row_count_sum = 0;
-            let mut block_count_sum: u64 = 0;
-
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
-            let segments = segments_io.read_segments(&snapshot.segments, true).await?;
-            for segment in segments {
-                let segment = segment?;
-                segment.blocks.iter().for_each(|block| {
-                    let block = block.as_ref();
-                    let row_count = block.row_count;
-                    if row_count != 0 {
-                        block_count_sum += 1;
-                        row_count_sum += row_count;
-                        for (i, col_stat) in block.col_stats.iter() {
-                            let density = match col_stat.distinct_of_values {
-                                Some(ndv) => ndv as f64 / row_count as f64,
-                                None => 0.0,
-                            };
-
-                            match sum_map.get_mut(i) {
-                                Some(sum) => {
-                                    *sum += density;
-                                }
-                                None => {
-                                    let _ = sum_map.insert(*i, density);
-                                }
-                            }
-                        }
-                    }
-                });
-            }
-            let mut ndv_map = HashMap::new();
-            for (i, sum) in sum_map.iter() {
-                let density_avg = *sum / block_count_sum as f64;
-                ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
+            let segments = segments_io
+                .read_segments(&snapshot.segments, true)
+                .await?
+                .into_iter()
+                .collect::<Result<Vec<_>>>()?;
+            let mut block_metas = vec![];
+            for segment in segments.iter() {
+                block_metas.extend(
+                    segment
+                        .blocks
+                        .iter()
+                        .filter(|block| block.row_count != 0)
+                        .cloned(),
+                );
             }
+            let (sketches, unsketched_blocks) = self
+                .merge_ndv_sketches(ctx, &snapshot.segments, &segments)
+                .await?;
+            let ndv_map = estimate_ndv(&block_metas, &sketches, &unsketched_blocks);
+            let histograms = self.build_histograms(ctx, &block_metas, &ndv_map).await?;
 
             // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            let table_statistics = TableSnapshotStatistics::new(ndv_map, histograms);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -111,4 +117,371 @@ impl FuseTable {
 
         Ok(())
     }
+
+    // Merges the NDV sketches of the segments, which are written with the segments. Only the
+    // columns of non-nested types have sketches. The segments without sketches (e.g. written
+    // by a mutation or by an older version) are not scanned, the NDV of their blocks is
+    // estimated by the block statistics, returned by column. The sketches which can't be
+    // read are rebuilt from the blocks of the segment.
+    #[async_backtrace::framed]
+    async fn merge_ndv_sketches(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        segment_locations: &[Location],
+        segments: &[Arc<SegmentInfo>],
+    ) -> Result<(NdvSketchesOfColumns, HashMap<ColumnId, Vec<Arc<BlockMeta>>>)> {
+        let schema = self.schema();
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| has_ndv_sketch(f.data_type()))
+            .collect::<Vec<_>>();
+        let mut merged = NdvSketchesOfColumns::new();
+        let mut unsketched_blocks: HashMap<ColumnId, Vec<Arc<BlockMeta>>> = HashMap::new();
+        if fields.is_empty() {
+            return Ok((merged, unsketched_blocks));
+        }
+
+        for ((segment_location, _), segment) in segment_locations.iter().zip(segments) {
+            let location = self
+                .meta_location_generator
+                .segment_ndv_sketches_location(segment_location);
+            let sketches = match read_ndv_sketches(&self.operator, &location).await {
+                Ok(Some(sketches)) => sketches,
+                Ok(None) => NdvSketchesOfColumns::new(),
+                Err(e) => {
+                    warn!(
+                        "cannot read the ndv sketches {}, rebuild them: {}",
+                        location, e
+                    );
+                    let sketches = self.build_ndv_sketches(ctx, &fields, segment).await?;
+                    let mut data = vec![];
+                    serialize_into_buf(&mut data, &sketches)?;
+                    write_data(data, &self.operator, &location).await?;
+                    sketches
+                }
+            };
+            for (_, field) in fields.iter() {
+                let column_id = field.column_id();
+                match sketches.get(&column_id) {
+                    Some(sketch) => merged.entry(column_id).or_default().merge(sketch),
+                    // the segment has no sketches, or the column is added afterwards
+                    None => unsketched_blocks.entry(column_id).or_default().extend(
+                        segment
+                            .blocks
+                            .iter()
+                            .filter(|block| block.row_count != 0)
+                            .cloned(),
+                    ),
+                }
+            }
+        }
+        Ok((merged, unsketched_blocks))
+    }
+
+    // Builds the NDV sketches of the columns from the blocks of a segment.
+    #[async_backtrace::framed]
+    async fn build_ndv_sketches(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        fields: &[(usize, &TableField)],
+        segment: &SegmentInfo,
+    ) -> Result<NdvSketchesOfColumns> {
+        let projection = Projection::Columns(fields.iter().map(|(i, _)| *i).collect());
+        let block_reader = self.create_block_reader(projection, false, ctx.clone())?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+        let mut sketches = NdvSketchesOfColumns::new();
+        for block_meta in segment.blocks.iter() {
+            let block = block_reader
+                .read_by_meta(&settings, block_meta, &self.storage_format)
+                .await?;
+            let num_rows = block.num_rows();
+            for ((_, field), entry) in fields.iter().zip(block.columns()) {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                sketches
+                    .entry(field.column_id())
+                    .or_default()
+                    .merge(&calc_column_ndv_sketch(&column));
+            }
+        }
+        Ok(sketches)
+    }
+
+    // Builds equi-height histograms of the numeric columns from the sampled blocks.
+    #[async_backtrace::framed]
+    async fn build_histograms(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        block_metas: &[Arc<BlockMeta>],
+        ndv_map: &HashMap<ColumnId, u64>,
+    ) -> Result<HashMap<ColumnId, Vec<HistogramBucket>>> {
+        let schema = self.schema();
+        // Only the numeric columns are supported by the histograms of the optimizer.
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| matches!(f.data_type().remove_nullable(), TableDataType::Number(_)))
+            .collect::<Vec<_>>();
+        if fields.is_empty() || block_metas.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let projection = Projection::Columns(fields.iter().map(|(i, _)| *i).collect());
+        let block_reader = self.create_block_reader(projection, false, ctx.clone())?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+
+        let step = (block_metas.len() + HISTOGRAM_SAMPLE_BLOCKS - 1) / HISTOGRAM_SAMPLE_BLOCKS;
+        let mut samples: Vec<Vec<Scalar>> = vec![vec![]; fields.len()];
+        let mut sampled_rows = 0;
+        for block_meta in block_metas.iter().step_by(step) {
+            let block = block_reader
+                .read_by_meta(&settings, block_meta, &self.storage_format)
+                .await?;
+            let num_rows = block.num_rows();
+            sampled_rows += num_rows as u64;
+            for (entry, sample) in block.columns().iter().zip(samples.iter_mut()) {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                sample.extend(
+                    column
+                        .iter()
+                        .filter(|v| !matches!(v, ScalarRef::Null))
+                        .map(|v| v.to_owned()),
+                );
+            }
+        }
+        if sampled_rows == 0 {
+            return Ok(HashMap::new());
+        }
+
+        let row_count: u64 = block_metas.iter().map(|b| b.row_count).sum();
+        let scale = row_count as f64 / sampled_rows as f64;
+        let mut histograms = HashMap::new();
+        for ((_, field), values) in fields.iter().zip(samples) {
+            let column_id = field.column_id();
+            let ndv = ndv_map.get(&column_id).copied().unwrap_or(0);
+            if let Some(histogram) = build_histogram(values, ndv, scale) {
+                histograms.insert(column_id, histogram);
+            }
+        }
+        Ok(histograms)
+    }
+}
+
+// Estimates the NDV of each column by the merged sketch of the column plus the NDV of its
+// blocks without sketches, otherwise (e.g. the inner columns of nested types) by the average
+// density of all the blocks.
+fn estimate_ndv(
+    block_metas: &[Arc<BlockMeta>],
+    sketches: &NdvSketchesOfColumns,
+    unsketched_blocks: &HashMap<ColumnId, Vec<Arc<BlockMeta>>>,
+) -> HashMap<ColumnId, u64> {
+    let row_count_sum: u64 = block_metas.iter().map(|block| block.row_count).sum();
+    let mut ndv_map = estimate_ndv_by_density(block_metas);
+    for (i, ndv) in ndv_map.iter_mut() {
+        if let Some(sketch) = sketches.get(i) {
+            let unsketched_ndv = unsketched_blocks
+                .get(i)
+                .and_then(|blocks| estimate_ndv_by_density(blocks).get(i).copied())
+                .unwrap_or(0);
+            *ndv = (sketch.count() + unsketched_ndv).min(row_count_sum);
+        }
+    }
+    ndv_map
+}
+
+// Estimates the NDV of each column by the average density of the blocks.
+fn estimate_ndv_by_density(block_metas: &[Arc<BlockMeta>]) -> HashMap<ColumnId, u64> {
+    let mut density_map: HashMap<ColumnId, f64> = HashMap::new();
+    let mut row_count_sum = 0;
+    for block in block_metas {
+        let row_count = block.row_count;
+        row_count_sum += row_count;
+        for (i, col_stat) in block.col_stats.iter() {
+            let density = match col_stat.distinct_of_values {
+                Some(ndv) => ndv as f64 / row_count as f64,
+                None => 0.0,
+            };
+            *density_map.entry(*i).or_default() += density;
+        }
+    }
+
+    let block_count = block_metas.len();
+    density_map
+        .into_iter()
+        .map(|(i, sum)| {
+            let density_avg = sum / block_count as f64;
+            (i, (density_avg * row_count_sum as f64) as u64)
+        })
+        .collect()
+}
+
+// Builds an equi-height histogram from the sampled non-null values. The rows and the
+// distinct values of the buckets are scaled up to the whole table.
+fn build_histogram(mut values: Vec<Scalar>, ndv: u64, scale: f64) -> Option<Vec<HistogramBucket>> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort();
+
+    let bucket_size = (values.len() + HISTOGRAM_BUCKETS - 1) / HISTOGRAM_BUCKETS;
+    // The first bucket only holds the min value, which is required by the optimizer.
+    let mut buckets = vec![HistogramBucket {
+        upper_bound: values[0].clone(),
+        num_values: 1.0,
+        num_distinct: 1.0,
+    }];
+    let mut sample_ndv = 0.0;
+    let mut start = 0;
+    while start < values.len() {
+        let mut end = (start + bucket_size).min(values.len());
+        // Keep the same values in one bucket.
+        while end < values.len() && values[end] == values[end - 1] {
+            end += 1;
+        }
+        let bucket = &values[start..end];
+        let num_distinct = 1 + bucket.windows(2).filter(|w| w[0] != w[1]).count();
+        sample_ndv += num_distinct as f64;
+        buckets.push(HistogramBucket {
+            upper_bound: bucket[bucket.len() - 1].clone(),
+            num_values: bucket.len() as f64 * scale,
+            num_distinct: num_distinct as f64,
+        });
+        start = end;
+    }
+
+    // The sample misses some distinct values, spread them over the buckets.
+    let ndv_scale = (ndv as f64 / sample_ndv).max(1.0);
+    for bucket in buckets.iter_mut().skip(1) {
+        bucket.num_distinct = (bucket.num_distinct * ndv_scale).min(bucket.num_values);
+    }
+    Some(buckets)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+    use std::hash::Hasher;
+    use std::sync::Arc;
+
+    use common_expression::types::number::NumberScalar;
+    use common_expression::Scalar;
+    use siphasher::sip::SipHasher;
+    use storages_common_table_meta::meta::BlockMeta;
+    use storages_common_table_meta::meta::ColumnStatistics;
+    use storages_common_table_meta::meta::Compression;
+    use storages_common_table_meta::meta::HyperLogLog;
+    use storages_common_table_meta::meta::NdvSketchesOfColumns;
+
+    use super::build_histogram;
+    use super::estimate_ndv;
+
+    fn sketch_of(ndv: u64) -> HyperLogLog {
+        let mut sketch = HyperLogLog::new();
+        for v in 0..ndv {
+            let mut hasher = SipHasher::new();
+            v.hash(&mut hasher);
+            sketch.add_hash(hasher.finish());
+        }
+        sketch
+    }
+
+    fn block_meta(row_count: u64, distinct_of_values: &[u64]) -> Arc<BlockMeta> {
+        let col_stats = distinct_of_values
+            .iter()
+            .enumerate()
+            .map(|(i, ndv)| {
+                (i as u32, ColumnStatistics {
+                    min: Scalar::Null,
+                    max: Scalar::Null,
+                    null_count: 0,
+                    in_memory_size: 0,
+                    distinct_of_values: Some(*ndv),
+                })
+            })
+            .collect();
+        Arc::new(BlockMeta::new(
+            row_count,
+            0,
+            0,
+            col_stats,
+            HashMap::new(),
+            None,
+            ("".to_string(), 0),
+            None,
+            0,
+            Compression::Lz4Raw,
+        ))
+    }
+
+    fn int_scalar(v: i64) -> Scalar {
+        Scalar::Number(NumberScalar::Int64(v))
+    }
+
+    #[test]
+    fn test_estimate_ndv() {
+        let block_metas = vec![block_meta(100, &[100, 10]), block_meta(100, &[50, 10])];
+        let mut sketches = NdvSketchesOfColumns::new();
+        sketches.insert(0, sketch_of(150));
+
+        let ndv_map = estimate_ndv(&block_metas, &sketches, &HashMap::new());
+        // by the sketch
+        assert!((140..=160).contains(&ndv_map[&0]), "{}", ndv_map[&0]);
+        // by the average density of the blocks, 10 distinct values in 100 rows
+        assert_eq!(ndv_map[&1], 20);
+
+        // the ndv is at most the number of rows
+        sketches.insert(0, sketch_of(10_000));
+        let ndv_map = estimate_ndv(&block_metas, &sketches, &HashMap::new());
+        assert_eq!(ndv_map[&0], 200);
+
+        // the blocks of the segments without sketches are estimated by their density
+        let block_metas = vec![
+            block_meta(100, &[100]),
+            block_meta(100, &[50]),
+            block_meta(100, &[20]),
+        ];
+        let mut sketches = NdvSketchesOfColumns::new();
+        sketches.insert(0, sketch_of(150));
+        let mut unsketched_blocks = HashMap::new();
+        unsketched_blocks.insert(0, vec![block_metas[2].clone()]);
+        let ndv_map = estimate_ndv(&block_metas, &sketches, &unsketched_blocks);
+        assert!((160..=180).contains(&ndv_map[&0]), "{}", ndv_map[&0]);
+    }
+
+    #[test]
+    fn test_build_histogram() {
+        assert!(build_histogram(vec![], 0, 1.0).is_none());
+
+        // 1000 distinct values in 100 buckets, following the bucket of the min value
+        let values = (1..=1000).rev().map(int_scalar).collect::<Vec<_>>();
+        let buckets = build_histogram(values, 4000, 4.0).unwrap();
+        assert_eq!(buckets.len(), 101);
+        assert_eq!(buckets[0].upper_bound, int_scalar(1));
+        assert_eq!(buckets[0].num_values, 1.0);
+        for (i, bucket) in buckets.iter().enumerate().skip(1) {
+            assert_eq!(bucket.upper_bound, int_scalar(i as i64 * 10));
+            // scaled up to the table
+            assert_eq!(bucket.num_values, 40.0);
+            assert_eq!(bucket.num_distinct, 40.0);
+        }
+
+        // the same values are kept in one bucket
+        let values = (0..50)
+            .flat_map(|v| std::iter::repeat(int_scalar(v)).take(4))
+            .collect::<Vec<_>>();
+        let buckets = build_histogram(values, 50, 1.0).unwrap();
+        assert_eq!(buckets.len(), 51);
+        for (i, bucket) in buckets.iter().enumerate().skip(1) {
+            assert_eq!(bucket.upper_bound, int_scalar(i as i64 - 1));
+            assert_eq!(bucket.num_values, 4.0);
+            assert_eq!(bucket.num_distinct, 1.0);
+        }
+    }
 }
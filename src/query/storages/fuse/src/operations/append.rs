@@ -35,6 +35,7 @@ use common_sql::evaluator::CompoundBlockOperator;
 
 use crate::operations::FuseTableSink;
 use crate::statistics::ClusterStatsGenerator;
+use crate::statistics::ClusterType;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -48,6 +49,13 @@ impl FuseTable {
         let block_compact_thresholds = self.get_block_compact_thresholds();
         let write_settings = self.get_write_settings();
 
+        // COPY handles the rows violating the CHECK constraints according to `ON_ERROR`.
+        let on_error = match append_mode {
+            AppendMode::Copy => ctx.get_on_error_mode(),
+            AppendMode::Normal => None,
+        };
+        self.add_check_constraints_transform(ctx.clone(), pipeline, on_error)?;
+
         match append_mode {
             AppendMode::Normal => {
                 pipeline.add_transform(|transform_input_port, transform_output_port| {
@@ -72,6 +80,7 @@ impl FuseTable {
             }
         }
 
+        let vector_indexes = self.vector_indexes()?;
         let max_page_size = self.get_max_page_size();
         let cluster_stats_gen = self.get_cluster_stats_gen(
             ctx.clone(),
@@ -114,6 +123,7 @@ impl FuseTable {
                     cluster_stats_gen.clone(),
                     block_compact_thresholds,
                     self.table_info.schema(),
+                    vector_indexes.clone(),
                     Some(transform_output_port),
                 )
             })?;
@@ -128,6 +138,7 @@ impl FuseTable {
                     cluster_stats_gen.clone(),
                     block_compact_thresholds,
                     self.table_info.schema(),
+                    vector_indexes.clone(),
                     None,
                 )
             })?;
@@ -147,6 +158,13 @@ impl FuseTable {
         if cluster_keys.is_empty() {
             return Ok(ClusterStatsGenerator::default());
         }
+        // The page pruner only works on cluster keys of columns,
+        // the pages of a space-filling curve key would never be used.
+        let max_page_size = if ClusterType::from_cluster_keys(&cluster_keys).is_curve() {
+            None
+        } else {
+            max_page_size
+        };
 
         let input_schema = self.table_info.schema();
         let mut merged: Vec<DataField> =
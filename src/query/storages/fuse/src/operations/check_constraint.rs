@@ -0,0 +1,254 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::ScalarRef;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::OnErrorMode;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::InputError;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::input_formats::InputFileMeta;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
+use common_sql::parse_check_constraint;
+use dashmap::DashMap;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::FuseTable;
+
+struct CheckConstraint {
+    name: String,
+    sql: String,
+    expr: Expr,
+}
+
+/// Evaluates the CHECK constraints of a table over the rows to be written.
+///
+/// A row violates a constraint if the constraint evaluates to `FALSE`, `NULL` is accepted.
+/// Violations abort the write, except for COPY, which handles them according to `ON_ERROR`
+/// like the errors of the input formats, accounted by the file of the rows:
+/// - `CONTINUE` filters out the violating rows.
+/// - `ABORT_N` filters out the violating rows, until `N` rows of all the files violate.
+/// - `SKIP_FILE_N` skips the files with `N` violating rows. The blocks are held until all
+///   the files are read, since the violations of a file can be found in its last block.
+///
+/// The statements merging into a table (`MERGE INTO`) are out of scope, they are not
+/// supported yet.
+pub struct TransformCheckConstraints {
+    func_ctx: FunctionContext,
+    schema: TableSchemaRef,
+    constraints: Arc<Vec<CheckConstraint>>,
+    on_error: Option<OnErrorMode>,
+    /// The errors of COPY by file, shared with the input formats.
+    on_error_map: Arc<DashMap<String, HashMap<u16, InputError>>>,
+    /// The violations of all the files, for `ABORT_N`.
+    num_violations: Arc<AtomicU64>,
+    /// The blocks held by file, and the violations of each file, for `SKIP_FILE_N`.
+    held_blocks: Vec<(String, DataBlock)>,
+    file_violations: HashMap<String, u64>,
+    processed_rows: usize,
+}
+
+impl TransformCheckConstraints {
+    fn violation(&self, constraint: &CheckConstraint, block: &DataBlock, row: usize) -> ErrorCode {
+        let mut column_refs = constraint
+            .expr
+            .column_refs()
+            .into_keys()
+            .collect::<Vec<_>>();
+        column_refs.sort();
+        let values = column_refs
+            .iter()
+            .map(|index| {
+                let value = block.get_by_offset(*index).value.index(row);
+                format!(
+                    "{} = {}",
+                    self.schema.field(*index).name(),
+                    value.unwrap_or(ScalarRef::Null)
+                )
+            })
+            .collect::<Vec<_>>();
+        ErrorCode::CheckConstraintViolated(format!(
+            "row {} violates check constraint {} CHECK ({}), values: {}",
+            self.processed_rows + row + 1,
+            constraint.name,
+            constraint.sql,
+            values.join(", ")
+        ))
+    }
+
+    // Accounts a violation of COPY to the file of the row, returns the error if the COPY
+    // is aborted.
+    fn on_violation(&mut self, file: &str, error: ErrorCode) -> Result<()> {
+        match self.on_error {
+            Some(OnErrorMode::Continue) | Some(OnErrorMode::SkipFileNum(_)) => {}
+            Some(OnErrorMode::AbortNum(n)) if n > 1 => {
+                if self.num_violations.fetch_add(1, Ordering::Relaxed) >= n - 1 {
+                    return Err(error);
+                }
+            }
+            _ => return Err(error),
+        }
+
+        *self.file_violations.entry(file.to_string()).or_default() += 1;
+        self.on_error_map
+            .entry(file.to_string())
+            .or_default()
+            .entry(error.code())
+            .and_modify(|input_error| input_error.num += 1)
+            .or_insert(InputError { err: error, num: 1 });
+        Ok(())
+    }
+}
+
+impl AccumulatingTransform for TransformCheckConstraints {
+    const NAME: &'static str = "CheckConstraintsTransform";
+
+    fn transform(&mut self, mut block: DataBlock) -> Result<Vec<DataBlock>> {
+        let file = block
+            .take_meta()
+            .and_then(InputFileMeta::downcast_from)
+            .map(|meta| meta.path)
+            .unwrap_or_default();
+        let num_rows = block.num_rows();
+        let func_ctx = self.func_ctx.clone();
+        let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let mut valid = MutableBitmap::from_len_set(num_rows);
+        let mut num_violations = 0;
+        let constraints = self.constraints.clone();
+        for constraint in constraints.iter() {
+            let value = evaluator.run(&constraint.expr)?;
+            for row in 0..num_rows {
+                if value.index(row) != Some(ScalarRef::Boolean(false)) || !valid.get(row) {
+                    continue;
+                }
+                let error = self.violation(constraint, &block, row);
+                self.on_violation(&file, error)?;
+                valid.set(row, false);
+                num_violations += 1;
+            }
+        }
+        self.processed_rows += num_rows;
+
+        let block = if num_violations == 0 {
+            block
+        } else {
+            block.filter_with_bitmap(&valid.into())?
+        };
+        if matches!(self.on_error, Some(OnErrorMode::SkipFileNum(_))) {
+            self.held_blocks.push((file, block));
+            return Ok(vec![]);
+        }
+        Ok(vec![block])
+    }
+
+    fn on_finish(&mut self, _output: bool) -> Result<Vec<DataBlock>> {
+        let max_violations = match self.on_error {
+            Some(OnErrorMode::SkipFileNum(n)) => n.max(1),
+            _ => return Ok(vec![]),
+        };
+        let held_blocks = std::mem::take(&mut self.held_blocks);
+        Ok(held_blocks
+            .into_iter()
+            .filter(|(file, _)| {
+                self.file_violations.get(file).copied().unwrap_or(0) < max_violations
+            })
+            .map(|(_, block)| block)
+            .collect())
+    }
+}
+
+impl FuseTable {
+    /// Adds a [`TransformCheckConstraints`] to the pipeline if the table has CHECK constraints.
+    /// The blocks of the pipeline are supposed to be of the table schema. `on_error` is the
+    /// `ON_ERROR` option of COPY, the violations abort the write without it.
+    pub fn add_check_constraints_transform(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        on_error: Option<OnErrorMode>,
+    ) -> Result<()> {
+        let check_constraints = &self.table_info.meta.check_constraints;
+        if check_constraints.is_empty() {
+            return Ok(());
+        }
+
+        let schema = self.table_info.schema();
+        let constraints = check_constraints
+            .iter()
+            .map(|(name, sql)| {
+                Ok(CheckConstraint {
+                    name: name.clone(),
+                    sql: sql.clone(),
+                    expr: parse_check_constraint(ctx.clone(), schema.clone(), sql)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let constraints = Arc::new(constraints);
+        let func_ctx = ctx.get_function_context()?;
+        let on_error_map = match ctx.get_on_error_map() {
+            Some(m) => m,
+            None => {
+                let m = Arc::new(DashMap::new());
+                ctx.set_on_error_map(m.clone());
+                m
+            }
+        };
+        let num_violations = Arc::new(AtomicU64::new(0));
+
+        // The files are skipped by a single processor holding all the blocks.
+        let skip_file = matches!(on_error, Some(OnErrorMode::SkipFileNum(_)));
+        let size = pipeline.output_len();
+        if skip_file {
+            pipeline.resize(1)?;
+        }
+        pipeline.add_transform(|input: Arc<InputPort>, output: Arc<OutputPort>| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                TransformCheckConstraints {
+                    func_ctx: func_ctx.clone(),
+                    schema: schema.clone(),
+                    constraints: constraints.clone(),
+                    on_error: on_error.clone(),
+                    on_error_map: on_error_map.clone(),
+                    num_violations: num_violations.clone(),
+                    held_blocks: vec![],
+                    file_violations: HashMap::new(),
+                    processed_rows: 0,
+                },
+            )))
+        })?;
+        if skip_file {
+            pipeline.resize(size)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use common_catalog::catalog::Catalog;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaId;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_CLONED_BY;
+use storages_common_table_meta::table::OPT_KEY_CLONE_SOURCES;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Make the table start from a copy of the given snapshot of the source table.
+    ///
+    /// Only the snapshot is copied, the segments and blocks are shared with the source
+    /// table. This table is recorded in the meta of all its sources (`OPT_KEY_CLONED_BY`),
+    /// and the gc of a source keeps the files referenced by the tables cloned from it.
+    #[async_backtrace::framed]
+    pub async fn do_clone(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source_snapshot_location: &str,
+    ) -> Result<()> {
+        // Register this table before sharing the files, from then on they are kept by the
+        // gc of the sources.
+        let catalog = ctx.get_catalog(self.table_info.catalog())?;
+        let table_id = self.table_info.ident.table_id;
+        let sources = self
+            .table_info
+            .options()
+            .get(OPT_KEY_CLONE_SOURCES)
+            .map_or_else(BTreeSet::new, |sources| parse_table_ids(sources));
+        for source_id in sources {
+            update_cloned_by(catalog.as_ref(), source_id, |cloned_by| {
+                cloned_by.insert(table_id);
+            })
+            .await?;
+        }
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: source_snapshot_location.to_owned(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(source_snapshot_location),
+            put_cache: false,
+        };
+        let source_snapshot = reader.read(&params).await?;
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            source_snapshot.schema.clone(),
+            source_snapshot.summary.clone(),
+            source_snapshot.segments.clone(),
+            self.cluster_key_meta.clone(),
+            // the table statistics are not shared, analyze the cloned table instead
+            None,
+        );
+
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &self.table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+}
+
+/// The ids of the tables cloned from the table of `table_meta`.
+pub(crate) fn get_cloned_by(table_meta: &TableMeta) -> BTreeSet<MetaId> {
+    table_meta
+        .options
+        .get(OPT_KEY_CLONED_BY)
+        .map_or_else(BTreeSet::new, |ids| parse_table_ids(ids))
+}
+
+// Update the ids of the tables cloned from the table `table_id` in its meta.
+#[async_backtrace::framed]
+async fn update_cloned_by(
+    catalog: &dyn Catalog,
+    table_id: MetaId,
+    f: impl Fn(&mut BTreeSet<MetaId>),
+) -> Result<()> {
+    loop {
+        let (ident, table_meta) = catalog.get_table_meta_by_id(table_id).await?;
+        let mut cloned_by = get_cloned_by(&table_meta);
+        f(&mut cloned_by);
+
+        let mut new_table_meta = table_meta.as_ref().clone();
+        let ids = cloned_by
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        new_table_meta
+            .options
+            .insert(OPT_KEY_CLONED_BY.to_owned(), ids);
+
+        let table_info = TableInfo {
+            ident: ident.clone(),
+            desc: format!("table_id: {}", table_id),
+            meta: table_meta.as_ref().clone(),
+            ..Default::default()
+        };
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(ident.seq),
+            new_table_meta,
+            copied_files: None,
+            update_stream_metas: vec![],
+        };
+        match catalog.update_table_meta(&table_info, req).await {
+            Ok(_) => return Ok(()),
+            // the table is updated concurrently, retry with the latest meta
+            Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn parse_table_ids(ids: &str) -> BTreeSet<MetaId> {
+    ids.split(',').filter_map(|id| id.parse().ok()).collect()
+}
@@ -406,6 +406,7 @@ impl FuseTable {
             seq: MatchSeq::Exact(table_version),
             new_table_meta,
             copied_files: copied_files.clone(),
+            update_stream_metas: ctx.get_consumed_streams(),
         };
 
         // 3. let's roll
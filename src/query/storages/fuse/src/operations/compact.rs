@@ -128,6 +128,9 @@ impl FuseTable {
 
         let mut mutator =
             BlockCompactMutator::new(ctx.clone(), thresholds, options, self.operator.clone());
+        // Blocks are rewritten with the current schema of the table, which differs from the
+        // schema of the base snapshot if columns are added, dropped or modified since then.
+        mutator.column_ids = schema.to_leaf_column_id_set();
         mutator.target_select().await?;
         if mutator.compact_tasks.is_empty() {
             return Ok(());
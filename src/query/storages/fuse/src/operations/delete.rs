@@ -55,6 +55,7 @@ use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::Pipeline;
 use crate::pruning::FusePruner;
 use crate::statistics::ClusterStatsGenerator;
+use crate::statistics::ClusterType;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -374,7 +375,11 @@ impl FuseTable {
             cluster_key_index.push(index);
         }
 
-        let max_page_size = self.get_max_page_size();
+        let max_page_size = if ClusterType::from_cluster_keys(&cluster_keys).is_curve() {
+            None
+        } else {
+            self.get_max_page_size()
+        };
         Ok(ClusterStatsGenerator::new(
             self.cluster_key_meta.as_ref().unwrap().0,
             cluster_key_index,
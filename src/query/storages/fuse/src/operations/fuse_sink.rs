@@ -28,6 +28,7 @@ use common_expression::FieldIndex;
 use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use common_io::prelude::serialize_into_buf;
 use common_pipeline_core::processors::port::OutputPort;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
@@ -43,6 +44,8 @@ use storages_common_table_meta::table::TableCompression;
 use super::AppendOperationLogEntry;
 use crate::io;
 use crate::io::TableMetaLocationGenerator;
+use crate::io::VectorIndexBuilder;
+use crate::io::VectorIndexDef;
 use crate::io::WriteSettings;
 use crate::metrics::metrics_inc_block_index_write_bytes;
 use crate::metrics::metrics_inc_block_index_write_milliseconds;
@@ -116,6 +119,8 @@ enum State {
         data: Vec<u8>,
         location: String,
         segment: Arc<SegmentInfo>,
+        vector_indexes: Vec<(String, Vec<u8>)>,
+        ndv_sketches: (String, Vec<u8>),
     },
     PreCommitSegment {
         location: String,
@@ -135,6 +140,7 @@ pub struct FuseTableSink {
 
     source_schema: TableSchemaRef,
     write_settings: WriteSettings,
+    vector_index_builders: Vec<VectorIndexBuilder>,
     // A dummy output port for distributed insert select to connect Exchange Sink.
     output: Option<Arc<OutputPort>>,
 }
@@ -150,8 +156,15 @@ impl FuseTableSink {
         cluster_stats_gen: ClusterStatsGenerator,
         thresholds: BlockThresholds,
         source_schema: TableSchemaRef,
+        vector_indexes: Vec<VectorIndexDef>,
         output: Option<Arc<OutputPort>>,
     ) -> Result<ProcessorPtr> {
+        // The virtual computed columns are removed from the blocks before written.
+        let stored_schema = source_schema.remove_virtual_computed_fields();
+        let vector_index_builders = vector_indexes
+            .into_iter()
+            .map(|def| VectorIndexBuilder::try_create(def, &stored_schema))
+            .collect::<Result<Vec<_>>>()?;
         Ok(ProcessorPtr::create(Box::new(FuseTableSink {
             ctx,
             input,
@@ -162,6 +175,7 @@ impl FuseTableSink {
             write_settings,
             cluster_stats_gen,
             source_schema,
+            vector_index_builders,
             output,
         })))
     }
@@ -218,13 +232,20 @@ impl Processor for FuseTableSink {
             State::NeedSerialize(data_block) => {
                 let (cluster_stats, block) =
                     self.cluster_stats_gen.gen_stats_for_append(&data_block)?;
+                let (source_schema, block) =
+                    io::remove_virtual_computed_columns(&self.source_schema, block);
+
+                let block_idx = self.accumulator.summary_block_count as usize;
+                for builder in self.vector_index_builders.iter_mut() {
+                    builder.add_block(&block, block_idx)?;
+                }
 
                 let (block_location, block_id) = self.meta_locations.gen_block_location();
 
                 let location = self.meta_locations.block_bloom_index_location(&block_id);
                 let bloom_index_state = BloomIndexState::try_create(
                     self.ctx.clone(),
-                    self.source_schema.clone(),
+                    source_schema.clone(),
                     &block,
                     location,
                 )?;
@@ -236,17 +257,13 @@ impl Processor for FuseTableSink {
                     block_location.0,
                     cluster_stats,
                     column_distinct_count,
-                    &self.source_schema,
+                    &source_schema,
                 )?;
 
                 // we need a configuration of block size threshold here
                 let mut data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-                let (size, meta_data) = io::serialize_block(
-                    &self.write_settings,
-                    &self.source_schema,
-                    block,
-                    &mut data,
-                )?;
+                let (size, meta_data) =
+                    io::serialize_block(&self.write_settings, &source_schema, block, &mut data)?;
 
                 self.state = State::Serialized {
                     data,
@@ -259,6 +276,8 @@ impl Processor for FuseTableSink {
             State::GenerateSegment => {
                 let acc = std::mem::take(&mut self.accumulator);
                 let col_stats = acc.summary()?;
+                let mut ndv_sketches = vec![];
+                serialize_into_buf(&mut ndv_sketches, &acc.ndv_sketches)?;
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, Statistics {
                     row_count: acc.summary_row_count,
@@ -270,10 +289,23 @@ impl Processor for FuseTableSink {
                     col_stats,
                 });
 
+                let location = self.meta_locations.gen_segment_info_location();
+                let mut vector_indexes = Vec::with_capacity(self.vector_index_builders.len());
+                for builder in self.vector_index_builders.iter_mut() {
+                    if let Some(index) = builder.finish(&self.meta_locations, &location)? {
+                        vector_indexes.push(index);
+                    }
+                }
+
                 self.state = State::SerializedSegment {
                     data: serde_json::to_vec(&segment_info)?,
-                    location: self.meta_locations.gen_segment_info_location(),
+                    ndv_sketches: (
+                        self.meta_locations.segment_ndv_sketches_location(&location),
+                        ndv_sketches,
+                    ),
+                    location,
                     segment: Arc::new(segment_info),
+                    vector_indexes,
                 }
             }
             State::PreCommitSegment { location, segment } => {
@@ -369,7 +401,16 @@ impl Processor for FuseTableSink {
                 data,
                 location,
                 segment,
+                vector_indexes,
+                ndv_sketches,
             } => {
+                // Write the vector indexes and the NDV sketches first, a committed segment
+                // is always accompanied by them.
+                for (index_location, index_data) in vector_indexes {
+                    io::write_data(index_data, &self.data_accessor, &index_location).await?;
+                }
+                let (sketches_location, sketches_data) = ndv_sketches;
+                io::write_data(sketches_data, &self.data_accessor, &sketches_location).await?;
                 self.data_accessor.write(&location, data).await?;
 
                 self.state = State::PreCommitSegment { location, segment };
@@ -24,7 +24,9 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::TableInfo;
 use storages_common_cache::CacheAccessor;
+use storages_common_cache::LoadParams;
 use storages_common_cache_manager::CachedObject;
 use storages_common_index::BloomIndexMeta;
 use storages_common_table_meta::meta::Location;
@@ -38,8 +40,11 @@ use tracing::warn;
 
 use crate::io::Files;
 use crate::io::ListSnapshotLiteOption;
+use crate::io::MetaReaders;
 use crate::io::SegmentsIO;
 use crate::io::SnapshotsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::clone::get_cloned_by;
 use crate::FuseTable;
 
 #[derive(Default)]
@@ -106,6 +111,23 @@ impl FuseTable {
                 (SnapshotId::new_v4(), None, None)
             };
 
+        // Files of this table that are shared with the tables cloned from it must be kept,
+        // and files shared from the source table (if this table is a clone) are not owned
+        // by this table.
+        let (
+            segments_referenced_by_clones,
+            ts_referenced_by_clones,
+            locations_referenced_by_clones,
+        ) = self.get_locations_referenced_by_clones(ctx).await?;
+        // Tagged snapshots, and the files referenced by them, are never purged.
+        let (
+            snapshots_referenced_by_tags,
+            ts_referenced_by_tags,
+            segments_referenced_by_tags,
+            locations_referenced_by_tags,
+        ) = self.get_locations_referenced_by_tags(ctx).await?;
+        let owned_prefix = format!("{}/", self.meta_location_generator.prefix());
+
         // 2. Get all snapshot(including root snapshot).
         let mut chained_snapshots = vec![];
         let mut all_segment_locations = HashSet::new();
@@ -179,9 +201,7 @@ impl FuseTable {
         // 3. Find.
         let mut snapshots_to_be_purged = HashSet::new();
         let mut segments_to_be_purged = HashSet::new();
-        // Todo(zhyass): exists bug, the ts_to_be_purged is empty, cannot be purged.
-        // We will do the fix in the purge refactoring.
-        let ts_to_be_purged: Vec<String> = vec![];
+        let mut ts_to_be_purged: Vec<String> = vec![];
 
         // 3.1 Find all the snapshots need to be deleted.
         {
@@ -202,6 +222,12 @@ impl FuseTable {
                         continue;
                     }
                 }
+                if !segment.0.starts_with(&owned_prefix)
+                    || segments_referenced_by_clones.contains(segment)
+                    || segments_referenced_by_tags.contains(segment)
+                {
+                    continue;
+                }
                 segments_to_be_purged.insert(segment.clone());
             }
         }
@@ -215,11 +241,19 @@ impl FuseTable {
                     self.operator.clone(),
                     self.snapshot_format_version().await?,
                 );
-                // Todo(zhyass): exists bug, we need to filter out some table statistic files
-                // based on the snapshots just like the segments.
-                let ts_to_be_purged = snapshots_io
+                // The statistic files of the root snapshot (excluded by the listing), the tagged
+                // snapshots and the latest snapshots of the clones are kept. The snapshots kept
+                // within the retention period may lose their statistics, which are optional.
+                ts_to_be_purged = snapshots_io
                     .read_table_statistic_files(&root_ts_location, None)
-                    .await?;
+                    .await?
+                    .into_iter()
+                    .filter(|loc| {
+                        loc.starts_with(&owned_prefix)
+                            && !ts_referenced_by_tags.contains(loc)
+                            && !ts_referenced_by_clones.contains(loc)
+                    })
+                    .collect();
                 let status_ts_scan_count = ts_to_be_purged.len();
                 let status_ts_scan_cost = start.elapsed().as_secs();
                 let status = format!(
@@ -232,6 +266,19 @@ impl FuseTable {
         }
 
         let chunk_size = ctx.get_settings().get_max_storage_io_requests()? as usize;
+        // The index files of the dropped vector indexes are not known any more and are left.
+        let schema = self.schema();
+        let vector_indexes = self
+            .vector_indexes()?
+            .into_iter()
+            .filter_map(|def| {
+                let column_id = schema
+                    .index_of(&def.column)
+                    .and_then(|index| schema.column_id_of_index(index))
+                    .ok()?;
+                Some((def, column_id))
+            })
+            .collect::<Vec<_>>();
 
         // 4. Purge segments&blocks by chunk size
         {
@@ -253,6 +300,12 @@ impl FuseTable {
                         {
                             continue;
                         }
+                        if !loc.starts_with(&owned_prefix)
+                            || locations_referenced_by_clones.block_location.contains(loc)
+                            || locations_referenced_by_tags.block_location.contains(loc)
+                        {
+                            continue;
+                        }
                         block_locations_to_be_purged.insert(loc.to_string());
                     }
                     status_block_to_be_purged_count += block_locations_to_be_purged.len();
@@ -269,6 +322,12 @@ impl FuseTable {
                         {
                             continue;
                         }
+                        if !loc.starts_with(&owned_prefix)
+                            || locations_referenced_by_clones.bloom_location.contains(loc)
+                            || locations_referenced_by_tags.bloom_location.contains(loc)
+                        {
+                            continue;
+                        }
                         bloom_locations_to_be_purged.insert(loc.to_string());
                     }
                     status_bloom_to_be_purged_count += bloom_locations_to_be_purged.len();
@@ -294,6 +353,35 @@ impl FuseTable {
                     .await?;
                 }
 
+                // 4. Try to purge the NDV sketches of the segments, which may not exist.
+                {
+                    let sketch_locations_to_be_purged =
+                        HashSet::from_iter(chunk.iter().map(|(segment_location, _)| {
+                            self.meta_location_generator
+                                .segment_ndv_sketches_location(segment_location)
+                        }));
+                    self.try_purge_location_files(ctx.clone(), sketch_locations_to_be_purged)
+                        .await?;
+                }
+
+                // 5. Try to purge the vector index files of the segments.
+                if !vector_indexes.is_empty() {
+                    let mut vector_index_locations_to_be_purged = HashSet::new();
+                    for (segment_location, _) in chunk {
+                        for (def, column_id) in &vector_indexes {
+                            vector_index_locations_to_be_purged.insert(
+                                self.meta_location_generator.segment_vector_index_location(
+                                    segment_location,
+                                    def,
+                                    *column_id,
+                                ),
+                            );
+                        }
+                    }
+                    self.try_purge_location_files(ctx.clone(), vector_index_locations_to_be_purged)
+                        .await?;
+                }
+
                 // Refresh status.
                 {
                     status_segment_to_be_purged_count += chunk.len();
@@ -333,6 +421,9 @@ impl FuseTable {
                 let mut snapshot_locations_to_be_purged = HashSet::new();
                 for (id, ver) in chunk {
                     if let Ok(loc) = location_gen.snapshot_location_from_uuid(id, *ver) {
+                        if snapshots_referenced_by_tags.contains(&loc) {
+                            continue;
+                        }
                         snapshot_locations_to_be_purged.insert(loc);
                     }
                 }
@@ -451,6 +542,135 @@ impl FuseTable {
             .await
     }
 
+    // Collect the segments, blocks and bloom indexes that are referenced by the
+    // tables cloned from this table, including their historical snapshots, and the
+    // table statistic files of their latest snapshots.
+    //
+    // The clones are recorded in the meta of this table when they are created. A dropped
+    // clone can be undropped as long as its meta exists, so its files are kept as well.
+    #[async_backtrace::framed]
+    async fn get_locations_referenced_by_clones(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<(HashSet<Location>, HashSet<String>, LocationTuple)> {
+        let catalog = ctx.get_catalog(self.table_info.catalog())?;
+        // The tables cloned after this table instance is loaded are only in the latest meta.
+        let (_, table_meta) = catalog
+            .get_table_meta_by_id(self.table_info.ident.table_id)
+            .await?;
+
+        let mut segments = HashSet::new();
+        let mut table_statistics = HashSet::new();
+        for clone_id in get_cloned_by(&table_meta) {
+            let (ident, meta) = match catalog.get_table_meta_by_id(clone_id).await {
+                Ok(res) => res,
+                // the meta of the dropped clone has been vacuumed
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                Err(e) => return Err(e),
+            };
+            let table_info = TableInfo {
+                ident,
+                desc: format!("table_id: {}", clone_id),
+                meta: meta.as_ref().clone(),
+                tenant: self.table_info.tenant.clone(),
+                ..Default::default()
+            };
+            let table = catalog.get_table_by_info(&table_info)?;
+            let clone = FuseTable::try_from_table(table.as_ref())?;
+            if let Some(snapshot) = clone.read_table_snapshot().await? {
+                table_statistics.extend(snapshot.table_statistics_location.clone());
+            }
+            if let Some(root_snapshot_location) = clone.snapshot_loc().await? {
+                let snapshots_io = SnapshotsIO::create(
+                    ctx.clone(),
+                    clone.operator.clone(),
+                    clone.snapshot_format_version().await?,
+                );
+                let snapshot_lites = snapshots_io
+                    .read_snapshot_lites_ext(
+                        root_snapshot_location,
+                        None,
+                        &ListSnapshotLiteOption::NeedSegmentsWithExclusion(None),
+                        None,
+                        |status| {
+                            ctx.set_status_info(&status);
+                        },
+                    )
+                    .await?;
+                segments.extend(snapshot_lites.segment_locations.into_keys());
+            }
+        }
+
+        if segments.is_empty() {
+            return Ok((segments, table_statistics, LocationTuple::default()));
+        }
+        let segment_locations = Vec::from_iter(segments.iter().cloned());
+        let locations = self
+            .get_block_locations(ctx.clone(), &segment_locations, false)
+            .await?;
+        Ok((segments, table_statistics, locations))
+    }
+
+    // Collect the snapshots tagged by `ALTER TABLE ... CREATE TAG`, and the table statistic
+    // files, segments, blocks and bloom indexes that are referenced by them.
+    #[async_backtrace::framed]
+    async fn get_locations_referenced_by_tags(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<(
+        HashSet<String>,
+        HashSet<String>,
+        HashSet<Location>,
+        LocationTuple,
+    )> {
+        let snapshots: HashSet<String> = self
+            .table_info
+            .meta
+            .snapshot_tags
+            .values()
+            .cloned()
+            .collect();
+        if snapshots.is_empty() {
+            return Ok((
+                snapshots,
+                HashSet::new(),
+                HashSet::new(),
+                LocationTuple::default(),
+            ));
+        }
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut table_statistics = HashSet::new();
+        let mut segments = HashSet::new();
+        for location in &snapshots {
+            let params = LoadParams {
+                location: location.clone(),
+                len_hint: None,
+                ver: TableMetaLocationGenerator::snapshot_version(location),
+                put_cache: false,
+            };
+            match reader.read(&params).await {
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                    warn!(
+                        "tagged snapshot of location {} not found. table: {}, ident {}",
+                        location, self.table_info.desc, self.table_info.ident,
+                    );
+                }
+                Err(e) => return Err(e),
+                Ok(snapshot) => {
+                    table_statistics.extend(snapshot.table_statistics_location.clone());
+                    segments.extend(snapshot.segments.iter().cloned());
+                }
+            }
+        }
+
+        let segment_locations = Vec::from_iter(segments.iter().cloned());
+        let locations = self
+            .get_block_locations(ctx.clone(), &segment_locations, false)
+            .await?;
+        Ok((snapshots, table_statistics, segments, locations))
+    }
+
     #[async_backtrace::framed]
     async fn get_block_locations(
         &self,
@@ -14,6 +14,8 @@
 
 mod analyze;
 mod append;
+mod check_constraint;
+mod clone;
 mod commit;
 mod compact;
 mod delete;
@@ -30,12 +32,14 @@ mod replace;
 mod replace_into;
 mod truncate;
 mod update;
+mod vector_index;
 
 mod fuse_source;
 mod read;
 mod revert;
 pub mod util;
 
+pub use check_constraint::TransformCheckConstraints;
 pub use compact::CompactOptions;
 pub use fuse_sink::BloomIndexState;
 pub use fuse_sink::FuseTableSink;
@@ -344,9 +344,11 @@ impl<'a> SegmentCompactor<'a> {
         let mut new_statistics = Statistics::default();
 
         self.compacted_state.num_fragments_compacted += fragments.len();
-        for (segment, _location) in fragments {
+        let mut fragment_locations = Vec::with_capacity(fragments.len());
+        for (segment, location) in fragments {
             merge_statistics_mut(&mut new_statistics, &segment.summary)?;
             blocks.append(&mut segment.blocks.clone());
+            fragment_locations.push(location);
         }
 
         merge_statistics_mut(&mut self.compacted_state.statistics, &new_statistics)?;
@@ -354,6 +356,9 @@ impl<'a> SegmentCompactor<'a> {
         // 2.2 write down new segment
         let new_segment = SegmentInfo::new(blocks, new_statistics);
         let location = self.segment_writer.write_segment(new_segment).await?;
+        self.segment_writer
+            .write_merged_ndv_sketches(&location.0, &fragment_locations)
+            .await?;
         self.compacted_state
             .new_segment_paths
             .push(location.0.clone());
@@ -111,6 +111,7 @@ impl Processor for FillInternalColumnProcessor {
                 block_location: block_meta.block_location.clone(),
                 segment_location: block_meta.segment_location.clone(),
                 snapshot_location: block_meta.snapshot_location.unwrap(),
+                is_deleted: block_meta.is_deleted,
             };
             for internal_column in self.internal_columns.values() {
                 let column =
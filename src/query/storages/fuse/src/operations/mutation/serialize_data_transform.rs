@@ -30,6 +30,7 @@ use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::table::TableCompression;
 
+use crate::io::remove_virtual_computed_columns;
 use crate::io::serialize_block;
 use crate::io::write_data;
 use crate::io::TableMetaLocationGenerator;
@@ -168,6 +169,7 @@ impl Processor for SerializeDataTransform {
                 let cluster_stats = self
                     .cluster_stats_gen
                     .gen_with_origin_stats(&block, std::mem::take(&mut self.origin_stats))?;
+                let (schema, block) = remove_virtual_computed_columns(&self.schema, block);
 
                 let row_count = block.num_rows() as u64;
                 let block_size = block.memory_size() as u64;
@@ -177,19 +179,17 @@ impl Processor for SerializeDataTransform {
                 let location = self.location_gen.block_bloom_index_location(&block_id);
                 let bloom_index_state = BloomIndexState::try_create(
                     self.ctx.clone(),
-                    self.schema.clone(),
+                    schema.clone(),
                     &block,
                     location,
                 )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
                     .map(|i| i.column_distinct_count.clone());
-                let col_stats =
-                    gen_columns_statistics(&block, column_distinct_count, &self.schema)?;
+                let col_stats = gen_columns_statistics(&block, column_distinct_count, &schema)?;
 
                 // serialize data block.
                 let mut block_data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-                let schema = self.schema.clone();
 
                 let (file_size, col_metas) =
                     serialize_block(&self.write_settings, &schema, block, &mut block_data)?;
@@ -32,7 +32,9 @@ use crate::operations::read::native_data_source_deserializer::NativeDeserializeD
 use crate::operations::read::native_data_source_reader::ReadNativeDataSource;
 use crate::operations::read::parquet_data_source_deserializer::DeserializeDataTransform;
 use crate::operations::read::parquet_data_source_reader::ReadParquetDataSource;
+use crate::pruning::RuntimeFilterPruner;
 
+#[allow(clippy::too_many_arguments)]
 pub fn build_fuse_native_source_pipeline(
     ctx: Arc<dyn TableContext>,
     pipeline: &mut Pipeline,
@@ -41,6 +43,7 @@ pub fn build_fuse_native_source_pipeline(
     plan: &DataSourcePlan,
     topk: Option<TopK>,
     mut max_io_requests: usize,
+    runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
 ) -> Result<()> {
     (max_threads, max_io_requests) =
         adjust_threads_and_request(true, max_threads, max_io_requests, plan);
@@ -52,7 +55,8 @@ pub fn build_fuse_native_source_pipeline(
 
     let mut source_builder = SourcePipeBuilder::create();
 
-    match block_reader.support_blocking_api() {
+    // Runtime filters are waited for asynchronously.
+    match block_reader.support_blocking_api() && runtime_filter_pruner.is_none() {
         true => {
             let partitions = dispatch_partitions(ctx.clone(), plan, max_threads);
             let mut partitions = StealablePartitions::new(partitions, ctx.clone());
@@ -94,6 +98,7 @@ pub fn build_fuse_native_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        runtime_filter_pruner.clone(),
                     )?,
                 );
             }
@@ -123,13 +128,15 @@ pub fn build_fuse_parquet_source_pipeline(
     plan: &DataSourcePlan,
     mut max_threads: usize,
     mut max_io_requests: usize,
+    runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
 ) -> Result<()> {
     (max_threads, max_io_requests) =
         adjust_threads_and_request(false, max_threads, max_io_requests, plan);
 
     let mut source_builder = SourcePipeBuilder::create();
 
-    match block_reader.support_blocking_api() {
+    // Runtime filters are waited for asynchronously.
+    match block_reader.support_blocking_api() && runtime_filter_pruner.is_none() {
         true => {
             let partitions = dispatch_partitions(ctx.clone(), plan, max_threads);
             let partitions = StealablePartitions::new(partitions, ctx.clone());
@@ -144,6 +151,7 @@ pub fn build_fuse_parquet_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        runtime_filter_pruner.clone(),
                     )?,
                 );
             }
@@ -165,6 +173,7 @@ pub fn build_fuse_parquet_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        runtime_filter_pruner.clone(),
                     )?,
                 );
             }
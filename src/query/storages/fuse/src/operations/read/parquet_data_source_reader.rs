@@ -27,11 +27,14 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_sources::SyncSource;
 use common_pipeline_sources::SyncSourcer;
+use common_profile::PlanProfSpan;
+use common_profile::ProfSpan;
 
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
+use crate::pruning::RuntimeFilterPruner;
 use crate::MergeIOReadResult;
 
 pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
@@ -43,6 +46,8 @@ pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     output: Arc<OutputPort>,
     output_data: Option<(Vec<PartInfoPtr>, Vec<MergeIOReadResult>)>,
     partitions: StealablePartitions,
+    runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
+    scan_prof_span: Option<PlanProfSpan>,
 }
 
 impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
@@ -52,8 +57,10 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
         partitions: StealablePartitions,
+        runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
+        let scan_prof_span = ctx.get_scan_prof_span();
 
         if BLOCKING_IO {
             SyncSourcer::create(ctx.clone(), output.clone(), ReadParquetDataSource::<true> {
@@ -64,6 +71,8 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 finished: false,
                 output_data: None,
                 partitions,
+                runtime_filter_pruner,
+                scan_prof_span,
             })
         } else {
             Ok(ProcessorPtr::create(Box::new(ReadParquetDataSource::<
@@ -76,24 +85,45 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 finished: false,
                 output_data: None,
                 partitions,
+                runtime_filter_pruner,
+                scan_prof_span,
             })))
         }
     }
 }
 
+impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
+    fn record_scan_bytes(&self, parts: &[PartInfoPtr]) -> Result<()> {
+        if let Some(scan_prof_span) = &self.scan_prof_span {
+            let mut scan_bytes = 0;
+            for part in parts {
+                scan_bytes += FusePartInfo::from_part(part)?.read_bytes();
+            }
+            scan_prof_span.update(ProfSpan {
+                scan_bytes,
+                ..Default::default()
+            });
+        }
+        Ok(())
+    }
+}
+
 impl SyncSource for ReadParquetDataSource<true> {
     const NAME: &'static str = "SyncReadParquetDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
         match self.partitions.steal_one(self.id) {
             None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
-                vec![part.clone()],
-                vec![self.block_reader.sync_read_columns_data_by_merge_io(
-                    &ReadSettings::from_ctx(&self.partitions.ctx)?,
-                    part,
-                )?],
-            )))),
+            Some(part) => {
+                self.record_scan_bytes(&[part.clone()])?;
+                Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
+                    vec![part.clone()],
+                    vec![self.block_reader.sync_read_columns_data_by_merge_io(
+                        &ReadSettings::from_ctx(&self.partitions.ctx)?,
+                        part,
+                    )?],
+                ))))
+            }
         }
     }
 }
@@ -134,7 +164,20 @@ impl Processor for ReadParquetDataSource<false> {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.partitions.steal(self.id, self.batch_size);
+        let parts = match &self.runtime_filter_pruner {
+            None => self.partitions.steal(self.id, self.batch_size),
+            // Keep on stealing while the runtime filters prune all the stolen parts.
+            Some(pruner) => loop {
+                let parts = self.partitions.steal(self.id, self.batch_size);
+                if parts.is_empty() {
+                    break parts;
+                }
+                let parts = pruner.prune(parts).await?;
+                if !parts.is_empty() {
+                    break parts;
+                }
+            },
+        };
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -160,7 +203,9 @@ impl Processor for ReadParquetDataSource<false> {
                 });
             }
 
-            self.output_data = Some((parts, futures::future::try_join_all(chunks).await?));
+            let chunks = futures::future::try_join_all(chunks).await?;
+            self.record_scan_bytes(&parts)?;
+            self.output_data = Some((parts, chunks));
             return Ok(());
         }
 
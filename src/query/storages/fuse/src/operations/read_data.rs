@@ -29,6 +29,7 @@ use storages_common_index::RangeIndex;
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::io::BlockReader;
 use crate::operations::fuse_source::build_fuse_source_pipeline;
+use crate::pruning::RuntimeFilterPruner;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -155,6 +156,13 @@ impl FuseTable {
             )
         });
 
+        let runtime_filter_pruner = RuntimeFilterPruner::try_create(
+            ctx.clone(),
+            self.operator.clone(),
+            self.table_info.schema(),
+            &plan.push_downs,
+        )?;
+
         build_fuse_source_pipeline(
             ctx,
             pipeline,
@@ -163,6 +171,7 @@ impl FuseTable {
             plan,
             topk,
             max_io_requests,
+            runtime_filter_pruner,
         )
     }
 }
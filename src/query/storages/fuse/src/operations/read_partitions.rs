@@ -148,6 +148,8 @@ impl FuseTable {
                 cluster_keys,
             )?
         };
+        let pruner = pruner
+            .with_vector_indexes(self.meta_location_generator.clone(), self.vector_indexes()?)?;
         let snapshot_loc = self.snapshot_loc().await?;
         let block_metas = pruner
             .pruning(segments_location, snapshot_loc, segment_id_map)
@@ -392,9 +394,9 @@ impl FuseTable {
         let location = meta.location.0.clone();
         let format_version = meta.location.1;
 
-        let sort_min_max = top_k.as_ref().map(|top_k| {
-            let stat = meta.col_stats.get(&top_k.column_id).unwrap();
-            (stat.min.clone(), stat.max.clone())
+        let sort_min_max = top_k.as_ref().and_then(|top_k| {
+            let stat = meta.col_stats.get(&top_k.column_id)?;
+            Some((stat.min.clone(), stat.max.clone()))
         });
 
         FusePartInfo::create(
@@ -425,15 +427,21 @@ impl FuseTable {
                     columns_meta.insert(*column_id, column_meta.clone());
                 }
             }
+            // blocks written before the type of the column was modified
+            for legacy_column in &column.legacy_columns {
+                if let Some(column_meta) = meta.col_metas.get(&legacy_column.column_id) {
+                    columns_meta.insert(legacy_column.column_id, column_meta.clone());
+                }
+            }
         }
 
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let format_version = meta.location.1;
 
-        let sort_min_max = top_k.map(|top_k| {
-            let stat = meta.col_stats.get(&top_k.column_id).unwrap();
-            (stat.min.clone(), stat.max.clone())
+        let sort_min_max = top_k.and_then(|top_k| {
+            let stat = meta.col_stats.get(&top_k.column_id)?;
+            Some((stat.min.clone(), stat.max.clone()))
         });
 
         // TODO
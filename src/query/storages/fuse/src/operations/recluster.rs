@@ -197,6 +197,7 @@ impl FuseTable {
             )?))
         })?;
 
+        let vector_indexes = self.vector_indexes()?;
         pipeline.add_sink(|input| {
             FuseTableSink::try_create(
                 input,
@@ -207,6 +208,7 @@ impl FuseTable {
                 cluster_stats_gen.clone(),
                 block_compact_thresholds,
                 self.table_info.schema(),
+                vector_indexes.clone(),
                 None,
             )
         })?;
@@ -123,7 +123,9 @@ impl FuseTable {
             })
         }
 
-        // 1. resize input to 1, since the UpsertTransform need to de-duplicate inputs "globally"
+        // 1. check the CHECK constraints, then resize input to 1, since the UpsertTransform
+        // need to de-duplicate inputs "globally"
+        self.add_check_constraints_transform(ctx.clone(), pipeline, None)?;
         pipeline.resize(1)?;
 
         // 2. connect with ReplaceIntoProcessor
@@ -76,6 +76,7 @@ impl FuseTable {
                     seq: MatchSeq::Exact(table_version),
                     new_table_meta,
                     copied_files: None,
+                    update_stream_metas: vec![],
                 })
                 .await?;
 
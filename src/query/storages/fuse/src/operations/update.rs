@@ -19,6 +19,8 @@ use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ComputedExpr;
 use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
 use common_expression::TableDataType;
@@ -26,6 +28,7 @@ use common_expression::TableField;
 use common_expression::TableSchema;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
+use common_sql::parse_computed_expr;
 use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::operations::mutation::MutationAction;
@@ -84,6 +87,8 @@ impl FuseTable {
         )
         .await?;
 
+        self.add_check_constraints_transform(ctx.clone(), pipeline, None)?;
+
         // TODO(zhyass): support cluster stats generator.
         pipeline.add_transform(|input, output| {
             SerializeDataTransform::try_create(
@@ -184,6 +189,30 @@ impl FuseTable {
             projection: offset_map.values().cloned().collect(),
         });
 
+        // Recompute the stored computed columns over the updated block.
+        let mut computed_exprs = vec![];
+        let mut computed_projection: Vec<FieldIndex> = (0..schema.num_fields()).collect();
+        for (index, field) in schema.fields().iter().enumerate() {
+            if let Some(ComputedExpr::Stored(expr)) = field.computed_expr() {
+                let expr = parse_computed_expr(
+                    ctx.clone(),
+                    schema.clone(),
+                    expr,
+                    &DataType::from(field.data_type()),
+                )?;
+                computed_projection[index] = schema.num_fields() + computed_exprs.len();
+                computed_exprs.push(expr);
+            }
+        }
+        if !computed_exprs.is_empty() {
+            ops.push(BlockOperator::Map {
+                exprs: computed_exprs,
+            });
+            ops.push(BlockOperator::Project {
+                projection: computed_projection,
+            });
+        }
+
         let block_reader = self.create_block_reader(projection.clone(), false, ctx.clone())?;
         let remain_reader = Arc::new(remain_reader);
         let (filter_expr, filter) = if let Some(remote_expr) = filter {
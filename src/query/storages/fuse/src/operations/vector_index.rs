@@ -0,0 +1,76 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use tracing::info;
+
+use crate::io::write_data;
+use crate::io::ReadSettings;
+use crate::io::SegmentsIO;
+use crate::io::VectorIndexBuilder;
+use crate::io::VectorIndexDef;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Builds the vector index of the segments of the current snapshot,
+    /// the segments written afterwards are indexed when they are written.
+    #[async_backtrace::framed]
+    pub async fn do_build_vector_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        def: &VectorIndexDef,
+    ) -> Result<()> {
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let schema = self.schema();
+        let field_index = schema.index_of(&def.column)?;
+        let block_reader =
+            self.create_block_reader(Projection::Columns(vec![field_index]), false, ctx.clone())?;
+        let mut builder =
+            VectorIndexBuilder::try_create(def.clone(), &schema.project(&[field_index]))?;
+        let settings = ReadSettings::from_ctx(&ctx)?;
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), schema.clone());
+        let segments = segments_io.read_segments(&snapshot.segments, false).await?;
+        for ((segment_location, _), segment) in snapshot.segments.iter().zip(segments) {
+            let segment = segment?;
+            for (block_idx, block_meta) in segment.blocks.iter().enumerate() {
+                let block = block_reader
+                    .read_by_meta(&settings, block_meta, &self.storage_format)
+                    .await?;
+                builder.add_block(&block, block_idx)?;
+            }
+            if let Some((location, data)) =
+                builder.finish(&self.meta_location_generator, segment_location)?
+            {
+                write_data(data, &self.operator, &location).await?;
+            }
+        }
+
+        info!(
+            "vector index {} built for {} segments of table {}",
+            def.name,
+            snapshot.segments.len(),
+            self.table_info.desc
+        );
+        Ok(())
+    }
+}
@@ -191,6 +191,7 @@ impl BlockPruner {
                         segment_id: segment_location.segment_id,
                         segment_location: segment_location.location.0.clone(),
                         snapshot_location: segment_location.snapshot_loc.clone(),
+                        is_deleted: false,
                     },
                     block,
                 ))
@@ -257,6 +258,7 @@ impl BlockPruner {
                             segment_id: segment_location.segment_id,
                             segment_location: segment_location.location.0.clone(),
                             snapshot_location: segment_location.snapshot_loc.clone(),
+                            is_deleted: false,
                         },
                         block_meta.clone(),
                     ))
@@ -38,10 +38,13 @@ use storages_common_table_meta::meta::Location;
 use tracing::warn;
 
 use super::create_segment_location_vector;
+use crate::io::TableMetaLocationGenerator;
+use crate::io::VectorIndexDef;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
 use crate::pruning::SegmentPruner;
+use crate::pruning::VectorIndexPruner;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -61,6 +64,7 @@ pub struct FusePruner {
     pub table_schema: TableSchemaRef,
     pub pruning_ctx: Arc<PruningContext>,
     pub push_down: Option<PushDownInfo>,
+    pub vector_index_pruner: Option<VectorIndexPruner>,
 }
 
 impl FusePruner {
@@ -160,11 +164,29 @@ impl FusePruner {
             table_schema,
             push_down: push_down.clone(),
             pruning_ctx,
+            vector_index_pruner: None,
         })
     }
 
+    // Prune the blocks by the vector indexes of the table,
+    // if a nearest neighbours search is pushed down.
+    pub fn with_vector_indexes(
+        mut self,
+        locations: TableMetaLocationGenerator,
+        indexes: Vec<VectorIndexDef>,
+    ) -> Result<Self> {
+        self.vector_index_pruner = VectorIndexPruner::try_create(
+            self.pruning_ctx.dal.clone(),
+            locations,
+            &self.table_schema,
+            &self.push_down,
+            indexes,
+        )?;
+        Ok(self)
+    }
+
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> vector index pruner -> topn pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &self,
@@ -177,7 +199,12 @@ impl FusePruner {
         // Segment pruner.
         let segment_pruner =
             SegmentPruner::create(self.pruning_ctx.clone(), self.table_schema.clone())?;
-        let metas = segment_pruner.pruning(segment_locs).await?;
+        let mut metas = segment_pruner.pruning(segment_locs).await?;
+
+        // Vector index pruner.
+        if let Some(vector_index_pruner) = &self.vector_index_pruner {
+            metas = vector_index_pruner.prune(metas).await?;
+        }
 
         // TopN pruner.
         self.topn_pruning(metas)
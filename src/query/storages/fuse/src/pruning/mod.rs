@@ -17,7 +17,9 @@ mod bloom_pruner;
 mod fuse_pruner;
 mod pruner_location;
 mod pruning_statistics;
+mod runtime_filter_pruner;
 mod segment_pruner;
+mod vector_index_pruner;
 
 pub use block_pruner::BlockPruner;
 pub use bloom_pruner::BloomPruner;
@@ -27,4 +29,6 @@ pub use fuse_pruner::PruningContext;
 pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
+pub use runtime_filter_pruner::RuntimeFilterPruner;
 pub use segment_pruner::SegmentPruner;
+pub use vector_index_pruner::VectorIndexPruner;
@@ -0,0 +1,231 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::OnceCell;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use common_catalog::runtime_filter_info::RuntimeFilterTarget;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use opendal::Operator;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CachedObject;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::SegmentInfo;
+use tracing::info;
+
+use crate::fuse_part::FusePartInfo;
+use crate::pruning::BloomPruner;
+use crate::pruning::BloomPrunerCreator;
+
+struct RuntimeFilters {
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+    bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
+}
+
+/// Prunes the parts of a scan by the runtime filters of the hash joins it is the probe side of.
+///
+/// The filters are only known once the build sides are read, so unlike `FusePruner`, it works
+/// on the parts while they are being read, against the block metas of the segment cache.
+pub struct RuntimeFilterPruner {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    table_schema: TableSchemaRef,
+    targets: Vec<RuntimeFilterTarget>,
+    filters: OnceCell<Option<RuntimeFilters>>,
+}
+
+impl RuntimeFilterPruner {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        table_schema: TableSchemaRef,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Option<Arc<Self>>> {
+        let targets = match push_downs {
+            Some(push_downs) if !push_downs.runtime_filters.is_empty() => {
+                push_downs.runtime_filters.clone()
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(Arc::new(RuntimeFilterPruner {
+            ctx,
+            dal,
+            table_schema,
+            targets,
+            filters: OnceCell::new(),
+        })))
+    }
+
+    /// Returns the parts that may match the runtime filters,
+    /// waiting for the filters to be published on first call.
+    #[async_backtrace::framed]
+    pub async fn prune(&self, parts: Vec<PartInfoPtr>) -> Result<Vec<PartInfoPtr>> {
+        let filters = match self.filters.get_or_try_init(|| self.prepare()).await? {
+            Some(filters) => filters,
+            None => return Ok(parts),
+        };
+
+        let mut kept = Vec::with_capacity(parts.len());
+        for part in parts {
+            if Self::should_keep(filters, &part).await {
+                kept.push(part);
+            }
+        }
+        Ok(kept)
+    }
+
+    #[async_backtrace::framed]
+    async fn prepare(&self) -> Result<Option<RuntimeFilters>> {
+        let mut conjunctions = Vec::with_capacity(self.targets.len());
+        for target in &self.targets {
+            // None if the build side can't tell which keys it has.
+            if let Some(info) = self.ctx.get_runtime_filter_channel(target.key).wait().await {
+                let expr = target.expr.as_expr(&BUILTIN_FUNCTIONS);
+                conjunctions.push(runtime_filter_expr(expr, &info)?);
+            }
+        }
+
+        info!(
+            "{} of {} runtime filters are applied to the scan",
+            conjunctions.len(),
+            self.targets.len()
+        );
+        let mut filter = match conjunctions.pop() {
+            Some(filter) => filter,
+            None => return Ok(None),
+        };
+        for conjunction in conjunctions {
+            filter = call("and", &[conjunction, filter])?;
+        }
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let range_pruner =
+            RangePrunerCreator::try_create(func_ctx.clone(), &self.table_schema, Some(&filter))?;
+        let bloom_pruner = BloomPrunerCreator::create(
+            func_ctx,
+            &self.table_schema,
+            self.dal.clone(),
+            Some(&filter),
+        )?;
+        Ok(Some(RuntimeFilters {
+            range_pruner,
+            bloom_pruner,
+        }))
+    }
+
+    async fn should_keep(filters: &RuntimeFilters, part: &PartInfoPtr) -> bool {
+        let part = match part.as_any().downcast_ref::<FusePartInfo>() {
+            Some(part) => part,
+            None => return true,
+        };
+        let index = match &part.block_meta_index {
+            Some(index) => index,
+            None => return true,
+        };
+
+        // The segments are read while the query is planned, so they are only looked up in the
+        // cache here, the parts whose segments have been evicted are kept.
+        let segment =
+            match SegmentInfo::cache().and_then(|cache| cache.get(&index.segment_location)) {
+                Some(segment) => segment,
+                None => return true,
+            };
+        if !filters.range_pruner.should_keep(&segment.summary.col_stats) {
+            return false;
+        }
+
+        let block = match segment.blocks.get(index.block_idx) {
+            Some(block) if block.location.0 == part.location => block,
+            _ => return true,
+        };
+        if !filters.range_pruner.should_keep(&block.col_stats) {
+            return false;
+        }
+
+        match &filters.bloom_pruner {
+            Some(bloom_pruner) => {
+                bloom_pruner
+                    .should_keep(
+                        &block.bloom_filter_index_location,
+                        block.bloom_filter_index_size,
+                        block.col_metas.keys().cloned().collect(),
+                    )
+                    .await
+            }
+            None => true,
+        }
+    }
+}
+
+fn call(name: &str, args: &[Expr<String>]) -> Result<Expr<String>> {
+    check_function(None, name, &[], args, &BUILTIN_FUNCTIONS)
+}
+
+/// Builds the predicate the probe key has to satisfy to match a key of the build side.
+fn runtime_filter_expr(target: Expr<String>, info: &RuntimeFilterInfo) -> Result<Expr<String>> {
+    let constant = |scalar: &Scalar| Expr::Constant {
+        span: None,
+        scalar: scalar.clone(),
+        data_type: info.data_type.clone(),
+    };
+
+    let (min, max) = match &info.min_max {
+        Some(min_max) => min_max,
+        None => {
+            // The build side has no non-NULL key, nothing matches.
+            return Ok(Expr::Constant {
+                span: None,
+                scalar: Scalar::Boolean(false),
+                data_type: DataType::Boolean,
+            });
+        }
+    };
+    let range = call("and", &[
+        call("gte", &[target.clone(), constant(min)])?,
+        call("lte", &[target.clone(), constant(max)])?,
+    ])?;
+
+    match &info.inlist {
+        Some(values) if values.len() > 1 => {
+            let mut points = values
+                .iter()
+                .map(|value| call("eq", &[target.clone(), constant(value)]))
+                .collect::<Result<Vec<_>>>()?;
+            // Balanced, not to overflow the stack of the evaluation of long lists.
+            while points.len() > 1 {
+                points = points
+                    .chunks(2)
+                    .map(|pair| match pair {
+                        [lhs, rhs] => call("or", &[lhs.clone(), rhs.clone()]),
+                        _ => Ok(pair[0].clone()),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+            }
+            call("and", &[range, points.remove(0)])
+        }
+        _ => Ok(range),
+    }
+}
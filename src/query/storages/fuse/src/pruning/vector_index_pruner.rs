@@ -0,0 +1,157 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::TableSchema;
+use common_vector::DistanceType;
+use futures::future;
+use opendal::Operator;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
+use tracing::info;
+
+use crate::io::read_vector_index;
+use crate::io::TableMetaLocationGenerator;
+use crate::io::VectorIndexDef;
+
+/// Prunes the blocks which can't contain the nearest neighbours of a vector,
+/// by the vector indexes of the segments.
+///
+/// The `limit` nearest vectors found in the indexes of all the segments are the candidates,
+/// only their blocks are kept. The segments without index are kept entirely, the rows of
+/// the blocks kept are re-ranked by the exact distance afterwards.
+pub struct VectorIndexPruner {
+    dal: Operator,
+    locations: TableMetaLocationGenerator,
+    def: VectorIndexDef,
+    column_id: ColumnId,
+    query: Vec<f32>,
+    limit: usize,
+}
+
+impl VectorIndexPruner {
+    /// Returns None if there is no nearest neighbours search pushed down without filter,
+    /// or no vector index of the column built for the distance.
+    pub fn try_create(
+        dal: Operator,
+        locations: TableMetaLocationGenerator,
+        table_schema: &TableSchema,
+        push_down: &Option<PushDownInfo>,
+        indexes: Vec<VectorIndexDef>,
+    ) -> Result<Option<Self>> {
+        let top_k = match push_down {
+            Some(PushDownInfo {
+                vector_top_k: Some(top_k),
+                filter: None,
+                prewhere: None,
+                ..
+            }) => top_k,
+            _ => return Ok(None),
+        };
+        let distance = match DistanceType::from_func_name(&top_k.func_name) {
+            Some(distance) => distance,
+            None => return Ok(None),
+        };
+        let def = match indexes
+            .into_iter()
+            .find(|def| def.column == top_k.column && def.distance == distance)
+        {
+            Some(def) => def,
+            None => return Ok(None),
+        };
+        let field_index = table_schema.index_of(&def.column)?;
+        let column_id = table_schema.column_id_of_index(field_index)?;
+
+        Ok(Some(VectorIndexPruner {
+            dal,
+            locations,
+            def,
+            column_id,
+            query: top_k.query.iter().map(|v| v.0).collect(),
+            limit: top_k.limit,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let segments: HashSet<String> = metas
+            .iter()
+            .map(|(index, _)| index.segment_location.clone())
+            .collect();
+        let searches = segments.into_iter().map(|segment| self.search(segment));
+        let results = future::try_join_all(searches).await?;
+
+        // The blocks to keep of each segment, None to keep all the blocks of an unindexed segment.
+        let mut kept_blocks: HashMap<String, Option<HashSet<usize>>> = HashMap::new();
+        let mut candidates = vec![];
+        for (segment, nearest) in results {
+            match nearest {
+                Some(nearest) => {
+                    candidates.extend(nearest.into_iter().map(|(block_idx, distance)| {
+                        (distance, segment.clone(), block_idx as usize)
+                    }));
+                    kept_blocks.insert(segment, Some(HashSet::new()));
+                }
+                None => {
+                    kept_blocks.insert(segment, None);
+                }
+            }
+        }
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (_, segment, block_idx) in candidates.into_iter().take(self.limit) {
+            if let Some(Some(blocks)) = kept_blocks.get_mut(&segment) {
+                blocks.insert(block_idx);
+            }
+        }
+
+        let before = metas.len();
+        let metas: Vec<_> = metas
+            .into_iter()
+            .filter(
+                |(index, _)| match kept_blocks.get(&index.segment_location) {
+                    Some(Some(blocks)) => blocks.contains(&index.block_idx),
+                    _ => true,
+                },
+            )
+            .collect();
+        info!(
+            "vector index {} pruning, blocks before:{}, after:{}",
+            self.def.name,
+            before,
+            metas.len()
+        );
+        Ok(metas)
+    }
+
+    // Returns the nearest vectors in the segment, None if the segment has no index.
+    async fn search(&self, segment: String) -> Result<(String, Option<Vec<(u32, f32)>>)> {
+        let location =
+            self.locations
+                .segment_vector_index_location(&segment, &self.def, self.column_id);
+        let nearest = match read_vector_index(&self.dal, &location).await? {
+            Some(index) => Some(index.search(&self.query, self.limit)?),
+            None => None,
+        };
+        Ok((segment, nearest))
+    }
+}
@@ -23,6 +23,7 @@ use storages_common_table_meta::meta;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::NdvSketchesOfColumns;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 use storages_common_table_meta::meta::Versioned;
 
@@ -37,6 +38,8 @@ pub struct StatisticsAccumulator {
     pub in_memory_size: u64,
     pub file_size: u64,
     pub index_size: u64,
+    /// The NDV sketches of the columns of the blocks added by `add_block`, merged.
+    pub ndv_sketches: NdvSketchesOfColumns,
 
     pub perfect_block_count: u64,
     pub thresholds: BlockThresholds,
@@ -66,6 +69,12 @@ impl StatisticsAccumulator {
         self.summary_row_count += block_statistics.block_rows_size;
         self.blocks_statistics
             .push(block_statistics.block_column_statistics.clone());
+        for (column_id, sketch) in block_statistics.block_ndv_sketches.iter() {
+            self.ndv_sketches
+                .entry(*column_id)
+                .or_default()
+                .merge(sketch);
+        }
 
         let row_count = block_statistics.block_rows_size;
         let block_size = block_statistics.block_bytes_size;
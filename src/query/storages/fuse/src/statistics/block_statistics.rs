@@ -19,6 +19,7 @@ use common_expression::DataBlock;
 use common_expression::TableSchemaRef;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::NdvSketchesOfColumns;
 
 use crate::statistics::column_statistic;
 
@@ -29,6 +30,7 @@ pub struct BlockStatistics {
     pub block_file_location: String,
     pub block_column_statistics: HashMap<ColumnId, ColumnStatistics>,
     pub block_cluster_statistics: Option<ClusterStatistics>,
+    pub block_ndv_sketches: NdvSketchesOfColumns,
 }
 
 impl BlockStatistics {
@@ -49,6 +51,7 @@ impl BlockStatistics {
                 schema,
             )?,
             block_cluster_statistics: cluster_stats,
+            block_ndv_sketches: column_statistic::gen_columns_ndv_sketches(data_block, schema),
         })
     }
 }
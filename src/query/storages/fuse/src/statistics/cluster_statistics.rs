@@ -12,15 +12,59 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
+
 use common_exception::Result;
 use common_expression::BlockThresholds;
 use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_sql::evaluator::BlockOperator;
 use storages_common_table_meta::meta::ClusterStatistics;
 
+/// How the rows of a table are ordered by its cluster keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClusterType {
+    /// Ordered by the tuple of the cluster keys, e.g. `CLUSTER BY (a, b)`.
+    Linear,
+    /// Ordered by the key of the Hilbert curve over the columns, `CLUSTER BY HILBERT(a, b)`.
+    Hilbert,
+    /// Ordered by the key of the Z-order curve over the columns, `CLUSTER BY ZORDER(a, b)`.
+    ZOrder,
+}
+
+impl ClusterType {
+    pub fn from_cluster_keys(cluster_keys: &[RemoteExpr<String>]) -> Self {
+        match cluster_keys {
+            [RemoteExpr::FunctionCall { id, .. }] => match id.name().as_str() {
+                "hilbert" => ClusterType::Hilbert,
+                "zorder" => ClusterType::ZOrder,
+                _ => ClusterType::Linear,
+            },
+            _ => ClusterType::Linear,
+        }
+    }
+
+    /// Whether the cluster key is the key of a space-filling curve, which is
+    /// opaque to the pruners.
+    pub fn is_curve(&self) -> bool {
+        !matches!(self, ClusterType::Linear)
+    }
+}
+
+impl Display for ClusterType {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ClusterType::Linear => write!(f, "linear"),
+            ClusterType::Hilbert => write!(f, "hilbert"),
+            ClusterType::ZOrder => write!(f, "zorder"),
+        }
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct ClusterStatsGenerator {
     cluster_key_id: u32,
@@ -13,6 +13,8 @@
 //  limitations under the License.
 
 use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
 
 use common_exception::Result;
 use common_expression::types::NumberType;
@@ -21,11 +23,16 @@ use common_expression::Column;
 use common_expression::DataBlock;
 use common_expression::FieldIndex;
 use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
 use common_expression::TableSchemaRef;
 use common_functions::aggregates::eval_aggr;
+use siphasher::sip::SipHasher;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::HyperLogLog;
+use storages_common_table_meta::meta::NdvSketchesOfColumns;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 
 pub fn calc_column_distinct_of_values(column: &Column, rows: usize) -> Result<u64> {
@@ -34,6 +41,50 @@ pub fn calc_column_distinct_of_values(column: &Column, rows: usize) -> Result<u6
     Ok(col[0])
 }
 
+/// Build the NDV sketch of the non-null values of the column.
+pub fn calc_column_ndv_sketch(column: &Column) -> HyperLogLog {
+    let mut sketch = HyperLogLog::new();
+    for value in column.iter() {
+        if matches!(value, ScalarRef::Null) {
+            continue;
+        }
+        // SipHasher with zero keys is stable, sketches persisted by different versions
+        // can be merged.
+        let mut hasher = SipHasher::new();
+        value.hash(&mut hasher);
+        sketch.add_hash(hasher.finish());
+    }
+    sketch
+}
+
+/// Whether the NDV of the column is estimated by a sketch, the inner columns of the
+/// nested types are not.
+pub fn has_ndv_sketch(data_type: &TableDataType) -> bool {
+    !matches!(
+        data_type.remove_nullable(),
+        TableDataType::Tuple { .. } | TableDataType::Array(_) | TableDataType::Map(_)
+    )
+}
+
+/// Build the NDV sketches of the columns of the block, written besides the segment.
+pub fn gen_columns_ndv_sketches(
+    data_block: &DataBlock,
+    schema: &TableSchemaRef,
+) -> NdvSketchesOfColumns {
+    let num_rows = data_block.num_rows();
+    let mut sketches = NdvSketchesOfColumns::new();
+    for (field, entry) in schema.fields().iter().zip(data_block.columns()) {
+        if !has_ndv_sketch(field.data_type()) {
+            continue;
+        }
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        sketches.insert(field.column_id(), calc_column_ndv_sketch(&column));
+    }
+    sketches
+}
+
 pub fn get_traverse_columns_dfs(data_block: &DataBlock) -> traverse::TraverseResult {
     traverse::traverse_columns_dfs(data_block.columns())
 }
@@ -21,9 +21,13 @@ pub mod reducers;
 pub use accumulator::StatisticsAccumulator;
 pub use block_statistics::BlockStatistics;
 pub use cluster_statistics::ClusterStatsGenerator;
+pub use cluster_statistics::ClusterType;
 pub use column_statistic::calc_column_distinct_of_values;
+pub use column_statistic::calc_column_ndv_sketch;
+pub use column_statistic::gen_columns_ndv_sketches;
 pub use column_statistic::gen_columns_statistics;
 pub use column_statistic::get_traverse_columns_dfs;
+pub use column_statistic::has_ndv_sketch;
 pub use column_statistic::traverse;
 pub use column_statistic::Trim;
 pub use column_statistic::STATS_REPLACEMENT_CHAR;
@@ -0,0 +1,648 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::InternalColumn;
+use common_catalog::plan::InternalColumnType;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PruningStatistics;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::CHANGE_ACTION;
+use common_catalog::plan::CHANGE_ROW_ID;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::serialize_column_binary;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::UpdateStreamMetaReq;
+use common_meta_types::MatchSeq;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
+use futures_util::TryStreamExt;
+use siphasher::sip128::Hasher128;
+use siphasher::sip128::SipHasher24;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_SOURCE_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_SOURCE_DATABASE_NAME;
+use storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_NAME;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+
+use crate::io::MetaReaders;
+use crate::io::SegmentsIO;
+use crate::io::SnapshotHistoryReader;
+use crate::operations::FillInternalColumnProcessor;
+use crate::FuseTable;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+
+/// The snapshot of the source table that the stream has been consumed up to,
+/// absent if the source table had no snapshot when the stream was created.
+pub const OPT_KEY_STREAM_OFFSET: &str = "offset_snapshot_location";
+
+/// A stream over a fuse table.
+///
+/// Reading a stream returns the rows inserted into or deleted from the source table since
+/// the offset snapshot, with the metadata columns `change$action` and `change$row_id`.
+/// The blocks added and removed are found by diffing the segments of the offset snapshot
+/// and the latest snapshot, then the rows of these blocks are diffed, see [`NetChanges`]:
+/// an updated row shows up as the deletion of its old values and the insertion of the new
+/// ones, the rows merely copied to a new block, e.g. by a mutation or a compaction, are
+/// not changes.
+///
+/// The offset is advanced when the stream is consumed by a DML statement, in the same meta
+/// transaction that commits the modified table; a plain query leaves it unchanged.
+///
+/// The columns of the stream are those of the source table when the stream was created,
+/// see [`StreamTable::check_source_schema`] for how ALTERs of the source table are handled.
+pub struct StreamTable {
+    table_info: TableInfo,
+    source_database_name: String,
+    source_table_name: String,
+    source_table_id: u64,
+    offset_snapshot_location: Option<String>,
+    // The source table as of the stream creation, only used to read the blocks.
+    source_reader: Box<FuseTable>,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = table_info.options();
+        let get_option = |key: &str| {
+            options.get(key).cloned().ok_or_else(|| {
+                ErrorCode::IllegalStream(format!(
+                    "Invalid stream {}, table option {} not found",
+                    table_info.name, key
+                ))
+            })
+        };
+
+        let source_database_name = get_option(OPT_KEY_SOURCE_DATABASE_NAME)?;
+        let source_table_name = get_option(OPT_KEY_SOURCE_TABLE_NAME)?;
+        let source_table_id = get_option(OPT_KEY_SOURCE_TABLE_ID)?
+            .parse::<u64>()
+            .map_err(|e| ErrorCode::IllegalStream(format!("Invalid source table id: {}", e)))?;
+        let source_database_id = get_option(OPT_KEY_SOURCE_DATABASE_ID)?;
+        let offset_snapshot_location = options.get(OPT_KEY_STREAM_OFFSET).cloned();
+
+        // The fields of the source table come first, followed by the metadata columns.
+        let schema = table_info.schema();
+        let num_source_fields = schema.num_fields().saturating_sub(2);
+        let source_schema = schema.project(&(0..num_source_fields).collect::<Vec<_>>());
+
+        let mut source_options = BTreeMap::new();
+        source_options.insert(OPT_KEY_DATABASE_ID.to_owned(), source_database_id);
+        for key in [OPT_KEY_STORAGE_FORMAT, OPT_KEY_TABLE_COMPRESSION] {
+            if let Some(value) = options.get(key) {
+                source_options.insert(key.to_owned(), value.clone());
+            }
+        }
+        let source_info = TableInfo {
+            ident: TableIdent::new(source_table_id, 0),
+            desc: format!("'{}'.'{}'", source_database_name, source_table_name),
+            name: source_table_name.clone(),
+            meta: TableMeta {
+                schema: Arc::new(source_schema),
+                catalog: table_info.meta.catalog.clone(),
+                engine: "FUSE".to_string(),
+                options: source_options,
+                storage_params: table_info.meta.storage_params.clone(),
+                part_prefix: table_info.meta.part_prefix.clone(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let source_reader = FuseTable::do_create(source_info)?;
+
+        Ok(Box::new(StreamTable {
+            table_info,
+            source_database_name,
+            source_table_name,
+            source_table_id,
+            offset_snapshot_location,
+            source_reader,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM STORAGE (CHANGES OF FUSE TABLE)".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Build the options of a stream on the given source table.
+    pub fn build_options(
+        source: &FuseTable,
+        source_database_name: &str,
+        offset_snapshot_location: Option<String>,
+    ) -> Result<BTreeMap<String, String>> {
+        let source_info = source.get_table_info();
+        let source_database_id = source_info
+            .options()
+            .get(OPT_KEY_DATABASE_ID)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::IllegalStream(format!(
+                    "Invalid fuse table {}, table option {} not found",
+                    source_info.desc, OPT_KEY_DATABASE_ID
+                ))
+            })?;
+
+        let mut options = BTreeMap::new();
+        options.insert(OPT_KEY_SOURCE_DATABASE_ID.to_owned(), source_database_id);
+        options.insert(
+            OPT_KEY_SOURCE_DATABASE_NAME.to_owned(),
+            source_database_name.to_owned(),
+        );
+        options.insert(
+            OPT_KEY_SOURCE_TABLE_ID.to_owned(),
+            source_info.ident.table_id.to_string(),
+        );
+        options.insert(
+            OPT_KEY_SOURCE_TABLE_NAME.to_owned(),
+            source_info.name.clone(),
+        );
+        for key in [OPT_KEY_STORAGE_FORMAT, OPT_KEY_TABLE_COMPRESSION] {
+            if let Some(value) = source_info.options().get(key) {
+                options.insert(key.to_owned(), value.clone());
+            }
+        }
+        if let Some(location) = offset_snapshot_location {
+            options.insert(OPT_KEY_STREAM_OFFSET.to_owned(), location);
+        }
+        Ok(options)
+    }
+
+    /// The schema of a stream on a table with the given schema.
+    pub fn stream_schema(source_schema: &TableSchema) -> TableSchema {
+        let mut schema = source_schema.clone();
+        for column in Self::change_columns() {
+            schema.add_internal_column(
+                column.column_name(),
+                column.table_data_type(),
+                column.column_id(),
+            );
+        }
+        schema
+    }
+
+    fn change_columns() -> [InternalColumn; 2] {
+        [
+            InternalColumn::new(CHANGE_ACTION, InternalColumnType::ChangeAction),
+            InternalColumn::new(CHANGE_ROW_ID, InternalColumnType::ChangeRowId),
+        ]
+    }
+
+    pub fn source_table_id(&self) -> u64 {
+        self.source_table_id
+    }
+
+    pub fn offset_snapshot_location(&self) -> Option<&String> {
+        self.offset_snapshot_location.as_ref()
+    }
+
+    #[async_backtrace::framed]
+    async fn source_table(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let table = ctx
+            .get_table(
+                &self.table_info.meta.catalog,
+                &self.source_database_name,
+                &self.source_table_name,
+            )
+            .await?;
+        if table.get_id() != self.source_table_id {
+            return Err(ErrorCode::IllegalStream(format!(
+                "The source table of stream {} has been dropped or recreated",
+                self.table_info.desc
+            )));
+        }
+        Ok(table)
+    }
+
+    /// Collect the blocks added and removed since the offset snapshot,
+    /// together with the location of the latest snapshot.
+    #[async_backtrace::framed]
+    async fn collect_changes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: &FuseTable,
+    ) -> Result<(
+        Vec<(Option<BlockMetaIndex>, Arc<BlockMeta>)>,
+        Option<String>,
+    )> {
+        let latest_location = source.snapshot_loc().await?;
+        if latest_location == self.offset_snapshot_location {
+            return Ok((vec![], None));
+        }
+        let latest_location = latest_location.unwrap_or_default();
+        let latest_segments = match source.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.segments.clone(),
+            None => vec![],
+        };
+        let offset_segments = match &self.offset_snapshot_location {
+            Some(offset_location) => {
+                self.offset_segments(source, &latest_location, offset_location)
+                    .await?
+            }
+            None => vec![],
+        };
+
+        let latest_set = latest_segments.iter().collect::<HashSet<_>>();
+        let offset_set = offset_segments.iter().collect::<HashSet<_>>();
+        let added_segments = latest_segments
+            .iter()
+            .filter(|location| !offset_set.contains(location))
+            .cloned()
+            .collect::<Vec<_>>();
+        let removed_segments = offset_segments
+            .iter()
+            .filter(|location| !latest_set.contains(location))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let segments_io = SegmentsIO::create(ctx, source.get_operator(), source.schema());
+        let added_blocks = Self::read_blocks(&segments_io, &added_segments).await?;
+        let removed_blocks = Self::read_blocks(&segments_io, &removed_segments).await?;
+
+        // blocks moved between segments, e.g. by segment compaction, are not changes.
+        let added_locations = added_blocks
+            .iter()
+            .map(|(_, _, block)| block.location.0.clone())
+            .collect::<HashSet<_>>();
+        let removed_locations = removed_blocks
+            .iter()
+            .map(|(_, _, block)| block.location.0.clone())
+            .collect::<HashSet<_>>();
+
+        let offset_location = self.offset_snapshot_location.clone().unwrap_or_default();
+        let mut changes = Vec::with_capacity(added_blocks.len() + removed_blocks.len());
+        for (segment_location, position, block) in added_blocks {
+            if !removed_locations.contains(&block.location.0) {
+                let index = Self::change_index(
+                    &segment_location,
+                    position,
+                    &block,
+                    &latest_location,
+                    false,
+                );
+                changes.push((Some(index), block));
+            }
+        }
+        for (segment_location, position, block) in removed_blocks {
+            if !added_locations.contains(&block.location.0) {
+                let index =
+                    Self::change_index(&segment_location, position, &block, &offset_location, true);
+                changes.push((Some(index), block));
+            }
+        }
+
+        Ok((changes, Some(latest_location)))
+    }
+
+    // The positions of the segments in the diff depend on the offset of the stream,
+    // so they are not recorded.
+    fn change_index(
+        segment_location: &Location,
+        (block_idx, block_num): (usize, usize),
+        block: &BlockMeta,
+        snapshot_location: &str,
+        is_deleted: bool,
+    ) -> BlockMetaIndex {
+        BlockMetaIndex {
+            segment_idx: 0,
+            block_idx,
+            range: None,
+            block_id: block_num - block_idx - 1,
+            block_location: block.location.0.clone(),
+            segment_id: 0,
+            segment_location: segment_location.0.clone(),
+            snapshot_location: Some(snapshot_location.to_owned()),
+            is_deleted,
+        }
+    }
+
+    /// Prune the changed blocks by the zone maps with the filter pushed down.
+    ///
+    /// The blocks are read whole, without prewhere, so that all their rows are diffed;
+    /// the filter is still evaluated above the scan.
+    fn prune_changes(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        push_downs: &Option<PushDownInfo>,
+        changes: Vec<(Option<BlockMetaIndex>, Arc<BlockMeta>)>,
+    ) -> Result<(
+        Vec<(Option<BlockMetaIndex>, Arc<BlockMeta>)>,
+        PruningStatistics,
+    )> {
+        let filter_expr = push_downs
+            .as_ref()
+            .and_then(|extra| extra.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+        // The schema of the stream, the metadata columns have no statistics and are never pruned.
+        let range_pruner = RangePrunerCreator::try_create(
+            ctx.get_function_context()?,
+            &self.schema(),
+            filter_expr.as_ref(),
+        )?;
+
+        let blocks_range_pruning_before = changes.len();
+        let changes = changes
+            .into_iter()
+            .filter(|(_, block)| range_pruner.should_keep(&block.col_stats))
+            .collect::<Vec<_>>();
+        let pruning_stats = PruningStatistics {
+            blocks_range_pruning_before,
+            blocks_range_pruning_after: changes.len(),
+            ..Default::default()
+        };
+        Ok((changes, pruning_stats))
+    }
+
+    /// The columns of the stream are fixed when it is created: the columns added to the
+    /// source table afterwards are not returned, and the stream can't be read any more
+    /// once one of its columns is dropped from the source table or its type is modified.
+    fn check_source_schema(&self, source: &FuseTable) -> Result<()> {
+        let current_schema = source.schema();
+        for field in self.source_reader.schema().fields() {
+            let unchanged = current_schema.fields().iter().any(|current| {
+                current.column_id() == field.column_id() && current.data_type() == field.data_type()
+            });
+            if !unchanged {
+                return Err(ErrorCode::IllegalStream(format!(
+                    "The column {} of the source table of stream {} has been dropped or modified, \
+                     the stream must be recreated",
+                    field.name(),
+                    self.table_info.desc
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Walk the snapshot chain of the source table back to the offset snapshot.
+    #[async_backtrace::framed]
+    async fn offset_segments(
+        &self,
+        source: &FuseTable,
+        latest_location: &str,
+        offset_location: &str,
+    ) -> Result<Vec<Location>> {
+        let location_generator = source.meta_location_generator().clone();
+        let reader = MetaReaders::table_snapshot_reader(source.get_operator());
+        let mut snapshot_stream = reader.snapshot_history(
+            latest_location.to_owned(),
+            source.snapshot_format_version().await?,
+            location_generator.clone(),
+        );
+        while let Some(snapshot) = snapshot_stream.try_next().await? {
+            let location = location_generator
+                .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version())?;
+            if location == offset_location {
+                return Ok(snapshot.segments.clone());
+            }
+        }
+
+        Err(ErrorCode::TableHistoricalDataNotFound(format!(
+            "The offset of stream {} is not in the history of the source table anymore, \
+             it may have been purged or reverted",
+            self.table_info.desc
+        )))
+    }
+
+    #[async_backtrace::framed]
+    async fn read_blocks(
+        segments_io: &SegmentsIO,
+        segment_locations: &[Location],
+    ) -> Result<Vec<(Location, (usize, usize), Arc<BlockMeta>)>> {
+        let segments = segments_io.read_segments(segment_locations, true).await?;
+        let mut blocks = vec![];
+        for (segment, location) in segments.into_iter().zip(segment_locations) {
+            let segment = segment?;
+            let block_num = segment.blocks.len();
+            for (block_idx, block) in segment.blocks.iter().enumerate() {
+                blocks.push((location.clone(), (block_idx, block_num), block.clone()));
+            }
+        }
+        Ok(blocks)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let source = self.source_table(ctx.clone()).await?;
+        let source = FuseTable::try_from_table(source.as_ref())?;
+        self.check_source_schema(source)?;
+        let (changes, latest_location) = self.collect_changes(ctx.clone(), source).await?;
+
+        // The new offset takes effect only if the stream is consumed by a DML statement.
+        if let Some(latest_location) = latest_location.filter(|_| ctx.get_consume_streams()) {
+            let mut new_stream_meta = self.table_info.meta.clone();
+            new_stream_meta
+                .options
+                .insert(OPT_KEY_STREAM_OFFSET.to_owned(), latest_location);
+            ctx.add_consumed_stream(UpdateStreamMetaReq {
+                stream_id: self.table_info.ident.table_id,
+                seq: MatchSeq::Exact(self.table_info.ident.seq),
+                new_stream_meta,
+            });
+        }
+
+        let num_changes = changes.len();
+        let (changes, pruning_stats) = self.prune_changes(&ctx, &push_downs, changes)?;
+        self.source_reader.read_partitions_with_metas(
+            self.source_reader.schema(),
+            None,
+            &changes,
+            num_changes,
+            pruning_stats,
+        )
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let source_schema: TableSchemaRef = self.source_reader.schema();
+        let mut source_plan = plan.clone();
+        source_plan.source_info = self.source_reader.get_data_source_info();
+        source_plan.output_schema = source_schema.clone();
+        source_plan.push_downs = None;
+        source_plan.query_internal_columns = true;
+        self.source_reader
+            .do_read_data(ctx, &source_plan, pipeline)?;
+
+        // `change$action` is filled by block, `change$row_id` is generated by `NetChanges`.
+        let [change_action, _] = Self::change_columns();
+        let change_columns = BTreeMap::from([(source_schema.num_fields(), change_action)]);
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(Box::new(
+                FillInternalColumnProcessor::create(change_columns.clone(), input, output),
+            )))
+        })?;
+
+        pipeline.resize(1)?;
+        let num_source_fields = source_schema.num_fields();
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                NetChanges::new(num_source_fields),
+            )))
+        })
+    }
+}
+
+/// Diff the rows of the blocks removed from and added to the source table.
+///
+/// The deleted and inserted rows with the same values cancel each other out, the rest are
+/// the changes. `change$row_id` is derived from the values of the row and its position
+/// among the rows with the same values, so it doesn't depend on the block holding the row
+/// and stays the same across compactions.
+///
+/// All the changed rows are buffered in memory until the diff is done.
+struct NetChanges {
+    num_source_fields: usize,
+    blocks: Vec<DataBlock>,
+}
+
+impl NetChanges {
+    fn new(num_source_fields: usize) -> Self {
+        NetChanges {
+            num_source_fields,
+            blocks: vec![],
+        }
+    }
+}
+
+impl AccumulatingTransform for NetChanges {
+    const NAME: &'static str = "NetChanges";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        self.blocks.push(data);
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        let blocks = std::mem::take(&mut self.blocks);
+        if !output || blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        let block = DataBlock::concat(&blocks)?;
+        let num_rows = block.num_rows();
+        let columns = block
+            .columns()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect::<Vec<_>>();
+        let (source_columns, change_action) = columns.split_at(self.num_source_fields);
+        let change_action = change_action[0].as_string().unwrap();
+
+        // The values of a row -> the deleted rows and the inserted rows with these values.
+        let mut rows: HashMap<Vec<u8>, (Vec<u32>, Vec<u32>)> = HashMap::new();
+        for row in 0..num_rows {
+            let mut values = vec![];
+            for column in source_columns {
+                serialize_column_binary(column, row, &mut values);
+            }
+            let (deleted, inserted) = rows.entry(values).or_default();
+            if change_action.index(row) == Some(b"DELETE".as_slice()) {
+                deleted.push(row as u32);
+            } else {
+                inserted.push(row as u32);
+            }
+        }
+
+        let mut indices = vec![];
+        let mut row_ids = StringColumnBuilder::with_capacity(0, 0);
+        for (values, (deleted, inserted)) in rows {
+            let unchanged = deleted.len().min(inserted.len());
+            if deleted.len() == inserted.len() {
+                continue;
+            }
+            let mut hasher = SipHasher24::new();
+            hasher.write(&values);
+            let hash = hasher.finish128().as_u128();
+            let changed = if deleted.len() > inserted.len() {
+                deleted
+            } else {
+                inserted
+            };
+            for (i, row) in changed.into_iter().enumerate().skip(unchanged) {
+                indices.push(row);
+                row_ids.put_str(&format!("{:032x}{:06x}", hash, i));
+                row_ids.commit_row();
+            }
+        }
+        if indices.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut changes = block.take(&indices)?;
+        changes.add_column(BlockEntry {
+            data_type: DataType::String,
+            value: Value::Column(Column::String(row_ids.build())),
+        });
+        Ok(vec![changes])
+    }
+}
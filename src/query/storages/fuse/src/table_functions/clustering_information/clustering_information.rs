@@ -38,6 +38,7 @@ use storages_common_table_meta::meta::BlockMeta;
 
 use crate::io::SegmentsIO;
 use crate::sessions::TableContext;
+use crate::statistics::ClusterType;
 use crate::FuseTable;
 use crate::Table;
 
@@ -107,6 +108,9 @@ impl<'a> ClusteringInformation<'a> {
         };
 
         let cluster_by_keys = self.plain_cluster_keys.clone();
+        // The overlaps and depths of a space-filling curve are those of the ranges of
+        // its key, the blocks of a well clustered table are narrow on every column.
+        let cluster_type = ClusterType::from_cluster_keys(&self.cluster_keys).to_string();
 
         Ok(DataBlock::new(
             vec![
@@ -144,6 +148,10 @@ impl<'a> ClusteringInformation<'a> {
                         JsonbValue::from(&info.block_depth_histogram).to_vec(),
                     )),
                 },
+                BlockEntry {
+                    data_type: DataType::String,
+                    value: Value::Scalar(Scalar::String(cluster_type.into_bytes())),
+                },
             ],
             1,
         ))
@@ -275,6 +283,7 @@ impl<'a> ClusteringInformation<'a> {
                 TableDataType::Number(NumberDataType::Float64),
             ),
             TableField::new("block_depth_histogram", TableDataType::Variant),
+            TableField::new("cluster_type", TableDataType::String),
         ])
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::DataBlock;
 use sha2::Digest;
 use sha2::Sha256;
 
@@ -53,6 +54,18 @@ pub struct ResultCacheValue {
     pub num_rows: usize,
     /// The sha256 of the partitions for each table in the query.
     pub partitions_shas: Vec<String>,
+    /// The snapshots of the tables scanned by the query.
+    #[serde(default)]
+    pub snapshot_ids: Vec<String>,
     /// The location of the result cache file.
     pub location: String,
 }
+
+/// The cached result of a sub plan, i.e. the intermediate result of an aggregation.
+#[derive(Clone)]
+pub enum SubresultCache {
+    /// The result is cached, read it instead of executing the sub plan.
+    Hit(Vec<DataBlock>),
+    /// The result is not cached, write it under the key after executing the sub plan.
+    Miss(String),
+}
@@ -37,9 +37,10 @@ pub struct ResultCacheReader {
     operator: Operator,
     /// To ensure the cache is valid.
     partitions_shas: Vec<String>,
+    snapshot_ids: Vec<String>,
 
     /// If true, the cache will be used even if it is inconsistent.
-    /// In another word, `partitions_sha` will not be checked.
+    /// In another word, `partitions_sha` and `snapshot_ids` will not be checked.
     tolerate_inconsistent: bool,
 }
 
@@ -47,6 +48,7 @@ impl ResultCacheReader {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         key: &str,
+        snapshot_ids: Vec<String>,
         kv_store: Arc<MetaStore>,
         tolerate_inconsistent: bool,
     ) -> Self {
@@ -58,6 +60,7 @@ impl ResultCacheReader {
             meta_mgr: ResultCacheMetaManager::create(kv_store, 0),
             meta_key,
             partitions_shas,
+            snapshot_ids,
             operator: DataOperator::instance().operator(),
             tolerate_inconsistent,
         }
@@ -80,7 +83,10 @@ impl ResultCacheReader {
     ) -> Result<Option<Vec<DataBlock>>> {
         match self.meta_mgr.get(meta_key).await? {
             Some(value) => {
-                if self.tolerate_inconsistent || value.partitions_shas == self.partitions_shas {
+                if self.tolerate_inconsistent
+                    || (value.partitions_shas == self.partitions_shas
+                        && value.snapshot_ids == self.snapshot_ids)
+                {
                     if value.num_rows == 0 {
                         Ok(Some(vec![DataBlock::empty()]))
                     } else {
@@ -37,10 +37,14 @@ pub struct WriteResultCacheSink {
     ctx: Arc<dyn TableContext>,
     sql: String,
     partitions_shas: Vec<String>,
+    snapshot_ids: Vec<String>,
 
     meta_mgr: ResultCacheMetaManager,
     meta_key: String,
     cache_writer: ResultCacheWriter,
+    /// Whether the cache holds the result of the whole query, which can be
+    /// read by `RESULT_SCAN` through the query id.
+    is_query_result: bool,
 }
 
 #[async_trait::async_trait]
@@ -79,6 +83,7 @@ impl AsyncMpscSink for WriteResultCacheSink {
             query_time: now,
             ttl,
             partitions_shas: self.partitions_shas.clone(),
+            snapshot_ids: self.snapshot_ids.clone(),
             result_size: self.cache_writer.current_bytes(),
             num_rows: self.cache_writer.num_rows(),
             location,
@@ -86,8 +91,10 @@ impl AsyncMpscSink for WriteResultCacheSink {
         self.meta_mgr
             .set(self.meta_key.clone(), value, MatchSeq::GE(0), expire_at)
             .await?;
-        self.ctx
-            .set_query_id_result_cache(self.ctx.get_id(), self.meta_key.clone());
+        if self.is_query_result {
+            self.ctx
+                .set_query_id_result_cache(self.ctx.get_id(), self.meta_key.clone());
+        }
         Ok(())
     }
 }
@@ -96,9 +103,34 @@ impl WriteResultCacheSink {
     pub fn try_create(
         ctx: Arc<dyn TableContext>,
         key: &str,
+        snapshot_ids: Vec<String>,
+        schema: TableSchemaRef,
+        inputs: Vec<Arc<InputPort>>,
+        kv_store: Arc<MetaStore>,
+    ) -> Result<ProcessorPtr> {
+        Self::create(ctx, key, snapshot_ids, schema, inputs, kv_store, true)
+    }
+
+    /// Create a sink to cache the result of a sub plan instead of the whole query,
+    /// the snapshots of the tables scanned by the sub plan are part of the key.
+    pub fn try_create_for_subresult(
+        ctx: Arc<dyn TableContext>,
+        key: &str,
+        schema: TableSchemaRef,
+        inputs: Vec<Arc<InputPort>>,
+        kv_store: Arc<MetaStore>,
+    ) -> Result<ProcessorPtr> {
+        Self::create(ctx, key, vec![], schema, inputs, kv_store, false)
+    }
+
+    fn create(
+        ctx: Arc<dyn TableContext>,
+        key: &str,
+        snapshot_ids: Vec<String>,
         schema: TableSchemaRef,
         inputs: Vec<Arc<InputPort>>,
         kv_store: Arc<MetaStore>,
+        is_query_result: bool,
     ) -> Result<ProcessorPtr> {
         let settings = ctx.get_settings();
         let max_bytes = settings.get_query_result_cache_max_bytes()?;
@@ -119,9 +151,11 @@ impl WriteResultCacheSink {
                 ctx,
                 sql,
                 partitions_shas,
+                snapshot_ids,
                 meta_mgr: ResultCacheMetaManager::create(kv_store, ttl),
                 meta_key,
                 cache_writer,
+                is_query_result,
             },
         ))))
     }
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::BuildHasher;
 use std::sync::Arc;
 
+use common_cache::CountableMeter;
 use common_catalog::table::Table;
 use common_exception::Result;
 use common_expression::types::NumberDataType;
@@ -46,9 +48,7 @@ impl SyncSystemTable for CachesTable {
     }
 
     fn get_full_data(&self, _ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let mut names = Vec::new();
-        let mut num_items = Vec::new();
-        let mut size = Vec::new();
+        let mut columns = CachesColumns::default();
 
         let cache_manager = CacheManager::instance();
 
@@ -63,73 +63,118 @@ impl SyncSystemTable for CachesTable {
         let table_column_array_cache = cache_manager.get_table_data_array_cache();
 
         if let Some(table_snapshot_cache) = table_snapshot_cache {
-            names.push("table_snapshot_cache");
-            num_items.push(table_snapshot_cache.len() as u64);
-            size.push(table_snapshot_cache.size());
+            columns.append("table_snapshot_cache", &table_snapshot_cache);
         }
         if let Some(table_snapshot_statistic_cache) = table_snapshot_statistic_cache {
-            names.push("table_snapshot_statistic_cache");
-            num_items.push(table_snapshot_statistic_cache.len() as u64);
-            size.push(table_snapshot_statistic_cache.size());
+            columns.append(
+                "table_snapshot_statistic_cache",
+                &table_snapshot_statistic_cache,
+            );
         }
 
         if let Some(segment_info_cache) = segment_info_cache {
-            names.push("segment_info_cache");
-            num_items.push(segment_info_cache.len() as u64);
-            size.push(segment_info_cache.size());
+            columns.append("segment_info_cache", &segment_info_cache);
         }
 
         if let Some(bloom_index_filter_cache) = bloom_index_filter_cache {
-            names.push("bloom_index_filter_cache");
-            num_items.push(bloom_index_filter_cache.len() as u64);
-            size.push(bloom_index_filter_cache.size());
+            columns.append("bloom_index_filter_cache", &bloom_index_filter_cache);
         }
 
         if let Some(bloom_index_meta_cache) = bloom_index_meta_cache {
-            names.push("bloom_index_meta_cache");
-            num_items.push(bloom_index_meta_cache.len() as u64);
-            size.push(bloom_index_meta_cache.size());
+            columns.append("bloom_index_meta_cache", &bloom_index_meta_cache);
         }
 
         if let Some(prune_partitions_cache) = prune_partitions_cache {
-            names.push("prune_partitions_cache");
-            num_items.push(prune_partitions_cache.len() as u64);
-            size.push(prune_partitions_cache.size());
+            columns.append("prune_partitions_cache", &prune_partitions_cache);
         }
 
         if let Some(file_meta_data_cache) = file_meta_data_cache {
-            names.push("file_meta_data_cache");
-            num_items.push(file_meta_data_cache.len() as u64);
-            size.push(file_meta_data_cache.size());
+            columns.append("file_meta_data_cache", &file_meta_data_cache);
         }
 
         if let Some(table_data_cache) = table_data_cache {
-            names.push("table_data_cache");
-            num_items.push(table_data_cache.len() as u64);
-            size.push(table_data_cache.size());
+            columns.append("table_data_cache", &table_data_cache);
         }
 
         if let Some(table_column_array_cache) = table_column_array_cache {
-            names.push("table_column_array_cache");
-            num_items.push(table_column_array_cache.len() as u64);
-            size.push(table_column_array_cache.size());
+            columns.append("table_column_array_cache", &table_column_array_cache);
         }
 
-        let names: Vec<_> = names.iter().map(|x| x.as_bytes().to_vec()).collect();
+        let names: Vec<_> = columns
+            .names
+            .iter()
+            .map(|x| x.as_bytes().to_vec())
+            .collect();
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(names),
-            UInt64Type::from_data(num_items),
-            UInt64Type::from_data(size),
+            UInt64Type::from_data(columns.num_items),
+            UInt64Type::from_data(columns.size),
+            StringType::from_opt_data(columns.policies),
+            UInt64Type::from_opt_data(columns.hits),
+            UInt64Type::from_opt_data(columns.misses),
+            UInt64Type::from_opt_data(columns.evictions),
         ]))
     }
 }
 
+#[derive(Default)]
+struct CachesColumns {
+    names: Vec<&'static str>,
+    num_items: Vec<u64>,
+    size: Vec<u64>,
+    policies: Vec<Option<Vec<u8>>>,
+    hits: Vec<Option<u64>>,
+    misses: Vec<Option<u64>>,
+    evictions: Vec<Option<u64>>,
+}
+
+impl CachesColumns {
+    // The policy and the statistics are NULL for the caches not tracking them.
+    fn append<C, V, S, M>(&mut self, name: &'static str, cache: &C)
+    where
+        C: CacheAccessor<String, V, S, M>,
+        S: BuildHasher,
+        M: CountableMeter<String, Arc<V>>,
+    {
+        self.names.push(name);
+        self.num_items.push(cache.len() as u64);
+        self.size.push(cache.size());
+
+        let statistics = cache.statistics();
+        self.policies.push(
+            statistics
+                .as_ref()
+                .map(|(policy, _)| policy.to_string().into_bytes()),
+        );
+        self.hits.push(statistics.as_ref().map(|(_, s)| s.hits));
+        self.misses.push(statistics.as_ref().map(|(_, s)| s.misses));
+        self.evictions
+            .push(statistics.as_ref().map(|(_, s)| s.evictions));
+    }
+}
+
 impl CachesTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let schema = TableSchemaRefExt::create(vec![
             TableField::new("name", TableDataType::String),
             TableField::new("num_items", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "policy",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "hits",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "misses",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "evictions",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -17,6 +17,7 @@
 
 extern crate core;
 
+mod background_jobs_table;
 mod build_options_table;
 mod caches_table;
 mod catalogs_table;
@@ -33,11 +34,14 @@ mod log_queue;
 mod malloc_stats_table;
 mod malloc_stats_totals_table;
 mod metrics_table;
+mod network_policies_table;
 mod one_table;
 mod processes_table;
 mod query_cache_table;
 mod query_log_table;
+mod query_profile_table;
 mod roles_table;
+mod sequences_table;
 mod settings_table;
 mod stages_table;
 mod table;
@@ -46,6 +50,9 @@ mod tables_table;
 mod tracing_table;
 mod users_table;
 
+pub use background_jobs_table::BackgroundJobLogElement;
+pub use background_jobs_table::BackgroundJobQueue;
+pub use background_jobs_table::BackgroundJobsTable;
 pub use build_options_table::BuildOptionsTable;
 pub use caches_table::CachesTable;
 pub use catalogs_table::CatalogsTable;
@@ -66,6 +73,7 @@ pub use log_queue::SystemLogTable;
 pub use malloc_stats_table::MallocStatsTable;
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
 pub use metrics_table::MetricsTable;
+pub use network_policies_table::NetworkPoliciesTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
 pub use query_cache_table::QueryCacheTable;
@@ -73,7 +81,11 @@ pub use query_log_table::LogType;
 pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
+pub use query_profile_table::QueryProfileElement;
+pub use query_profile_table::QueryProfileQueue;
+pub use query_profile_table::QueryProfileTable;
 pub use roles_table::RolesTable;
+pub use sequences_table::SequencesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
 pub use table::SyncOneBlockSystemTable;
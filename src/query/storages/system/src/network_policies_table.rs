@@ -0,0 +1,112 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct NetworkPoliciesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for NetworkPoliciesTable {
+    const NAME: &'static str = "system.network_policies";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let mut policies = UserApiProvider::instance()
+            .get_network_policies(&tenant)
+            .await?;
+        policies.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names: Vec<&str> = policies.iter().map(|x| x.name.as_str()).collect();
+        let allowed_ip_lists: Vec<String> = policies
+            .iter()
+            .map(|x| x.allowed_ip_list.join(","))
+            .collect();
+        let blocked_ip_lists: Vec<String> = policies
+            .iter()
+            .map(|x| x.blocked_ip_list.join(","))
+            .collect();
+        let comments: Vec<&str> = policies.iter().map(|x| x.comment.as_str()).collect();
+        let created_on: Vec<i64> = policies
+            .iter()
+            .map(|x| x.create_on.timestamp_micros())
+            .collect();
+        let updated_on: Vec<Option<i64>> = policies
+            .iter()
+            .map(|x| x.update_on.map(|v| v.timestamp_micros()))
+            .collect();
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(allowed_ip_lists),
+            StringType::from_data(blocked_ip_lists),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_on),
+            TimestampType::from_opt_data(updated_on),
+        ]))
+    }
+}
+
+impl NetworkPoliciesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("allowed_ip_list", TableDataType::String),
+            TableField::new("blocked_ip_list", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new(
+                "updated_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'network_policies'".to_string(),
+            name: "network_policies".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemNetworkPolicies".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(NetworkPoliciesTable { table_info })
+    }
+}
@@ -37,6 +37,8 @@ pub enum LogType {
     Finish = 2,
     Error = 3,
     Aborted = 4,
+    LoginSuccess = 5,
+    LoginFailure = 6,
 }
 
 fn date_str<S>(dt: &i32, s: S) -> Result<S::Ok, S::Error>
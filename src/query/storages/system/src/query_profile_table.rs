@@ -0,0 +1,170 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// The runtime statistics of an operator of a profiled query,
+/// the times are in nanoseconds.
+#[derive(Clone)]
+pub struct QueryProfileElement {
+    pub query_id: String,
+    pub plan_id: u32,
+    pub parent_plan_id: Option<u32>,
+    pub plan_name: String,
+    pub output_rows: u64,
+    pub output_bytes: u64,
+    /// The bytes of the column data read from the storage, only for the table scans.
+    pub scan_bytes: u64,
+    pub process_time: u64,
+    pub wait_time: u64,
+    pub wall_time: u64,
+    pub peak_memory_usage: u64,
+    pub spill_count: u64,
+    pub spill_bytes: u64,
+    /// The pruning statistics, only for the table scans.
+    pub segments_range_pruned: u64,
+    pub blocks_range_pruned: u64,
+    pub blocks_bloom_pruned: u64,
+}
+
+impl SystemLogElement for QueryProfileElement {
+    const TABLE_NAME: &'static str = "query_profile";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("plan_id", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "parent_plan_id",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt32))),
+            ),
+            TableField::new("plan_name", TableDataType::String),
+            TableField::new("output_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "output_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("scan_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "process_time",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("wait_time", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("wall_time", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "peak_memory_usage",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("spill_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("spill_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "segments_range_pruned",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "blocks_range_pruned",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "blocks_bloom_pruned",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt32(self.plan_id)).as_ref());
+        columns.next().unwrap().push(
+            self.parent_plan_id
+                .map_or(Scalar::Null, |id| Scalar::Number(NumberScalar::UInt32(id)))
+                .as_ref(),
+        );
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.plan_name.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.output_rows)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.output_bytes)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.scan_bytes)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.process_time)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.wait_time)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.wall_time)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.peak_memory_usage)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.spill_count)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.spill_bytes)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.segments_range_pruned)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.blocks_range_pruned)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.blocks_bloom_pruned)).as_ref());
+        Ok(())
+    }
+}
+
+pub type QueryProfileQueue = SystemLogQueue<QueryProfileElement>;
+pub type QueryProfileTable = SystemLogTable<QueryProfileElement>;
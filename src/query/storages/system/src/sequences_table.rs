@@ -0,0 +1,103 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct SequencesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for SequencesTable {
+    const NAME: &'static str = "system.sequences";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let mut sequences = UserApiProvider::instance().get_sequences(&tenant).await?;
+        sequences.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names: Vec<&str> = sequences.iter().map(|x| x.name.as_str()).collect();
+        let starts: Vec<u64> = sequences.iter().map(|x| x.start).collect();
+        let increments: Vec<u64> = sequences.iter().map(|x| x.increment).collect();
+        let nexts: Vec<u64> = sequences.iter().map(|x| x.next).collect();
+        let comments: Vec<&str> = sequences.iter().map(|x| x.comment.as_str()).collect();
+        let created_on: Vec<i64> = sequences
+            .iter()
+            .map(|x| x.create_on.timestamp_micros())
+            .collect();
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(starts),
+            UInt64Type::from_data(increments),
+            UInt64Type::from_data(nexts),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_on),
+        ]))
+    }
+}
+
+impl SequencesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("start", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("increment", TableDataType::Number(NumberDataType::UInt64)),
+            // The first value not reserved by any query node yet.
+            TableField::new(
+                "next_reserved",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'sequences'".to_string(),
+            name: "sequences".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemSequences".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(SequencesTable { table_info })
+    }
+}
@@ -17,7 +17,12 @@
 extern crate core;
 
 mod jwt;
+mod masking_policy;
+mod network_policy;
+mod password_policy;
 mod role_mgr;
+mod row_access_policy;
+mod sequence;
 mod user;
 mod user_api;
 mod user_mgr;
@@ -34,5 +39,6 @@ pub use jwt::*;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 pub use role_mgr::BUILTIN_ROLE_PUBLIC;
+pub use sequence::SEQUENCE_RANGE_CHECK_INTERVAL;
 pub use user::CertifiedInfo;
 pub use user_api::UserApiProvider;
@@ -0,0 +1,166 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::NetworkPolicy;
+use common_meta_app::principal::UserInfo;
+use common_meta_types::MatchSeq;
+use tracing::warn;
+
+use crate::UserApiProvider;
+
+/// Network policy operations.
+impl UserApiProvider {
+    // Add a new network policy.
+    #[async_backtrace::framed]
+    pub async fn add_network_policy(
+        &self,
+        tenant: &str,
+        policy: NetworkPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        policy.validate()?;
+        let client = self.get_network_policy_api_client(tenant)?;
+        let add_network_policy = client.add_network_policy(policy);
+        match add_network_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::NETWORK_POLICY_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Update a network policy.
+    #[async_backtrace::framed]
+    pub async fn update_network_policy(&self, tenant: &str, policy: NetworkPolicy) -> Result<u64> {
+        policy.validate()?;
+        let client = self.get_network_policy_api_client(tenant)?;
+        let update_network_policy = client.update_network_policy(policy, MatchSeq::GE(1));
+        match update_network_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while update network policy).")),
+        }
+    }
+
+    // Get a network policy by name.
+    #[async_backtrace::framed]
+    pub async fn get_network_policy(&self, tenant: &str, name: &str) -> Result<NetworkPolicy> {
+        let client = self.get_network_policy_api_client(tenant)?;
+        let get_network_policy = client.get_network_policy(name, MatchSeq::GE(0));
+        Ok(get_network_policy.await?.data)
+    }
+
+    // Get all network policies for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_network_policies(&self, tenant: &str) -> Result<Vec<NetworkPolicy>> {
+        let client = self.get_network_policy_api_client(tenant)?;
+        let get_network_policies = client.get_network_policies();
+
+        match get_network_policies.await {
+            Err(e) => Err(e.add_message_back("(while get network policies).")),
+            Ok(policies) => Ok(policies),
+        }
+    }
+
+    // Drop a network policy by name, the policy can not be dropped if it is used by any user.
+    #[async_backtrace::framed]
+    pub async fn drop_network_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let users = self.get_users(tenant).await?;
+        for user in users {
+            if user.option.network_policy().map(|s| s.as_str()) == Some(name) {
+                return Err(ErrorCode::NetworkPolicyIsUsedByUser(format!(
+                    "network policy {} is used by user {}",
+                    name,
+                    user.identity()
+                )));
+            }
+        }
+
+        let client = self.get_network_policy_api_client(tenant)?;
+        let drop_network_policy = client.drop_network_policy(name, MatchSeq::GE(1));
+        match drop_network_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_NETWORK_POLICY {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop network policy)"))
+                }
+            }
+        }
+    }
+
+    // Check the network policy in the user option exists.
+    #[async_backtrace::framed]
+    pub async fn verify_network_policy(&self, tenant: &str, name: Option<&String>) -> Result<()> {
+        if let Some(name) = name {
+            self.get_network_policy(tenant, name).await?;
+        }
+        Ok(())
+    }
+
+    // Check the client ip is allowed to login as the user.
+    // The network policy of the user takes precedence over the global one,
+    // a client with unknown ip is rejected if any policy applies.
+    #[async_backtrace::framed]
+    pub async fn check_network_policy(
+        &self,
+        tenant: &str,
+        user_info: &UserInfo,
+        global_policy: Option<&str>,
+        client_ip: Option<IpAddr>,
+    ) -> Result<()> {
+        let (name, policy) = match (user_info.option.network_policy(), global_policy) {
+            (Some(name), _) => (name.as_str(), self.get_network_policy(tenant, name).await?),
+            (None, Some(name)) if !name.is_empty() => {
+                match self.get_network_policy(tenant, name).await {
+                    Ok(policy) => (name, policy),
+                    // A dropped global policy must not lock every user out.
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_NETWORK_POLICY => {
+                        warn!("global network policy {} does not exist, ignored", name);
+                        return Ok(());
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            _ => return Ok(()),
+        };
+
+        let allowed = match &client_ip {
+            Some(ip) => policy.is_allowed(ip)?,
+            None => false,
+        };
+        if !allowed {
+            return Err(ErrorCode::ClientIpNotAllowed(format!(
+                "client ip {} is not allowed to login as user {} by network policy '{}'",
+                client_ip.map_or("unknown".to_string(), |ip| ip.to_string()),
+                user_info.identity(),
+                name
+            )));
+        }
+        Ok(())
+    }
+}
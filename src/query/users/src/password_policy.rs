@@ -0,0 +1,244 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::UserApi;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserOption;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Password policy operations.
+impl UserApiProvider {
+    // Add a new password policy.
+    #[async_backtrace::framed]
+    pub async fn add_password_policy(
+        &self,
+        tenant: &str,
+        policy: PasswordPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        policy.validate()?;
+        let client = self.get_password_policy_api_client(tenant)?;
+        let add_password_policy = client.add_password_policy(policy);
+        match add_password_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::PASSWORD_POLICY_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Update a password policy.
+    #[async_backtrace::framed]
+    pub async fn update_password_policy(
+        &self,
+        tenant: &str,
+        policy: PasswordPolicy,
+    ) -> Result<u64> {
+        policy.validate()?;
+        let client = self.get_password_policy_api_client(tenant)?;
+        let update_password_policy = client.update_password_policy(policy, MatchSeq::GE(1));
+        match update_password_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while update password policy).")),
+        }
+    }
+
+    // Get a password policy by name.
+    #[async_backtrace::framed]
+    pub async fn get_password_policy(&self, tenant: &str, name: &str) -> Result<PasswordPolicy> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let get_password_policy = client.get_password_policy(name, MatchSeq::GE(0));
+        Ok(get_password_policy.await?.data)
+    }
+
+    // Get all password policies for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_password_policies(&self, tenant: &str) -> Result<Vec<PasswordPolicy>> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let get_password_policies = client.get_password_policies();
+
+        match get_password_policies.await {
+            Err(e) => Err(e.add_message_back("(while get password policies).")),
+            Ok(policies) => Ok(policies),
+        }
+    }
+
+    // Drop a password policy by name, the policy can not be dropped if it is used by any user.
+    #[async_backtrace::framed]
+    pub async fn drop_password_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let users = self.get_users(tenant).await?;
+        for user in users {
+            if user.option.password_policy().map(|s| s.as_str()) == Some(name) {
+                return Err(ErrorCode::PasswordPolicyIsUsedByUser(format!(
+                    "password policy {} is used by user {}",
+                    name,
+                    user.identity()
+                )));
+            }
+        }
+
+        let client = self.get_password_policy_api_client(tenant)?;
+        let drop_password_policy = client.drop_password_policy(name, MatchSeq::GE(1));
+        match drop_password_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop password policy)"))
+                }
+            }
+        }
+    }
+
+    // Check a new password of a user against the password policy in the user option.
+    // `user_info` is the existing user when the password is altered, it is used to check
+    // the password is not reused.
+    #[async_backtrace::framed]
+    pub async fn verify_password(
+        &self,
+        tenant: &str,
+        user_option: &UserOption,
+        password: Option<&String>,
+        user_info: Option<&UserInfo>,
+    ) -> Result<()> {
+        let policy = match user_option.password_policy() {
+            Some(name) => self.get_password_policy(tenant, name).await?,
+            None => return Ok(()),
+        };
+
+        if let Some(password) = password {
+            policy.check_password(password)?;
+            if let Some(user_info) = user_info {
+                let mut history_auth_infos = vec![user_info.auth_info.clone()];
+                history_auth_infos.extend(user_info.history_auth_infos.iter().cloned());
+                policy.check_history(password.as_bytes(), &history_auth_infos)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Check the user is not locked out because of too many failed logins.
+    #[async_backtrace::framed]
+    pub async fn check_login_lockout(&self, tenant: &str, user_info: &UserInfo) -> Result<()> {
+        let policy = match self.get_user_password_policy(tenant, user_info).await? {
+            Some(policy) => policy,
+            None => return Ok(()),
+        };
+
+        if let Some(lockout_until) = policy.lockout_until(&user_info.password_fails) {
+            if lockout_until > Utc::now() {
+                return Err(ErrorCode::UserLocked(format!(
+                    "user {} is locked until {} because of too many failed logins",
+                    user_info.identity(),
+                    lockout_until
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // Record a failed login, the user will be locked out once the failed logins
+    // reach the max retries of the password policy.
+    #[async_backtrace::framed]
+    pub async fn record_login_failure(&self, tenant: &str, user_info: &UserInfo) -> Result<()> {
+        let policy = match self.get_user_password_policy(tenant, user_info).await? {
+            Some(policy) => policy,
+            None => return Ok(()),
+        };
+
+        let now = Utc::now();
+        let client = self.get_user_api_client(tenant)?;
+        client
+            .update_user_with(
+                user_info.identity(),
+                MatchSeq::GE(1),
+                |ui: &mut UserInfo| {
+                    // The previous lockout is over, start counting again.
+                    if ui.password_fails.len() as u64 >= policy.max_retries {
+                        ui.password_fails.clear();
+                    }
+                    ui.password_fails.push(now);
+                },
+            )
+            .await
+            .map_err(|e| e.add_message_back("(while record login failure)"))?;
+        Ok(())
+    }
+
+    // Check the password is not expired after a successful login,
+    // and reset the failed logins of the user.
+    #[async_backtrace::framed]
+    pub async fn record_login_success(&self, tenant: &str, user_info: &UserInfo) -> Result<()> {
+        let policy = match self.get_user_password_policy(tenant, user_info).await? {
+            Some(policy) => policy,
+            None => return Ok(()),
+        };
+
+        if policy.is_expired(user_info.password_update_on, Utc::now()) {
+            return Err(ErrorCode::PasswordExpired(format!(
+                "the password of user {} is expired, please ask the administrator to reset it",
+                user_info.identity()
+            )));
+        }
+
+        if !user_info.password_fails.is_empty() {
+            let client = self.get_user_api_client(tenant)?;
+            client
+                .update_user_with(
+                    user_info.identity(),
+                    MatchSeq::GE(1),
+                    |ui: &mut UserInfo| {
+                        ui.password_fails.clear();
+                    },
+                )
+                .await
+                .map_err(|e| e.add_message_back("(while record login success)"))?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn get_user_password_policy(
+        &self,
+        tenant: &str,
+        user_info: &UserInfo,
+    ) -> Result<Option<PasswordPolicy>> {
+        let name = match user_info.option.password_policy() {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        match self.get_password_policy(tenant, name).await {
+            Ok(policy) => Ok(Some(policy)),
+            // The policy may have been dropped concurrently, ignore it.
+            Err(e) if e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
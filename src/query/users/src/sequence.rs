@@ -0,0 +1,181 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::Sequence;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// The minimal number of values a query node reserves from meta at a time.
+const SEQUENCE_CACHE_SIZE: u64 = 1000;
+
+/// How long a reserved range is used before checking that its sequence is still in meta.
+pub const SEQUENCE_RANGE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Values of a sequence reserved by this query node but not handed out yet.
+#[derive(Clone, Debug, Default)]
+pub struct SequenceRange {
+    // the id of the sequence the values are reserved from.
+    sequence_id: u64,
+    // when the sequence was last found in meta.
+    checked_at: Option<Instant>,
+    next: u64,
+    remaining: u64,
+    increment: u64,
+}
+
+impl SequenceRange {
+    fn take(&mut self, count: u64, values: &mut Vec<u64>) {
+        let n = count.min(self.remaining);
+        for _ in 0..n {
+            values.push(self.next);
+            self.next = self.next.wrapping_add(self.increment);
+        }
+        self.remaining -= n;
+    }
+}
+
+/// Sequence operations.
+impl UserApiProvider {
+    // Add a new sequence.
+    #[async_backtrace::framed]
+    pub async fn add_sequence(
+        &self,
+        tenant: &str,
+        sequence: Sequence,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        sequence.validate()?;
+        let client = self.get_sequence_api_client(tenant)?;
+        let add_sequence = client.add_sequence(sequence);
+        match add_sequence.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::SEQUENCE_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a sequence by name.
+    #[async_backtrace::framed]
+    pub async fn get_sequence(&self, tenant: &str, name: &str) -> Result<Sequence> {
+        let client = self.get_sequence_api_client(tenant)?;
+        let get_sequence = client.get_sequence(name, MatchSeq::GE(0));
+        Ok(get_sequence.await?.data)
+    }
+
+    // Get all sequences for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_sequences(&self, tenant: &str) -> Result<Vec<Sequence>> {
+        let client = self.get_sequence_api_client(tenant)?;
+        let get_sequences = client.get_sequences();
+
+        match get_sequences.await {
+            Err(e) => Err(e.add_message_back("(while get sequences).")),
+            Ok(sequences) => Ok(sequences),
+        }
+    }
+
+    // Drop a sequence by name, the values reserved by this node are discarded.
+    #[async_backtrace::framed]
+    pub async fn drop_sequence(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        self.sequence_ranges
+            .lock()
+            .remove(&(tenant.to_string(), name.to_string()));
+
+        let client = self.get_sequence_api_client(tenant)?;
+        let drop_sequence = client.drop_sequence(name, MatchSeq::GE(1));
+        match drop_sequence.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_SEQUENCE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop sequence)"))
+                }
+            }
+        }
+    }
+
+    // Get the next `count` values of a sequence.
+    // Values are handed out from the range reserved by this node, a new range of at least
+    // `SEQUENCE_CACHE_SIZE` values is reserved from meta when it runs out. So the values are
+    // unique across the cluster, but they are not contiguous and not ordered between nodes.
+    //
+    // The sequence may have been dropped and recreated by another node, so the reserved range
+    // is checked against the sequence in meta at most once per `SEQUENCE_RANGE_CHECK_INTERVAL`
+    // and discarded if it comes from another sequence.
+    #[async_backtrace::framed]
+    pub async fn next_sequence_values(
+        &self,
+        tenant: &str,
+        name: &str,
+        count: u64,
+    ) -> Result<Vec<u64>> {
+        let key = (tenant.to_string(), name.to_string());
+        let check_needed = match self.sequence_ranges.lock().get(&key) {
+            Some(range) => range
+                .checked_at
+                .map_or(true, |at| at.elapsed() >= SEQUENCE_RANGE_CHECK_INTERVAL),
+            None => false,
+        };
+        let current_id = match check_needed {
+            true => Some(self.get_sequence(tenant, name).await?.id),
+            false => None,
+        };
+
+        let mut values = Vec::with_capacity(count as usize);
+        {
+            let mut ranges = self.sequence_ranges.lock();
+            match (ranges.get_mut(&key), current_id) {
+                (Some(range), Some(id)) if range.sequence_id != id => {
+                    ranges.remove(&key);
+                }
+                (Some(range), current_id) => {
+                    if current_id.is_some() {
+                        range.checked_at = Some(Instant::now());
+                    }
+                    range.take(count, &mut values);
+                }
+                (None, _) => {}
+            }
+        }
+
+        let needed = count - values.len() as u64;
+        if needed > 0 {
+            let reserve = needed.max(SEQUENCE_CACHE_SIZE);
+            let client = self.get_sequence_api_client(tenant)?;
+            let sequence = client.allocate_sequence_values(name, reserve).await?;
+            let mut range = SequenceRange {
+                sequence_id: sequence.id,
+                checked_at: Some(Instant::now()),
+                next: sequence.next,
+                remaining: reserve,
+                increment: sequence.increment,
+            };
+            range.take(needed, &mut values);
+            self.sequence_ranges.lock().insert(key, range);
+        }
+        Ok(values)
+    }
+}
@@ -13,7 +13,10 @@
 // limitations under the License.
 
 mod jwt;
+mod network_policy;
+mod password_policy;
 mod role_cache_mgr;
 mod role_mgr;
+mod sequence;
 mod user_mgr;
 mod user_udf;
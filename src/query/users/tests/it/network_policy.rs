@@ -0,0 +1,168 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::NetworkPolicy;
+use common_meta_app::principal::UserInfo;
+use common_users::UserApiProvider;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_network_policy() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_mgr = UserApiProvider::try_create_simple(conf).await?;
+
+    let tenant = "test";
+    let policy_name = "test_policy";
+    let global_policy_name = "global_policy";
+    let ip = |s: &str| Some(s.parse::<IpAddr>().unwrap());
+
+    // add policies.
+    {
+        let policy = NetworkPolicy {
+            allowed_ip_list: vec!["192.168.1.0/24".to_string()],
+            blocked_ip_list: vec!["192.168.1.99".to_string()],
+            ..NetworkPolicy::new(policy_name, Utc::now())
+        };
+        user_mgr.add_network_policy(tenant, policy, false).await?;
+
+        let policy = NetworkPolicy {
+            allowed_ip_list: vec!["10.0.0.0/8".to_string()],
+            ..NetworkPolicy::new(global_policy_name, Utc::now())
+        };
+        user_mgr.add_network_policy(tenant, policy, false).await?;
+    }
+
+    // add policy again, error.
+    {
+        let policy = NetworkPolicy {
+            allowed_ip_list: vec!["10.0.0.1".to_string()],
+            ..NetworkPolicy::new(policy_name, Utc::now())
+        };
+        let res = user_mgr.add_network_policy(tenant, policy, false).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::NETWORK_POLICY_ALREADY_EXISTS
+        );
+    }
+
+    // add invalid policy, error.
+    {
+        let policy = NetworkPolicy {
+            allowed_ip_list: vec!["10.0.0.256".to_string()],
+            ..NetworkPolicy::new("invalid_policy", Utc::now())
+        };
+        let res = user_mgr.add_network_policy(tenant, policy, false).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::ILLEGAL_NETWORK_POLICY);
+    }
+
+    let mut user_info = UserInfo::new("test_user", "%", AuthInfo::None);
+    user_info
+        .option
+        .set_network_policy(Some(policy_name.to_string()));
+    user_mgr.add_user(tenant, user_info.clone(), false).await?;
+
+    // check the user policy, which takes precedence over the global policy.
+    {
+        user_mgr
+            .check_network_policy(
+                tenant,
+                &user_info,
+                Some(global_policy_name),
+                ip("192.168.1.10"),
+            )
+            .await?;
+
+        for client_ip in [ip("192.168.1.99"), ip("10.0.0.1"), None] {
+            let res = user_mgr
+                .check_network_policy(tenant, &user_info, Some(global_policy_name), client_ip)
+                .await;
+            assert_eq!(res.unwrap_err().code(), ErrorCode::CLIENT_IP_NOT_ALLOWED);
+        }
+    }
+
+    // check the global policy.
+    {
+        let user_info = UserInfo::new("other_user", "%", AuthInfo::None);
+        user_mgr
+            .check_network_policy(tenant, &user_info, Some(global_policy_name), ip("10.0.0.1"))
+            .await?;
+        let res = user_mgr
+            .check_network_policy(
+                tenant,
+                &user_info,
+                Some(global_policy_name),
+                ip("192.168.1.10"),
+            )
+            .await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::CLIENT_IP_NOT_ALLOWED);
+
+        // no policy at all.
+        user_mgr
+            .check_network_policy(tenant, &user_info, None, ip("192.168.1.10"))
+            .await?;
+        // unknown global policy is ignored.
+        user_mgr
+            .check_network_policy(
+                tenant,
+                &user_info,
+                Some("unknown_policy"),
+                ip("192.168.1.10"),
+            )
+            .await?;
+    }
+
+    // drop policy used by user, error.
+    {
+        let res = user_mgr
+            .drop_network_policy(tenant, policy_name, false)
+            .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::NETWORK_POLICY_IS_USED_BY_USER
+        );
+    }
+
+    // drop policies.
+    {
+        user_mgr
+            .drop_user(tenant, user_info.identity(), false)
+            .await?;
+        user_mgr
+            .drop_network_policy(tenant, policy_name, false)
+            .await?;
+        user_mgr
+            .drop_network_policy(tenant, global_policy_name, false)
+            .await?;
+        let policies = user_mgr.get_network_policies(tenant).await?;
+        assert!(policies.is_empty());
+
+        let res = user_mgr
+            .drop_network_policy(tenant, policy_name, false)
+            .await;
+        assert!(res.is_err());
+        user_mgr
+            .drop_network_policy(tenant, policy_name, true)
+            .await?;
+    }
+
+    Ok(())
+}
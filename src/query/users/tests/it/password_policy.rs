@@ -0,0 +1,155 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::AuthType;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserOption;
+use common_users::UserApiProvider;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_password_policy() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_mgr = UserApiProvider::try_create_simple(conf).await?;
+
+    let tenant = "test";
+    let policy_name = "test_policy";
+    let password = "Databend1".to_string();
+
+    // add policy.
+    {
+        let policy = PasswordPolicy {
+            max_retries: 2,
+            history: 1,
+            ..PasswordPolicy::new(policy_name, Utc::now())
+        };
+        user_mgr.add_password_policy(tenant, policy, false).await?;
+    }
+
+    // add policy again, error.
+    {
+        let policy = PasswordPolicy::new(policy_name, Utc::now());
+        let res = user_mgr.add_password_policy(tenant, policy, false).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::PASSWORD_POLICY_ALREADY_EXISTS
+        );
+    }
+
+    // add invalid policy, error.
+    {
+        let policy = PasswordPolicy {
+            min_length: 1,
+            ..PasswordPolicy::new("invalid_policy", Utc::now())
+        };
+        let res = user_mgr.add_password_policy(tenant, policy, false).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::ILLEGAL_PASSWORD_POLICY);
+    }
+
+    let user_option = UserOption::default().with_password_policy(Some(policy_name.to_string()));
+
+    // verify password.
+    {
+        user_mgr
+            .verify_password(tenant, &user_option, Some(&password), None)
+            .await?;
+
+        let res = user_mgr
+            .verify_password(tenant, &user_option, Some(&"databend".to_string()), None)
+            .await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::INVALID_PASSWORD);
+
+        let unknown_option =
+            UserOption::default().with_password_policy(Some("unknown_policy".to_string()));
+        let res = user_mgr
+            .verify_password(tenant, &unknown_option, Some(&password), None)
+            .await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_PASSWORD_POLICY);
+    }
+
+    let auth_info = AuthInfo::new(AuthType::Sha256Password, &Some(password.clone()))?;
+    let mut user_info = UserInfo::new("test_user", "%", auth_info);
+    user_info.option = user_option.clone();
+    user_mgr.add_user(tenant, user_info.clone(), false).await?;
+
+    // reuse the current password, error.
+    {
+        let res = user_mgr
+            .verify_password(tenant, &user_option, Some(&password), Some(&user_info))
+            .await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::INVALID_PASSWORD);
+    }
+
+    // lockout after max retries.
+    {
+        user_mgr.check_login_lockout(tenant, &user_info).await?;
+        user_mgr.record_login_failure(tenant, &user_info).await?;
+        user_mgr.record_login_failure(tenant, &user_info).await?;
+
+        let user_info = user_mgr.get_user(tenant, user_info.identity()).await?;
+        assert_eq!(user_info.password_fails.len(), 2);
+        let res = user_mgr.check_login_lockout(tenant, &user_info).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::USER_LOCKED);
+    }
+
+    // login success resets the failures.
+    {
+        let user_info = user_mgr.get_user(tenant, user_info.identity()).await?;
+        user_mgr.record_login_success(tenant, &user_info).await?;
+
+        let user_info = user_mgr.get_user(tenant, user_info.identity()).await?;
+        assert!(user_info.password_fails.is_empty());
+        user_mgr.check_login_lockout(tenant, &user_info).await?;
+    }
+
+    // drop policy used by user, error.
+    {
+        let res = user_mgr
+            .drop_password_policy(tenant, policy_name, false)
+            .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::PASSWORD_POLICY_IS_USED_BY_USER
+        );
+    }
+
+    // drop policy.
+    {
+        user_mgr
+            .drop_user(tenant, user_info.identity(), false)
+            .await?;
+        user_mgr
+            .drop_password_policy(tenant, policy_name, false)
+            .await?;
+        let policies = user_mgr.get_password_policies(tenant).await?;
+        assert!(policies.is_empty());
+
+        let res = user_mgr
+            .drop_password_policy(tenant, policy_name, false)
+            .await;
+        assert!(res.is_err());
+        user_mgr
+            .drop_password_policy(tenant, policy_name, true)
+            .await?;
+    }
+
+    Ok(())
+}
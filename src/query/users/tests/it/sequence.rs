@@ -0,0 +1,119 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_meta_app::principal::Sequence;
+use common_meta_types::MatchSeq;
+use common_users::UserApiProvider;
+use common_users::SEQUENCE_RANGE_CHECK_INTERVAL;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_sequence() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_mgr = UserApiProvider::try_create_simple(conf).await?;
+
+    let tenant = "test";
+    let name = "test_seq";
+
+    // add sequence.
+    {
+        let sequence = Sequence::new(name, 10, 2, Utc::now());
+        user_mgr.add_sequence(tenant, sequence, false).await?;
+    }
+
+    // add sequence again, error.
+    {
+        let sequence = Sequence::new(name, 1, 1, Utc::now());
+        let res = user_mgr.add_sequence(tenant, sequence.clone(), false).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::SEQUENCE_ALREADY_EXISTS);
+
+        user_mgr.add_sequence(tenant, sequence, true).await?;
+    }
+
+    // add invalid sequence, error.
+    {
+        let sequence = Sequence::new("invalid_seq", 1, 0, Utc::now());
+        let res = user_mgr.add_sequence(tenant, sequence, false).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::ILLEGAL_SEQUENCE);
+    }
+
+    // values are handed out from the range reserved by this node.
+    {
+        let values = user_mgr.next_sequence_values(tenant, name, 3).await?;
+        assert_eq!(values, vec![10, 12, 14]);
+
+        let values = user_mgr.next_sequence_values(tenant, name, 2).await?;
+        assert_eq!(values, vec![16, 18]);
+
+        let sequence = user_mgr.get_sequence(tenant, name).await?;
+        assert_eq!(sequence.next, 10 + 1000 * 2);
+    }
+
+    // a batch larger than the reserved range.
+    {
+        let values = user_mgr.next_sequence_values(tenant, name, 1200).await?;
+        assert_eq!(values.len(), 1200);
+        assert_eq!(values[0], 20);
+        assert_eq!(values[994], 2008);
+        assert_eq!(values[995], 2010);
+        assert_eq!(values[1199], 2418);
+    }
+
+    // get sequences.
+    {
+        let sequences = user_mgr.get_sequences(tenant).await?;
+        assert_eq!(sequences.len(), 1);
+        assert_eq!(sequences[0].next, 2010 + 1000 * 2);
+    }
+
+    // drop sequence.
+    {
+        user_mgr.drop_sequence(tenant, name, false).await?;
+        let res = user_mgr.drop_sequence(tenant, name, false).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_SEQUENCE);
+        user_mgr.drop_sequence(tenant, name, true).await?;
+
+        let res = user_mgr.next_sequence_values(tenant, name, 1).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_SEQUENCE);
+    }
+
+    // drop and recreate the sequence bypassing this node, e.g. from another node,
+    // the values reserved from the dropped sequence are not handed out any more
+    // once the reserved range is checked again.
+    {
+        let sequence = Sequence::new(name, 1, 1, Utc::now());
+        user_mgr.add_sequence(tenant, sequence, false).await?;
+        let values = user_mgr.next_sequence_values(tenant, name, 2).await?;
+        assert_eq!(values, vec![1, 2]);
+
+        let client = user_mgr.get_sequence_api_client(tenant)?;
+        client.drop_sequence(name, MatchSeq::GE(1)).await?;
+        let sequence = Sequence::new(name, 100, 1, Utc::now());
+        client.add_sequence(sequence).await?;
+
+        let values = user_mgr.next_sequence_values(tenant, name, 2).await?;
+        assert_eq!(values, vec![3, 4]);
+
+        tokio::time::sleep(SEQUENCE_RANGE_CHECK_INTERVAL).await;
+        let values = user_mgr.next_sequence_values(tenant, name, 2).await?;
+        assert_eq!(values, vec![100, 101]);
+    }
+
+    Ok(())
+}
@@ -100,6 +100,16 @@ echo "select count(*)=1 from fuse_block('default', 't20_0012_a')" | $TEST_USER_C
 echo "GRANT SELECT ON system.fuse_block TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
 echo "select count(*)=1 from fuse_block('default', 't20_0012_a')" | $TEST_USER_CONNECT
 
+## sequence
+echo "create sequence seq20_0012" | $MYSQL_CLIENT_CONNECT
+echo "select nextval('seq20_0012')" | $TEST_USER_CONNECT
+echo "insert into default.t20_0012_b values(nextval('seq20_0012'))" | $TEST_USER_CONNECT
+echo "GRANT UPDATE ON *.* TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select nextval('seq20_0012')" | $TEST_USER_CONNECT
+echo "insert into default.t20_0012_b values(nextval('seq20_0012'))" | $TEST_USER_CONNECT
+echo "select c from default.t20_0012_b order by c" | $MYSQL_CLIENT_CONNECT
+echo "drop sequence seq20_0012" | $MYSQL_CLIENT_CONNECT
+
 ## Drop table.
 echo "drop table default.t20_0012 all" | $MYSQL_CLIENT_CONNECT
 echo "drop table default.t20_0012_a all" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,62 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-user\npassword=${TEST_USER_PASSWORD}" >> password.out
+
+## create user
+echo "create user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+## create table
+echo "create table t20_0014(id int, name string, salary int)" | $MYSQL_CLIENT_CONNECT
+echo "create stream s20_0014 on table t20_0014" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0014 values(1, 'a', 100),(2, 'b', 200)" | $MYSQL_CLIENT_CONNECT
+
+## no privilege
+echo "select id from t20_0014 order by id" | $TEST_USER_CONNECT
+## grant column privileges
+echo "GRANT SELECT (id, name) ON default.t20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT (nonexistent) ON default.t20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT INSERT (id) ON default.t20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "show grants for 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+## granted columns
+echo "select id, name from t20_0014 order by id" | $TEST_USER_CONNECT
+echo "select name from t20_0014 where id = 2" | $TEST_USER_CONNECT
+## columns not granted
+echo "select id from t20_0014 where salary > 100" | $TEST_USER_CONNECT
+echo "select * from t20_0014 order by id" | $TEST_USER_CONNECT
+## the stream reads the columns of its source table
+echo "GRANT SELECT ON default.s20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select id from s20_0014 order by id" | $TEST_USER_CONNECT
+echo "select salary from s20_0014 order by id" | $TEST_USER_CONNECT
+## the clone reads all the columns
+echo "GRANT CREATE ON default.* TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "create table t20_0014_clone clone t20_0014" | $TEST_USER_CONNECT
+## the source queries of CREATE TABLE AS and INSERT SELECT are checked by columns
+echo "create table t20_0014_ctas as select id, salary from t20_0014" | $TEST_USER_CONNECT
+echo "create table t20_0014_ctas as select id, name from t20_0014" | $TEST_USER_CONNECT
+echo "GRANT INSERT ON default.t20_0014_ctas TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0014_ctas select id, name from t20_0014 where salary > 100" | $TEST_USER_CONNECT
+echo "insert into t20_0014_ctas select id, name from t20_0014" | $TEST_USER_CONNECT
+echo "select count(*) from t20_0014_ctas" | $MYSQL_CLIENT_CONNECT
+## revoke column privilege
+echo "REVOKE SELECT (name) ON default.t20_0014 FROM 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select id, name from t20_0014 order by id" | $TEST_USER_CONNECT
+echo "select id from t20_0014 order by id" | $TEST_USER_CONNECT
+## table privilege covers all the columns
+echo "GRANT SELECT ON default.t20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select salary from t20_0014 order by id" | $TEST_USER_CONNECT
+echo "create table t20_0014_clone clone t20_0014" | $TEST_USER_CONNECT
+echo "select count(*) from t20_0014_clone" | $MYSQL_CLIENT_CONNECT
+
+## Drop table.
+echo "drop table default.t20_0014_clone all" | $MYSQL_CLIENT_CONNECT
+echo "drop table default.t20_0014_ctas all" | $MYSQL_CLIENT_CONNECT
+echo "drop stream default.s20_0014" | $MYSQL_CLIENT_CONNECT
+echo "drop table default.t20_0014 all" | $MYSQL_CLIENT_CONNECT
+
+## Drop user
+echo "drop user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf password.out
@@ -0,0 +1,39 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists t_check_copy;" | $MYSQL_CLIENT_CONNECT
+echo "create table t_check_copy(a int, check (a > 0));" | $MYSQL_CLIENT_CONNECT
+
+# One file satisfying the constraint, one file with a row violating it.
+DATADIR=$(mktemp -d)
+printf '1\n2\n3\n' > ${DATADIR}/valid.csv
+printf '4\n0\n5\n' > ${DATADIR}/invalid.csv
+
+COPY="COPY INTO t_check_copy FROM 'fs://${DATADIR}/' FILE_FORMAT = (type = CSV) FORCE = true"
+
+# the violating row is skipped
+echo "$COPY ON_ERROR=continue" | $MYSQL_CLIENT_CONNECT
+echo "select count(1) from t_check_copy" | $MYSQL_CLIENT_CONNECT
+echo "truncate table t_check_copy" | $MYSQL_CLIENT_CONNECT
+
+# the file with the violating row is skipped
+echo "$COPY ON_ERROR=skip_file" | $MYSQL_CLIENT_CONNECT
+echo "select count(1) from t_check_copy" | $MYSQL_CLIENT_CONNECT
+echo "truncate table t_check_copy" | $MYSQL_CLIENT_CONNECT
+
+echo "$COPY ON_ERROR=skip_file_2" | $MYSQL_CLIENT_CONNECT
+echo "select count(1) from t_check_copy" | $MYSQL_CLIENT_CONNECT
+echo "truncate table t_check_copy" | $MYSQL_CLIENT_CONNECT
+
+# aborted at the second violating row
+echo "$COPY ON_ERROR=abort_2" | $MYSQL_CLIENT_CONNECT
+echo "select count(1) from t_check_copy" | $MYSQL_CLIENT_CONNECT
+echo "truncate table t_check_copy" | $MYSQL_CLIENT_CONNECT
+
+echo "$COPY ON_ERROR=abort" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "violates check constraint"
+echo "select count(1) from t_check_copy" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists t_check_copy;" | $MYSQL_CLIENT_CONNECT
+rm -rf ${DATADIR}